@@ -79,11 +79,6 @@ pub struct Cli {
     #[arg(short = 'v', long)]
     pub version: bool,
 
-    // === Compatibility flags (accepted but ignored) ===
-    /// Additional directories to allow tool access to
-    #[arg(long)]
-    pub add_dir: Vec<String>,
-
     /// Agent for the current session
     #[arg(long)]
     pub agent: Option<String>,
@@ -92,6 +87,15 @@ pub struct Cli {
     #[arg(long)]
     pub agents: Option<String>,
 
+    /// Load plugins from directories for this session only
+    #[arg(long)]
+    pub plugin_dir: Vec<String>,
+
+    // === Compatibility flags (accepted but ignored) ===
+    /// Additional directories to allow tool access to
+    #[arg(long)]
+    pub add_dir: Vec<String>,
+
     /// Append a system prompt to the default system prompt
     #[arg(long)]
     pub append_system_prompt: Option<String>,
@@ -136,10 +140,6 @@ pub struct Cli {
     #[arg(long)]
     pub json_schema: Option<String>,
 
-    /// Load plugins from directories for this session only
-    #[arg(long)]
-    pub plugin_dir: Vec<String>,
-
     /// Re-emit user messages from stdin back on stdout
     #[arg(long)]
     pub replay_user_messages: bool,
@@ -289,6 +289,14 @@ pub struct SimulatorOptions {
     /// When not set, displays "Claudeless" branding instead of "Claude Code"
     #[arg(long, env = "CLAUDELESS_CLAUDE_VERSION")]
     pub claude_version: Option<String>,
+
+    /// Run live tools in a temporary copy of the working directory
+    #[arg(long, env = "CLAUDELESS_SANDBOX")]
+    pub sandbox: bool,
+
+    /// Write the sandbox diff as JSON to this file on exit (implies --sandbox)
+    #[arg(long, env = "CLAUDELESS_SANDBOX_DIFF")]
+    pub sandbox_diff: Option<String>,
 }
 
 // Thread-local override for `should_use_tui()` in tests.
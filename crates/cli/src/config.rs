@@ -3,6 +3,8 @@
 
 //! Scenario configuration types for TOML/JSON scenario files.
 
+use crate::state::{ContentBlock, TaskStatus};
+use crate::tools::SandboxDiff;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -12,6 +14,8 @@ pub const DEFAULT_MODEL: &str = "claude-opus-4-5-20251101";
 pub const DEFAULT_CLAUDE_VERSION: &str = "2.1.12";
 /// Default user display name
 pub const DEFAULT_USER_NAME: &str = "Alfred";
+/// Signature reported on simulated thinking blocks
+pub const THINKING_SIGNATURE: &str = "sig_01234567890abcdef";
 
 fn default_true() -> bool {
     true
@@ -161,6 +165,53 @@ impl TimingConfig {
     }
 }
 
+/// Plan usage state shown in the TUI
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum UsageLimitState {
+    /// "Approaching usage limit" notice
+    Approaching,
+    /// 5-hour limit reached; prompts are rejected until reset
+    Reached,
+    /// "Approaching Opus usage limit" notice
+    OpusApproaching,
+    /// Opus limit reached; responses fall back to another model
+    OpusReached,
+}
+
+/// Usage limit configuration (scenario [usage_limit] section)
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct UsageLimitConfig {
+    /// Usage state to simulate
+    pub state: UsageLimitState,
+
+    /// Minutes after launch_timestamp when the limit resets (default: 300)
+    #[serde(default)]
+    pub resets_in_minutes: Option<u64>,
+
+    /// Model named in the Opus fallback notice (default: "Sonnet 4.5")
+    #[serde(default)]
+    pub fallback_model: Option<String>,
+}
+
+impl UsageLimitConfig {
+    /// Default reset window (5 hours)
+    pub const DEFAULT_RESETS_IN_MINUTES: u64 = 300;
+    /// Default model named in the Opus fallback notice
+    pub const DEFAULT_FALLBACK_MODEL: &'static str = "Sonnet 4.5";
+
+    /// Validate the usage limit configuration.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.resets_in_minutes == Some(0) {
+            return Err(
+                "Invalid usage_limit.resets_in_minutes: must be greater than 0".to_string(),
+            );
+        }
+        Ok(())
+    }
+}
+
 /// Top-level scenario configuration
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
@@ -181,6 +232,26 @@ pub struct ScenarioConfig {
     #[serde(default)]
     pub tool_execution: Option<ToolExecutionConfig>,
 
+    /// Simulated plan usage limit state
+    #[serde(default)]
+    pub usage_limit: Option<UsageLimitConfig>,
+
+    /// Files seeded into the virtual filesystem (`vfs` mode)
+    #[serde(default)]
+    pub files: Vec<FileSpec>,
+
+    /// Pages and search results served to WebFetch and WebSearch
+    #[serde(default)]
+    pub web: Option<WebConfig>,
+
+    /// Subagents run by the Task tool, matched in order
+    #[serde(default)]
+    pub subagents: Vec<SubagentSpec>,
+
+    /// Checks on the task list when the session ends
+    #[serde(default)]
+    pub tasks: Option<TasksConfig>,
+
     /// Session identity configuration
     #[serde(flatten)]
     pub identity: IdentityConfig,
@@ -200,6 +271,15 @@ impl ScenarioConfig {
         self.identity.validate()?;
         self.environment.validate()?;
         self.timing.validate()?;
+        if let Some(ref usage_limit) = self.usage_limit {
+            usage_limit.validate()?;
+        }
+        if let Some(ref web) = self.web {
+            web.validate()?;
+        }
+        if let Some(ref tasks) = self.tasks {
+            tasks.validate()?;
+        }
         Ok(())
     }
 }
@@ -215,6 +295,207 @@ pub struct ToolExecutionConfig {
     /// Per-tool configuration overrides
     #[serde(default)]
     pub tools: HashMap<String, ToolConfig>,
+
+    /// Run live tools in a copy of the project
+    #[serde(default)]
+    pub sandbox: Option<SandboxConfig>,
+
+    /// Virtual filesystem settings (`vfs` mode)
+    #[serde(default)]
+    pub vfs: Option<VfsConfig>,
+}
+
+/// Virtual filesystem configuration
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct VfsConfig {
+    /// Directory whose files seed the tree (relative to the scenario file)
+    #[serde(default)]
+    pub fixture_dir: Option<String>,
+
+    /// Write the final tree to this directory when the session ends
+    #[serde(default)]
+    pub export_dir: Option<String>,
+
+    /// Files expected in the final tree, checked when the session ends
+    #[serde(default)]
+    pub expect: Vec<FileSpec>,
+}
+
+/// A file path and its contents
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct FileSpec {
+    /// File path (relative paths resolve against the working directory)
+    pub path: String,
+
+    /// File contents (a string, or a `$file` reference)
+    pub content: serde_json::Value,
+}
+
+impl FileSpec {
+    /// Contents as text; `$file` references to JSON files are re-serialized.
+    pub fn content_text(&self) -> String {
+        match self.content {
+            serde_json::Value::String(ref s) => s.clone(),
+            ref other => serde_json::to_string_pretty(other).unwrap_or_default(),
+        }
+    }
+}
+
+/// Web fixtures for WebFetch and WebSearch
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct WebConfig {
+    /// Pages by URL
+    #[serde(default)]
+    pub pages: HashMap<String, WebPageSpec>,
+
+    /// Search results by query
+    #[serde(default)]
+    pub search: HashMap<String, WebSearchSpec>,
+
+    /// Fetch `http://localhost` URLs without a page fixture from a local server
+    #[serde(default)]
+    pub localhost: bool,
+}
+
+impl WebConfig {
+    /// Check that every page URL and redirect target parses.
+    pub fn validate(&self) -> Result<(), String> {
+        for (url, page) in &self.pages {
+            for url in std::iter::once(url).chain(page.redirect.as_ref()) {
+                if url::Url::parse(url).is_err() {
+                    return Err(format!("Invalid web page URL '{}'", url));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A page served to WebFetch
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct WebPageSpec {
+    /// Page contents returned as the fetch result (a string, or a `$file` reference)
+    #[serde(default)]
+    pub body: serde_json::Value,
+
+    /// HTTP status code (defaults to 200, or 301 with `redirect`)
+    #[serde(default)]
+    pub status: Option<u16>,
+
+    /// Content type (defaults to `text/html`)
+    #[serde(default)]
+    pub content_type: Option<String>,
+
+    /// Redirect to this URL instead of returning a body
+    #[serde(default)]
+    pub redirect: Option<String>,
+}
+
+impl WebPageSpec {
+    /// Body as text; `$file` references to JSON files are re-serialized.
+    pub fn body_text(&self) -> String {
+        match self.body {
+            serde_json::Value::Null => String::new(),
+            serde_json::Value::String(ref s) => s.clone(),
+            ref other => serde_json::to_string_pretty(other).unwrap_or_default(),
+        }
+    }
+}
+
+/// Results returned to WebSearch for one query
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct WebSearchSpec {
+    /// Result links, best first
+    #[serde(default)]
+    pub results: Vec<WebSearchResult>,
+
+    /// Text following the links, standing in for the search summary
+    #[serde(default)]
+    pub summary: Option<String>,
+}
+
+/// One WebSearch result link
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct WebSearchResult {
+    pub title: String,
+    pub url: String,
+}
+
+/// Task list configuration
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct TasksConfig {
+    /// Tasks expected when the session ends, in ID order
+    #[serde(default)]
+    pub expect: Option<Vec<ExpectedTask>>,
+}
+
+impl TasksConfig {
+    /// Check that every expected status is a known task status.
+    pub fn validate(&self) -> Result<(), String> {
+        for task in self.expect.iter().flatten() {
+            if let Some(ref status) = task.status {
+                if TaskStatus::parse(status).is_none() {
+                    return Err(format!(
+                        "Invalid task status '{}' for '{}'",
+                        status, task.subject
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A task expected in the final task list. Unset fields are not checked.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExpectedTask {
+    /// Task subject
+    pub subject: String,
+
+    /// Task ID
+    #[serde(default)]
+    pub id: Option<String>,
+
+    /// Status: pending, in_progress or completed
+    #[serde(default)]
+    pub status: Option<String>,
+
+    /// Owner
+    #[serde(default)]
+    pub owner: Option<String>,
+
+    /// IDs of the tasks this one waits for
+    #[serde(default)]
+    pub blocked_by: Option<Vec<String>>,
+
+    /// IDs of the tasks waiting for this one
+    #[serde(default)]
+    pub blocks: Option<Vec<String>>,
+}
+
+/// Sandbox workspace configuration
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SandboxConfig {
+    /// Project directory to copy (defaults to the working directory)
+    #[serde(default)]
+    pub source: Option<String>,
+
+    /// Write the sandbox diff as JSON to this file when the session ends
+    #[serde(default)]
+    pub diff_file: Option<String>,
+
+    /// Expected sandbox diff, checked when the session ends
+    #[serde(default)]
+    pub expect: Option<SandboxDiff>,
 }
 
 /// Configuration for a specific tool
@@ -233,6 +514,16 @@ pub struct ToolConfig {
     #[serde(default)]
     pub error: Option<String>,
 
+    /// Executable that runs this tool instead (see `tools::external`).
+    /// Relative paths resolve against the scenario file.
+    #[serde(default)]
+    pub command: Option<String>,
+
+    /// Largest text result in characters before it is saved to a file and
+    /// replaced by a preview (see `tools::limits`).
+    #[serde(default)]
+    pub max_result_chars: Option<usize>,
+
     /// Pre-configured answers for AskUserQuestion tool.
     /// Keys are question text, values are selected option label(s).
     /// For multi-select, join labels with ", ".
@@ -249,6 +540,8 @@ pub enum ToolExecutionMode {
     /// Execute built-in tools directly
     #[default]
     Live,
+    /// Execute file tools against an in-memory filesystem
+    Vfs,
 }
 
 /// A single response rule
@@ -277,6 +570,30 @@ pub struct ResponseRule {
     pub turns: Vec<ConversationTurn>,
 }
 
+/// A subagent run by the Task tool
+///
+/// The first entry whose `subagent_type` and `pattern` both match the task
+/// handles it. The subagent answers the task prompt with `response`; while
+/// `turns` remain, each turn answers the previous step's tool results.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SubagentSpec {
+    /// Agent type to handle (any type when unset)
+    #[serde(default)]
+    pub subagent_type: Option<String>,
+
+    /// Pattern to match against the task prompt (any prompt when unset)
+    #[serde(default)]
+    pub pattern: Option<PatternSpec>,
+
+    /// The subagent's first response
+    pub response: ResponseSpec,
+
+    /// Follow-up responses after the subagent's tool calls
+    #[serde(default)]
+    pub turns: Vec<ConversationTurn>,
+}
+
 /// Pattern specification for matching prompts
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -291,6 +608,8 @@ pub enum PatternSpec {
     Contains { text: String },
     /// Match any prompt
     Any,
+    /// Match a turn that follows the Skill tool loading the named skill
+    Skill { name: String },
 }
 
 /// Response specification
@@ -316,6 +635,14 @@ pub enum ResponseSpec {
         /// Delay before responding (ms)
         #[serde(default)]
         delay_ms: Option<u64>,
+
+        /// Extended thinking emitted before the text
+        #[serde(default)]
+        thinking: Option<String>,
+
+        /// Redacted thinking data (opaque, rendered without content)
+        #[serde(default)]
+        redacted_thinking: Option<String>,
     },
 }
 
@@ -358,6 +685,45 @@ impl ResponseSpec {
         }
     }
 
+    /// Thinking content blocks, in the order they precede the text.
+    pub fn thinking_blocks(&self) -> Vec<ContentBlock> {
+        let ResponseSpec::Detailed {
+            thinking,
+            redacted_thinking,
+            ..
+        } = self
+        else {
+            return vec![];
+        };
+        let mut blocks = Vec::new();
+        if let Some(thinking) = thinking {
+            blocks.push(ContentBlock::Thinking {
+                thinking: thinking.clone(),
+                signature: THINKING_SIGNATURE.to_string(),
+            });
+        }
+        if let Some(data) = redacted_thinking {
+            blocks.push(ContentBlock::RedactedThinking { data: data.clone() });
+        }
+        blocks
+    }
+
+    /// Scenario usage, or an estimate that bills thinking as output tokens.
+    pub fn usage_or_estimate(&self) -> UsageSpec {
+        let (text, usage) = self.text_and_usage();
+        usage.unwrap_or_else(|| {
+            let mut estimate = UsageSpec::estimate(&text);
+            if let ResponseSpec::Detailed {
+                thinking: Some(thinking),
+                ..
+            } = self
+            {
+                estimate.output_tokens += crate::usage::estimate_tokens(thinking);
+            }
+            estimate
+        })
+    }
+
     /// Extract text and optional usage from a response.
     pub fn text_and_usage(&self) -> (String, Option<UsageSpec>) {
         match self {
@@ -220,6 +220,34 @@ mode = "live"
     assert_eq!(tool_exec.mode, ToolExecutionMode::Live);
 }
 
+#[test]
+fn test_parse_vfs_mode_with_files() {
+    let toml_str = r#"
+[tool_execution]
+mode = "vfs"
+
+[tool_execution.vfs]
+export_dir = "out"
+
+[[tool_execution.vfs.expect]]
+path = "src/main.rs"
+content = "fn main() { run() }"
+
+[[files]]
+path = "src/main.rs"
+content = "fn main() {}"
+"#;
+    let config: ScenarioConfig = toml::from_str(toml_str).unwrap();
+    let tool_exec = config.tool_execution.unwrap();
+    assert_eq!(tool_exec.mode, ToolExecutionMode::Vfs);
+    let vfs = tool_exec.vfs.unwrap();
+    assert_eq!(vfs.export_dir.as_deref(), Some("out"));
+    assert_eq!(vfs.expect[0].content_text(), "fn main() { run() }");
+    assert_eq!(config.files.len(), 1);
+    assert_eq!(config.files[0].path, "src/main.rs");
+    assert_eq!(config.files[0].content_text(), "fn main() {}");
+}
+
 #[test]
 fn test_tool_execution_mode_default() {
     let mode = ToolExecutionMode::default();
@@ -355,6 +383,8 @@ fn test_response_spec_text_detailed() {
         tool_calls: vec![],
         usage: None,
         delay_ms: None,
+        thinking: None,
+        redacted_thinking: None,
     };
     assert_eq!(spec.text(), "Detailed text");
 }
@@ -383,6 +413,8 @@ fn test_response_spec_tool_calls_detailed() {
         tool_calls: vec![call],
         usage: None,
         delay_ms: None,
+        thinking: None,
+        redacted_thinking: None,
     };
     assert_eq!(spec.tool_calls().len(), 1);
     assert_eq!(spec.tool_calls()[0].tool, "Bash");
@@ -401,6 +433,8 @@ fn test_response_spec_delay_ms_detailed() {
         tool_calls: vec![],
         usage: None,
         delay_ms: Some(100),
+        thinking: None,
+        redacted_thinking: None,
     };
     assert_eq!(spec.delay_ms(), Some(100));
 }
@@ -447,3 +481,108 @@ auto_approve = true
     assert!(bash.auto_approve);
     assert!(bash.answers.is_none());
 }
+
+#[test]
+fn test_parse_usage_limit() {
+    let toml_str = r#"
+[usage_limit]
+state = "opus_reached"
+resets_in_minutes = 90
+fallback_model = "Sonnet 4"
+"#;
+    let config: ScenarioConfig = toml::from_str(toml_str).unwrap();
+    let usage_limit = config.usage_limit.unwrap();
+    assert_eq!(usage_limit.state, UsageLimitState::OpusReached);
+    assert_eq!(usage_limit.resets_in_minutes, Some(90));
+    assert_eq!(usage_limit.fallback_model.as_deref(), Some("Sonnet 4"));
+}
+
+#[test]
+fn test_usage_limit_zero_reset_rejected() {
+    let toml_str = r#"
+[usage_limit]
+state = "reached"
+resets_in_minutes = 0
+"#;
+    let config: ScenarioConfig = toml::from_str(toml_str).unwrap();
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_parse_detailed_response_with_thinking() {
+    let toml_str = r#"
+[[responses]]
+pattern = { type = "any" }
+response = { text = "Done.", thinking = "Let me consider the request.", redacted_thinking = "opaque" }
+"#;
+    let config: ScenarioConfig = toml::from_str(toml_str).unwrap();
+    let response = config.responses[0].response.as_ref().unwrap();
+
+    let blocks = response.thinking_blocks();
+    assert_eq!(blocks.len(), 2);
+    assert!(matches!(
+        &blocks[0],
+        ContentBlock::Thinking { thinking, signature }
+            if thinking == "Let me consider the request." && signature == THINKING_SIGNATURE
+    ));
+    assert!(matches!(&blocks[1], ContentBlock::RedactedThinking { data } if data == "opaque"));
+}
+
+#[test]
+fn test_usage_estimate_bills_thinking_as_output() {
+    let plain = ResponseSpec::Simple("Done.".to_string());
+    let thinking = ResponseSpec::Detailed {
+        text: "Done.".to_string(),
+        tool_calls: vec![],
+        usage: None,
+        delay_ms: None,
+        thinking: Some("Let me consider the request.".to_string()),
+        redacted_thinking: None,
+    };
+    assert!(thinking.usage_or_estimate().output_tokens > plain.usage_or_estimate().output_tokens);
+    assert!(plain.thinking_blocks().is_empty());
+}
+
+#[test]
+fn test_parse_web_fixtures() {
+    let toml_str = r#"
+[web]
+localhost = true
+
+[web.pages."https://example.com/docs"]
+body = "Docs"
+
+[web.pages."https://example.com/old"]
+redirect = "https://docs.example.org/new"
+
+[web.search."rust async"]
+summary = "Tokio is the usual runtime."
+results = [{ title = "Tokio", url = "https://tokio.rs/" }]
+"#;
+    let config: ScenarioConfig = toml::from_str(toml_str).unwrap();
+    config.validate().unwrap();
+    let web = config.web.unwrap();
+    assert!(web.localhost);
+    assert_eq!(web.pages["https://example.com/docs"].body_text(), "Docs");
+    assert_eq!(
+        web.pages["https://example.com/old"].redirect.as_deref(),
+        Some("https://docs.example.org/new")
+    );
+    let search = &web.search["rust async"];
+    assert_eq!(search.results[0].title, "Tokio");
+    assert_eq!(
+        search.summary.as_deref(),
+        Some("Tokio is the usual runtime.")
+    );
+}
+
+#[test]
+fn test_web_page_invalid_url_rejected() {
+    let toml_str = r#"
+[web.pages."not a url"]
+body = "x"
+"#;
+    let config: ScenarioConfig = toml::from_str(toml_str).unwrap();
+    let err = config.validate().unwrap_err();
+    assert!(err.contains("Invalid web page URL 'not a url'"));
+}
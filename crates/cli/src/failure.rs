@@ -87,6 +87,14 @@ impl FailureExecutor {
             .record_error(&error_text, error_class, None)
     }
 
+    /// Error message for a failure, as shown when it ends a subagent.
+    pub(crate) fn error_text(spec: &FailureSpec) -> String {
+        match spec {
+            FailureSpec::MalformedJson { raw } => raw.clone(),
+            _ => Self::spec_to_error_params(spec).0,
+        }
+    }
+
     /// Convert a FailureSpec to error parameters for JSONL recording.
     fn spec_to_error_params(spec: &FailureSpec) -> (String, &'static str) {
         match spec {
@@ -580,6 +580,14 @@ pub fn claudeless_options_section() -> String {
                     flags: "--failure <mode>",
                     description: "Failure mode to inject (choices: \"network-unreachable\", \"connection-timeout\", \"auth-error\", \"rate-limit\", \"out-of-credits\", \"partial-response\", \"malformed-json\")",
                 },
+                HelpItem::Entry {
+                    flags: "--sandbox",
+                    description: "Run live tools in a temporary copy of the working directory",
+                },
+                HelpItem::Entry {
+                    flags: "--sandbox-diff <file>",
+                    description: "Write the sandbox diff as JSON to this file on exit",
+                },
                 HelpItem::Entry {
                     flags: "--scenario <file>",
                     description: "Scenario file for scripted responses",
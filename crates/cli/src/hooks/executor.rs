@@ -181,12 +181,15 @@ impl HookExecutor {
             .spawn()
             .map_err(|e| HookError::Spawn(e.to_string()))?;
 
-        // Write message to stdin
+        // Write message to stdin. A hook may exit without reading it, so a
+        // broken pipe is left for the exit status to explain.
         if let Some(mut stdin) = child.stdin.take() {
-            stdin
-                .write_all(message_json.as_bytes())
-                .await
-                .map_err(|e| HookError::Io(e.to_string()))?;
+            match stdin.write_all(message_json.as_bytes()).await {
+                Err(e) if e.kind() != std::io::ErrorKind::BrokenPipe => {
+                    return Err(HookError::Io(e.to_string()));
+                }
+                _ => {}
+            }
             drop(stdin); // Close stdin to signal EOF
         }
 
@@ -157,3 +157,23 @@ fn test_matcher_filtering_no_matcher_registers_for_all() {
     assert!(executor.has_hooks(&HookEvent::Notification));
     assert_eq!(executor.hook_count(&HookEvent::Notification), 1);
 }
+
+#[tokio::test]
+async fn test_blocking_hook_that_ignores_stdin_blocks() {
+    let dir = tempfile::tempdir().unwrap();
+    let script_path = dir.path().join("block_hook.sh");
+    std::fs::write(&script_path, "#!/bin/bash\necho 'blocked' >&2\nexit 2\n").unwrap();
+
+    let mut executor = HookExecutor::new();
+    executor.register(
+        HookEvent::PromptSubmit,
+        HookConfig::new(&script_path, 5000).with_blocking(true),
+    );
+
+    // Larger than a pipe buffer, so the write fails once the hook exits
+    let message = HookMessage::prompt_submit("test", "x".repeat(1 << 20));
+    let responses = executor.execute(&message).await.unwrap();
+    assert_eq!(responses.len(), 1);
+    assert!(!responses[0].proceed);
+    assert_eq!(responses[0].error.as_deref(), Some("blocked"));
+}
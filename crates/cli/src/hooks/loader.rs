@@ -19,6 +19,7 @@ pub fn load_hooks(settings: &ClaudeSettings) -> std::io::Result<HookExecutor> {
     for (event_name, entries) in &settings.hooks {
         let event = match event_name.as_str() {
             "Stop" => Some(HookEvent::Stop),
+            "SubagentStop" => Some(HookEvent::SubagentStop),
             "PreToolUse" => Some(HookEvent::PreToolExecution),
             "PostToolUse" => Some(HookEvent::PostToolExecution),
             "SessionStart" => Some(HookEvent::SessionStart),
@@ -194,6 +194,28 @@ fn test_load_hooks_session_start() {
     assert_eq!(executor.hook_count(&HookEvent::SessionStart), 1);
 }
 
+#[test]
+fn test_load_hooks_subagent_stop() {
+    let settings = ClaudeSettings {
+        hooks: HashMap::from([(
+            "SubagentStop".to_string(),
+            vec![HookDefEntry {
+                matcher: None,
+                hooks: vec![HookCommand {
+                    command_type: "bash".to_string(),
+                    command: "echo subagent-stop".to_string(),
+                    timeout: 5000,
+                }],
+            }],
+        )]),
+        ..Default::default()
+    };
+
+    let executor = load_hooks(&settings).unwrap();
+    assert!(executor.has_hooks(&HookEvent::SubagentStop));
+    assert!(!executor.has_hooks(&HookEvent::Stop));
+}
+
 #[test]
 fn test_load_hooks_notification_with_matcher() {
     let settings = ClaudeSettings {
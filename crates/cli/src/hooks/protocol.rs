@@ -27,6 +27,8 @@ pub enum HookEvent {
     PreCompact,
     /// Claude finishes responding
     Stop,
+    /// A subagent (Task tool call) finishes responding
+    SubagentStop,
 }
 
 impl HookEvent {
@@ -42,6 +44,7 @@ impl HookEvent {
             HookEvent::PromptSubmit => "PromptSubmit",
             HookEvent::PreCompact => "PreCompact",
             HookEvent::Stop => "Stop",
+            HookEvent::SubagentStop => "SubagentStop",
         }
     }
 }
@@ -166,6 +169,15 @@ impl HookMessage {
         }
     }
 
+    /// Create a subagent stop message (same payload as Stop)
+    pub fn subagent_stop(session_id: impl Into<String>, stop_hook_active: bool) -> Self {
+        Self {
+            event: HookEvent::SubagentStop,
+            session_id: session_id.into(),
+            payload: HookPayload::Stop { stop_hook_active },
+        }
+    }
+
     /// Produce flat wire-format JSON matching real Claude Code's hook protocol.
     ///
     /// Real Claude Code sends flat JSON to hooks with `hook_event_name` at top level,
@@ -19,6 +19,7 @@ fn test_hook_event_wire_names() {
     assert_eq!(HookEvent::PostToolExecution.wire_name(), "PostToolUse");
     assert_eq!(HookEvent::Notification.wire_name(), "Notification");
     assert_eq!(HookEvent::Stop.wire_name(), "Stop");
+    assert_eq!(HookEvent::SubagentStop.wire_name(), "SubagentStop");
     assert_eq!(HookEvent::SessionStart.wire_name(), "SessionStart");
     assert_eq!(HookEvent::SessionEnd.wire_name(), "SessionEnd");
     assert_eq!(HookEvent::PromptSubmit.wire_name(), "PromptSubmit");
@@ -459,6 +460,16 @@ fn test_pre_compact_auto_omits_custom_instructions() {
     assert!(json["payload"].get("custom_instructions").is_none());
 }
 
+#[test]
+fn test_subagent_stop_wire_json() {
+    let msg = HookMessage::subagent_stop("test-session", false);
+    let json = msg.to_wire_json();
+
+    assert_eq!(json["hook_event_name"], "SubagentStop");
+    assert_eq!(json["session_id"], "test-session");
+    assert_eq!(json["stop_hook_active"], false);
+}
+
 #[test]
 fn test_stop_payload_matches_spec() {
     let msg = HookMessage::stop("test-session", true);
@@ -77,7 +77,24 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     } else {
         // Move runtime to allow mutation
         let mut runtime = runtime;
-        runtime.execute_print_mode().await
+        runtime.execute_print_mode().await?;
+        finish_workspace(runtime);
+        Ok(())
+    }
+}
+
+/// Report the sandbox diff, vfs contents and task list, exiting with an error if they fail expectations.
+///
+/// Takes the runtime so the sandbox is removed before any early exit.
+fn finish_workspace(runtime: Runtime) {
+    let result = runtime
+        .finish_sandbox()
+        .and_then(|_| runtime.finish_vfs())
+        .and_then(|_| runtime.finish_tasks());
+    drop(runtime);
+    if let Err(msg) = result {
+        print_error(msg);
+        std::process::exit(1);
     }
 }
 
@@ -139,6 +156,7 @@ async fn run_tui_mode(runtime: Runtime) -> Result<(), Box<dyn std::error::Error>
     // Shutdown MCP servers before exiting
     if let Some(runtime) = app.take_runtime() {
         runtime.shutdown_mcp().await;
+        finish_workspace(runtime);
     }
 
     match exit_reason {
@@ -75,6 +75,11 @@ pub struct McpToolDef {
 
     /// Which server provides this tool.
     pub server_name: String,
+
+    /// Whether the server marks the tool read-only (`readOnlyHint`), which
+    /// lets it run alongside other read-only calls.
+    #[serde(default)]
+    pub read_only: bool,
 }
 
 impl McpToolDef {
@@ -94,6 +99,7 @@ impl McpToolDef {
     ///     description: "Read a file".into(),
     ///     input_schema: serde_json::json!({}),
     ///     server_name: "filesystem".into(),
+    ///     read_only: false,
     /// };
     /// assert_eq!(tool.qualified_name(), "mcp__filesystem__read_file");
     /// ```
@@ -147,6 +147,7 @@ fn test_qualified_name_format() {
         description: "Read a file".into(),
         input_schema: serde_json::json!({}),
         server_name: "filesystem".into(),
+        read_only: false,
     };
 
     assert_eq!(tool.qualified_name(), "mcp__filesystem__read_file");
@@ -159,6 +160,7 @@ fn test_qualified_name_preserves_underscores_in_tool_name() {
         description: "Read a text file".into(),
         input_schema: serde_json::json!({}),
         server_name: "filesystem".into(),
+        read_only: false,
     };
 
     assert_eq!(tool.qualified_name(), "mcp__filesystem__read_text_file");
@@ -171,6 +173,7 @@ fn test_qualified_name_preserves_underscores_in_server_name() {
         description: "Run a query".into(),
         input_schema: serde_json::json!({}),
         server_name: "my_database".into(),
+        read_only: false,
     };
 
     assert_eq!(tool.qualified_name(), "mcp__my_database__query");
@@ -226,6 +229,7 @@ fn test_qualified_name_roundtrip() {
         description: "List directory".into(),
         input_schema: serde_json::json!({}),
         server_name: "filesystem".into(),
+        read_only: false,
     };
 
     let qualified = tool.qualified_name();
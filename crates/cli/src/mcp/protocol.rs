@@ -148,6 +148,19 @@ pub struct ToolInfo {
 
     /// JSON Schema for tool input.
     pub input_schema: serde_json::Value,
+
+    /// Hints about the tool's behavior.
+    #[serde(default)]
+    pub annotations: ToolAnnotations,
+}
+
+/// Behavior hints a server attaches to a tool.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolAnnotations {
+    /// The tool does not modify its environment.
+    #[serde(default)]
+    pub read_only_hint: bool,
 }
 
 impl ToolInfo {
@@ -158,6 +171,7 @@ impl ToolInfo {
             description: self.description.unwrap_or_default(),
             input_schema: self.input_schema,
             server_name: server_name.into(),
+            read_only: self.annotations.read_only_hint,
         }
     }
 }
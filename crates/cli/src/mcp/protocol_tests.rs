@@ -246,6 +246,7 @@ fn tool_info_to_def_conversion() {
         name: "test_tool".into(),
         description: Some("A test tool".into()),
         input_schema: json!({"type": "object"}),
+        annotations: ToolAnnotations::default(),
     };
 
     let def = info.into_tool_def("my-server");
@@ -261,6 +262,7 @@ fn tool_info_to_def_conversion_no_description() {
         name: "no_desc_tool".into(),
         description: None,
         input_schema: json!({}),
+        annotations: ToolAnnotations::default(),
     };
 
     let def = info.into_tool_def("server");
@@ -268,6 +270,18 @@ fn tool_info_to_def_conversion_no_description() {
     assert_eq!(def.description, ""); // None becomes empty string
 }
 
+#[test]
+fn tool_info_read_only_hint_marks_def_read_only() {
+    let info: ToolInfo = serde_json::from_value(json!({
+        "name": "lookup",
+        "inputSchema": {"type": "object"},
+        "annotations": {"readOnlyHint": true, "title": "Lookup"}
+    }))
+    .unwrap();
+
+    assert!(info.into_tool_def("server").read_only);
+}
+
 #[test]
 fn serialize_tool_call_params() {
     let params = ToolCallParams {
@@ -11,8 +11,8 @@ use super::config::{McpConfig, McpServerDef, McpToolDef};
 use super::tools::McpToolResult;
 use super::transport::TransportError;
 use std::collections::HashMap;
+use std::future::Future;
 use std::sync::Arc;
-use tokio::sync::Mutex;
 
 /// MCP server with optional live client connection.
 #[derive(Debug)]
@@ -30,7 +30,7 @@ pub struct McpServer {
     pub status: McpServerStatus,
 
     /// Live client connection (None until spawned).
-    client: Option<Arc<Mutex<McpClient>>>,
+    client: Option<Arc<McpClient>>,
 }
 
 /// Status of an MCP server.
@@ -85,7 +85,7 @@ impl McpServer {
             .map(|t| t.clone().into_tool_def(&self.name))
             .collect();
 
-        self.client = Some(Arc::new(Mutex::new(client)));
+        self.client = Some(Arc::new(client));
         self.status = McpServerStatus::Running;
         Ok(())
     }
@@ -98,7 +98,7 @@ impl McpServer {
     /// Get the client (for internal use).
     // NOTE(compat): Reserved for future use by McpManager or advanced scenarios
     #[allow(dead_code)]
-    pub(crate) fn client(&self) -> Option<&Arc<Mutex<McpClient>>> {
+    pub(crate) fn client(&self) -> Option<&Arc<McpClient>> {
         self.client.as_ref()
     }
 
@@ -111,8 +111,7 @@ impl McpServer {
         arguments: serde_json::Value,
     ) -> Result<McpToolResult, ClientError> {
         let client = self.client.as_ref().ok_or(ClientError::NotInitialized)?;
-        let guard = client.lock().await;
-        let result = guard.call_tool(name, arguments).await?;
+        let result = client.call_tool(name, arguments).await?;
         Ok(result.into_tool_result())
     }
 
@@ -124,8 +123,7 @@ impl McpServer {
         timeout_ms: u64,
     ) -> Result<McpToolResult, ClientError> {
         let client = self.client.as_ref().ok_or(ClientError::NotInitialized)?;
-        let guard = client.lock().await;
-        let result = guard
+        let result = client
             .call_tool_with_timeout(name, arguments, timeout_ms)
             .await?;
         Ok(result.into_tool_result())
@@ -139,8 +137,7 @@ impl McpServer {
         if let Some(client_arc) = self.client.take() {
             // Try to unwrap the Arc; if other references exist, we can't shutdown cleanly
             match Arc::try_unwrap(client_arc) {
-                Ok(mutex) => {
-                    let client = mutex.into_inner();
+                Ok(client) => {
                     client.shutdown().await?;
                 }
                 Err(_arc) => {
@@ -261,6 +258,23 @@ impl McpManager {
         tool_name: &str,
         arguments: serde_json::Value,
     ) -> Result<McpToolResult, ClientError> {
+        self.start_tool_call(tool_name, arguments)?.await
+    }
+
+    /// Route a tool call to its server and return the pending call.
+    ///
+    /// The returned future owns the server connection rather than borrowing
+    /// the manager, so callers can release a lock on the manager before
+    /// awaiting it. Calls to one server overlap, since the transport matches
+    /// responses to requests by id.
+    pub fn start_tool_call(
+        &self,
+        tool_name: &str,
+        arguments: serde_json::Value,
+    ) -> Result<
+        impl Future<Output = Result<McpToolResult, ClientError>> + Send + 'static,
+        ClientError,
+    > {
         let server_name = self
             .tool_server_map
             .get(tool_name)
@@ -268,7 +282,12 @@ impl McpManager {
         let server = self.servers.get(server_name).ok_or_else(|| {
             ClientError::ToolNotFound(format!("server '{}' not found", server_name))
         })?;
-        server.call_tool(tool_name, arguments).await
+        let client = server.client.clone().ok_or(ClientError::NotInitialized)?;
+        let tool_name = tool_name.to_string();
+        Ok(async move {
+            let result = client.call_tool(&tool_name, arguments).await?;
+            Ok(result.into_tool_result())
+        })
     }
 
     /// Shutdown all server connections gracefully.
@@ -340,6 +359,13 @@ impl McpManager {
         self.tool_server_map.contains_key(name)
     }
 
+    /// Check if a tool's server marks it read-only.
+    pub fn is_read_only_tool(&self, name: &str) -> bool {
+        self.server_for_tool(name)
+            .and_then(|server| server.tools.iter().find(|tool| tool.name == name))
+            .is_some_and(|tool| tool.read_only)
+    }
+
     /// Get server for a tool.
     pub fn server_for_tool(&self, tool_name: &str) -> Option<&McpServer> {
         let server_name = self.tool_server_map.get(tool_name)?;
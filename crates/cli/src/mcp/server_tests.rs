@@ -50,6 +50,7 @@ fn test_tool_registration() {
             description: "Read a file".into(),
             input_schema: serde_json::json!({"type": "object"}),
             server_name: "fs".into(),
+            read_only: false,
         },
     );
 
@@ -84,6 +85,7 @@ fn test_server_for_tool() {
             description: "Read".into(),
             input_schema: serde_json::json!({}),
             server_name: "fs".into(),
+            read_only: false,
         },
     );
 
@@ -216,6 +218,38 @@ mod integration_tests {
         manager.shutdown().await;
     }
 
+    #[tokio::test]
+    async fn test_manager_overlaps_read_only_calls_to_one_server() {
+        let mut manager = McpManager::new();
+        manager.add_server(McpServer::from_def("echo", echo_server_def()));
+        let results = manager.initialize(false).await;
+        assert!(results.iter().all(|(_, r)| r.is_ok()));
+        assert!(manager.is_read_only_tool("slow_echo"));
+
+        let slow_call = || {
+            manager
+                .start_tool_call("slow_echo", serde_json::json!({"seconds": 0.5}))
+                .expect("start failed")
+        };
+
+        let start = std::time::Instant::now();
+        slow_call().await.expect("call failed");
+        let one = start.elapsed();
+
+        let start = std::time::Instant::now();
+        let (first, second) = tokio::join!(slow_call(), slow_call());
+        let two = start.elapsed();
+
+        assert!(first.expect("call failed").success);
+        assert!(second.expect("call failed").success);
+        assert!(
+            two < one + std::time::Duration::from_millis(300),
+            "two calls took {two:?}, one took {one:?}"
+        );
+
+        manager.shutdown().await;
+    }
+
     #[tokio::test]
     async fn test_manager_call_unknown_tool() {
         let manager = McpManager::new();
@@ -8,7 +8,7 @@
 //!
 //! This module provides the low-level transport layer for communicating with MCP servers
 //! over stdio. It handles process spawning, buffered I/O, JSON-RPC message serialization,
-//! timeout handling, and graceful shutdown.
+//! matching responses to requests by id, timeout handling, and graceful shutdown.
 //!
 //! # Example
 //!
@@ -37,13 +37,16 @@
 //! # }
 //! ```
 
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, BufWriter};
 use tokio::process::{Child, ChildStdin, ChildStdout, Command};
-use tokio::sync::Mutex;
+use tokio::sync::{oneshot, Mutex};
+use tokio::task::JoinHandle;
 use tokio::time::timeout;
 
 use super::config::McpServerDef;
@@ -171,15 +174,6 @@ pub enum TransportError {
     #[error("failed to parse response: {0}")]
     Parse(String),
 
-    /// Response ID doesn't match request ID.
-    #[error("response id {response} doesn't match request id {request}")]
-    IdMismatch {
-        /// The request ID that was sent.
-        request: u64,
-        /// The response ID that was received.
-        response: u64,
-    },
-
     /// JSON-RPC error response from server.
     #[error("{0}")]
     JsonRpc(#[from] JsonRpcError),
@@ -209,7 +203,9 @@ pub enum TransportError {
 /// # Thread Safety
 ///
 /// The transport is designed for safe concurrent access:
-/// - `Mutex<Option<...>>` for stdin/stdout allows exclusive access during operations
+/// - `Mutex<Option<...>>` for stdin is held only while a message is written
+/// - A reader task hands each response to the request with the same id, so
+///   several requests can be in flight and answered in any order
 /// - `AtomicU64` for request IDs avoids locking for ID generation
 /// - `AtomicBool` for shutdown flag allows lock-free checks
 pub struct StdioTransport {
@@ -219,8 +215,11 @@ pub struct StdioTransport {
     /// Buffered writer for stdin.
     stdin: Mutex<Option<BufWriter<ChildStdin>>>,
 
-    /// Buffered reader for stdout.
-    stdout: Mutex<Option<BufReader<ChildStdout>>>,
+    /// Requests awaiting a response, by id (None once stdout has closed).
+    pending: Arc<PendingRequests>,
+
+    /// Task reading responses from stdout.
+    reader: std::sync::Mutex<Option<JoinHandle<()>>>,
 
     /// Next request ID (atomically incremented).
     next_id: AtomicU64,
@@ -246,6 +245,15 @@ impl std::fmt::Debug for StdioTransport {
     }
 }
 
+impl Drop for StdioTransport {
+    fn drop(&mut self) {
+        self.stop_reader();
+    }
+}
+
+/// Response channels for in-flight requests, keyed by request id.
+type PendingRequests = std::sync::Mutex<Option<HashMap<u64, oneshot::Sender<JsonRpcResponse>>>>;
+
 impl StdioTransport {
     /// Spawn a new child process and create a transport for communication.
     ///
@@ -297,14 +305,24 @@ impl StdioTransport {
             .take()
             .ok_or(TransportError::StdoutNotAvailable)?;
 
+        let server_name = server_name.into();
+        let pending = Arc::new(std::sync::Mutex::new(Some(HashMap::new())));
+        let reader = tokio::spawn(read_responses(
+            BufReader::new(stdout),
+            Arc::clone(&pending),
+            server_name.clone(),
+            debug,
+        ));
+
         Ok(Self {
             child: Mutex::new(Some(child)),
             stdin: Mutex::new(Some(BufWriter::new(stdin))),
-            stdout: Mutex::new(Some(BufReader::new(stdout))),
+            pending,
+            reader: std::sync::Mutex::new(Some(reader)),
             next_id: AtomicU64::new(1),
             shutdown: AtomicBool::new(false),
             debug,
-            server_name: server_name.into(),
+            server_name,
         })
     }
 
@@ -341,6 +359,31 @@ impl StdioTransport {
         Ok(())
     }
 
+    /// Register a request id and return the channel its response arrives on.
+    fn register(&self, id: u64) -> Result<oneshot::Receiver<JsonRpcResponse>, TransportError> {
+        let (tx, rx) = oneshot::channel();
+        let mut guard = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+        let pending = guard.as_mut().ok_or(TransportError::ProcessExited)?;
+        pending.insert(id, tx);
+        Ok(rx)
+    }
+
+    /// Forget a request id that will no longer be waited on.
+    fn unregister(&self, id: u64) {
+        let mut guard = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(pending) = guard.as_mut() {
+            pending.remove(&id);
+        }
+    }
+
+    /// Stop the reader task, which closes stdout.
+    fn stop_reader(&self) {
+        let mut guard = self.reader.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(reader) = guard.take() {
+            reader.abort();
+        }
+    }
+
     // =========================================================================
     // Public API
     // =========================================================================
@@ -374,32 +417,10 @@ impl StdioTransport {
         self.write_message(notification).await
     }
 
-    /// Receive a JSON-RPC response from the child process.
-    ///
-    /// Reads one line and parses it as a JSON-RPC response.
-    pub async fn receive(&self) -> Result<JsonRpcResponse, TransportError> {
-        self.require_not_shutdown()?;
-
-        let mut guard = self.stdout.lock().await;
-        let stdout = guard.as_mut().ok_or(TransportError::StdoutNotAvailable)?;
-
-        let mut line = String::new();
-        let bytes_read = stdout.read_line(&mut line).await?;
-
-        if bytes_read == 0 {
-            return Err(TransportError::ProcessExited);
-        }
-
-        if self.debug {
-            eprintln!("MCP JSON-RPC [{}] <- {}", self.server_name, line.trim());
-        }
-
-        serde_json::from_str(&line).map_err(|e| TransportError::Parse(e.to_string()))
-    }
-
     /// Send a JSON-RPC request and wait for the response.
     ///
     /// This is the primary method for request-response communication.
+    /// Requests may overlap: each waits for the response carrying its own id.
     /// Returns an error if the response doesn't arrive within the timeout.
     ///
     /// # Arguments
@@ -407,11 +428,6 @@ impl StdioTransport {
     /// * `method` - The JSON-RPC method name
     /// * `params` - Optional parameters for the method
     /// * `timeout_ms` - Timeout in milliseconds
-    ///
-    /// # Note
-    ///
-    /// The current implementation assumes responses arrive in order. For more complex
-    /// scenarios with out-of-order responses, a pending requests map would be needed.
     pub async fn request(
         &self,
         method: impl Into<String>,
@@ -421,21 +437,24 @@ impl StdioTransport {
         let id = self.next_request_id();
         let request = JsonRpcRequest::new(id, method, params);
 
-        // Send the request
-        self.send(&request).await?;
+        // Register before sending so a fast response can't be missed
+        self.require_not_shutdown()?;
+        let receiver = self.register(id)?;
+        if let Err(e) = self.send(&request).await {
+            self.unregister(id);
+            return Err(e);
+        }
 
         // Wait for response with timeout
-        let response = timeout(Duration::from_millis(timeout_ms), self.receive())
-            .await
-            .map_err(|_| TransportError::Timeout(timeout_ms))??;
-
-        // Verify response ID matches
-        if response.id != id {
-            return Err(TransportError::IdMismatch {
-                request: id,
-                response: response.id,
-            });
-        }
+        let response = match timeout(Duration::from_millis(timeout_ms), receiver).await {
+            Ok(Ok(response)) => response,
+            // The reader dropped the channel: stdout closed
+            Ok(Err(_)) => return Err(TransportError::ProcessExited),
+            Err(_) => {
+                self.unregister(id);
+                return Err(TransportError::Timeout(timeout_ms));
+            }
+        };
 
         // Extract result or error
         response.into_result().map_err(TransportError::from)
@@ -461,10 +480,7 @@ impl StdioTransport {
         }
 
         // Close stdout
-        {
-            let mut guard = self.stdout.lock().await;
-            guard.take();
-        }
+        self.stop_reader();
 
         // Wait for process to exit, then kill if necessary
         let mut guard = self.child.lock().await;
@@ -510,6 +526,46 @@ impl StdioTransport {
     }
 }
 
+/// Read responses from stdout and hand each to the request with its id.
+///
+/// Lines that aren't responses, or whose id nobody is waiting on, are
+/// skipped. When stdout closes, pending requests fail with
+/// [`TransportError::ProcessExited`].
+async fn read_responses(
+    mut stdout: BufReader<ChildStdout>,
+    pending: Arc<PendingRequests>,
+    server_name: String,
+    debug: bool,
+) {
+    let mut line = String::new();
+    loop {
+        line.clear();
+        match stdout.read_line(&mut line).await {
+            Ok(0) | Err(_) => break,
+            Ok(_) => {}
+        }
+
+        if debug {
+            eprintln!("MCP JSON-RPC [{}] <- {}", server_name, line.trim());
+        }
+
+        let Ok(response) = serde_json::from_str::<JsonRpcResponse>(&line) else {
+            continue;
+        };
+        let sender = pending
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_mut()
+            .and_then(|pending| pending.remove(&response.id));
+        if let Some(sender) = sender {
+            let _ = sender.send(response);
+        }
+    }
+
+    // Dropping the senders wakes every waiting request
+    pending.lock().unwrap_or_else(|e| e.into_inner()).take();
+}
+
 #[cfg(test)]
 #[path = "transport_tests.rs"]
 mod tests;
@@ -210,7 +210,7 @@ for line in sys.stdin:
     }
 
     #[tokio::test]
-    async fn receive_returns_process_exited_on_eof() {
+    async fn request_returns_process_exited_on_eof() {
         let def = McpServerDef {
             command: "true".to_string(), // Exits immediately
             ..Default::default()
@@ -221,7 +221,25 @@ for line in sys.stdin:
         // Give process time to exit
         tokio::time::sleep(Duration::from_millis(100)).await;
 
-        let result = transport.receive().await;
+        let result = transport.request("test", None, 5000).await;
+        assert!(matches!(
+            result,
+            Err(TransportError::ProcessExited | TransportError::Io(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn pending_request_fails_when_process_exits() {
+        // Reads one request, then exits without answering
+        let def = McpServerDef {
+            command: "sh".to_string(),
+            args: vec!["-c".to_string(), "read line".to_string()],
+            ..Default::default()
+        };
+
+        let transport = StdioTransport::spawn(&def, "test", false).await.unwrap();
+
+        let result = transport.request("test", None, 5000).await;
         assert!(matches!(result, Err(TransportError::ProcessExited)));
     }
 
@@ -323,19 +341,6 @@ mod shutdown_tests {
         assert!(matches!(result, Err(TransportError::Shutdown)));
     }
 
-    #[tokio::test]
-    async fn receive_fails_after_shutdown() {
-        let def = McpServerDef {
-            command: "cat".to_string(),
-            ..Default::default()
-        };
-        let transport = StdioTransport::spawn(&def, "test", false).await.unwrap();
-        transport.shutdown().await.unwrap();
-
-        let result = transport.receive().await;
-        assert!(matches!(result, Err(TransportError::Shutdown)));
-    }
-
     #[tokio::test]
     async fn shutdown_force_kills_unresponsive_process() {
         // Process that ignores stdin close
@@ -420,15 +425,6 @@ mod error_display {
 
         let err = TransportError::Timeout(5000);
         assert_eq!(format!("{}", err), "request timed out after 5000ms");
-
-        let err = TransportError::IdMismatch {
-            request: 1,
-            response: 2,
-        };
-        assert_eq!(
-            format!("{}", err),
-            "response id 2 doesn't match request id 1"
-        );
     }
 
     #[test]
@@ -490,10 +486,6 @@ mod transport_error_coverage {
             TransportError::Parse("parse".into()),
             TransportError::ProcessExited,
             TransportError::Timeout(1000),
-            TransportError::IdMismatch {
-                request: 1,
-                response: 2,
-            },
             TransportError::JsonRpc(JsonRpcError {
                 code: -32600,
                 message: "test".into(),
@@ -539,4 +531,40 @@ for line in sys.stdin:
 
         transport.shutdown().await.unwrap();
     }
+
+    #[tokio::test]
+    async fn overlapping_requests_match_responses_by_id() {
+        // Answers each request after the delay it asks for, so the
+        // second request is answered first
+        let def = McpServerDef {
+            command: "python3".to_string(),
+            args: vec![
+                "-c".to_string(),
+                r#"
+import sys, json, threading, time
+def answer(req):
+    time.sleep(req["params"]["delay"])
+    print(json.dumps({"jsonrpc": "2.0", "id": req["id"], "result": req["params"]}), flush=True)
+for line in sys.stdin:
+    threading.Thread(target=answer, args=(json.loads(line),)).start()
+"#
+                .to_string(),
+            ],
+            ..Default::default()
+        };
+
+        let transport = StdioTransport::spawn(&def, "test", false).await.unwrap();
+
+        let start = std::time::Instant::now();
+        let (slow, fast) = tokio::join!(
+            transport.request("test", Some(serde_json::json!({"delay": 0.5})), 5000),
+            transport.request("test", Some(serde_json::json!({"delay": 0.1})), 5000),
+        );
+
+        assert_eq!(slow.unwrap()["delay"], 0.5);
+        assert_eq!(fast.unwrap()["delay"], 0.1);
+        assert!(start.elapsed() < Duration::from_millis(900));
+
+        transport.shutdown().await.unwrap();
+    }
 }
@@ -5,7 +5,7 @@
 
 use crate::cli::OutputFormat;
 use crate::config::{ResponseSpec, ToolCallSpec, UsageSpec};
-use crate::state::{to_io_json, ContentBlock};
+use crate::state::{to_io_error, to_io_json, ContentBlock};
 use serde::{Deserialize, Serialize};
 use std::io::Write;
 
@@ -15,145 +15,21 @@ pub use output_diagnostic::{
     print_error, print_mcp, print_mcp_error, print_mcp_warning, print_warning,
 };
 
+#[path = "output_result.rs"]
+mod output_result;
+pub use output_result::{
+    ModelUsage, ModelUsageEntry, PermissionDenial, ResultOutput, ResultStats, ResultUsage,
+};
+
 #[path = "output_events.rs"]
 mod output_events;
 pub use output_events::{
     AssistantEvent, AssistantMessageContent, CondensedAssistantEvent, CondensedMessage,
     ContentBlockDeltaEvent, ContentBlockStartEvent, ContentBlockStopEvent, ExtendedUsage,
-    McpServerInfo, SystemInitEvent,
+    McpServerInfo, PluginInfo, SystemInitEvent, ToolResultMessage, ToolResultUserEvent, ToolSchema,
+    BUILTIN_AGENTS,
 };
 
-/// Detailed usage statistics for result output
-pub use crate::usage::UsageWithCost as ResultUsage;
-
-/// Per-model usage breakdown
-#[derive(Clone, Debug, Default, Serialize, Deserialize)]
-pub struct ModelUsage {
-    #[serde(flatten)]
-    pub models: std::collections::HashMap<String, ResultUsage>,
-}
-
-/// Result wrapper for JSON output matching real Claude's `--output-format json`
-#[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct ResultOutput {
-    #[serde(rename = "type")]
-    pub output_type: String,
-    pub subtype: String,
-    pub cost_usd: f64,
-    pub is_error: bool,
-    pub duration_ms: u64,
-    pub duration_api_ms: u64,
-    pub num_turns: u32,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error: Option<String>,
-    pub session_id: String,
-    pub uuid: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub retry_after: Option<u64>,
-    #[serde(rename = "modelUsage")]
-    pub model_usage: ModelUsage,
-    pub usage: ResultUsage,
-    pub permission_denials: Vec<String>,
-}
-
-impl ResultOutput {
-    /// Create a base result with common defaults.
-    fn base(session_id: String) -> Self {
-        Self {
-            output_type: "result".to_string(),
-            subtype: "success".to_string(),
-            cost_usd: 0.0,
-            is_error: false,
-            duration_ms: 0,
-            duration_api_ms: 0,
-            num_turns: 0,
-            result: None,
-            error: None,
-            session_id,
-            uuid: uuid_stub(),
-            retry_after: None,
-            model_usage: ModelUsage::default(),
-            usage: ResultUsage::from_tokens(0, 0),
-            permission_denials: vec![],
-        }
-    }
-
-    /// Create a success result with usage based on response
-    pub fn success(result: String, session_id: String, duration_ms: u64) -> Self {
-        Self::success_with_usage(
-            result,
-            session_id,
-            duration_ms,
-            100,
-            0, // Will be estimated below
-            "claude-opus-4-5-20251101",
-        )
-    }
-
-    /// Create a success result with custom usage
-    pub fn success_with_usage(
-        result: String,
-        session_id: String,
-        duration_ms: u64,
-        input_tokens: u32,
-        output_tokens: u32,
-        model: &str,
-    ) -> Self {
-        let output_tokens = if output_tokens == 0 {
-            estimate_tokens(&result)
-        } else {
-            output_tokens
-        };
-        let usage = ResultUsage::from_tokens(input_tokens, output_tokens);
-        let mut model_usage = ModelUsage::default();
-        model_usage.models.insert(
-            model.to_string(),
-            ResultUsage::from_tokens(input_tokens, output_tokens),
-        );
-
-        Self {
-            cost_usd: usage.cost_usd,
-            duration_ms,
-            duration_api_ms: duration_ms.saturating_sub(50),
-            num_turns: 1,
-            result: Some(result),
-            model_usage,
-            usage,
-            ..Self::base(session_id)
-        }
-    }
-
-    /// Create an error result
-    pub fn error(error: String, session_id: String, duration_ms: u64) -> Self {
-        Self {
-            subtype: "error".to_string(),
-            is_error: true,
-            duration_ms,
-            duration_api_ms: duration_ms.saturating_sub(10),
-            error: Some(error),
-            ..Self::base(session_id)
-        }
-    }
-
-    /// Create a rate limit error result
-    pub fn rate_limit(retry_after: u64, session_id: String) -> Self {
-        Self {
-            subtype: "error".to_string(),
-            is_error: true,
-            duration_ms: 50,
-            duration_api_ms: 50,
-            error: Some(format!(
-                "Rate limited. Retry after {} seconds.",
-                retry_after
-            )),
-            retry_after: Some(retry_after),
-            ..Self::base(session_id)
-        }
-    }
-}
-
 /// JSON response structure matching Claude's output
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct JsonResponse {
@@ -251,6 +127,7 @@ pub struct OutputWriter<W: Write> {
     writer: W,
     format: OutputFormat,
     model: String,
+    stats: Option<ResultStats>,
 }
 
 impl<W: Write> OutputWriter<W> {
@@ -260,6 +137,30 @@ impl<W: Write> OutputWriter<W> {
             writer,
             format,
             model,
+            stats: None,
+        }
+    }
+
+    /// Report accumulated run statistics in the result object.
+    pub fn with_stats(mut self, stats: ResultStats) -> Self {
+        self.stats = Some(stats);
+        self
+    }
+
+    /// Build the success result, preferring accumulated run statistics.
+    fn success_result(&self, text: String, session_id: &str, usage: &UsageSpec) -> ResultOutput {
+        match self.stats {
+            Some(ref stats) => {
+                ResultOutput::success_with_stats(text, session_id.to_string(), stats, &self.model)
+            }
+            None => ResultOutput::success_with_usage(
+                text,
+                session_id.to_string(),
+                1000,
+                usage.input_tokens,
+                usage.output_tokens,
+                &self.model,
+            ),
         }
     }
 
@@ -289,9 +190,10 @@ impl<W: Write> OutputWriter<W> {
         response: &ResponseSpec,
         tool_calls: &[ToolCallSpec],
     ) -> std::io::Result<()> {
-        let (text, usage) = response.text_and_usage();
+        let text = response.text().to_string();
 
-        let mut content = vec![ContentBlock::Text { text: text.clone() }];
+        let mut content = response.thinking_blocks();
+        content.push(ContentBlock::Text { text: text.clone() });
         for tc in tool_calls {
             content.push(ContentBlock::ToolUse {
                 id: format!("toolu_{}", uuid_stub()),
@@ -300,10 +202,7 @@ impl<W: Write> OutputWriter<W> {
             });
         }
 
-        let usage = usage.unwrap_or_else(|| UsageSpec {
-            input_tokens: 100,
-            output_tokens: estimate_tokens(&text),
-        });
+        let usage = response.usage_or_estimate();
 
         let json_response = JsonResponse {
             id: format!("msg_{}", uuid_stub()),
@@ -398,10 +297,7 @@ impl<W: Write> OutputWriter<W> {
         }
 
         // message_delta
-        let usage = usage.unwrap_or_else(|| UsageSpec {
-            input_tokens: 100,
-            output_tokens: estimate_tokens(&text),
-        });
+        let usage = usage.unwrap_or_else(|| UsageSpec::estimate(&text));
         let msg_delta = StreamEvent::MessageDelta {
             delta: MessageDelta {
                 stop_reason: "end_turn".to_string(),
@@ -468,17 +364,12 @@ impl<W: Write> OutputWriter<W> {
     ) -> std::io::Result<()> {
         let (text, usage_spec) = response.text_and_usage();
 
-        let result = if let Some(usage) = usage_spec {
-            ResultOutput::success_with_usage(
-                text,
-                session_id.to_string(),
-                1000,
-                usage.input_tokens,
-                usage.output_tokens,
-                &self.model,
-            )
-        } else {
-            ResultOutput::success(text, session_id.to_string(), 1000)
+        let result = match (&self.stats, usage_spec) {
+            (None, None) => ResultOutput::success(text, session_id.to_string(), 1000),
+            _ => {
+                let usage = response.usage_or_estimate();
+                self.success_result(text, session_id, &usage)
+            }
         };
 
         self.write_result(&result)
@@ -486,10 +377,12 @@ impl<W: Write> OutputWriter<W> {
 
     /// Write stream-JSON in real Claude format (condensed 3-event format)
     ///
-    /// Real Claude CLI outputs 3 events:
+    /// Real Claude CLI outputs 3 events for a single model step:
     /// 1. System init event
     /// 2. Assistant event with full message (no subtype)
     /// 3. Result event
+    ///
+    /// Multi-step turns are written with the step methods below instead.
     fn write_real_stream_json(
         &mut self,
         response: &ResponseSpec,
@@ -497,34 +390,58 @@ impl<W: Write> OutputWriter<W> {
         tools: Vec<String>,
         mcp_servers: Vec<McpServerInfo>,
     ) -> std::io::Result<()> {
-        let (text, usage) = response.text_and_usage();
-        let tool_calls = response.tool_calls();
+        let text = response.text().to_string();
+        let usage = response.usage_or_estimate();
+        let tool_uses: Vec<(String, ToolCallSpec)> = response
+            .tool_calls()
+            .iter()
+            .enumerate()
+            .map(|(i, call)| (format!("toolu_{:08x}", i), call.clone()))
+            .collect();
 
-        let msg_id = format!("msg_{}", uuid_stub());
-
-        // 1. System init with tools and MCP servers
         let init = SystemInitEvent::with_mcp_servers(session_id, tools, mcp_servers);
-        self.write_json_line(&init)?;
+        self.write_init(&init)?;
+        let thinking = response.thinking_blocks();
+        self.write_assistant_step(session_id, &thinking, &text, &tool_uses, &usage)?;
+        self.write_stream_result(text, session_id, &usage)
+    }
 
-        // 2. Condensed assistant event with full message content
-        let usage_spec = usage.unwrap_or_else(|| UsageSpec {
-            input_tokens: 100,
-            output_tokens: estimate_tokens(&text),
-        });
+    /// Write the stream-json system init event.
+    pub fn write_init(&mut self, init: &SystemInitEvent) -> std::io::Result<()> {
+        self.write_json_line(init)
+    }
 
-        // Build content blocks: text + any tool_use blocks
-        let mut content_blocks = vec![serde_json::json!({"type": "text", "text": text})];
-        for (i, call) in tool_calls.iter().enumerate() {
+    /// Write one model step as a condensed assistant event.
+    ///
+    /// Each step gets its own message ID. Thinking blocks come first, and
+    /// empty text is omitted when the step only calls tools, matching real
+    /// Claude's tool-use messages.
+    pub fn write_assistant_step(
+        &mut self,
+        session_id: &str,
+        thinking: &[ContentBlock],
+        text: &str,
+        tool_uses: &[(String, ToolCallSpec)],
+        usage: &UsageSpec,
+    ) -> std::io::Result<()> {
+        let mut content_blocks = thinking
+            .iter()
+            .map(|block| serde_json::to_value(block).map_err(to_io_error))
+            .collect::<std::io::Result<Vec<_>>>()?;
+        if !text.is_empty() || tool_uses.is_empty() {
+            content_blocks.push(serde_json::json!({"type": "text", "text": text}));
+        }
+        for (id, call) in tool_uses {
             content_blocks.push(serde_json::json!({
                 "type": "tool_use",
-                "id": format!("toolu_{:08x}", i),
+                "id": id,
                 "name": call.tool,
                 "input": call.input,
             }));
         }
 
         let message = CondensedMessage {
-            id: msg_id,
+            id: format!("msg_{}", uuid::Uuid::new_v4().simple()),
             model: self.model.clone(),
             role: "assistant".to_string(),
             message_type: "message".to_string(),
@@ -532,22 +449,36 @@ impl<W: Write> OutputWriter<W> {
             stop_reason: None,
             stop_sequence: None,
             usage: serde_json::json!({
-                "input_tokens": usage_spec.input_tokens,
-                "output_tokens": usage_spec.output_tokens
+                "input_tokens": usage.input_tokens,
+                "output_tokens": usage.output_tokens
             }),
         };
         let assistant = CondensedAssistantEvent::new(message, session_id);
-        self.write_json_line(&assistant)?;
-
-        // 3. Final result with usage
-        let result = ResultOutput::success_with_usage(
-            text,
-            session_id.to_string(),
-            1000,
-            usage_spec.input_tokens,
-            usage_spec.output_tokens,
-            &self.model,
-        );
+        self.write_json_line(&assistant)
+    }
+
+    /// Write a step's tool results, one user event per result as the real
+    /// CLI sends them (and as the session JSONL records them).
+    pub fn write_tool_results_step(
+        &mut self,
+        session_id: &str,
+        results: &[crate::tools::result::ToolExecutionResult],
+    ) -> std::io::Result<()> {
+        for result in results {
+            let content = vec![ToolResultBlock::from_result(result)];
+            self.write_json_line(&ToolResultUserEvent::new(content, session_id))?;
+        }
+        Ok(())
+    }
+
+    /// Write the final stream-json result event.
+    pub fn write_stream_result(
+        &mut self,
+        text: String,
+        session_id: &str,
+        usage: &UsageSpec,
+    ) -> std::io::Result<()> {
+        let result = self.success_result(text, session_id, usage);
         self.write_json_line(&result)
     }
 
@@ -605,11 +536,6 @@ fn uuid_stub() -> String {
     "01234567890abcdef".to_string()
 }
 
-/// Estimate token count from text (rough approximation: 4 chars per token)
-fn estimate_tokens(text: &str) -> u32 {
-    (text.len() / 4).max(1) as u32
-}
-
 #[cfg(test)]
 #[path = "output_tests.rs"]
 mod tests;
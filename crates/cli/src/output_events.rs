@@ -3,7 +3,10 @@
 
 //! Event types matching real Claude CLI output format.
 
-use crate::event_types::{content_block, line_type, mcp_status, subtype};
+use super::ToolResultBlock;
+use crate::config::{DEFAULT_CLAUDE_VERSION, DEFAULT_MODEL};
+use crate::event_types::{content_block, line_type, mcp_status, role, subtype};
+use crate::tui::slash_menu::PRINT_MODE_COMMANDS;
 use serde::{Deserialize, Serialize};
 
 /// Generate a deterministic UUID-like stub for testing.
@@ -51,15 +54,71 @@ impl McpServerInfo {
     }
 }
 
+/// A `--plugin-dir` plugin reported in the init event.
+///
+/// ```json
+/// {"plugins": [{"name": "release-tools", "path": "/path/to/release-tools"}]}
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct PluginInfo {
+    /// Plugin name, from its manifest or directory.
+    pub name: String,
+    /// Plugin directory.
+    pub path: String,
+}
+
+/// Input schema of a tool listed in the init event.
+///
+/// Shaped like a tool definition in the Messages API:
+/// ```json
+/// {"name": "Read", "input_schema": {"type": "object", "properties": {...}}}
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ToolSchema {
+    /// Tool name, as listed in `tools`.
+    pub name: String,
+    /// JSON schema of the tool's input.
+    pub input_schema: serde_json::Value,
+}
+
+/// Built-in agent types reported in the init event.
+pub const BUILTIN_AGENTS: &[&str] = &[
+    "Bash",
+    "general-purpose",
+    "statusline-setup",
+    "Explore",
+    "Plan",
+    "claude-code-guide",
+];
+
 /// System init event for stream-json
+///
+/// `new` fills in the session defaults; print mode overrides the
+/// context-dependent fields (cwd, model, permission mode, settings).
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SystemInitEvent {
     #[serde(rename = "type")]
     pub event_type: String,
     pub subtype: String,
+    pub cwd: String,
     pub session_id: String,
     pub tools: Vec<String>,
+    /// Input schemas for the listed tools that have one.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tool_schemas: Vec<ToolSchema>,
     pub mcp_servers: Vec<McpServerInfo>,
+    pub model: String,
+    #[serde(rename = "permissionMode")]
+    pub permission_mode: String,
+    pub slash_commands: Vec<String>,
+    #[serde(rename = "apiKeySource")]
+    pub api_key_source: String,
+    pub claude_code_version: String,
+    pub output_style: String,
+    pub agents: Vec<String>,
+    pub skills: Vec<String>,
+    pub plugins: Vec<PluginInfo>,
+    pub uuid: String,
 }
 
 impl SystemInitEvent {
@@ -67,9 +126,23 @@ impl SystemInitEvent {
         Self {
             event_type: line_type::SYSTEM.to_string(),
             subtype: subtype::INIT.to_string(),
+            cwd: std::env::current_dir()
+                .map(|p| p.to_string_lossy().into_owned())
+                .unwrap_or_default(),
             session_id: session_id.into(),
             tools,
+            tool_schemas: vec![],
             mcp_servers: vec![],
+            model: DEFAULT_MODEL.to_string(),
+            permission_mode: "default".to_string(),
+            slash_commands: PRINT_MODE_COMMANDS.iter().map(|c| c.to_string()).collect(),
+            api_key_source: "none".to_string(),
+            claude_code_version: DEFAULT_CLAUDE_VERSION.to_string(),
+            output_style: "default".to_string(),
+            agents: BUILTIN_AGENTS.iter().map(|a| a.to_string()).collect(),
+            skills: vec![],
+            plugins: vec![],
+            uuid: uuid_stub(),
         }
     }
 
@@ -80,11 +153,8 @@ impl SystemInitEvent {
         mcp_servers: Vec<McpServerInfo>,
     ) -> Self {
         Self {
-            event_type: line_type::SYSTEM.to_string(),
-            subtype: subtype::INIT.to_string(),
-            session_id: session_id.into(),
-            tools,
             mcp_servers,
+            ..Self::new(session_id, tools)
         }
     }
 }
@@ -147,6 +217,7 @@ pub struct CondensedAssistantEvent {
     #[serde(rename = "type")]
     pub event_type: String,
     pub message: CondensedMessage,
+    pub parent_tool_use_id: Option<String>,
     pub session_id: String,
     pub uuid: String,
 }
@@ -156,6 +227,7 @@ impl CondensedAssistantEvent {
         Self {
             event_type: line_type::ASSISTANT.to_string(),
             message,
+            parent_tool_use_id: None,
             session_id: session_id.into(),
             uuid: uuid_stub(),
         }
@@ -176,6 +248,39 @@ pub struct CondensedMessage {
     pub usage: serde_json::Value,
 }
 
+/// User event carrying tool results back to the model (stream-json)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ToolResultUserEvent {
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub message: ToolResultMessage,
+    pub parent_tool_use_id: Option<String>,
+    pub session_id: String,
+    pub uuid: String,
+}
+
+impl ToolResultUserEvent {
+    pub fn new(content: Vec<ToolResultBlock>, session_id: impl Into<String>) -> Self {
+        Self {
+            event_type: line_type::USER.to_string(),
+            message: ToolResultMessage {
+                role: role::USER.to_string(),
+                content,
+            },
+            parent_tool_use_id: None,
+            session_id: session_id.into(),
+            uuid: uuid_stub(),
+        }
+    }
+}
+
+/// Message content for a tool result user event
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ToolResultMessage {
+    pub role: String,
+    pub content: Vec<ToolResultBlock>,
+}
+
 /// Extended usage info matching real Claude
 pub use crate::usage::ExtendedTokenCounts as ExtendedUsage;
 
@@ -0,0 +1,259 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Result object for `--output-format json` and the final stream-json line.
+
+use crate::usage::{estimate_tokens, TokenCounts, UsageWithCost};
+use serde::{Deserialize, Serialize};
+
+/// Detailed usage statistics for result output
+pub use crate::usage::UsageWithCost as ResultUsage;
+
+/// Context window reported in `modelUsage` entries.
+const CONTEXT_WINDOW: u32 = 200_000;
+
+/// Generate a deterministic UUID-like stub for testing.
+fn uuid_stub() -> String {
+    "01234567890abcdef".to_string()
+}
+
+/// Per-model usage entry (camelCase, matching real Claude's `modelUsage`).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelUsageEntry {
+    pub input_tokens: u32,
+    pub output_tokens: u32,
+    pub cache_read_input_tokens: u32,
+    pub cache_creation_input_tokens: u32,
+    pub web_search_requests: u32,
+    #[serde(rename = "costUSD")]
+    pub cost_usd: f64,
+    pub context_window: u32,
+}
+
+impl From<&UsageWithCost> for ModelUsageEntry {
+    fn from(usage: &UsageWithCost) -> Self {
+        Self {
+            input_tokens: usage.input_tokens,
+            output_tokens: usage.output_tokens,
+            cache_read_input_tokens: usage.cache_read_input_tokens,
+            cache_creation_input_tokens: usage.cache_creation_input_tokens,
+            web_search_requests: 0,
+            cost_usd: usage.cost_usd,
+            context_window: CONTEXT_WINDOW,
+        }
+    }
+}
+
+/// Per-model usage breakdown
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ModelUsage {
+    #[serde(flatten)]
+    pub models: std::collections::HashMap<String, ModelUsageEntry>,
+}
+
+impl ModelUsage {
+    /// Usage attributed to a single model.
+    pub fn single(model: &str, usage: &ResultUsage) -> Self {
+        let mut model_usage = Self::default();
+        model_usage
+            .models
+            .insert(model.to_string(), ModelUsageEntry::from(usage));
+        model_usage
+    }
+}
+
+/// A tool call that was denied by permission checks.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct PermissionDenial {
+    pub tool_name: String,
+    pub tool_use_id: String,
+    pub tool_input: serde_json::Value,
+}
+
+impl PermissionDenial {
+    pub fn new(
+        tool_name: impl Into<String>,
+        tool_use_id: impl Into<String>,
+        tool_input: serde_json::Value,
+    ) -> Self {
+        Self {
+            tool_name: tool_name.into(),
+            tool_use_id: tool_use_id.into(),
+            tool_input,
+        }
+    }
+}
+
+/// Run statistics accumulated across agent turns.
+///
+/// Each auto-continued step and Stop hook continuation counts as a turn.
+/// Durations come from the runtime clock, so fake clocks give stable values.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ResultStats {
+    /// Wall time for the whole run
+    pub duration_ms: u64,
+    /// Time spent waiting on (simulated) API responses
+    pub duration_api_ms: u64,
+    /// Number of model turns
+    pub num_turns: u32,
+    /// Token usage summed over all turns
+    pub usage: TokenCounts,
+    /// Tool calls denied by permission checks
+    pub permission_denials: Vec<PermissionDenial>,
+}
+
+impl ResultStats {
+    /// Fold another turn's statistics into this one.
+    pub fn merge(&mut self, other: ResultStats) {
+        self.duration_ms += other.duration_ms;
+        self.duration_api_ms += other.duration_api_ms;
+        self.num_turns += other.num_turns;
+        self.usage.add(&other.usage);
+        self.permission_denials.extend(other.permission_denials);
+    }
+}
+
+/// Result wrapper for JSON output matching real Claude's `--output-format json`
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ResultOutput {
+    #[serde(rename = "type")]
+    pub output_type: String,
+    pub subtype: String,
+    pub cost_usd: f64,
+    pub is_error: bool,
+    pub duration_ms: u64,
+    pub duration_api_ms: u64,
+    pub num_turns: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub session_id: String,
+    pub uuid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_after: Option<u64>,
+    #[serde(rename = "modelUsage")]
+    pub model_usage: ModelUsage,
+    pub usage: ResultUsage,
+    pub permission_denials: Vec<PermissionDenial>,
+}
+
+impl ResultOutput {
+    /// Create a base result with common defaults.
+    fn base(session_id: String) -> Self {
+        Self {
+            output_type: "result".to_string(),
+            subtype: "success".to_string(),
+            cost_usd: 0.0,
+            is_error: false,
+            duration_ms: 0,
+            duration_api_ms: 0,
+            num_turns: 0,
+            result: None,
+            error: None,
+            session_id,
+            uuid: uuid_stub(),
+            retry_after: None,
+            model_usage: ModelUsage::default(),
+            usage: ResultUsage::from_tokens(0, 0),
+            permission_denials: vec![],
+        }
+    }
+
+    /// Create a success result with usage based on response
+    pub fn success(result: String, session_id: String, duration_ms: u64) -> Self {
+        Self::success_with_usage(
+            result,
+            session_id,
+            duration_ms,
+            100,
+            0, // Will be estimated below
+            "claude-opus-4-5-20251101",
+        )
+    }
+
+    /// Create a success result with custom usage
+    pub fn success_with_usage(
+        result: String,
+        session_id: String,
+        duration_ms: u64,
+        input_tokens: u32,
+        output_tokens: u32,
+        model: &str,
+    ) -> Self {
+        let output_tokens = if output_tokens == 0 {
+            estimate_tokens(&result)
+        } else {
+            output_tokens
+        };
+        let usage = ResultUsage::from_tokens(input_tokens, output_tokens);
+        let model_usage = ModelUsage::single(model, &usage);
+
+        Self {
+            cost_usd: usage.cost_usd,
+            duration_ms,
+            duration_api_ms: duration_ms.saturating_sub(50),
+            num_turns: 1,
+            result: Some(result),
+            model_usage,
+            usage,
+            ..Self::base(session_id)
+        }
+    }
+
+    /// Create a success result from accumulated run statistics
+    pub fn success_with_stats(
+        result: String,
+        session_id: String,
+        stats: &ResultStats,
+        model: &str,
+    ) -> Self {
+        let usage = ResultUsage::from_tokens(stats.usage.input_tokens, stats.usage.output_tokens);
+        let model_usage = ModelUsage::single(model, &usage);
+
+        Self {
+            cost_usd: usage.cost_usd,
+            duration_ms: stats.duration_ms.max(stats.duration_api_ms),
+            duration_api_ms: stats.duration_api_ms,
+            num_turns: stats.num_turns,
+            result: Some(result),
+            model_usage,
+            usage,
+            permission_denials: stats.permission_denials.clone(),
+            ..Self::base(session_id)
+        }
+    }
+
+    /// Create an error result
+    pub fn error(error: String, session_id: String, duration_ms: u64) -> Self {
+        Self {
+            subtype: "error".to_string(),
+            is_error: true,
+            duration_ms,
+            duration_api_ms: duration_ms.saturating_sub(10),
+            error: Some(error),
+            ..Self::base(session_id)
+        }
+    }
+
+    /// Create a rate limit error result
+    pub fn rate_limit(retry_after: u64, session_id: String) -> Self {
+        Self {
+            subtype: "error".to_string(),
+            is_error: true,
+            duration_ms: 50,
+            duration_api_ms: 50,
+            error: Some(format!(
+                "Rate limited. Retry after {} seconds.",
+                retry_after
+            )),
+            retry_after: Some(retry_after),
+            ..Self::base(session_id)
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "output_result_tests.rs"]
+mod tests;
@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use serde_json::json;
+
+fn stats() -> ResultStats {
+    ResultStats {
+        duration_ms: 120,
+        duration_api_ms: 80,
+        num_turns: 3,
+        usage: TokenCounts::new(300, 45),
+        permission_denials: vec![PermissionDenial::new(
+            "Bash",
+            "toolu_00000001",
+            json!({ "command": "rm -rf /tmp/x" }),
+        )],
+    }
+}
+
+#[test]
+fn success_with_stats_reports_turns_and_durations() {
+    let result =
+        ResultOutput::success_with_stats("Done".to_string(), "s".to_string(), &stats(), "m");
+
+    assert_eq!(result.num_turns, 3);
+    assert_eq!(result.duration_ms, 120);
+    assert_eq!(result.duration_api_ms, 80);
+    assert_eq!(result.usage.input_tokens, 300);
+    assert_eq!(result.usage.output_tokens, 45);
+}
+
+#[test]
+fn duration_ms_is_never_below_api_time() {
+    let mut stats = stats();
+    stats.duration_ms = 0;
+    let result = ResultOutput::success_with_stats("Done".to_string(), "s".to_string(), &stats, "m");
+
+    assert_eq!(result.duration_ms, 80);
+}
+
+#[test]
+fn cost_matches_model_usage() {
+    let result =
+        ResultOutput::success_with_stats("Done".to_string(), "s".to_string(), &stats(), "m");
+    let parsed = serde_json::to_value(&result).unwrap();
+
+    let model_cost = parsed["modelUsage"]["m"]["costUSD"].as_f64().unwrap();
+    assert_eq!(parsed["cost_usd"].as_f64().unwrap(), model_cost);
+    assert_eq!(parsed["usage"]["cost_usd"].as_f64().unwrap(), model_cost);
+}
+
+#[test]
+fn model_usage_uses_camel_case_fields() {
+    let result =
+        ResultOutput::success_with_stats("Done".to_string(), "s".to_string(), &stats(), "m");
+    let parsed = serde_json::to_value(&result).unwrap();
+    let entry = &parsed["modelUsage"]["m"];
+
+    assert_eq!(entry["inputTokens"], 300);
+    assert_eq!(entry["outputTokens"], 45);
+    assert_eq!(entry["cacheReadInputTokens"], 0);
+    assert_eq!(entry["cacheCreationInputTokens"], 0);
+    assert_eq!(entry["webSearchRequests"], 0);
+    assert_eq!(entry["contextWindow"], 200_000);
+}
+
+#[test]
+fn permission_denials_serialize_with_tool_use_id_and_input() {
+    let result =
+        ResultOutput::success_with_stats("Done".to_string(), "s".to_string(), &stats(), "m");
+    let parsed = serde_json::to_value(&result).unwrap();
+
+    assert_eq!(
+        parsed["permission_denials"],
+        json!([{
+            "tool_name": "Bash",
+            "tool_use_id": "toolu_00000001",
+            "tool_input": { "command": "rm -rf /tmp/x" }
+        }])
+    );
+}
+
+#[test]
+fn merge_accumulates_turns() {
+    let mut total = ResultStats::default();
+    total.merge(stats());
+    total.merge(stats());
+
+    assert_eq!(total.num_turns, 6);
+    assert_eq!(total.duration_api_ms, 160);
+    assert_eq!(total.usage, TokenCounts::new(600, 90));
+    assert_eq!(total.permission_denials.len(), 2);
+}
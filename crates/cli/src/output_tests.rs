@@ -2,6 +2,7 @@
 // Copyright (c) 2026 Alfred Jean LLC
 
 use super::*;
+use crate::usage::estimate_tokens;
 
 #[test]
 fn test_text_output() {
@@ -25,6 +26,8 @@ fn test_text_output_detailed() {
         tool_calls: vec![],
         usage: None,
         delay_ms: None,
+        thinking: None,
+        redacted_thinking: None,
     };
     writer.write_response(&response, &[]).unwrap();
 
@@ -85,6 +88,8 @@ fn test_json_output_with_usage() {
             output_tokens: 25,
         }),
         delay_ms: None,
+        thinking: None,
+        redacted_thinking: None,
     };
     writer.write_response(&response, &[]).unwrap();
 
@@ -419,6 +424,80 @@ fn test_write_real_stream_json_response() {
     assert_eq!(last["subtype"], "success");
 }
 
+#[test]
+fn test_assistant_step_omits_empty_text_before_tool_use() {
+    let mut buf = Vec::new();
+    let mut writer = OutputWriter::new(
+        &mut buf,
+        OutputFormat::StreamJson,
+        "claude-test".to_string(),
+    );
+
+    let call = ToolCallSpec {
+        tool: "Bash".to_string(),
+        input: serde_json::json!({"command": "ls"}),
+        result: None,
+    };
+    writer
+        .write_assistant_step(
+            "session-123",
+            &[],
+            "",
+            &[("toolu_00000003".to_string(), call)],
+            &UsageSpec::new(10, 5),
+        )
+        .unwrap();
+
+    let parsed: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+    assert_eq!(parsed["type"], "assistant");
+    assert!(parsed["parent_tool_use_id"].is_null());
+    assert_eq!(
+        parsed["message"]["content"],
+        serde_json::json!([{
+            "type": "tool_use",
+            "id": "toolu_00000003",
+            "name": "Bash",
+            "input": {"command": "ls"}
+        }])
+    );
+    assert_eq!(parsed["message"]["usage"]["output_tokens"], 5);
+}
+
+#[test]
+fn test_tool_results_step_writes_user_event_per_result() {
+    let mut buf = Vec::new();
+    let mut writer = OutputWriter::new(
+        &mut buf,
+        OutputFormat::StreamJson,
+        "claude-test".to_string(),
+    );
+
+    let results = vec![
+        crate::tools::result::ToolExecutionResult::success("toolu_00000000", "ok"),
+        crate::tools::result::ToolExecutionResult::error("toolu_00000001", "boom"),
+    ];
+    writer
+        .write_tool_results_step("session-123", &results)
+        .unwrap();
+
+    let events: Vec<serde_json::Value> = String::from_utf8(buf)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(events.len(), 2);
+    for event in &events {
+        assert_eq!(event["type"], "user");
+        assert_eq!(event["session_id"], "session-123");
+        assert_eq!(event["message"]["role"], "user");
+        assert_eq!(event["message"]["content"].as_array().unwrap().len(), 1);
+    }
+    let first = &events[0]["message"]["content"][0];
+    assert_eq!(first["type"], "tool_result");
+    assert_eq!(first["tool_use_id"], "toolu_00000000");
+    assert_eq!(events[1]["message"]["content"][0]["is_error"], true);
+}
+
 #[test]
 fn test_real_stream_json_event_sequence() {
     let mut buf = Vec::new();
@@ -600,7 +679,6 @@ fn test_mcp_servers_format_matches_real_claude() {
 /// - skills: available skills
 /// - plugins: loaded plugins
 #[test]
-#[ignore = "claudeless init event is missing these fields"]
 fn test_init_event_has_extended_fields() {
     // Document expected fields from real Claude CLI
     let expected_fields = vec![
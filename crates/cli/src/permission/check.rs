@@ -5,7 +5,7 @@
 
 use super::bypass::PermissionBypass;
 use super::mode::PermissionMode;
-use super::pattern::PermissionPatterns;
+use super::pattern::{PermissionPatterns, ToolPattern};
 use crate::config::ToolConfig;
 use std::collections::HashMap;
 
@@ -94,6 +94,19 @@ impl PermissionChecker {
         tool_name: &str,
         action: &str,
         tool_input: Option<&str>,
+    ) -> PermissionResult {
+        self.check_with_allowed(tool_name, action, tool_input, &[])
+    }
+
+    /// Check permission, also auto-approving calls that match `allowed`
+    /// (a custom slash command's `allowed-tools`) after the settings deny
+    /// and allow patterns.
+    pub fn check_with_allowed(
+        &self,
+        tool_name: &str,
+        action: &str,
+        tool_input: Option<&str>,
+        allowed: &[ToolPattern],
     ) -> PermissionResult {
         // 1. Bypass overrides everything
         if self.bypass.is_active() {
@@ -123,6 +136,9 @@ impl PermissionChecker {
         if self.settings_patterns.is_allowed(tool_name, tool_input) {
             return PermissionResult::Allowed;
         }
+        if allowed.iter().any(|p| p.matches(tool_name, tool_input)) {
+            return PermissionResult::Allowed;
+        }
 
         // 5. Fall back to mode-based checking
         self.check_by_mode(tool_name, action)
@@ -147,6 +147,39 @@ fn test_settings_deny_blocks() {
     ));
 }
 
+#[test]
+fn test_allowed_tools_auto_approve_after_settings_deny() {
+    use crate::state::PermissionSettings;
+
+    let settings = PermissionSettings {
+        allow: vec![],
+        deny: vec!["Bash(git push:*)".to_string()],
+        additional_directories: vec![],
+    };
+    let checker = PermissionChecker::with_patterns(
+        PermissionMode::Default,
+        PermissionBypass::default(),
+        PermissionPatterns::from_settings(&settings),
+    );
+    let allowed: Vec<_> = ["Bash(git:*)"]
+        .iter()
+        .filter_map(|p| ToolPattern::parse(p))
+        .collect();
+
+    assert_eq!(
+        checker.check_with_allowed("Bash", "execute", Some("git status"), &allowed),
+        PermissionResult::Allowed
+    );
+    assert!(matches!(
+        checker.check_with_allowed("Bash", "execute", Some("git push"), &allowed),
+        PermissionResult::Denied { .. }
+    ));
+    assert!(matches!(
+        checker.check_with_allowed("Bash", "execute", Some("ls"), &allowed),
+        PermissionResult::NeedsPrompt { .. }
+    ));
+}
+
 #[test]
 fn test_settings_deny_beats_allow() {
     use crate::state::PermissionSettings;
@@ -188,6 +221,8 @@ fn test_scenario_override_auto_approve() {
             auto_approve: true,
             result: None,
             error: None,
+            command: None,
+            max_result_chars: None,
             answers: None,
         },
     );
@@ -214,6 +249,8 @@ fn test_scenario_override_error() {
             auto_approve: false,
             result: None,
             error: Some("Simulated error".to_string()),
+            command: None,
+            max_result_chars: None,
             answers: None,
         },
     );
@@ -247,6 +284,8 @@ fn test_scenario_overrides_beat_settings() {
             auto_approve: true,
             result: None,
             error: None,
+            command: None,
+            max_result_chars: None,
             answers: None,
         },
     );
@@ -285,6 +324,8 @@ fn test_bypass_beats_everything() {
             auto_approve: false,
             result: None,
             error: Some("Error".to_string()),
+            command: None,
+            max_result_chars: None,
             answers: None,
         },
     );
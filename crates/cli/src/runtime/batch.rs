@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Concurrent execution of read-only tool calls.
+//!
+//! Consecutive calls that change nothing (Read, Glob, Grep, WebFetch,
+//! WebSearch, and MCP tools their server marks `readOnlyHint`) run as one
+//! batch. Availability checks and PreToolUse hooks run in call order, then
+//! the calls execute at the same time. Once all have finished, results are
+//! recorded to JSONL and PostToolUse hooks fire in call order, so output
+//! does not depend on which call finished first. Other calls run alone.
+
+use std::ops::Range;
+
+use futures::future::join_all;
+
+use crate::config::ToolCallSpec;
+use crate::mcp::config::McpToolDef;
+use crate::tools::tool_name::ToolName;
+use crate::tools::{ExecutionContext, ToolExecutionResult};
+
+use super::core::{tool_use_id, PendingPermission};
+use super::Runtime;
+
+impl Runtime {
+    /// Split a turn's tool calls into runs that execute together: each
+    /// run of consecutive concurrency-safe calls, and every other call on
+    /// its own.
+    pub(super) fn tool_batches(&self, tool_calls: &[ToolCallSpec]) -> Vec<Range<usize>> {
+        let mut batches: Vec<Range<usize>> = Vec::new();
+        let mut last_safe = false;
+        for (i, call) in tool_calls.iter().enumerate() {
+            let safe = self.is_concurrency_safe(call);
+            match batches.last_mut() {
+                Some(batch) if safe && last_safe => batch.end = i + 1,
+                _ => batches.push(i..i + 1),
+            }
+            last_safe = safe;
+        }
+        batches
+    }
+
+    /// Whether a call may run alongside other concurrency-safe calls.
+    fn is_concurrency_safe(&self, call: &ToolCallSpec) -> bool {
+        if let Some(tool) = ToolName::parse(&call.tool) {
+            return tool.is_concurrency_safe();
+        }
+        let Some(ref manager) = self.mcp_manager else {
+            return false;
+        };
+        let name = McpToolDef::parse_qualified_name(&call.tool)
+            .map(|(_server, tool)| tool)
+            .unwrap_or_else(|| call.tool.clone());
+        manager.read().is_read_only_tool(&name)
+    }
+
+    /// Execute a batch of concurrency-safe calls.
+    ///
+    /// Returns the results in call order. A call that needs a permission
+    /// prompt ends the batch like it ends a turn; the calls after it have
+    /// already run, but their results are dropped unrecorded.
+    pub(super) async fn execute_batch(
+        &self,
+        calls: &[ToolCallSpec],
+        first_index: usize,
+        user_uuid: Option<&str>,
+        ctx: &ExecutionContext,
+    ) -> (Vec<ToolExecutionResult>, Option<PendingPermission>) {
+        let ids: Vec<String> = (0..calls.len())
+            .map(|i| tool_use_id(first_index + i))
+            .collect();
+
+        // Tool checks and PreToolUse hooks run before any call starts.
+        // Rejected calls are recorded like executed ones; blocked calls are not
+        let mut early_results = Vec::with_capacity(calls.len());
+        for (call, id) in calls.iter().zip(&ids) {
+            let early = match self.rejected_call(call, id, self.session_agent()) {
+                Some(rejected) => Some((rejected, true)),
+                None => self
+                    .fire_pre_tool_use_hook(call, id)
+                    .await
+                    .map(|blocked| (blocked, false)),
+            };
+            early_results.push(early);
+        }
+
+        let executor = &self.executor;
+        let running = calls
+            .iter()
+            .zip(&ids)
+            .zip(&early_results)
+            .filter(|(_, early)| early.is_none())
+            .map(|((call, id), _)| executor.execute(call, id, ctx));
+        let mut executed = join_all(running).await.into_iter();
+
+        let mut results = Vec::with_capacity(calls.len());
+        for ((call, id), early) in calls.iter().zip(ids).zip(early_results) {
+            if let Some((early, recorded)) = early {
+                if recorded {
+                    let assistant_uuid = self.record_tool_use(call, &id, user_uuid);
+                    self.record_tool_result(&id, &early, assistant_uuid.as_deref());
+                }
+                results.push(early);
+                continue;
+            }
+            let Some(result) = executed.next() else {
+                break;
+            };
+            let assistant_uuid = self.record_tool_use(call, &id, user_uuid);
+            if result.needs_prompt {
+                let pending = PendingPermission {
+                    tool_call: call.clone(),
+                    tool_use_id: id,
+                };
+                return (results, Some(pending));
+            }
+            self.record_tool_result(&id, &result, assistant_uuid.as_deref());
+            self.fire_post_tool_use_hook(call, &id, &result).await;
+            results.push(result);
+        }
+        (results, None)
+    }
+}
+
+#[cfg(test)]
+#[path = "batch_tests.rs"]
+mod tests;
@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use clap::Parser;
+use parking_lot::Mutex;
+use serde_json::json;
+
+use crate::cli::Cli;
+use crate::config::{ResolvedTimeouts, ToolCallSpec};
+use crate::tools::{ExecutionContext, ToolExecutionResult, ToolExecutor, ToolFuture};
+
+use super::super::{Runtime, RuntimeContext};
+
+/// Executor that waits the number of milliseconds in a call's canned
+/// result, then logs the call's ID.
+#[derive(Clone, Default)]
+struct SlowExecutor {
+    finished: Arc<Mutex<Vec<String>>>,
+}
+
+impl ToolExecutor for SlowExecutor {
+    fn execute<'a>(
+        &'a self,
+        call: &'a ToolCallSpec,
+        tool_use_id: &'a str,
+        _ctx: &'a ExecutionContext,
+    ) -> ToolFuture<'a> {
+        Box::pin(async move {
+            let ms = call.result.as_deref().unwrap_or("0").parse().unwrap();
+            tokio::time::sleep(Duration::from_millis(ms)).await;
+            self.finished.lock().push(tool_use_id.to_string());
+            ToolExecutionResult::success(tool_use_id, format!("{} after {}ms", call.tool, ms))
+        })
+    }
+
+    fn name(&self) -> &'static str {
+        "slow"
+    }
+}
+
+fn runtime(executor: SlowExecutor) -> Runtime {
+    let cli = Cli::try_parse_from(["claude", "-p", "go"]).unwrap();
+    let context = RuntimeContext::build(None, &cli);
+    Runtime::new(
+        context,
+        None,
+        Box::new(executor),
+        None,
+        None,
+        None,
+        cli,
+        ResolvedTimeouts::default(),
+    )
+}
+
+fn call(tool: &str, ms: u64) -> ToolCallSpec {
+    let input = match tool {
+        "Read" => json!({ "file_path": "/work/notes.txt" }),
+        "Grep" | "Glob" => json!({ "pattern": "*" }),
+        "Bash" => json!({ "command": "true" }),
+        "WebFetch" => json!({ "url": "https://example.com", "prompt": "Summarize" }),
+        "Write" => json!({ "file_path": "/work/notes.txt", "content": "" }),
+        _ => json!({}),
+    };
+    ToolCallSpec {
+        tool: tool.to_string(),
+        input,
+        result: Some(ms.to_string()),
+    }
+}
+
+#[test]
+fn tool_batches_group_consecutive_read_only_calls() {
+    let runtime = runtime(SlowExecutor::default());
+    let calls = [
+        call("Read", 0),
+        call("Grep", 0),
+        call("Bash", 0),
+        call("Glob", 0),
+        call("WebFetch", 0),
+        call("Write", 0),
+        call("mcp__db__query", 0),
+    ];
+
+    assert_eq!(
+        runtime.tool_batches(&calls),
+        vec![0..2, 2..3, 3..5, 5..6, 6..7]
+    );
+}
+
+#[tokio::test]
+async fn read_only_calls_run_concurrently_with_results_in_call_order() {
+    let executor = SlowExecutor::default();
+    let mut runtime = runtime(executor.clone());
+    let calls = [call("Read", 300), call("Grep", 150), call("Glob", 0)];
+
+    let (results, pending) = runtime.execute_tools_for_turn("go", &[], "", &calls).await;
+
+    assert!(pending.is_none());
+    let texts: Vec<_> = results.iter().map(|r| r.text().unwrap()).collect();
+    assert_eq!(
+        texts,
+        vec!["Read after 300ms", "Grep after 150ms", "Glob after 0ms"]
+    );
+    let ids: Vec<_> = results.iter().map(|r| r.tool_use_id.clone()).collect();
+    let mut finished = executor.finished.lock().clone();
+    finished.reverse();
+    assert_eq!(finished, ids);
+}
+
+#[tokio::test]
+async fn other_calls_run_alone() {
+    let executor = SlowExecutor::default();
+    let mut runtime = runtime(executor.clone());
+    let calls = [call("Read", 150), call("Bash", 0), call("Read", 0)];
+
+    let (results, _) = runtime.execute_tools_for_turn("go", &[], "", &calls).await;
+
+    let ids: Vec<_> = results.iter().map(|r| r.tool_use_id.clone()).collect();
+    assert_eq!(*executor.finished.lock(), ids);
+}
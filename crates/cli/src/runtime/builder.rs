@@ -3,23 +3,24 @@
 
 //! RuntimeBuilder for constructing Runtime with fluent API.
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use parking_lot::RwLock;
 
 use crate::cli::Cli;
-use crate::config::ResolvedTimeouts;
+use crate::config::{ResolvedTimeouts, ToolExecutionMode};
 use crate::hooks::load_hooks;
 use crate::mcp::{load_mcp_config, McpConfig, McpManager};
-use crate::output::{print_mcp, print_mcp_warning};
+use crate::output::{print_mcp, print_mcp_warning, print_warning};
 use crate::permission::PermissionBypass;
 use crate::scenario::Scenario;
 use crate::state::io::JsonLoad;
 use crate::state::{
-    ClaudeSettings, SessionsIndex, SettingsLoader, SettingsPaths, StateDirectory, StateWriter,
+    AgentDefinition, AgentsLoader, ClaudeSettings, CommandsLoader, CustomCommand, SessionsIndex,
+    SettingsLoader, SettingsPaths, Skill, SkillsLoader, StateDirectory, StateWriter, TaskStore,
 };
-use crate::tools::create_executor_with_mcp_and_permissions;
+use crate::tools::{create_executor_with_mcp_and_permissions, Sandbox, Vfs, Web};
 
 use super::core::Runtime;
 use super::RuntimeContext;
@@ -191,8 +192,35 @@ impl RuntimeBuilder {
         }
 
         // Build runtime context
-        let runtime_ctx =
-            RuntimeContext::build(self.scenario.as_ref().map(|s| s.config()), &self.cli);
+        let runtime_ctx = RuntimeContext::build_with_settings(
+            self.scenario.as_ref().map(|s| s.config()),
+            &self.cli,
+            settings.clone(),
+        );
+
+        // Copy the project into a sandbox when requested
+        let scenario_sandbox = self
+            .scenario
+            .as_ref()
+            .and_then(|s| s.config().tool_execution.as_ref())
+            .and_then(|te| te.sandbox.clone());
+        let sandbox = if scenario_sandbox.is_some()
+            || self.cli.simulator.sandbox
+            || self.cli.simulator.sandbox_diff.is_some()
+        {
+            let mut config = scenario_sandbox.unwrap_or_default();
+            if let Some(ref diff_file) = self.cli.simulator.sandbox_diff {
+                config.diff_file = Some(diff_file.clone());
+            }
+            let source = config
+                .source
+                .as_ref()
+                .map(PathBuf::from)
+                .unwrap_or_else(|| runtime_ctx.working_directory.clone());
+            Some((Arc::new(Sandbox::new(source)?), config))
+        } else {
+            None
+        };
 
         // Create state writer (unless --no-session-persistence)
         let state_writer = if !self.cli.session.no_session_persistence {
@@ -261,6 +289,26 @@ impl RuntimeBuilder {
             .map(|te| te.mode.clone())
             .unwrap_or_default();
 
+        // Seed the in-memory filesystem for vfs mode
+        let vfs = if execution_mode == ToolExecutionMode::Vfs {
+            let scenario = self.scenario.as_ref().map(|s| s.config());
+            let config = scenario
+                .and_then(|c| c.tool_execution.as_ref())
+                .and_then(|te| te.vfs.clone())
+                .unwrap_or_default();
+            let vfs = Vfs::new(&runtime_ctx.working_directory);
+            if let Some(ref dir) = config.fixture_dir {
+                vfs.seed_dir(Path::new(dir))
+                    .map_err(|e| RuntimeBuildError::Vfs(format!("{}: {}", dir, e)))?;
+            }
+            for file in scenario.map(|c| c.files.as_slice()).unwrap_or_default() {
+                vfs.insert(&file.path, file.content_text());
+            }
+            Some((Arc::new(vfs), config))
+        } else {
+            None
+        };
+
         // Build permission checker from runtime context
         let bypass = PermissionBypass::new(
             self.cli.permissions.allow_dangerously_skip_permissions,
@@ -272,13 +320,14 @@ impl RuntimeBuilder {
             .and_then(|s| s.config().tool_execution.as_ref())
             .map(|te| te.tools.clone())
             .unwrap_or_default();
-        let checker = runtime_ctx.permission_checker_with_overrides(bypass, scenario_tools);
+        let checker = runtime_ctx.permission_checker_with_overrides(bypass, scenario_tools.clone());
 
         // Create executor with MCP support and permission checking
         let executor = create_executor_with_mcp_and_permissions(
             execution_mode,
             self.mcp_manager.as_ref().map(Arc::clone),
             state_writer.as_ref().map(Arc::clone),
+            &scenario_tools,
             checker,
         );
 
@@ -289,7 +338,31 @@ impl RuntimeBuilder {
                 .and_then(|s| s.config().timing.timeouts.as_ref()),
         );
 
-        Ok(Runtime::new(
+        // Index web fixtures for WebFetch and WebSearch
+        let web = self
+            .scenario
+            .as_ref()
+            .and_then(|s| s.config().web.as_ref())
+            .map(|config| Arc::new(Web::new(config)));
+
+        // Keep the task list next to the session's todos, reloading it on resume
+        let task_store = state_writer
+            .as_ref()
+            .map(|writer| TaskStore::open(writer.read().tasks_dir()))
+            .unwrap_or_else(TaskStore::in_memory);
+
+        // Load custom agents for the Task tool
+        let agents = load_agents(&self.cli, &runtime_ctx.working_directory);
+        if let Some(ref name) = self.cli.agent {
+            if !agents.iter().any(|agent| agent.name == *name) {
+                print_warning(format_args!("Agent '{}' not found", name));
+            }
+        }
+
+        let skills = load_skills(&self.cli, &runtime_ctx.working_directory);
+        let commands = load_commands(&self.cli, &runtime_ctx.working_directory);
+
+        let runtime = Runtime::new(
             runtime_ctx,
             self.scenario,
             executor,
@@ -298,7 +371,24 @@ impl RuntimeBuilder {
             self.mcp_manager,
             self.cli,
             timeouts,
-        ))
+        );
+        let runtime = runtime
+            .with_agents(agents)
+            .with_skills(skills)
+            .with_commands(commands)
+            .with_task_store(Arc::new(task_store));
+        let runtime = match sandbox {
+            Some((sandbox, config)) => runtime.with_sandbox(sandbox, config),
+            None => runtime,
+        };
+        let runtime = match web {
+            Some(web) => runtime.with_web(web),
+            None => runtime,
+        };
+        Ok(match vfs {
+            Some((vfs, config)) => runtime.with_vfs(vfs, config),
+            None => runtime,
+        })
     }
 
     /// Build with default initialization from CLI args.
@@ -337,6 +427,50 @@ fn load_settings(cli: &Cli) -> ClaudeSettings {
     }
 }
 
+/// Load custom agents from plugins, agents directories and `--agents`.
+fn load_agents(cli: &Cli, working_dir: &Path) -> Vec<AgentDefinition> {
+    let state_dir = StateDirectory::resolve().ok();
+    AgentsLoader::resolve(
+        state_dir.as_ref().map(|dir| dir.root()),
+        working_dir,
+        cli.setting_sources.as_deref(),
+    )
+    .with_plugin_dirs(&cli.plugin_dir)
+    .with_cli_json(cli.agents.as_deref())
+    .load()
+}
+
+/// Load skills from plugins and skills directories, unless
+/// `--disable-slash-commands` turns them off.
+fn load_skills(cli: &Cli, working_dir: &Path) -> Vec<Skill> {
+    if cli.disable_slash_commands {
+        return Vec::new();
+    }
+    let state_dir = StateDirectory::resolve().ok();
+    SkillsLoader::resolve(
+        state_dir.as_ref().map(|dir| dir.root()),
+        working_dir,
+        cli.setting_sources.as_deref(),
+    )
+    .with_plugin_dirs(&cli.plugin_dir)
+    .load()
+}
+
+/// Load custom slash commands from commands directories, unless
+/// `--disable-slash-commands` turns them off.
+fn load_commands(cli: &Cli, working_dir: &Path) -> Vec<CustomCommand> {
+    if cli.disable_slash_commands {
+        return Vec::new();
+    }
+    let state_dir = StateDirectory::resolve().ok();
+    CommandsLoader::resolve(
+        state_dir.as_ref().map(|dir| dir.root()),
+        working_dir,
+        cli.setting_sources.as_deref(),
+    )
+    .load()
+}
+
 /// Errors that can occur when building a Runtime.
 #[derive(Debug, thiserror::Error)]
 pub enum RuntimeBuildError {
@@ -357,6 +491,12 @@ pub enum RuntimeBuildError {
 
     #[error("Session not found: {0}")]
     SessionNotFound(String),
+
+    #[error("Failed to create sandbox: {0}")]
+    Sandbox(#[from] crate::tools::SandboxError),
+
+    #[error("Failed to seed virtual filesystem from {0}")]
+    Vfs(String),
 }
 
 #[cfg(test)]
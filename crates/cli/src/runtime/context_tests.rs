@@ -342,6 +342,8 @@ fn test_permission_checker_with_overrides() {
             auto_approve: true,
             result: None,
             error: None,
+            command: None,
+            max_result_chars: None,
             answers: None,
         },
     );
@@ -9,13 +9,20 @@ use std::time::Duration;
 use parking_lot::RwLock;
 
 use crate::cli::Cli;
-use crate::config::{FailureSpec, ResolvedTimeouts, ResponseSpec, ToolCallSpec};
+use crate::config::{
+    FailureSpec, ResolvedTimeouts, ResponseSpec, SandboxConfig, ToolCallSpec, UsageSpec, VfsConfig,
+};
 use crate::failure::FailureExecutor;
 use crate::hooks::{HookEvent, HookExecutor, HookMessage, StopHookResponse};
 use crate::mcp::McpManager;
+use crate::output::{PermissionDenial, ResultStats};
 use crate::scenario::Scenario;
-use crate::state::{ContentBlock, StateWriter};
-use crate::tools::{ExecutionContext, ToolExecutionResult, ToolExecutor};
+use crate::state::{AgentDefinition, ContentBlock, CustomCommand, Skill, StateWriter, TaskStore};
+use crate::time::{Clock, ClockHandle};
+use crate::tools::schema::input_error;
+use crate::tools::{
+    BackgroundShells, ExecutionContext, Sandbox, ToolExecutionResult, ToolExecutor, Vfs, Web,
+};
 
 use super::RuntimeContext;
 
@@ -28,6 +35,23 @@ pub struct PendingPermission {
     pub tool_use_id: String,
 }
 
+/// One model step within a turn: an assistant message and its tool results.
+///
+/// Auto-continued turns produce one step per scenario response, in order.
+#[derive(Debug)]
+pub struct TurnStep {
+    /// Thinking blocks emitted before the text.
+    pub thinking: Vec<ContentBlock>,
+    /// Assistant text for this step.
+    pub text: String,
+    /// Tool calls paired with their tool use IDs.
+    pub tool_uses: Vec<(String, ToolCallSpec)>,
+    /// Results for the executed tool calls (shorter when a call is pending).
+    pub tool_results: Vec<ToolExecutionResult>,
+    /// Token usage for this step (from the scenario or estimated).
+    pub usage: UsageSpec,
+}
+
 /// Result of a single agent turn.
 ///
 /// This is the unified result type used by both print mode and TUI mode.
@@ -48,6 +72,10 @@ pub struct TurnResult {
     pub is_hook_continuation: bool,
     /// If a tool needs an interactive permission prompt, this contains the pending call.
     pub pending_permission: Option<PendingPermission>,
+    /// Turn count, simulated API time, usage, and denials for the result object.
+    pub stats: ResultStats,
+    /// Each model step in the order it happened (for stream-json output).
+    pub steps: Vec<TurnStep>,
 }
 
 impl TurnResult {
@@ -80,6 +108,36 @@ pub struct Runtime {
     pub(super) timeouts: ResolvedTimeouts,
     /// Whether currently in a stop hook continuation.
     pub(super) stop_hook_active: bool,
+    /// Clock for response delays and result durations.
+    pub(super) clock: ClockHandle,
+    /// Index of the next tool use ID, so IDs stay unique across steps.
+    pub(super) next_tool_use_index: usize,
+    /// Token usage of subagents run since the last step, added to the
+    /// turn's totals.
+    pub(super) subagent_usage: UsageSpec,
+    /// Sandbox that live tools run in (optional).
+    pub(super) sandbox: Option<Arc<Sandbox>>,
+    /// Diff output and expectations for the sandbox.
+    pub(super) sandbox_config: SandboxConfig,
+    /// In-memory filesystem for `vfs` mode (optional).
+    pub(super) vfs: Option<Arc<Vfs>>,
+    /// Export and expectations for the in-memory filesystem.
+    pub(super) vfs_config: VfsConfig,
+    /// Shells started with `run_in_background`.
+    pub(super) background_shells: Arc<BackgroundShells>,
+    /// Web fixtures for WebFetch and WebSearch (optional).
+    pub(super) web: Option<Arc<Web>>,
+    /// Custom agents the Task tool can run.
+    pub(super) agents: Vec<AgentDefinition>,
+    /// Skills the Skill tool can load.
+    pub(super) skills: Vec<Skill>,
+    /// Custom slash commands.
+    pub(super) commands: Vec<CustomCommand>,
+    /// Custom command the current prompt ran, whose `allowed-tools` and
+    /// `model` apply until the next prompt.
+    pub(super) active_command: Option<CustomCommand>,
+    /// Task list for TaskCreate, TaskGet, TaskUpdate and TaskList.
+    pub(super) task_store: Arc<TaskStore>,
 }
 
 impl Runtime {
@@ -106,9 +164,113 @@ impl Runtime {
             cli,
             timeouts,
             stop_hook_active: false,
+            clock: ClockHandle::system(),
+            next_tool_use_index: 0,
+            subagent_usage: UsageSpec::default(),
+            sandbox: None,
+            sandbox_config: SandboxConfig::default(),
+            vfs: None,
+            vfs_config: VfsConfig::default(),
+            background_shells: Arc::new(BackgroundShells::new()),
+            web: None,
+            agents: Vec::new(),
+            skills: Vec::new(),
+            commands: Vec::new(),
+            active_command: None,
+            task_store: Arc::new(TaskStore::in_memory()),
         }
     }
 
+    /// Use the given clock for response delays and result durations.
+    pub fn with_clock(mut self, clock: ClockHandle) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Run live tools in the given sandbox.
+    pub fn with_sandbox(mut self, sandbox: Arc<Sandbox>, config: SandboxConfig) -> Self {
+        self.sandbox = Some(sandbox);
+        self.sandbox_config = config;
+        self
+    }
+
+    /// Get the sandbox (if any).
+    pub fn sandbox(&self) -> Option<&Arc<Sandbox>> {
+        self.sandbox.as_ref()
+    }
+
+    /// Run file tools against the given in-memory filesystem.
+    pub fn with_vfs(mut self, vfs: Arc<Vfs>, config: VfsConfig) -> Self {
+        self.vfs = Some(vfs);
+        self.vfs_config = config;
+        self
+    }
+
+    /// Get the in-memory filesystem (if any).
+    pub fn vfs(&self) -> Option<&Arc<Vfs>> {
+        self.vfs.as_ref()
+    }
+
+    /// Serve WebFetch and WebSearch from the given fixtures.
+    pub fn with_web(mut self, web: Arc<Web>) -> Self {
+        self.web = Some(web);
+        self
+    }
+
+    /// Make the given custom agents available to the Task tool.
+    pub fn with_agents(mut self, agents: Vec<AgentDefinition>) -> Self {
+        self.agents = agents;
+        self
+    }
+
+    /// Get the custom agents.
+    pub fn agents(&self) -> &[AgentDefinition] {
+        &self.agents
+    }
+
+    /// Make the given skills available to the Skill tool.
+    pub fn with_skills(mut self, skills: Vec<Skill>) -> Self {
+        self.skills = skills;
+        self
+    }
+
+    /// Get the skills.
+    pub fn skills(&self) -> &[Skill] {
+        &self.skills
+    }
+
+    /// Use the given custom slash commands.
+    pub fn with_commands(mut self, commands: Vec<CustomCommand>) -> Self {
+        self.commands = commands;
+        self
+    }
+
+    /// Get the custom slash commands.
+    pub fn commands(&self) -> &[CustomCommand] {
+        &self.commands
+    }
+
+    /// Keep the task list in the given store.
+    pub fn with_task_store(mut self, task_store: Arc<TaskStore>) -> Self {
+        self.task_store = task_store;
+        self
+    }
+
+    /// Get the task list.
+    pub fn task_store(&self) -> &Arc<TaskStore> {
+        &self.task_store
+    }
+
+    /// Get the background shells started this session.
+    pub fn background_shells(&self) -> &Arc<BackgroundShells> {
+        &self.background_shells
+    }
+
+    /// Get the runtime clock.
+    pub fn clock(&self) -> &ClockHandle {
+        &self.clock
+    }
+
     /// Get the session ID.
     pub fn session_id(&self) -> String {
         self.context.session_id.to_string()
@@ -178,9 +340,13 @@ impl Runtime {
     /// Returns `Ok(TurnResult)` on success, or `Err(FailureSpec)` if the scenario
     /// specifies a failure. On failure, error is recorded to JSONL before returning.
     pub async fn execute(&mut self, prompt: &str) -> Result<TurnResult, FailureSpec> {
+        let mut stats = ResultStats::default();
+
         // Apply response delay if configured (only on initial prompts)
         if !self.stop_hook_active && self.timeouts.response_delay_ms > 0 {
-            tokio::time::sleep(Duration::from_millis(self.timeouts.response_delay_ms)).await;
+            let delay = self.timeouts.response_delay_ms;
+            self.clock.sleep(Duration::from_millis(delay)).await;
+            stats.duration_api_ms += delay;
         }
 
         // Accumulate tool calls and results across auto-continued turns.
@@ -189,6 +355,7 @@ impl Runtime {
         // This simulates the real Claude agent loop: tool result → next API call → next tool.
         let mut all_tool_calls: Vec<ToolCallSpec> = Vec::new();
         let mut all_tool_results: Vec<ToolExecutionResult> = Vec::new();
+        let mut steps: Vec<TurnStep> = Vec::new();
         let mut current_prompt = prompt.to_string();
         let mut final_text;
 
@@ -206,27 +373,60 @@ impl Runtime {
             // Get response delay from spec if detailed
             let response_delay = response_spec.as_ref().and_then(|r| r.delay_ms());
             if let Some(delay) = response_delay {
-                tokio::time::sleep(Duration::from_millis(delay)).await;
+                self.clock.sleep(Duration::from_millis(delay)).await;
+                stats.duration_api_ms += delay;
             }
 
             // Get response and tool calls
             let response = response_spec.unwrap_or(ResponseSpec::Simple(String::new()));
             let response_text = response.text().to_string();
+            let thinking = response.thinking_blocks();
             let tool_calls = response.tool_calls().to_vec();
+            let usage = response.usage_or_estimate();
+            stats.num_turns += 1;
+            stats.usage.add(&usage);
 
             // Execute tools and collect results
+            let first_index = self.next_tool_use_index;
             let (tool_results, pending_permission) = self
-                .execute_tools_for_turn(&current_prompt, &response_text, &tool_calls)
+                .execute_tools_for_turn(&current_prompt, &thinking, &response_text, &tool_calls)
                 .await;
+            stats.usage.add(&std::mem::take(&mut self.subagent_usage));
+
+            // Each result lines up with its call; a pending call ends the list early
+            stats.permission_denials.extend(
+                tool_calls
+                    .iter()
+                    .zip(&tool_results)
+                    .filter(|(_, result)| result.permission_denied)
+                    .map(|(call, result)| {
+                        PermissionDenial::new(&call.tool, &result.tool_use_id, call.input.clone())
+                    }),
+            );
 
             // Record the turn to state if no tool calls (tool calls record their own state)
             if tool_calls.is_empty() {
                 if let Some(ref writer) = self.state {
-                    let _ = writer.write().record_turn(&current_prompt, &response_text);
+                    let _ = writer.write().record_turn_with_thinking(
+                        &current_prompt,
+                        &thinking,
+                        &response_text,
+                    );
                 }
             }
 
             // Accumulate this step's tool calls and results
+            steps.push(TurnStep {
+                thinking,
+                text: response_text.clone(),
+                tool_uses: tool_calls
+                    .iter()
+                    .enumerate()
+                    .map(|(i, call)| (tool_use_id(first_index + i), call.clone()))
+                    .collect(),
+                tool_results: tool_results.clone(),
+                usage,
+            });
             all_tool_calls.extend(tool_calls);
             all_tool_results.extend(tool_results);
             final_text = response_text;
@@ -238,6 +438,8 @@ impl Runtime {
                     tool_calls: all_tool_calls,
                     usage: None,
                     delay_ms: None,
+                    thinking: None,
+                    redacted_thinking: None,
                 };
                 return Ok(TurnResult {
                     response: merged,
@@ -245,6 +447,8 @@ impl Runtime {
                     hook_continuation: None,
                     is_hook_continuation: self.stop_hook_active,
                     pending_permission,
+                    stats,
+                    steps,
                 });
             }
 
@@ -257,7 +461,7 @@ impl Runtime {
                 // Build continuation prompt from tool results
                 current_prompt = all_tool_results
                     .iter()
-                    .filter_map(|r| r.text())
+                    .flat_map(|r| r.text().into_iter().chain(r.meta_message.as_deref()))
                     .collect::<Vec<_>>()
                     .join("\n");
                 continue;
@@ -276,6 +480,8 @@ impl Runtime {
                 tool_calls: all_tool_calls,
                 usage: None,
                 delay_ms: None,
+                thinking: None,
+                redacted_thinking: None,
             }
         };
 
@@ -294,6 +500,8 @@ impl Runtime {
             hook_continuation,
             is_hook_continuation,
             pending_permission: None,
+            stats,
+            steps,
         })
     }
 
@@ -335,9 +543,11 @@ impl Runtime {
     /// the tool hasn't actually executed yet.
     ///
     /// Fires PreToolUse hooks before each tool execution and PostToolUse hooks after.
-    async fn execute_tools_for_turn(
+    /// Consecutive read-only calls run concurrently (see [`super::batch`]).
+    pub(super) async fn execute_tools_for_turn(
         &mut self,
         prompt: &str,
+        thinking: &[ContentBlock],
         response_text: &str,
         tool_calls: &[ToolCallSpec],
     ) -> (Vec<ToolExecutionResult>, Option<PendingPermission>) {
@@ -352,6 +562,15 @@ impl Runtime {
             None
         };
 
+        // Record thinking ahead of the assistant text
+        if !thinking.is_empty() {
+            if let (Some(ref state_writer), Some(ref uuid)) = (&self.state, &user_uuid) {
+                let _ = state_writer
+                    .write()
+                    .record_assistant_content(uuid, thinking.to_vec());
+            }
+        }
+
         // Record initial assistant text (if any)
         if !response_text.is_empty() {
             if let (Some(ref state_writer), Some(ref uuid)) = (&self.state, &user_uuid) {
@@ -361,47 +580,50 @@ impl Runtime {
             }
         }
 
-        // Create execution context
-        let mut ctx = ExecutionContext::default();
-        if let Some(ref cwd) = self.cli.cwd {
-            ctx = ctx.with_cwd(cwd);
-        }
+        let ctx = self.execution_context();
 
         let mut results = Vec::with_capacity(tool_calls.len());
         let mut pending_permission = None;
+        let first_index = self.next_tool_use_index;
+        self.next_tool_use_index += tool_calls.len();
+
+        for batch in self.tool_batches(tool_calls) {
+            // Runs of read-only calls execute together
+            if batch.len() > 1 {
+                let (batch_results, pending) = self
+                    .execute_batch(
+                        &tool_calls[batch.clone()],
+                        first_index + batch.start,
+                        user_uuid.as_deref(),
+                        &ctx,
+                    )
+                    .await;
+                results.extend(batch_results);
+                if pending.is_some() {
+                    pending_permission = pending;
+                    break;
+                }
+                continue;
+            }
 
-        for (i, call) in tool_calls.iter().enumerate() {
-            let tool_use_id = format!("toolu_{:08x}", i);
+            let i = batch.start;
+            let call = &tool_calls[i];
+            let tool_use_id = tool_use_id(first_index + i);
+
+            // Tools outside the --agent allowlist, invalid tool input, and
+            // Tasks naming unknown agents fail before any hooks run
+            if let Some(rejected) = self.rejected_call(call, &tool_use_id, self.session_agent()) {
+                let assistant_uuid = self.record_tool_use(call, &tool_use_id, user_uuid.as_deref());
+                self.record_tool_result(&tool_use_id, &rejected, assistant_uuid.as_deref());
+                results.push(rejected);
+                continue;
+            }
 
             // Fire PreToolUse hook before any early returns (TUI pending_permission)
             // so external systems (e.g. oddjobs) always see tool call notifications.
-            if let Some(ref hook_executor) = self.hook_executor {
-                if hook_executor.has_hooks(&HookEvent::PreToolExecution) {
-                    let pre_msg = HookMessage::tool_execution(
-                        self.context.session_id.to_string(),
-                        HookEvent::PreToolExecution,
-                        &call.tool,
-                        call.input.clone(),
-                        None,
-                        Some(tool_use_id.clone()),
-                    );
-                    match hook_executor.execute(&pre_msg).await {
-                        Ok(responses) => {
-                            if responses.iter().any(|r| !r.proceed) {
-                                let error_msg = responses
-                                    .iter()
-                                    .find(|r| !r.proceed)
-                                    .and_then(|r| r.error.as_deref())
-                                    .unwrap_or("Blocked by PreToolUse hook");
-                                results.push(ToolExecutionResult::error(&tool_use_id, error_msg));
-                                continue;
-                            }
-                        }
-                        Err(e) => {
-                            eprintln!("PreToolUse hook error: {e}");
-                        }
-                    }
-                }
+            if let Some(blocked) = self.fire_pre_tool_use_hook(call, &tool_use_id).await {
+                results.push(blocked);
+                continue;
             }
 
             // For ExitPlanMode: return as pending for TUI mode interactive dialog
@@ -444,23 +666,26 @@ impl Runtime {
             let call = call.as_ref();
 
             // Record assistant message with tool_use block
-            let assistant_uuid =
-                if let (Some(ref state_writer), Some(ref uuid)) = (&self.state, &user_uuid) {
-                    let tool_use_block = ContentBlock::ToolUse {
-                        id: tool_use_id.clone(),
-                        name: call.tool.clone(),
-                        input: call.input.clone(),
-                    };
-                    state_writer
-                        .write()
-                        .record_assistant_tool_use(uuid, vec![tool_use_block])
-                        .ok()
-                } else {
-                    None
-                };
-
-            // Execute tool
-            let result = self.executor.execute(call, &tool_use_id, &ctx);
+            let assistant_uuid = self.record_tool_use(call, &tool_use_id, user_uuid.as_deref());
+
+            // Execute tool; a Task with a scenario subagent runs the subagent,
+            // and a Skill loads the skill
+            let result = match self.subagent_for(call) {
+                Some(subagent) => {
+                    self.run_subagent(
+                        call,
+                        &tool_use_id,
+                        subagent,
+                        assistant_uuid.as_deref(),
+                        &ctx,
+                    )
+                    .await
+                }
+                None => match self.load_skill(call, &tool_use_id) {
+                    Some(result) => result,
+                    None => self.executor.execute(call, &tool_use_id, &ctx).await,
+                },
+            };
 
             // If this tool needs a permission prompt, stop here — don't record
             // the result to JSONL since the tool hasn't actually executed.
@@ -473,33 +698,11 @@ impl Runtime {
             }
 
             // Record tool result to JSONL
-            if let (Some(ref state_writer), Some(ref asst_uuid)) = (&self.state, &assistant_uuid) {
-                let result_content = result.text().unwrap_or("");
-                let tool_use_result = result.tool_use_result().unwrap_or(serde_json::json!({}));
-                let _ = state_writer.write().record_tool_result(
-                    &tool_use_id,
-                    result_content,
-                    asst_uuid,
-                    tool_use_result,
-                );
-            }
+            self.record_tool_result(&tool_use_id, &result, assistant_uuid.as_deref());
 
             // Fire PostToolUse hook after tool execution (fire-and-forget)
-            if let Some(ref hook_executor) = self.hook_executor {
-                if hook_executor.has_hooks(&HookEvent::PostToolExecution) {
-                    let post_msg = HookMessage::tool_execution(
-                        self.context.session_id.to_string(),
-                        HookEvent::PostToolExecution,
-                        &call.tool,
-                        call.input.clone(),
-                        result.text().map(|s| s.to_string()),
-                        Some(tool_use_id.clone()),
-                    );
-                    if let Err(e) = hook_executor.execute(&post_msg).await {
-                        eprintln!("PostToolUse hook error: {e}");
-                    }
-                }
-            }
+            self.fire_post_tool_use_hook(call, &tool_use_id, &result)
+                .await;
 
             results.push(result);
         }
@@ -518,6 +721,76 @@ impl Runtime {
         (results, pending_permission)
     }
 
+    /// Record an assistant message with a call's tool_use block, returning
+    /// its UUID.
+    pub(super) fn record_tool_use(
+        &self,
+        call: &ToolCallSpec,
+        tool_use_id: &str,
+        user_uuid: Option<&str>,
+    ) -> Option<String> {
+        let (Some(state_writer), Some(uuid)) = (&self.state, user_uuid) else {
+            return None;
+        };
+        let tool_use_block = ContentBlock::ToolUse {
+            id: tool_use_id.to_string(),
+            name: call.tool.clone(),
+            input: call.input.clone(),
+        };
+        state_writer
+            .write()
+            .record_assistant_tool_use(uuid, vec![tool_use_block])
+            .ok()
+    }
+
+    /// Record a tool result, and any meta message it carries, after the
+    /// assistant message `assistant_uuid`.
+    pub(super) fn record_tool_result(
+        &self,
+        tool_use_id: &str,
+        result: &ToolExecutionResult,
+        assistant_uuid: Option<&str>,
+    ) {
+        let (Some(state_writer), Some(asst_uuid)) = (&self.state, assistant_uuid) else {
+            return;
+        };
+        let result_content = result.text().unwrap_or("");
+        let tool_use_result = result.tool_use_result().unwrap_or(serde_json::json!({}));
+        let recorded = state_writer.write().record_tool_result(
+            tool_use_id,
+            result_content,
+            asst_uuid,
+            tool_use_result,
+        );
+        if let Ok(result_uuid) = recorded {
+            self.record_meta_message(result, result_uuid);
+        }
+    }
+
+    /// Create the context tools execute in.
+    pub(super) fn execution_context(&self) -> ExecutionContext {
+        let mut ctx = ExecutionContext::default()
+            .with_env(self.context.settings_env().clone())
+            .with_session_id(self.context.session_id.to_string())
+            .with_background_shells(Arc::clone(&self.background_shells))
+            .with_task_store(Arc::clone(&self.task_store))
+            .with_clock(self.clock.clone())
+            .with_allowed_tools(self.command_allowed_tools());
+        if let Some(ref cwd) = self.cli.cwd {
+            ctx = ctx.with_cwd(cwd);
+        }
+        if let Some(ref sandbox) = self.sandbox {
+            ctx = ctx.with_sandbox(Arc::clone(sandbox));
+        }
+        if let Some(ref vfs) = self.vfs {
+            ctx = ctx.with_vfs(Arc::clone(vfs));
+        }
+        if let Some(ref web) = self.web {
+            ctx = ctx.with_web(Arc::clone(web));
+        }
+        ctx
+    }
+
     /// Get pre-configured answers from scenario tool config.
     fn get_scenario_answers(&self, tool_name: &str) -> Option<serde_json::Value> {
         let tool_exec = self.scenario.as_ref()?.config().tool_execution.as_ref()?;
@@ -526,13 +799,96 @@ impl Runtime {
         Some(serde_json::json!(answers))
     }
 
+    /// An error result for a call that fails before any hooks run, checked
+    /// in the real CLI's order: a tool outside `agent`'s allowlist, input
+    /// that does not match the tool's schema, then a Task naming an unknown
+    /// agent type.
+    pub(super) fn rejected_call(
+        &self,
+        call: &ToolCallSpec,
+        tool_use_id: &str,
+        agent: Option<&AgentDefinition>,
+    ) -> Option<ToolExecutionResult> {
+        if agent.is_some_and(|agent| !agent.allows_tool(&call.tool)) {
+            return Some(ToolExecutionResult::error(
+                tool_use_id,
+                format!("No such tool available: {}", call.tool),
+            ));
+        }
+        input_error(call, tool_use_id).or_else(|| self.unknown_agent_type(call, tool_use_id))
+    }
+
+    /// Fire PreToolUse hook, returning an error result if a hook blocks the call.
+    pub(super) async fn fire_pre_tool_use_hook(
+        &self,
+        call: &ToolCallSpec,
+        tool_use_id: &str,
+    ) -> Option<ToolExecutionResult> {
+        let hook_executor = self.hook_executor.as_ref()?;
+        if !hook_executor.has_hooks(&HookEvent::PreToolExecution) {
+            return None;
+        }
+        let pre_msg = HookMessage::tool_execution(
+            self.context.session_id.to_string(),
+            HookEvent::PreToolExecution,
+            &call.tool,
+            call.input.clone(),
+            None,
+            Some(tool_use_id.to_string()),
+        );
+        match hook_executor.execute(&pre_msg).await {
+            Ok(responses) => {
+                let blocked = responses.iter().find(|r| !r.proceed)?;
+                let error_msg = blocked
+                    .error
+                    .as_deref()
+                    .unwrap_or("Blocked by PreToolUse hook");
+                Some(ToolExecutionResult::error(tool_use_id, error_msg))
+            }
+            Err(e) => {
+                eprintln!("PreToolUse hook error: {e}");
+                None
+            }
+        }
+    }
+
+    /// Fire PostToolUse hook (fire-and-forget).
+    pub(super) async fn fire_post_tool_use_hook(
+        &self,
+        call: &ToolCallSpec,
+        tool_use_id: &str,
+        result: &ToolExecutionResult,
+    ) {
+        if let Some(ref hook_executor) = self.hook_executor {
+            if hook_executor.has_hooks(&HookEvent::PostToolExecution) {
+                let post_msg = HookMessage::tool_execution(
+                    self.context.session_id.to_string(),
+                    HookEvent::PostToolExecution,
+                    &call.tool,
+                    call.input.clone(),
+                    result.text().map(|s| s.to_string()),
+                    Some(tool_use_id.to_string()),
+                );
+                if let Err(e) = hook_executor.execute(&post_msg).await {
+                    eprintln!("PostToolUse hook error: {e}");
+                }
+            }
+        }
+    }
+
     /// Fire Stop hook and return continuation prompt if blocked.
     async fn fire_stop_hook(&self) -> Option<String> {
+        let stop_msg =
+            HookMessage::stop(self.context.session_id.to_string(), self.stop_hook_active);
+        self.stop_hook_continuation(&stop_msg).await
+    }
+
+    /// Fire a Stop or SubagentStop hook and return the continuation prompt
+    /// if a hook blocked stopping.
+    pub(super) async fn stop_hook_continuation(&self, msg: &HookMessage) -> Option<String> {
         if let Some(ref executor) = self.hook_executor {
-            if executor.has_hooks(&HookEvent::Stop) {
-                let stop_msg =
-                    HookMessage::stop(self.context.session_id.to_string(), self.stop_hook_active);
-                if let Ok(responses) = executor.execute(&stop_msg).await {
+            if executor.has_hooks(&msg.event) {
+                if let Ok(responses) = executor.execute(msg).await {
                     for resp in responses {
                         if let Some(data) = resp.data {
                             if let Ok(stop_resp) = serde_json::from_value::<StopHookResponse>(data)
@@ -597,6 +953,11 @@ impl Runtime {
     }
 }
 
+/// Tool use ID for the given session-wide call index.
+pub(super) fn tool_use_id(index: usize) -> String {
+    format!("toolu_{:08x}", index)
+}
+
 #[cfg(test)]
 #[path = "core_tests.rs"]
 mod tests;
@@ -9,6 +9,7 @@ use std::fs;
 use crate::cli::{Cli, FORCE_TUI};
 use crate::config::{ResolvedTimeouts, ToolCallSpec};
 use crate::hooks::{HookConfig, HookEvent, HookExecutor};
+use crate::time::{Clock, ClockHandle};
 use crate::tools::executor::MockExecutor;
 
 use super::{Runtime, RuntimeContext};
@@ -83,7 +84,7 @@ async fn pre_tool_use_hook_fires_for_exit_plan_mode_in_tui() {
     }];
 
     let (results, pending) = runtime
-        .execute_tools_for_turn("test", "", &tool_calls)
+        .execute_tools_for_turn("test", &[], "", &tool_calls)
         .await;
 
     FORCE_TUI.set(None);
@@ -121,12 +122,20 @@ async fn pre_tool_use_hook_fires_for_ask_user_question_in_tui() {
 
     let tool_calls = vec![ToolCallSpec {
         tool: "AskUserQuestion".to_string(),
-        input: serde_json::json!({"questions": []}),
+        input: serde_json::json!({"questions": [{
+            "question": "Which language?",
+            "header": "Language",
+            "options": [
+                {"label": "Rust", "description": "Systems"},
+                {"label": "Go", "description": "Services"}
+            ],
+            "multiSelect": false
+        }]}),
         result: None,
     }];
 
     let (results, pending) = runtime
-        .execute_tools_for_turn("test", "", &tool_calls)
+        .execute_tools_for_turn("test", &[], "", &tool_calls)
         .await;
 
     FORCE_TUI.set(None);
@@ -171,7 +180,7 @@ async fn pre_tool_use_hook_blocking_prevents_tui_pending_permission() {
     }];
 
     let (results, pending) = runtime
-        .execute_tools_for_turn("test", "", &tool_calls)
+        .execute_tools_for_turn("test", &[], "", &tool_calls)
         .await;
 
     FORCE_TUI.set(None);
@@ -204,7 +213,7 @@ async fn pre_tool_use_hook_fires_for_regular_tools() {
     }];
 
     let (results, pending) = runtime
-        .execute_tools_for_turn("test", "", &tool_calls)
+        .execute_tools_for_turn("test", &[], "", &tool_calls)
         .await;
 
     // Hook should have fired
@@ -218,3 +227,53 @@ async fn pre_tool_use_hook_fires_for_regular_tools() {
     assert_eq!(results.len(), 1);
     assert!(!results[0].is_error);
 }
+
+#[tokio::test(flavor = "current_thread")]
+async fn invalid_tool_input_fails_before_hooks() {
+    let tmp = tempfile::tempdir().unwrap();
+    let script = create_hook_script(tmp.path());
+    let marker = tmp.path().join("hook_fired");
+
+    let mut hook_executor = HookExecutor::new();
+    hook_executor.register(HookEvent::PreToolExecution, HookConfig::new(&script, 5000));
+
+    let cli = Cli::try_parse_from(["claude", "-p", "test"]).unwrap();
+    let mut runtime = build_test_runtime(Some(hook_executor), cli);
+
+    let tool_calls = vec![ToolCallSpec {
+        tool: "Read".to_string(),
+        input: serde_json::json!({"path": "/dev/null"}),
+        result: None,
+    }];
+
+    let (results, _) = runtime
+        .execute_tools_for_turn("test", &[], "", &tool_calls)
+        .await;
+
+    assert!(!marker.exists(), "PreToolUse hook should not fire");
+    assert_eq!(results.len(), 1);
+    assert!(results[0].is_error);
+    assert_eq!(
+        results[0].text(),
+        Some(
+            "<tool_use_error>InputValidationError: Read failed due to the following issues:\n\
+             The required parameter `file_path` is missing\n\
+             An unexpected parameter `path` was provided</tool_use_error>"
+        )
+    );
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn execute_reports_stats_from_clock() {
+    let cli = Cli::try_parse_from(["claude", "-p", "test"]).unwrap();
+    let clock = ClockHandle::fake_at_epoch();
+    let mut runtime = build_test_runtime(None, cli).with_clock(clock.clone());
+
+    let result = runtime.execute("hello").await.unwrap();
+
+    let delay = ResolvedTimeouts::default().response_delay_ms;
+    assert_eq!(result.stats.num_turns, 1);
+    assert_eq!(result.stats.duration_api_ms, delay);
+    assert_eq!(clock.now_millis(), delay);
+    assert!(result.stats.permission_denials.is_empty());
+}
@@ -8,11 +8,18 @@
 //! - [`Runtime`] - Core runtime for executing prompts
 //! - [`RuntimeBuilder`] - Fluent API for constructing Runtime instances
 
+mod batch;
 mod builder;
 mod context;
 mod core;
 mod print_mode;
+mod sandbox;
+mod skill;
+mod slash_command;
+mod subagent;
+mod tasks;
+mod vfs;
 
 pub use builder::{RuntimeBuildError, RuntimeBuilder};
 pub use context::RuntimeContext;
-pub use core::{PendingPermission, Runtime, TurnResult};
+pub use core::{PendingPermission, Runtime, TurnResult, TurnStep};
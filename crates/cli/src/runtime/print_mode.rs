@@ -5,9 +5,17 @@
 
 use std::io::{self, Write};
 
+use crate::cli::OutputFormat;
 use crate::failure::FailureExecutor;
-use crate::output::{McpServerInfo, OutputWriter};
+use crate::output::{
+    McpServerInfo, OutputWriter, PermissionDenial, PluginInfo, ResultStats, SystemInitEvent,
+    ToolSchema,
+};
 use crate::runtime::TurnResult;
+use crate::state::agents::plugin_name;
+use crate::time::Clock;
+use crate::tools::schema::input_schema;
+use crate::tools::tool_name::ToolName;
 
 use super::Runtime;
 
@@ -35,6 +43,9 @@ impl Runtime {
         // Fire session start hook
         self.fire_session_start_hook().await;
 
+        // Expand a custom slash command into its prompt
+        let prompt = self.expand_prompt(&prompt).await?;
+
         // Execute the response loop
         self.execute_response_loop(&prompt).await?;
 
@@ -66,6 +77,9 @@ impl Runtime {
         initial_prompt: &str,
     ) -> Result<(), Box<dyn std::error::Error>> {
         let mut current_prompt = initial_prompt.to_string();
+        let started_ms = self.clock.now_millis();
+        let mut stats = ResultStats::default();
+        let mut first_turn = true;
 
         loop {
             // Execute turn (handles failure detection and JSONL recording)
@@ -79,10 +93,30 @@ impl Runtime {
                 }
             };
 
-            // Write output
-            self.write_turn_result(&result)?;
+            stats.merge(result.stats.clone());
+
+            // Non-interactive mode can't show permission prompts, so the call is denied
+            if let Some(ref pending) = result.pending_permission {
+                stats.permission_denials.push(PermissionDenial::new(
+                    &pending.tool_call.tool,
+                    &pending.tool_use_id,
+                    pending.tool_call.input.clone(),
+                ));
+            }
+            stats.duration_ms = self.clock.now_millis().saturating_sub(started_ms);
+
+            // JSON output is a single result object, written for the final turn only
+            let is_final =
+                result.pending_permission.is_some() || result.hook_continuation.is_none();
+            match self.cli.output.output_format {
+                OutputFormat::StreamJson => {
+                    self.write_stream_json_turn(&result, &stats, first_turn, is_final)?
+                }
+                OutputFormat::Json if !is_final => {}
+                _ => self.write_turn_result(&result, &stats)?,
+            }
+            first_turn = false;
 
-            // Non-interactive mode can't show permission prompts
             if result.pending_permission.is_some() {
                 break;
             }
@@ -97,16 +131,21 @@ impl Runtime {
         Ok(())
     }
 
-    /// Write a turn result to stdout.
-    fn write_turn_result(&self, result: &TurnResult) -> Result<(), Box<dyn std::error::Error>> {
+    /// Write a turn result to stdout, reporting the run statistics so far.
+    fn write_turn_result(
+        &self,
+        result: &TurnResult,
+        stats: &ResultStats,
+    ) -> Result<(), Box<dyn std::error::Error>> {
         let mut stdout = io::stdout();
 
         // Use real Claude format
         let mut writer = OutputWriter::new(
             &mut stdout,
             self.cli.output.output_format.clone(),
-            self.cli.model.clone(),
-        );
+            self.turn_model().to_string(),
+        )
+        .with_stats(stats.clone());
 
         // Combine builtin tools with MCP tools
         let mut tools: Vec<String> = self.cli.allowed_tools.clone();
@@ -131,6 +170,152 @@ impl Runtime {
         Ok(())
     }
 
+    /// Write a turn as stream-json events in the order they happened.
+    ///
+    /// The init event opens the stream and the result event closes it, so
+    /// hook continuations only add assistant/user pairs for their steps.
+    fn write_stream_json_turn(
+        &self,
+        result: &TurnResult,
+        stats: &ResultStats,
+        first_turn: bool,
+        is_final: bool,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut stdout = io::stdout();
+        let mut writer = OutputWriter::new(
+            &mut stdout,
+            self.cli.output.output_format.clone(),
+            self.turn_model().to_string(),
+        )
+        .with_stats(stats.clone());
+        let session_id = self.context.session_id.to_string();
+
+        if first_turn {
+            writer.write_init(&self.init_event())?;
+        }
+
+        for step in &result.steps {
+            writer.write_assistant_step(
+                &session_id,
+                &step.thinking,
+                &step.text,
+                &step.tool_uses,
+                &step.usage,
+            )?;
+            if !step.tool_results.is_empty() {
+                writer.write_tool_results_step(&session_id, &step.tool_results)?;
+            }
+        }
+
+        if is_final {
+            let text = result.response_text().to_string();
+            writer.write_stream_result(text, &session_id, &stats.usage)?;
+        }
+
+        stdout.flush()?;
+        Ok(())
+    }
+
+    /// Build the stream-json init event from the runtime context and settings.
+    pub fn init_event(&self) -> SystemInitEvent {
+        let mut tools: Vec<String> = self.cli.allowed_tools.clone();
+        tools.extend(self.mcp_tool_names());
+        let tool_schemas = self.tool_schemas(&tools);
+        let defaults = SystemInitEvent::with_mcp_servers(
+            self.context.session_id.to_string(),
+            tools,
+            self.mcp_server_info(),
+        );
+
+        let settings = self.context.settings();
+        let permission_mode = serde_json::to_value(&self.context.permission_mode)
+            .ok()
+            .and_then(|v| v.as_str().map(String::from))
+            .unwrap_or(defaults.permission_mode.clone());
+        let api_key_source = if settings.extra.contains_key("apiKeyHelper") {
+            "apiKeyHelper".to_string()
+        } else if self
+            .context
+            .settings_env()
+            .contains_key("ANTHROPIC_API_KEY")
+            || std::env::var_os("ANTHROPIC_API_KEY").is_some()
+        {
+            "ANTHROPIC_API_KEY".to_string()
+        } else {
+            defaults.api_key_source.clone()
+        };
+        let output_style = settings
+            .extra
+            .get("outputStyle")
+            .and_then(|v| v.as_str())
+            .map(String::from)
+            .unwrap_or(defaults.output_style.clone());
+        let skills = self.skill_names();
+        let slash_commands = if self.cli.disable_slash_commands {
+            vec![]
+        } else {
+            let mut commands = defaults.slash_commands.clone();
+            commands.extend(self.command_names());
+            commands.extend(skills.iter().cloned());
+            commands
+        };
+
+        SystemInitEvent {
+            cwd: self
+                .context
+                .working_directory
+                .to_string_lossy()
+                .into_owned(),
+            model: self.context.model.clone(),
+            permission_mode,
+            slash_commands,
+            api_key_source,
+            claude_code_version: self.context.claude_version.clone(),
+            output_style,
+            agents: self.agent_types(),
+            skills,
+            plugins: self.plugin_info(),
+            tool_schemas,
+            ..defaults
+        }
+    }
+
+    /// Input schemas for the init event's tools: built-in schemas, and the
+    /// schemas MCP servers report. Patterns like `Bash(git:*)` list their
+    /// tool's schema once.
+    fn tool_schemas(&self, tools: &[String]) -> Vec<ToolSchema> {
+        let mcp_schemas: Vec<(String, serde_json::Value)> = match &self.mcp_manager {
+            Some(manager) => manager
+                .read()
+                .tools()
+                .iter()
+                .map(|def| (def.qualified_name(), def.input_schema.clone()))
+                .collect(),
+            None => vec![],
+        };
+        let mut schemas: Vec<ToolSchema> = Vec::new();
+        for tool in tools {
+            let name = tool.split('(').next().unwrap_or(tool);
+            if schemas.iter().any(|schema| schema.name == name) {
+                continue;
+            }
+            let schema = match ToolName::parse(name) {
+                Some(builtin) => Some(input_schema(builtin)),
+                None => mcp_schemas
+                    .iter()
+                    .find(|(qualified, _)| qualified == name)
+                    .map(|(_, schema)| schema.clone()),
+            };
+            if let Some(input_schema) = schema {
+                schemas.push(ToolSchema {
+                    name: name.to_string(),
+                    input_schema,
+                });
+            }
+        }
+        schemas
+    }
+
     /// Write queue-operation for print mode (unless persistence is disabled).
     fn write_queue_operation(&self) -> Result<(), Box<dyn std::error::Error>> {
         if self.cli.print && !self.cli.session.no_session_persistence {
@@ -156,6 +341,22 @@ impl Runtime {
         }
     }
 
+    /// Get `--plugin-dir` plugins for init event.
+    ///
+    /// Directories that do not exist are left out, as the loaders skip them.
+    pub fn plugin_info(&self) -> Vec<PluginInfo> {
+        self.cli
+            .plugin_dir
+            .iter()
+            .filter_map(|dir| std::path::absolute(dir).ok())
+            .filter(|dir| dir.is_dir())
+            .map(|dir| PluginInfo {
+                name: plugin_name(&dir),
+                path: dir.to_string_lossy().into_owned(),
+            })
+            .collect()
+    }
+
     /// Get MCP server info for init event.
     pub fn mcp_server_info(&self) -> Vec<McpServerInfo> {
         use crate::mcp::McpServerStatus;
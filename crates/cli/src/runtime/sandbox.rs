@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Sandbox diff reporting at the end of a session.
+
+use crate::tools::SandboxDiff;
+
+use super::Runtime;
+
+impl Runtime {
+    /// Write and check the sandbox diff once the session is over.
+    ///
+    /// The diff is written to the configured `diff_file`, then compared with
+    /// the scenario's `expect` table. Returns an error message on a mismatch
+    /// or when the diff cannot be produced.
+    pub fn finish_sandbox(&self) -> Result<(), String> {
+        let Some(ref sandbox) = self.sandbox else {
+            return Ok(());
+        };
+
+        let diff = sandbox
+            .diff()
+            .map_err(|e| format!("Failed to diff sandbox: {}", e))?;
+
+        if let Some(ref path) = self.sandbox_config.diff_file {
+            std::fs::write(path, diff_json(&diff))
+                .map_err(|e| format!("Failed to write sandbox diff '{}': {}", path, e))?;
+        }
+
+        match self.sandbox_config.expect {
+            Some(ref expected) if *expected != diff => Err(format!(
+                "Sandbox diff does not match expectation\nExpected: {}\nActual: {}",
+                diff_json(expected),
+                diff_json(&diff)
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn diff_json(diff: &SandboxDiff) -> String {
+    serde_json::to_string_pretty(diff).unwrap_or_default()
+}
+
+#[cfg(test)]
+#[path = "sandbox_tests.rs"]
+mod tests;
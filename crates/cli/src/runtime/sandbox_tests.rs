@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::sync::Arc;
+
+use clap::Parser;
+use tempfile::TempDir;
+
+use crate::cli::Cli;
+use crate::config::{ResolvedTimeouts, SandboxConfig};
+use crate::tools::executor::MockExecutor;
+use crate::tools::{Sandbox, SandboxDiff};
+
+use super::super::{Runtime, RuntimeContext};
+
+fn sandboxed_runtime(project: &TempDir, config: SandboxConfig) -> Runtime {
+    let cli = Cli::try_parse_from(["claude", "-p", "hello"]).unwrap();
+    let context = RuntimeContext::build(None, &cli);
+    let sandbox = Arc::new(Sandbox::new(project.path()).unwrap());
+    Runtime::new(
+        context,
+        None,
+        Box::new(MockExecutor::new()),
+        None,
+        None,
+        None,
+        cli,
+        ResolvedTimeouts::default(),
+    )
+    .with_sandbox(sandbox, config)
+}
+
+#[test]
+fn finish_sandbox_writes_diff_file() {
+    let project = TempDir::new().unwrap();
+    let out = TempDir::new().unwrap();
+    let diff_file = out.path().join("diff.json");
+    let runtime = sandboxed_runtime(
+        &project,
+        SandboxConfig {
+            diff_file: Some(diff_file.to_string_lossy().into_owned()),
+            ..Default::default()
+        },
+    );
+    let root = runtime.sandbox().unwrap().root().to_path_buf();
+    std::fs::write(root.join("new.txt"), "hello").unwrap();
+
+    runtime.finish_sandbox().unwrap();
+
+    let diff: SandboxDiff =
+        serde_json::from_str(&std::fs::read_to_string(&diff_file).unwrap()).unwrap();
+    assert_eq!(diff.added, vec!["new.txt"]);
+    assert!(!project.path().join("new.txt").exists());
+}
+
+#[test]
+fn finish_sandbox_reports_unexpected_diff() {
+    let project = TempDir::new().unwrap();
+    let runtime = sandboxed_runtime(
+        &project,
+        SandboxConfig {
+            expect: Some(SandboxDiff::default()),
+            ..Default::default()
+        },
+    );
+    let root = runtime.sandbox().unwrap().root().to_path_buf();
+    std::fs::write(root.join("stray.txt"), "oops").unwrap();
+
+    let err = runtime.finish_sandbox().unwrap_err();
+    assert!(err.contains("does not match"), "{}", err);
+    assert!(err.contains("stray.txt"), "{}", err);
+}
@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Skills loaded by the Skill tool.
+//!
+//! A Skill call naming a discovered skill returns `Launching skill: <name>`
+//! and adds the skill's instructions to the conversation as a meta message
+//! (`isMeta` with the call's `sourceToolUseID`), which is also part of the
+//! prompt the next scenario turn matches against.
+
+use std::path::Path;
+
+use serde_json::json;
+
+use crate::config::ToolCallSpec;
+use crate::state::skills::launch_message;
+use crate::state::Skill;
+use crate::tools::ToolExecutionResult;
+
+use super::Runtime;
+
+impl Runtime {
+    /// The skill with the given name.
+    pub(super) fn skill(&self, name: &str) -> Option<&Skill> {
+        self.skills.iter().find(|skill| skill.name == name)
+    }
+
+    /// Skill names, as listed in the init event.
+    pub fn skill_names(&self) -> Vec<String> {
+        self.skills.iter().map(|skill| skill.name.clone()).collect()
+    }
+
+    /// Load the skill a Skill call names.
+    ///
+    /// Skill calls with a canned `result` run like any other tool.
+    pub(super) fn load_skill(
+        &self,
+        call: &ToolCallSpec,
+        tool_use_id: &str,
+    ) -> Option<ToolExecutionResult> {
+        if call.tool != "Skill" || call.result.is_some() {
+            return None;
+        }
+        let Some(name) = call.input["skill"].as_str() else {
+            return Some(ToolExecutionResult::error(
+                tool_use_id,
+                "Missing required parameter: skill",
+            ));
+        };
+        let name = name.trim_start_matches('/');
+        let Some(skill) = self.skill(name) else {
+            return Some(ToolExecutionResult::error(
+                tool_use_id,
+                format!("Unknown skill: {}", name),
+            ));
+        };
+
+        let mut instructions = skill_instructions(&skill.dir, &skill.content);
+        if let Some(args) = call.input["args"].as_str().filter(|a| !a.is_empty()) {
+            instructions.push_str(&format!("\n\nARGUMENTS: {}", args));
+        }
+        Some(
+            ToolExecutionResult::success_with_result(
+                tool_use_id,
+                launch_message(&skill.name),
+                json!({ "success": true, "commandName": skill.name }),
+            )
+            .with_meta_message(instructions),
+        )
+    }
+
+    /// Record a result's meta message after the tool result `parent_uuid`,
+    /// returning the UUID of the last message written.
+    pub(super) fn record_meta_message(
+        &self,
+        result: &ToolExecutionResult,
+        parent_uuid: String,
+    ) -> String {
+        let (Some(writer), Some(text)) = (&self.state, result.meta_message.as_deref()) else {
+            return parent_uuid;
+        };
+        writer
+            .write()
+            .record_meta_message(text, &parent_uuid, &result.tool_use_id)
+            .unwrap_or(parent_uuid)
+    }
+}
+
+/// The meta message injected when a skill loads.
+fn skill_instructions(dir: &Path, content: &str) -> String {
+    format!(
+        "Base directory for this skill: {}\n\n{}",
+        dir.display(),
+        content
+    )
+}
+
+#[cfg(test)]
+#[path = "skill_tests.rs"]
+mod tests;
@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use clap::Parser;
+use std::path::PathBuf;
+
+use crate::cli::Cli;
+use crate::config::{ResolvedTimeouts, ScenarioConfig, ToolCallSpec};
+use crate::scenario::Scenario;
+use crate::state::{Skill, SkillSource};
+use crate::tools::executor::MockExecutor;
+
+use super::super::{Runtime, RuntimeContext};
+
+const SCENARIO: &str = r#"
+[[responses]]
+pattern = { type = "contains", text = "deploy" }
+response = { text = "Loading", tool_calls = [{ tool = "Skill", input = { skill = "deploy", args = "staging" } }] }
+turns = [{ expect = { type = "skill", name = "deploy" }, response = "Deployed with the skill" }]
+"#;
+
+fn skill_runtime() -> Runtime {
+    let cli = Cli::try_parse_from(["claude", "-p", "deploy"]).unwrap();
+    let config: ScenarioConfig = toml::from_str(SCENARIO).unwrap();
+    let scenario = Scenario::from_config(config).unwrap();
+    let context = RuntimeContext::build(Some(scenario.config()), &cli);
+    Runtime::new(
+        context,
+        Some(scenario),
+        Box::new(MockExecutor::new()),
+        None,
+        None,
+        None,
+        cli,
+        ResolvedTimeouts::default(),
+    )
+    .with_skills(vec![Skill {
+        name: "deploy".into(),
+        description: "Deploys the app".into(),
+        content: "Run make deploy.".into(),
+        dir: PathBuf::from("/skills/deploy"),
+        source: SkillSource::Project,
+    }])
+}
+
+fn skill_call(input: serde_json::Value) -> ToolCallSpec {
+    ToolCallSpec {
+        tool: "Skill".to_string(),
+        input,
+        result: None,
+    }
+}
+
+#[test]
+fn load_skill_launches_skill_with_instructions() {
+    let runtime = skill_runtime();
+
+    let result = runtime
+        .load_skill(&skill_call(serde_json::json!({ "skill": "/deploy" })), "t1")
+        .unwrap();
+
+    assert!(!result.is_error);
+    assert_eq!(result.text(), Some("Launching skill: deploy"));
+    assert_eq!(
+        result.tool_use_result().unwrap(),
+        serde_json::json!({ "success": true, "commandName": "deploy" })
+    );
+    assert_eq!(
+        result.meta_message.as_deref(),
+        Some("Base directory for this skill: /skills/deploy\n\nRun make deploy.")
+    );
+}
+
+#[test]
+fn load_skill_rejects_unknown_skill() {
+    let runtime = skill_runtime();
+
+    let result = runtime
+        .load_skill(&skill_call(serde_json::json!({ "skill": "lint" })), "t1")
+        .unwrap();
+
+    assert!(result.is_error);
+    assert_eq!(result.text(), Some("Unknown skill: lint"));
+}
+
+#[test]
+fn skill_with_canned_result_goes_to_executor() {
+    let runtime = skill_runtime();
+    let mut call = skill_call(serde_json::json!({ "skill": "deploy" }));
+    call.result = Some("done".into());
+
+    assert!(runtime.load_skill(&call, "t1").is_none());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn skill_pattern_matches_turn_after_skill_loads() {
+    let mut runtime = skill_runtime();
+
+    let result = runtime.execute("deploy").await.unwrap();
+
+    assert_eq!(result.response_text(), "Deployed with the skill");
+    assert!(result.tool_results[0]
+        .meta_message
+        .as_deref()
+        .unwrap()
+        .ends_with("ARGUMENTS: staging"));
+}
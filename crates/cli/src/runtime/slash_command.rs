@@ -0,0 +1,136 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Custom slash commands.
+//!
+//! A prompt like `/name args` naming a custom command is replaced by the
+//! command's expanded body before it is matched against the scenario.
+//! `` !`cmd` `` runs through the Bash tool, so it needs the command's
+//! `allowed-tools` (or settings) to permit it. The command's `allowed-tools`
+//! keep applying to tool calls, and its `model` to the output, until the
+//! next prompt.
+
+use std::path::Path;
+
+use serde_json::json;
+
+use crate::config::ToolCallSpec;
+use crate::permission::ToolPattern;
+use crate::state::commands::{
+    bash_commands, expand_bash, expand_file_references, parse_invocation, CustomCommand,
+};
+
+use super::core::tool_use_id;
+use super::Runtime;
+
+impl Runtime {
+    /// The custom command a prompt runs, if any.
+    pub fn custom_command(&self, prompt: &str) -> Option<&CustomCommand> {
+        let (name, _) = parse_invocation(prompt)?;
+        self.commands.iter().find(|command| command.name == name)
+    }
+
+    /// Custom command names, as listed in the init event.
+    pub fn command_names(&self) -> Vec<String> {
+        self.commands
+            .iter()
+            .map(|command| command.name.clone())
+            .collect()
+    }
+
+    /// Expand a user prompt that runs a custom command.
+    ///
+    /// Other prompts are returned unchanged and end the previous command's
+    /// `allowed-tools` and `model`.
+    pub async fn expand_prompt(&mut self, prompt: &str) -> Result<String, String> {
+        self.active_command = self.custom_command(prompt).cloned();
+        let (Some(command), Some((_, args))) =
+            (self.active_command.clone(), parse_invocation(prompt))
+        else {
+            return Ok(prompt.to_string());
+        };
+
+        let text = command.substitute_arguments(args);
+        // Commands run in order, stopping at the first failure
+        let mut outputs = Vec::new();
+        for cmd in bash_commands(&text) {
+            outputs.push(self.run_command_bash(&cmd).await?);
+        }
+        let mut outputs = outputs.into_iter();
+        let text = expand_bash(&text, |_| Ok(outputs.next().unwrap_or_default()))?;
+        Ok(expand_file_references(&text, |path| {
+            self.read_referenced_file(path)
+        }))
+    }
+
+    /// Model the current turn runs with.
+    pub fn turn_model(&self) -> &str {
+        self.active_command
+            .as_ref()
+            .and_then(|command| command.model.as_deref())
+            .unwrap_or(&self.cli.model)
+    }
+
+    /// Tools the running custom command allows without asking.
+    pub(super) fn command_allowed_tools(&self) -> Vec<ToolPattern> {
+        self.active_command
+            .iter()
+            .flat_map(|command| &command.allowed_tools)
+            .filter_map(|tool| ToolPattern::parse(tool))
+            .collect()
+    }
+
+    /// Run a `` !`cmd` `` through the Bash tool, returning its output.
+    async fn run_command_bash(&mut self, cmd: &str) -> Result<String, String> {
+        let id = tool_use_id(self.next_tool_use_index);
+        self.next_tool_use_index += 1;
+        let call = ToolCallSpec {
+            tool: "Bash".to_string(),
+            input: json!({ "command": cmd }),
+            result: None,
+        };
+        let ctx = self.execution_context();
+        let result = self.executor.execute(&call, &id, &ctx).await;
+
+        if result.needs_prompt || result.permission_denied {
+            return Err(format!(
+                "Bash command permission check failed for pattern \"!`{}`\": This command requires approval",
+                cmd
+            ));
+        }
+        // Bash results carry the command's own output apart from the exit code
+        let output = match result.tool_use_result {
+            Some(ref output) => [&output["stdout"], &output["stderr"]]
+                .iter()
+                .filter_map(|stream| stream.as_str().filter(|s| !s.is_empty()))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            None => result.text().unwrap_or_default().to_string(),
+        };
+        if result.is_error {
+            return Err(format!(
+                "Bash command failed for pattern \"!`{}`\": {}",
+                cmd, output
+            ));
+        }
+        Ok(output)
+    }
+
+    /// Read a file an `@path` reference names, from wherever file tools
+    /// read.
+    fn read_referenced_file(&self, path: &str) -> Option<String> {
+        let cwd = self.cli.cwd.as_deref().map(Path::new);
+        if let Some(ref vfs) = self.vfs {
+            return vfs.read_to_string(&vfs.resolve(path, cwd)).ok();
+        }
+        let resolved = match self.sandbox {
+            Some(ref sandbox) => sandbox.resolve(path, cwd).ok()?,
+            None => self.context.working_directory.join(path),
+        };
+        std::fs::read_to_string(resolved).ok()
+    }
+}
+
+#[cfg(test)]
+#[path = "slash_command_tests.rs"]
+mod tests;
@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use clap::Parser;
+use tempfile::TempDir;
+
+use crate::cli::Cli;
+use crate::config::ResolvedTimeouts;
+use crate::permission::{PermissionBypass, PermissionChecker, PermissionMode};
+use crate::state::{CommandSource, CustomCommand};
+use crate::tools::builtin::BuiltinExecutor;
+use crate::tools::executor::PermissionCheckingExecutor;
+
+use super::super::{Runtime, RuntimeContext};
+
+fn command(name: &str, content: &str) -> CustomCommand {
+    CustomCommand::from_markdown(content, name, CommandSource::Project).unwrap()
+}
+
+fn command_runtime(cwd: &TempDir, commands: Vec<CustomCommand>) -> Runtime {
+    let cwd = cwd.path().to_str().unwrap();
+    let cli = Cli::try_parse_from(["claude", "-p", "--cwd", cwd, "--model", "sonnet"]).unwrap();
+    let context = RuntimeContext::build(None, &cli);
+    let checker = PermissionChecker::new(PermissionMode::Default, PermissionBypass::default());
+    Runtime::new(
+        context,
+        None,
+        Box::new(PermissionCheckingExecutor::new(
+            Box::new(BuiltinExecutor::new()),
+            checker,
+        )),
+        None,
+        None,
+        None,
+        cli,
+        ResolvedTimeouts::default(),
+    )
+    .with_commands(commands)
+}
+
+#[tokio::test]
+async fn expand_prompt_substitutes_arguments() {
+    let dir = TempDir::new().unwrap();
+    let mut runtime = command_runtime(
+        &dir,
+        vec![command(
+            "fix",
+            "---\nmodel: haiku\n---\nFix issue #$1 ($ARGUMENTS)",
+        )],
+    );
+
+    let prompt = runtime.expand_prompt("/fix 12 quickly").await.unwrap();
+
+    assert_eq!(prompt, "Fix issue #12 (12 quickly)");
+    assert_eq!(runtime.turn_model(), "haiku");
+}
+
+#[tokio::test]
+async fn expand_prompt_leaves_other_prompts_and_ends_command() {
+    let dir = TempDir::new().unwrap();
+    let mut runtime = command_runtime(
+        &dir,
+        vec![command(
+            "fix",
+            "---\nmodel: haiku\nallowed-tools: Bash\n---\nFix it",
+        )],
+    );
+    runtime.expand_prompt("/fix").await.unwrap();
+    assert_eq!(runtime.command_allowed_tools().len(), 1);
+
+    assert_eq!(runtime.expand_prompt("/compact").await.unwrap(), "/compact");
+    assert_eq!(runtime.expand_prompt("hello").await.unwrap(), "hello");
+    assert_eq!(runtime.turn_model(), "sonnet");
+    assert!(runtime.command_allowed_tools().is_empty());
+}
+
+#[tokio::test]
+async fn expand_prompt_runs_allowed_bash() {
+    let dir = TempDir::new().unwrap();
+    let mut runtime = command_runtime(
+        &dir,
+        vec![command(
+            "greet",
+            "---\nallowed-tools: Bash(echo:*)\n---\nSaid: !`echo hi`",
+        )],
+    );
+
+    assert_eq!(runtime.expand_prompt("/greet").await.unwrap(), "Said: hi");
+}
+
+#[tokio::test]
+async fn expand_prompt_rejects_bash_without_permission() {
+    let dir = TempDir::new().unwrap();
+    let mut runtime = command_runtime(&dir, vec![command("greet", "Said: !`echo hi`")]);
+
+    let err = runtime.expand_prompt("/greet").await.unwrap_err();
+
+    assert_eq!(
+        err,
+        "Bash command permission check failed for pattern \"!`echo hi`\": This command requires approval"
+    );
+}
+
+#[tokio::test]
+async fn expand_prompt_includes_referenced_files() {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(dir.path().join("notes.md"), "Ship on Friday\n").unwrap();
+    let mut runtime = command_runtime(&dir, vec![command("review", "Review @$1")]);
+
+    let prompt = runtime.expand_prompt("/review notes.md").await.unwrap();
+
+    assert_eq!(prompt, "Review Ship on Friday");
+}
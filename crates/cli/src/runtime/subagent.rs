@@ -0,0 +1,343 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Subagents run by the Task tool.
+//!
+//! A Task call matching one of the scenario's `subagents` runs a nested turn
+//! loop. The subagent's tool calls execute like the main agent's, its
+//! messages are written to the session JSONL as a sidechain (`isSidechain`
+//! with the subagent's `agentId`), and its final text becomes the Task
+//! result. SubagentStop hooks fire when it finishes and may send it back to
+//! work, like Stop hooks do for the main agent, until its responses run
+//! out. Its token usage is added to the turn's.
+//!
+//! Custom agents (from `--agents`, `agents/` directories and plugins) are
+//! valid `subagent_type`s alongside the built-in ones, and a subagent running
+//! as a custom agent can only call the tools in that agent's allowlist.
+
+use std::time::Duration;
+
+use serde_json::json;
+use sha2::{Digest, Sha256};
+
+use crate::config::{ResponseSpec, ToolCallSpec, UsageSpec};
+use crate::failure::FailureExecutor;
+use crate::hooks::HookMessage;
+use crate::output::BUILTIN_AGENTS;
+use crate::scenario::Scenario;
+use crate::state::{AgentDefinition, ContentBlock};
+use crate::time::Clock;
+use crate::tools::{ExecutionContext, ToolExecutionResult};
+
+use super::core::tool_use_id;
+use super::Runtime;
+
+/// Agent type used when a Task call names none.
+const DEFAULT_SUBAGENT_TYPE: &str = "general-purpose";
+
+impl Runtime {
+    /// The custom agent with the given name.
+    pub(super) fn agent(&self, name: &str) -> Option<&AgentDefinition> {
+        self.agents.iter().find(|agent| agent.name == name)
+    }
+
+    /// The custom agent selected with `--agent`, if any.
+    pub(super) fn session_agent(&self) -> Option<&AgentDefinition> {
+        self.cli.agent.as_deref().and_then(|name| self.agent(name))
+    }
+
+    /// Agent types a Task call may name: built-in, then custom agents.
+    pub fn agent_types(&self) -> Vec<String> {
+        BUILTIN_AGENTS
+            .iter()
+            .map(|name| name.to_string())
+            .chain(self.agents.iter().map(|agent| agent.name.clone()))
+            .collect()
+    }
+
+    /// An error result for a Task call naming an unknown agent type.
+    ///
+    /// This is the last check in `rejected_call`, after the agent's
+    /// allowlist and `input_error`, so the input already matches the Task
+    /// schema. Agent types defined only by the scenario's `subagents` are
+    /// accepted. A call with a canned `result` skips this check but not the
+    /// earlier two.
+    pub(super) fn unknown_agent_type(
+        &self,
+        call: &ToolCallSpec,
+        tool_use_id: &str,
+    ) -> Option<ToolExecutionResult> {
+        if call.tool != "Task" || call.result.is_some() {
+            return None;
+        }
+        let subagent_type = call.input["subagent_type"]
+            .as_str()
+            .unwrap_or(DEFAULT_SUBAGENT_TYPE);
+        let agent_types = self.agent_types();
+        let in_scenario = self.scenario.as_ref().is_some_and(|scenario| {
+            scenario
+                .config()
+                .subagents
+                .iter()
+                .any(|spec| spec.subagent_type.as_deref() == Some(subagent_type))
+        });
+        if in_scenario || agent_types.iter().any(|name| name == subagent_type) {
+            return None;
+        }
+        Some(ToolExecutionResult::error(
+            tool_use_id,
+            format!(
+                "Agent type '{}' not found. Available agents: {}",
+                subagent_type,
+                agent_types.join(", ")
+            ),
+        ))
+    }
+
+    /// The scenario subagent that handles a Task call, if any.
+    ///
+    /// Task calls with a canned `result`, or that no subagent matches, go
+    /// to the tool executor like any other tool.
+    pub(super) fn subagent_for(&self, call: &ToolCallSpec) -> Option<Scenario> {
+        if call.tool != "Task" || call.result.is_some() {
+            return None;
+        }
+        let subagent_type = call.input["subagent_type"]
+            .as_str()
+            .unwrap_or(DEFAULT_SUBAGENT_TYPE);
+        let prompt = call.input["prompt"].as_str().unwrap_or_default();
+        self.scenario.as_ref()?.subagent(subagent_type, prompt)
+    }
+
+    /// Run a subagent to completion and return its final text as the Task
+    /// result.
+    ///
+    /// `parent_uuid` is the main conversation's Task tool_use message, which
+    /// the subagent's prompt follows in the sidechain.
+    pub(super) async fn run_subagent(
+        &mut self,
+        call: &ToolCallSpec,
+        task_tool_use_id: &str,
+        mut subagent: Scenario,
+        parent_uuid: Option<&str>,
+        ctx: &ExecutionContext,
+    ) -> ToolExecutionResult {
+        let Some(prompt) = call.input["prompt"].as_str() else {
+            return ToolExecutionResult::error(
+                task_tool_use_id,
+                format!("Missing 'prompt' field in {} tool input", call.tool),
+            );
+        };
+        let agent = call.input["subagent_type"]
+            .as_str()
+            .and_then(|name| self.agent(name))
+            .cloned();
+        let started_ms = self.clock.now_millis();
+        let agent_id = agent_id(&self.session_id(), task_tool_use_id);
+
+        if let Some(ref writer) = self.state {
+            writer.write().set_sidechain(Some(agent_id.clone()));
+        }
+        let mut last_uuid = self.state.as_ref().and_then(|writer| {
+            writer
+                .write()
+                .record_user_message_with_parent(prompt, parent_uuid)
+                .ok()
+        });
+
+        let mut usage = UsageSpec::default();
+        let mut tool_use_count = 0;
+        let mut current_prompt = prompt.to_string();
+        let mut stop_hook_active = false;
+        let mut final_text = String::new();
+        let outcome = loop {
+            // A subagent out of responses stops with its last text. It answers
+            // once and then follows its turns, so this also ends SubagentStop
+            // continuations
+            let Some(matched) = subagent.match_prompt(&current_prompt) else {
+                break Ok(final_text);
+            };
+            if let Some(failure) = subagent.get_failure(&matched) {
+                break Err(FailureExecutor::error_text(failure));
+            }
+            let response = subagent
+                .get_response(&matched)
+                .cloned()
+                .unwrap_or(ResponseSpec::Simple(String::new()));
+            if let Some(delay) = response.delay_ms() {
+                self.clock.sleep(Duration::from_millis(delay)).await;
+            }
+            usage.add(&response.usage_or_estimate());
+
+            let tool_calls = response.tool_calls();
+            let mut content = response.thinking_blocks();
+            if !response.text().is_empty() || tool_calls.is_empty() {
+                content.push(ContentBlock::Text {
+                    text: response.text().to_string(),
+                });
+            }
+            last_uuid = self.record_subagent_message(last_uuid, content, tool_calls.is_empty());
+            final_text = response.text().to_string();
+
+            let mut result_texts = Vec::with_capacity(tool_calls.len());
+            for call in tool_calls {
+                let result = self
+                    .run_subagent_tool(call, agent.as_ref(), &mut last_uuid, ctx)
+                    .await;
+                tool_use_count += 1;
+                result_texts.push(result.text().unwrap_or_default().to_string());
+                result_texts.extend(result.meta_message);
+            }
+
+            // Follow the subagent's turns, answering each with the tool results
+            if subagent.has_active_sequence() {
+                current_prompt = result_texts.join("\n");
+                continue;
+            }
+
+            let stop_msg =
+                HookMessage::subagent_stop(self.context.session_id.to_string(), stop_hook_active);
+            let Some(continuation) = self.stop_hook_continuation(&stop_msg).await else {
+                break Ok(final_text);
+            };
+            last_uuid = match (&self.state, last_uuid.as_deref()) {
+                (Some(writer), Some(parent)) => writer
+                    .write()
+                    .record_user_message_with_parent(&continuation, Some(parent))
+                    .ok(),
+                _ => last_uuid,
+            };
+            current_prompt = continuation;
+            stop_hook_active = true;
+        };
+
+        if let Some(ref writer) = self.state {
+            writer.write().set_sidechain(None);
+        }
+        // The subagent's model calls count toward the session's usage
+        self.subagent_usage.add(&usage);
+
+        let text = match outcome {
+            Ok(text) => text,
+            Err(error) => return ToolExecutionResult::error(task_tool_use_id, error),
+        };
+        ToolExecutionResult::success_with_result(
+            task_tool_use_id,
+            text.clone(),
+            json!({
+                "status": "completed",
+                "prompt": prompt,
+                "agentId": agent_id,
+                "content": [{ "type": "text", "text": text }],
+                "totalDurationMs": self.clock.now_millis().saturating_sub(started_ms),
+                "totalTokens": usage.total(),
+                "totalToolUseCount": tool_use_count,
+                "usage": usage,
+            }),
+        )
+    }
+
+    /// Run one of a subagent's tool calls, recording it to the sidechain.
+    ///
+    /// `agent` is the custom agent the subagent runs as, whose allowlist
+    /// applies.
+    async fn run_subagent_tool(
+        &mut self,
+        call: &ToolCallSpec,
+        agent: Option<&AgentDefinition>,
+        last_uuid: &mut Option<String>,
+        ctx: &ExecutionContext,
+    ) -> ToolExecutionResult {
+        let tool_use_id = tool_use_id(self.next_tool_use_index);
+        self.next_tool_use_index += 1;
+
+        let tool_use_block = ContentBlock::ToolUse {
+            id: tool_use_id.clone(),
+            name: call.tool.clone(),
+            input: call.input.clone(),
+        };
+        let assistant_uuid = match (&self.state, last_uuid.as_deref()) {
+            (Some(writer), Some(parent)) => writer
+                .write()
+                .record_assistant_tool_use(parent, vec![tool_use_block])
+                .ok(),
+            _ => None,
+        };
+
+        let result = if call.tool == "Task" {
+            // Subagents cannot start subagents of their own
+            ToolExecutionResult::error(&tool_use_id, "No such tool available: Task")
+        } else if let Some(rejected) = self.rejected_call(call, &tool_use_id, agent) {
+            rejected
+        } else if let Some(blocked) = self.fire_pre_tool_use_hook(call, &tool_use_id).await {
+            blocked
+        } else {
+            let result = match self.load_skill(call, &tool_use_id) {
+                Some(result) => result,
+                None => self.executor.execute(call, &tool_use_id, ctx).await,
+            };
+            if result.needs_prompt {
+                // There is no one to ask from inside a subagent
+                ToolExecutionResult::permission_denied(
+                    &tool_use_id,
+                    format!(
+                        "{} requires approval, which subagents cannot request",
+                        call.tool
+                    ),
+                )
+            } else {
+                result
+            }
+        };
+
+        if let (Some(writer), Some(parent)) = (&self.state, assistant_uuid.as_deref()) {
+            let tool_use_result = result.tool_use_result().unwrap_or(json!({}));
+            let recorded = writer.write().record_tool_result(
+                &tool_use_id,
+                result.text().unwrap_or(""),
+                parent,
+                tool_use_result,
+            );
+            *last_uuid = recorded
+                .ok()
+                .map(|uuid| self.record_meta_message(&result, uuid));
+        }
+        self.fire_post_tool_use_hook(call, &tool_use_id, &result)
+            .await;
+        result
+    }
+
+    /// Record a subagent's assistant message after `parent`, returning the
+    /// new message's UUID (or `parent` when there is nothing to record).
+    fn record_subagent_message(
+        &self,
+        parent: Option<String>,
+        content: Vec<ContentBlock>,
+        is_final: bool,
+    ) -> Option<String> {
+        let (Some(writer), Some(parent_uuid)) = (&self.state, parent.as_deref()) else {
+            return parent;
+        };
+        if content.is_empty() {
+            return parent;
+        }
+        let mut writer = writer.write();
+        let recorded = if is_final {
+            writer.record_assistant_content_final(parent_uuid, content)
+        } else {
+            writer.record_assistant_content(parent_uuid, content)
+        };
+        recorded.ok().or(parent)
+    }
+}
+
+/// Agent ID for the subagent a Task call starts: eight hex digits derived
+/// from the session and the call, so reruns write the same sidechain.
+fn agent_id(session_id: &str, task_tool_use_id: &str) -> String {
+    let digest = Sha256::digest(format!("{}/{}", session_id, task_tool_use_id));
+    digest[..4].iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+#[path = "subagent_tests.rs"]
+mod tests;
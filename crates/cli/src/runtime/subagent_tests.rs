@@ -0,0 +1,290 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use clap::Parser;
+use std::fs;
+
+use crate::cli::Cli;
+use crate::config::{ResolvedTimeouts, ScenarioConfig, ToolCallSpec};
+use crate::hooks::{HookConfig, HookEvent, HookExecutor};
+use crate::scenario::Scenario;
+use crate::state::agents::parse_agents_json;
+use crate::tools::executor::MockExecutor;
+
+use super::super::{Runtime, RuntimeContext};
+
+const SUBAGENTS: &str = r#"
+[[subagents]]
+subagent_type = "Explore"
+response = { text = "Looking", tool_calls = [{ tool = "Glob", input = { pattern = "*.rs" }, result = "src/main.rs" }] }
+turns = [{ expect = { type = "contains", text = "main.rs" }, response = "The entry point is src/main.rs" }]
+
+[[subagents]]
+subagent_type = "Nested"
+response = { text = "", tool_calls = [{ tool = "Task", input = { prompt = "again" } }] }
+turns = [{ expect = { type = "any" }, response = "Gave up" }]
+
+[[subagents]]
+subagent_type = "Unlucky"
+response = { text = "Searching", tool_calls = [{ tool = "Glob", input = { pattern = "*.py" }, result = "No files found" }] }
+turns = [{ expect = { type = "contains", text = "main.rs" }, response = "Found it" }]
+
+[[subagents]]
+subagent_type = "Stubborn"
+response = "Still working"
+
+[[subagents]]
+subagent_type = "reviewer"
+response = { text = "Checking", tool_calls = [{ tool = "Glob", input = { pattern = "*.rs" }, result = "src/main.rs" }] }
+turns = [{ expect = { type = "contains", text = "No such tool available: Glob" }, response = "Glob is off limits" }]
+"#;
+
+const AGENTS: &str =
+    r#"{"reviewer": {"description": "Reviews code", "prompt": "Review", "tools": ["Read"]}}"#;
+
+fn build_runtime(hook_executor: Option<HookExecutor>) -> Runtime {
+    build_runtime_with_args(hook_executor, &[])
+}
+
+fn build_runtime_with_args(hook_executor: Option<HookExecutor>, args: &[&str]) -> Runtime {
+    let cli = Cli::try_parse_from(["claude", "-p", "test"].iter().chain(args)).unwrap();
+    let config: ScenarioConfig = toml::from_str(SUBAGENTS).unwrap();
+    let scenario = Scenario::from_config(config).unwrap();
+    let context = RuntimeContext::build(Some(scenario.config()), &cli);
+    Runtime::new(
+        context,
+        Some(scenario),
+        Box::new(MockExecutor::new()),
+        None, // state
+        hook_executor,
+        None, // mcp_manager
+        cli,
+        ResolvedTimeouts::default(),
+    )
+    .with_agents(parse_agents_json(AGENTS).unwrap())
+}
+
+fn task(subagent_type: &str, prompt: &str) -> Vec<ToolCallSpec> {
+    vec![ToolCallSpec {
+        tool: "Task".to_string(),
+        input: serde_json::json!({
+            "description": "Find things",
+            "prompt": prompt,
+            "subagent_type": subagent_type,
+        }),
+        result: None,
+    }]
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn task_returns_subagent_final_text() {
+    let mut runtime = build_runtime(None);
+
+    let (results, pending) = runtime
+        .execute_tools_for_turn("go", &[], "", &task("Explore", "Find the entry point"))
+        .await;
+
+    assert!(pending.is_none());
+    assert_eq!(results.len(), 1);
+    assert!(!results[0].is_error);
+    assert_eq!(results[0].text(), Some("The entry point is src/main.rs"));
+
+    let tool_use_result = results[0].tool_use_result().unwrap();
+    assert_eq!(tool_use_result["status"], "completed");
+    assert_eq!(tool_use_result["prompt"], "Find the entry point");
+    assert_eq!(tool_use_result["totalToolUseCount"], 1);
+    assert_eq!(
+        tool_use_result["agentId"],
+        super::agent_id(&runtime.session_id(), "toolu_00000000")
+    );
+    assert_eq!(
+        tool_use_result["content"][0]["text"],
+        "The entry point is src/main.rs"
+    );
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn subagent_tool_ids_continue_the_session_sequence() {
+    let mut runtime = build_runtime(None);
+
+    runtime
+        .execute_tools_for_turn("go", &[], "", &task("Explore", "Find it"))
+        .await;
+
+    // The Task call and the subagent's Glob call each took an ID
+    assert_eq!(runtime.next_tool_use_index, 2);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn task_without_matching_subagent_goes_to_executor() {
+    let mut runtime = build_runtime(None);
+
+    let (results, _) = runtime
+        .execute_tools_for_turn("go", &[], "", &task("Plan", "Plan it"))
+        .await;
+
+    assert!(results[0].is_error);
+    assert!(results[0].text().unwrap().contains("Task"));
+    assert!(results[0].tool_use_result().is_none());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn subagents_cannot_start_subagents() {
+    let mut runtime = build_runtime(None);
+
+    let (results, _) = runtime
+        .execute_tools_for_turn("go", &[], "", &task("Nested", "Delegate this"))
+        .await;
+
+    assert_eq!(results[0].text(), Some("Gave up"));
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn subagent_stops_with_last_text_when_turn_does_not_match() {
+    let mut runtime = build_runtime(None);
+
+    let (results, _) = runtime
+        .execute_tools_for_turn("go", &[], "", &task("Unlucky", "Find main"))
+        .await;
+
+    assert_eq!(results[0].text(), Some("Searching"));
+    assert_eq!(
+        results[0].tool_use_result().unwrap()["totalToolUseCount"],
+        1
+    );
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn subagent_stop_hook_fires_when_subagent_finishes() {
+    let tmp = tempfile::tempdir().unwrap();
+    let marker = tmp.path().join("subagent_stop");
+    let script = tmp.path().join("subagent_stop.sh");
+    fs::write(
+        &script,
+        format!("#!/bin/bash\ncat > {}\n", marker.to_string_lossy()),
+    )
+    .unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    let mut hook_executor = HookExecutor::new();
+    hook_executor.register(HookEvent::SubagentStop, HookConfig::new(&script, 5000));
+    let mut runtime = build_runtime(Some(hook_executor));
+
+    runtime
+        .execute_tools_for_turn("go", &[], "", &task("Explore", "Find it"))
+        .await;
+
+    let input: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&marker).unwrap()).unwrap();
+    assert_eq!(input["hook_event_name"], "SubagentStop");
+    assert_eq!(input["stop_hook_active"], false);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn blocking_subagent_stop_hook_ends_when_responses_run_out() {
+    let tmp = tempfile::tempdir().unwrap();
+    let log = tmp.path().join("subagent_stop.log");
+    let script = tmp.path().join("subagent_stop.sh");
+    fs::write(
+        &script,
+        format!(
+            "#!/bin/bash\necho fired >> {}\necho '{{\"decision\": \"block\", \"reason\": \"keep going\"}}'\n",
+            log.to_string_lossy()
+        ),
+    )
+    .unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    let mut hook_executor = HookExecutor::new();
+    hook_executor.register(HookEvent::SubagentStop, HookConfig::new(&script, 5000));
+    let mut runtime = build_runtime(Some(hook_executor));
+
+    let (results, _) = runtime
+        .execute_tools_for_turn("go", &[], "", &task("Stubborn", "Finish up"))
+        .await;
+
+    // The continuation finds no response left, so the subagent stops
+    assert_eq!(results[0].text(), Some("Still working"));
+    assert_eq!(fs::read_to_string(&log).unwrap().lines().count(), 1);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn subagent_usage_counts_toward_the_turn() {
+    let mut runtime = build_runtime(None);
+
+    let (results, _) = runtime
+        .execute_tools_for_turn("go", &[], "", &task("Explore", "Find it"))
+        .await;
+
+    let usage = &results[0].tool_use_result().unwrap()["usage"];
+    assert!(runtime.subagent_usage.output_tokens > 0);
+    assert_eq!(
+        serde_json::to_value(&runtime.subagent_usage).unwrap(),
+        *usage
+    );
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn custom_agent_subagent_is_limited_to_its_tools() {
+    let mut runtime = build_runtime(None);
+
+    let (results, _) = runtime
+        .execute_tools_for_turn("go", &[], "", &task("reviewer", "Review main"))
+        .await;
+
+    assert_eq!(results[0].text(), Some("Glob is off limits"));
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn task_with_unknown_agent_type_fails() {
+    let mut runtime = build_runtime(None);
+
+    let (results, _) = runtime
+        .execute_tools_for_turn("go", &[], "", &task("nobody", "Do it"))
+        .await;
+
+    assert!(results[0].is_error);
+    let text = results[0].text().unwrap();
+    assert!(
+        text.starts_with("Agent type 'nobody' not found."),
+        "{}",
+        text
+    );
+    assert!(text.contains("general-purpose"));
+    assert!(text.ends_with("reviewer"));
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn session_agent_allowlist_applies_to_main_conversation() {
+    let mut runtime = build_runtime_with_args(None, &["--agent", "reviewer"]);
+    let glob = vec![ToolCallSpec {
+        tool: "Glob".to_string(),
+        input: serde_json::json!({ "pattern": "*.rs" }),
+        result: Some("src/main.rs".to_string()),
+    }];
+
+    let (results, _) = runtime.execute_tools_for_turn("go", &[], "", &glob).await;
+
+    assert!(results[0].is_error);
+    assert_eq!(results[0].text(), Some("No such tool available: Glob"));
+}
+
+#[test]
+fn agent_id_is_derived_from_session_and_call() {
+    let id = super::agent_id("session-1", "toolu_00000000");
+    assert_eq!(id.len(), 8);
+    assert!(id.chars().all(|c| c.is_ascii_hexdigit()));
+    assert_eq!(id, super::agent_id("session-1", "toolu_00000000"));
+    assert_ne!(id, super::agent_id("session-1", "toolu_00000001"));
+    assert_ne!(id, super::agent_id("session-2", "toolu_00000000"));
+}
@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Task list checks at the end of a session.
+
+use super::Runtime;
+use crate::config::ExpectedTask;
+use crate::state::Task;
+
+impl Runtime {
+    /// Check the final task list against the scenario's `[tasks] expect`.
+    ///
+    /// Tasks are compared in ID order; only the fields set on each expected
+    /// task are checked. Returns an error message listing every mismatch.
+    pub fn finish_tasks(&self) -> Result<(), String> {
+        let Some(expected) = self
+            .scenario
+            .as_ref()
+            .and_then(|s| s.config().tasks.as_ref())
+            .and_then(|tasks| tasks.expect.as_ref())
+        else {
+            return Ok(());
+        };
+
+        let actual = self.task_store.list();
+        let mut mismatches = Vec::new();
+        if actual.len() != expected.len() {
+            mismatches.push(format!(
+                "  expected {} tasks, found {}",
+                expected.len(),
+                actual.len()
+            ));
+        }
+        for (index, want) in expected.iter().enumerate() {
+            match actual.get(index) {
+                Some(task) => mismatches.extend(task_mismatches(want, task)),
+                None => mismatches.push(format!("  {}: missing", want.subject)),
+            }
+        }
+
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "Task list does not match expected tasks\n{}",
+                mismatches.join("\n")
+            ))
+        }
+    }
+}
+
+/// Differences between an expected task and the task in its position.
+fn task_mismatches(want: &ExpectedTask, task: &Task) -> Vec<String> {
+    let mut fields: Vec<(&str, String, String)> = Vec::new();
+    fields.push(("subject", want.subject.clone(), task.subject.clone()));
+    if let Some(ref id) = want.id {
+        fields.push(("id", id.clone(), task.id.clone()));
+    }
+    if let Some(ref status) = want.status {
+        fields.push(("status", status.clone(), task.status.to_string()));
+    }
+    if let Some(ref owner) = want.owner {
+        fields.push((
+            "owner",
+            owner.clone(),
+            task.owner.clone().unwrap_or_default(),
+        ));
+    }
+    if let Some(ref blocked_by) = want.blocked_by {
+        fields.push((
+            "blocked_by",
+            blocked_by.join(", "),
+            task.blocked_by.join(", "),
+        ));
+    }
+    if let Some(ref blocks) = want.blocks {
+        fields.push(("blocks", blocks.join(", "), task.blocks.join(", ")));
+    }
+
+    fields
+        .into_iter()
+        .filter(|(_, want, actual)| want != actual)
+        .map(|(field, want, actual)| {
+            format!(
+                "  #{} {}: expected {} '{}', found '{}'",
+                task.id, task.subject, field, want, actual
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "tasks_tests.rs"]
+mod tests;
@@ -0,0 +1,112 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use clap::Parser;
+
+use crate::cli::Cli;
+use crate::config::{ResolvedTimeouts, ScenarioConfig};
+use crate::scenario::Scenario;
+use crate::state::{TaskStatus, TaskUpdate};
+use crate::tools::executor::MockExecutor;
+
+use super::super::{Runtime, RuntimeContext};
+
+const EXPECTED: &str = r#"
+[[tasks.expect]]
+subject = "Build"
+status = "completed"
+blocks = ["2"]
+
+[[tasks.expect]]
+subject = "Deploy"
+id = "2"
+blocked_by = ["1"]
+"#;
+
+fn tasks_runtime(scenario: &str) -> Runtime {
+    let cli = Cli::try_parse_from(["claude", "-p", "hello"]).unwrap();
+    let config: ScenarioConfig = toml::from_str(scenario).unwrap();
+    let scenario = Scenario::from_config(config).unwrap();
+    let context = RuntimeContext::build(Some(scenario.config()), &cli);
+    Runtime::new(
+        context,
+        Some(scenario),
+        Box::new(MockExecutor::new()),
+        None,
+        None,
+        None,
+        cli,
+        ResolvedTimeouts::default(),
+    )
+}
+
+fn add_build_and_deploy(runtime: &Runtime) {
+    let store = runtime.task_store();
+    store.create("Build", "", None, None).unwrap();
+    store.create("Deploy", "", None, None).unwrap();
+    store
+        .update(
+            "2",
+            TaskUpdate {
+                add_blocked_by: vec!["1".into()],
+                ..Default::default()
+            },
+        )
+        .unwrap();
+}
+
+#[test]
+fn finish_tasks_without_expectations_passes() {
+    let runtime = tasks_runtime("");
+    add_build_and_deploy(&runtime);
+
+    runtime.finish_tasks().unwrap();
+}
+
+#[test]
+fn finish_tasks_accepts_matching_graph() {
+    let runtime = tasks_runtime(EXPECTED);
+    add_build_and_deploy(&runtime);
+    runtime
+        .task_store()
+        .update(
+            "1",
+            TaskUpdate {
+                status: Some(TaskStatus::Completed),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+    runtime.finish_tasks().unwrap();
+}
+
+#[test]
+fn finish_tasks_lists_mismatches() {
+    let runtime = tasks_runtime(EXPECTED);
+    add_build_and_deploy(&runtime);
+    runtime
+        .task_store()
+        .create("Verify", "", None, None)
+        .unwrap();
+
+    let err = runtime.finish_tasks().unwrap_err();
+
+    assert!(err.contains("expected 2 tasks, found 3"), "{}", err);
+    assert!(
+        err.contains("#1 Build: expected status 'completed', found 'pending'"),
+        "{}",
+        err
+    );
+    assert!(!err.contains("Deploy"), "{}", err);
+}
+
+#[test]
+fn invalid_expected_status_is_rejected() {
+    let config: ScenarioConfig =
+        toml::from_str("[[tasks.expect]]\nsubject = \"Build\"\nstatus = \"done\"\n").unwrap();
+
+    assert!(config.validate().unwrap_err().contains("done"));
+}
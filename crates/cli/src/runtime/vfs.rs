@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Virtual filesystem export and checks at the end of a session.
+
+use std::path::Path;
+
+use super::Runtime;
+
+impl Runtime {
+    /// Export and check the in-memory filesystem once the session is over.
+    ///
+    /// The tree is written to the configured `export_dir`, then each
+    /// `expect` entry is compared with the final contents. Returns an error
+    /// message listing every missing or differing file.
+    pub fn finish_vfs(&self) -> Result<(), String> {
+        let Some(ref vfs) = self.vfs else {
+            return Ok(());
+        };
+
+        if let Some(ref dir) = self.vfs_config.export_dir {
+            vfs.export(Path::new(dir))
+                .map_err(|e| format!("Failed to export vfs to '{}': {}", dir, e))?;
+        }
+
+        let mismatches: Vec<String> = self
+            .vfs_config
+            .expect
+            .iter()
+            .filter_map(|file| {
+                let path = vfs.resolve(&file.path, None);
+                match vfs.read(&path) {
+                    Ok(actual) if actual == file.content_text().as_bytes() => None,
+                    Ok(_) => Some(format!("  {}: content differs", file.path)),
+                    Err(_) => Some(format!("  {}: missing", file.path)),
+                }
+            })
+            .collect();
+
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "Virtual filesystem does not match expected files\n{}",
+                mismatches.join("\n")
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "vfs_tests.rs"]
+mod tests;
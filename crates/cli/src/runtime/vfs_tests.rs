@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::sync::Arc;
+
+use clap::Parser;
+use serde_json::json;
+use tempfile::TempDir;
+
+use crate::cli::Cli;
+use crate::config::{FileSpec, ResolvedTimeouts, VfsConfig};
+use crate::tools::executor::MockExecutor;
+use crate::tools::Vfs;
+
+use super::super::{Runtime, RuntimeContext};
+
+fn vfs_runtime(config: VfsConfig) -> Runtime {
+    let cli = Cli::try_parse_from(["claude", "-p", "hello"]).unwrap();
+    let context = RuntimeContext::build(None, &cli);
+    let vfs = Arc::new(Vfs::new("/work"));
+    vfs.insert("src/main.rs", "fn main() {}");
+    Runtime::new(
+        context,
+        None,
+        Box::new(MockExecutor::new()),
+        None,
+        None,
+        None,
+        cli,
+        ResolvedTimeouts::default(),
+    )
+    .with_vfs(vfs, config)
+}
+
+fn file(path: &str, content: &str) -> FileSpec {
+    FileSpec {
+        path: path.to_string(),
+        content: json!(content),
+    }
+}
+
+#[test]
+fn finish_vfs_exports_tree() {
+    let out = TempDir::new().unwrap();
+    let runtime = vfs_runtime(VfsConfig {
+        export_dir: Some(out.path().to_string_lossy().into_owned()),
+        ..Default::default()
+    });
+
+    runtime.finish_vfs().unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(out.path().join("src/main.rs")).unwrap(),
+        "fn main() {}"
+    );
+}
+
+#[test]
+fn finish_vfs_accepts_matching_files() {
+    let runtime = vfs_runtime(VfsConfig {
+        expect: vec![file("src/main.rs", "fn main() {}")],
+        ..Default::default()
+    });
+
+    runtime.finish_vfs().unwrap();
+}
+
+#[test]
+fn finish_vfs_lists_mismatches() {
+    let runtime = vfs_runtime(VfsConfig {
+        expect: vec![file("src/main.rs", "changed"), file("README.md", "docs")],
+        ..Default::default()
+    });
+
+    let err = runtime.finish_vfs().unwrap_err();
+    assert!(err.contains("src/main.rs: content differs"), "{}", err);
+    assert!(err.contains("README.md: missing"), "{}", err);
+}
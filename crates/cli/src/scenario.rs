@@ -3,7 +3,9 @@
 
 //! Scenario matching and loading.
 
-use crate::config::{FailureSpec, PatternSpec, ResponseSpec, ScenarioConfig, ToolCallSpec};
+use crate::config::{
+    FailureSpec, PatternSpec, ResponseRule, ResponseSpec, ScenarioConfig, ToolCallSpec,
+};
 use std::path::Path;
 use std::sync::Arc;
 use thiserror::Error;
@@ -63,6 +65,8 @@ pub struct Scenario {
     current_turn: usize,
     /// Compiled matchers for turns (indexed by rule_index, then turn_index)
     compiled_turns: Vec<Vec<Matcher>>,
+    /// Compiled task prompt matchers, one per subagent
+    compiled_subagents: Vec<Matcher>,
 }
 
 impl std::fmt::Debug for Scenario {
@@ -74,6 +78,7 @@ impl std::fmt::Debug for Scenario {
             .field("active_rule", &self.active_rule)
             .field("current_turn", &self.current_turn)
             .field("compiled_turns_count", &self.compiled_turns.len())
+            .field("compiled_subagents_count", &self.compiled_subagents.len())
             .finish()
     }
 }
@@ -142,6 +147,17 @@ impl Scenario {
             compiled_turns.push(turn_matchers);
         }
 
+        // Compile subagent prompt patterns and their turn patterns
+        let mut compiled_subagents = Vec::new();
+        for subagent in &config.subagents {
+            compiled_subagents.push(compile_pattern(
+                subagent.pattern.as_ref().unwrap_or(&PatternSpec::Any),
+            )?);
+            for turn in &subagent.turns {
+                compile_pattern(&turn.expect)?;
+            }
+        }
+
         let match_counts = vec![0; config.responses.len()];
 
         Ok(Self {
@@ -151,6 +167,7 @@ impl Scenario {
             active_rule: None,
             current_turn: 0,
             compiled_turns,
+            compiled_subagents,
         })
     }
 
@@ -252,6 +269,38 @@ impl Scenario {
         self.current_turn = 0;
     }
 
+    /// Scenario for the subagent that handles a Task call, if any.
+    ///
+    /// The first subagent whose type and prompt pattern both match is used.
+    /// Its scenario answers the task prompt with the subagent's response and
+    /// then follows its turns until one fails to match, starting fresh for
+    /// every Task call.
+    pub fn subagent(&self, subagent_type: &str, prompt: &str) -> Option<Scenario> {
+        let (spec, _) = self
+            .config
+            .subagents
+            .iter()
+            .zip(&self.compiled_subagents)
+            .find(|(spec, matcher)| {
+                spec.subagent_type
+                    .as_deref()
+                    .is_none_or(|t| t == subagent_type)
+                    && matcher(prompt)
+            })?;
+        let config = ScenarioConfig {
+            name: format!("{} subagent", subagent_type),
+            responses: vec![ResponseRule {
+                pattern: PatternSpec::Any,
+                response: Some(spec.response.clone()),
+                failure: None,
+                max_matches: Some(1),
+                turns: spec.turns.clone(),
+            }],
+            ..Default::default()
+        };
+        Self::from_config(config).ok()
+    }
+
     /// Get the default response if configured
     pub fn default_response(&self) -> Option<&ResponseSpec> {
         self.config.default_response.as_ref()
@@ -323,6 +372,50 @@ fn resolve_file_references_in_config(
         }
     }
 
+    // Resolve in subagent responses and their turns
+    for subagent in &mut config.subagents {
+        resolve_file_references_in_response(&mut subagent.response, base_dir)?;
+        for turn in &mut subagent.turns {
+            resolve_file_references_in_response(&mut turn.response, base_dir)?;
+        }
+    }
+
+    // Resolve in virtual filesystem seeds and expectations
+    for file in &mut config.files {
+        file.content = resolve_file_references_in_value(file.content.take(), base_dir)?;
+    }
+    // Resolve tool commands given as paths (bare names are looked up on PATH)
+    if let Some(ref mut tool_execution) = config.tool_execution {
+        for command in tool_execution
+            .tools
+            .values_mut()
+            .filter_map(|tool| tool.command.as_mut())
+        {
+            if command.contains('/') {
+                *command = base_dir.join(&*command).to_string_lossy().into_owned();
+            }
+        }
+    }
+    if let Some(ref mut vfs) = config
+        .tool_execution
+        .as_mut()
+        .and_then(|te| te.vfs.as_mut())
+    {
+        for file in &mut vfs.expect {
+            file.content = resolve_file_references_in_value(file.content.take(), base_dir)?;
+        }
+        if let Some(ref mut dir) = vfs.fixture_dir {
+            *dir = base_dir.join(&*dir).to_string_lossy().into_owned();
+        }
+    }
+
+    // Resolve in web page bodies
+    if let Some(ref mut web) = config.web {
+        for page in web.pages.values_mut() {
+            page.body = resolve_file_references_in_value(page.body.take(), base_dir)?;
+        }
+    }
+
     Ok(())
 }
 
@@ -407,6 +500,12 @@ fn compile_pattern(spec: &PatternSpec) -> Result<Matcher, ScenarioError> {
             Ok(Arc::new(move |prompt| prompt.contains(&text)))
         }
         PatternSpec::Any => Ok(Arc::new(|_| true)),
+        PatternSpec::Skill { name } => {
+            let launched = crate::state::skills::launch_message(name);
+            Ok(Arc::new(move |prompt| {
+                prompt.lines().any(|line| line == launched)
+            }))
+        }
     }
 }
 
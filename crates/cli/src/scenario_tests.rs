@@ -91,6 +91,29 @@ fn test_contains_match() {
     assert!(scenario.match_prompt("everything is fine").is_none());
 }
 
+#[test]
+fn test_skill_match() {
+    let config = simple_config(vec![ResponseRule {
+        pattern: PatternSpec::Skill {
+            name: "deploy".to_string(),
+        },
+        response: Some(ResponseSpec::Simple("Deploying".to_string())),
+        failure: None,
+        max_matches: None,
+        turns: Vec::new(),
+    }]);
+
+    let mut scenario = Scenario::from_config(config).unwrap();
+
+    assert!(scenario
+        .match_prompt("Launching skill: deploy\nBase directory for this skill: /s")
+        .is_some());
+    assert!(scenario
+        .match_prompt("Launching skill: deploy-prod")
+        .is_none());
+    assert!(scenario.match_prompt("use the deploy skill").is_none());
+}
+
 #[test]
 fn test_any_match() {
     let config = simple_config(vec![ResponseRule {
@@ -663,3 +686,93 @@ fn test_response_text_or_default_returns_empty_when_no_default() {
     let mut scenario = Scenario::from_config(config).unwrap();
     assert_eq!(scenario.response_text_or_default("no match"), "");
 }
+
+#[test]
+fn test_subagent_matches_type_then_prompt() {
+    let config: ScenarioConfig = toml::from_str(
+        r#"
+[[subagents]]
+subagent_type = "Explore"
+pattern = { type = "contains", text = "auth" }
+response = "Auth lives in src/auth.rs"
+
+[[subagents]]
+subagent_type = "Explore"
+response = "Nothing found"
+
+[[subagents]]
+response = "Generic answer"
+"#,
+    )
+    .unwrap();
+    let scenario = Scenario::from_config(config).unwrap();
+
+    let text = |subagent_type: &str, prompt: &str| {
+        let mut subagent = scenario.subagent(subagent_type, prompt).unwrap();
+        subagent.response_text_or_default(prompt)
+    };
+    assert_eq!(
+        text("Explore", "Find the auth code"),
+        "Auth lives in src/auth.rs"
+    );
+    assert_eq!(text("Explore", "Find the parser"), "Nothing found");
+    assert_eq!(
+        text("general-purpose", "Find the auth code"),
+        "Generic answer"
+    );
+}
+
+#[test]
+fn test_subagent_follows_turns_from_a_fresh_start() {
+    let config: ScenarioConfig = toml::from_str(
+        r#"
+[[subagents]]
+response = { text = "", tool_calls = [{ tool = "Glob", input = { pattern = "*.rs" } }] }
+turns = [{ expect = { type = "any" }, response = "Found main.rs" }]
+"#,
+    )
+    .unwrap();
+    let scenario = Scenario::from_config(config).unwrap();
+
+    for _ in 0..2 {
+        let mut subagent = scenario.subagent("Explore", "List sources").unwrap();
+        let first = subagent.match_prompt("List sources").unwrap();
+        assert_eq!(subagent.get_response(&first).unwrap().tool_calls().len(), 1);
+        assert!(subagent.has_active_sequence());
+        assert_eq!(
+            subagent.response_text_or_default("main.rs"),
+            "Found main.rs"
+        );
+        assert!(!subagent.has_active_sequence());
+    }
+}
+
+#[test]
+fn test_no_subagent_for_unmatched_task() {
+    let config: ScenarioConfig = toml::from_str(
+        r#"
+[[subagents]]
+subagent_type = "Plan"
+response = "A plan"
+"#,
+    )
+    .unwrap();
+    let scenario = Scenario::from_config(config).unwrap();
+    assert!(scenario.subagent("Explore", "Find it").is_none());
+}
+
+#[test]
+fn test_invalid_subagent_pattern_rejected() {
+    let config: ScenarioConfig = toml::from_str(
+        r#"
+[[subagents]]
+pattern = { type = "regex", pattern = "(" }
+response = "x"
+"#,
+    )
+    .unwrap();
+    assert!(matches!(
+        Scenario::from_config(config),
+        Err(ScenarioError::Regex(_))
+    ));
+}
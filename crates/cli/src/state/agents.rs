@@ -0,0 +1,255 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Custom agent definitions.
+//!
+//! Agents come from four places, later sources overriding earlier ones with
+//! the same name:
+//! 1. Plugins (`<plugin>/agents/*.md`), named `<plugin>:<agent>`
+//! 2. User (`~/.claude/agents/*.md`)
+//! 3. The `--agents` JSON flag
+//! 4. Project (`.claude/agents/*.md`)
+//!
+//! Markdown definitions carry `name`, `description`, `tools` and `model` in
+//! their frontmatter, and the agent's system prompt as the body.
+
+use super::frontmatter::Frontmatter;
+use super::settings_source::SettingSource;
+use crate::output::print_warning;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Where an agent was defined.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AgentSource {
+    /// A `--plugin-dir` plugin, by plugin name.
+    Plugin(String),
+    /// `~/.claude/agents/`
+    User,
+    /// The `--agents` flag.
+    Cli,
+    /// `.claude/agents/`
+    Project,
+}
+
+/// A custom agent.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AgentDefinition {
+    /// Name the Task tool's `subagent_type` refers to.
+    pub name: String,
+    /// When to use the agent.
+    pub description: String,
+    /// The agent's system prompt.
+    pub prompt: String,
+    /// Tools the agent may call; `None` allows every tool.
+    pub tools: Option<Vec<String>>,
+    /// Model alias or ID, or `inherit`.
+    pub model: Option<String>,
+    /// Where the agent was defined.
+    pub source: AgentSource,
+}
+
+impl AgentDefinition {
+    /// Parse a markdown agent definition. The name defaults to the file stem.
+    pub fn from_markdown(
+        content: &str,
+        file_stem: &str,
+        source: AgentSource,
+    ) -> Result<Self, String> {
+        let doc = Frontmatter::parse(content)?;
+        let Some(description) = doc.text("description") else {
+            return Err("missing required 'description' field".to_string());
+        };
+        Ok(Self {
+            name: doc.text("name").unwrap_or(file_stem).to_string(),
+            description: description.to_string(),
+            tools: doc.list("tools"),
+            model: doc.text("model").map(str::to_string),
+            prompt: doc.body,
+            source,
+        })
+    }
+
+    /// Whether the agent may call `tool`.
+    ///
+    /// Allowlist entries may carry a rule specifier, as in `Bash(git:*)`,
+    /// which allows the tool itself.
+    pub fn allows_tool(&self, tool: &str) -> bool {
+        let Some(ref tools) = self.tools else {
+            return true;
+        };
+        tools.iter().any(|entry| {
+            let name = entry
+                .split_once('(')
+                .map_or(entry.as_str(), |(name, _)| name);
+            name == "*" || name == tool
+        })
+    }
+}
+
+/// An agent in the `--agents` JSON object.
+#[derive(Deserialize)]
+struct JsonAgent {
+    description: String,
+    prompt: String,
+    #[serde(default)]
+    tools: Option<Vec<String>>,
+    #[serde(default)]
+    model: Option<String>,
+}
+
+/// Parse the `--agents` JSON object, keyed by agent name.
+pub fn parse_agents_json(input: &str) -> Result<Vec<AgentDefinition>, String> {
+    let agents: BTreeMap<String, JsonAgent> =
+        serde_json::from_str(input).map_err(|e| e.to_string())?;
+    Ok(agents
+        .into_iter()
+        .map(|(name, agent)| AgentDefinition {
+            name,
+            description: agent.description,
+            prompt: agent.prompt,
+            tools: agent.tools,
+            model: agent.model,
+            source: AgentSource::Cli,
+        })
+        .collect())
+}
+
+/// Directories and flags to load agents from.
+#[derive(Clone, Debug, Default)]
+pub struct AgentsLoader {
+    /// Plugin roots (`--plugin-dir`).
+    pub plugin_dirs: Vec<PathBuf>,
+    /// User agents directory (~/.claude/agents)
+    pub user: Option<PathBuf>,
+    /// `--agents` JSON.
+    pub cli_json: Option<String>,
+    /// Project agents directory (.claude/agents)
+    pub project: Option<PathBuf>,
+}
+
+impl AgentsLoader {
+    /// Resolve agent locations, loading user and project agents only for
+    /// the given setting sources (all when `None`).
+    pub fn resolve(
+        state_dir: Option<&Path>,
+        working_dir: &Path,
+        sources: Option<&[SettingSource]>,
+    ) -> Self {
+        let sources = sources.unwrap_or(SettingSource::all());
+        Self {
+            plugin_dirs: Vec::new(),
+            user: state_dir
+                .filter(|_| sources.contains(&SettingSource::User))
+                .map(|dir| dir.join("agents")),
+            cli_json: None,
+            project: sources
+                .contains(&SettingSource::Project)
+                .then(|| working_dir.join(".claude").join("agents")),
+        }
+    }
+
+    /// Also load agents from these plugin directories.
+    pub fn with_plugin_dirs(mut self, plugin_dirs: &[String]) -> Self {
+        self.plugin_dirs = plugin_dirs.iter().map(PathBuf::from).collect();
+        self
+    }
+
+    /// Also load agents from `--agents` JSON.
+    pub fn with_cli_json(mut self, cli_json: Option<&str>) -> Self {
+        self.cli_json = cli_json.map(str::to_string);
+        self
+    }
+
+    /// Load all agents, sorted by name.
+    ///
+    /// Invalid definitions are skipped with a warning.
+    pub fn load(&self) -> Vec<AgentDefinition> {
+        let mut agents = BTreeMap::new();
+        let mut add = |found: Vec<AgentDefinition>| {
+            for agent in found {
+                agents.insert(agent.name.clone(), agent);
+            }
+        };
+
+        for plugin_dir in &self.plugin_dirs {
+            let plugin = plugin_name(plugin_dir);
+            let mut found = load_dir(
+                &plugin_dir.join("agents"),
+                AgentSource::Plugin(plugin.clone()),
+            );
+            for agent in &mut found {
+                agent.name = format!("{}:{}", plugin, agent.name);
+            }
+            add(found);
+        }
+        if let Some(ref dir) = self.user {
+            add(load_dir(dir, AgentSource::User));
+        }
+        if let Some(ref json) = self.cli_json {
+            match parse_agents_json(json) {
+                Ok(found) => add(found),
+                Err(e) => print_warning(format_args!("Failed to parse --agents: {}", e)),
+            }
+        }
+        if let Some(ref dir) = self.project {
+            add(load_dir(dir, AgentSource::Project));
+        }
+
+        agents.into_values().collect()
+    }
+}
+
+/// Load the `*.md` agent definitions in a directory.
+fn load_dir(dir: &Path, source: AgentSource) -> Vec<AgentDefinition> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "md"))
+        .collect();
+    paths.sort();
+
+    paths
+        .iter()
+        .filter_map(|path| {
+            let stem = path.file_stem()?.to_string_lossy();
+            let loaded = std::fs::read_to_string(path)
+                .map_err(|e| e.to_string())
+                .and_then(|content| {
+                    AgentDefinition::from_markdown(&content, &stem, source.clone())
+                });
+            match loaded {
+                Ok(agent) => Some(agent),
+                Err(e) => {
+                    print_warning(format_args!(
+                        "Failed to load agent from {}: {}",
+                        path.display(),
+                        e
+                    ));
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+/// A plugin's name from `.claude-plugin/plugin.json`, or its directory name.
+pub fn plugin_name(plugin_dir: &Path) -> String {
+    std::fs::read_to_string(plugin_dir.join(".claude-plugin").join("plugin.json"))
+        .ok()
+        .and_then(|manifest| serde_json::from_str::<serde_json::Value>(&manifest).ok())
+        .and_then(|manifest| manifest["name"].as_str().map(str::to_string))
+        .unwrap_or_else(|| {
+            plugin_dir
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        })
+}
+
+#[cfg(test)]
+#[path = "agents_tests.rs"]
+mod tests;
@@ -0,0 +1,178 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+use std::fs;
+use tempfile::TempDir;
+
+fn write_agent(dir: &Path, file: &str, content: &str) {
+    fs::create_dir_all(dir).unwrap();
+    fs::write(dir.join(file), content).unwrap();
+}
+
+#[test]
+fn markdown_agent_reads_frontmatter_and_prompt() {
+    let agent = AgentDefinition::from_markdown(
+        "---\nname: reviewer\ndescription: Reviews code\ntools: Read, Grep\nmodel: haiku\n---\nYou review code.\n",
+        "file-stem",
+        AgentSource::Project,
+    )
+    .unwrap();
+
+    assert_eq!(agent.name, "reviewer");
+    assert_eq!(agent.description, "Reviews code");
+    assert_eq!(agent.prompt, "You review code.");
+    assert_eq!(agent.tools, Some(vec!["Read".into(), "Grep".into()]));
+    assert_eq!(agent.model.as_deref(), Some("haiku"));
+}
+
+#[test]
+fn markdown_agent_name_defaults_to_file_stem() {
+    let agent = AgentDefinition::from_markdown(
+        "---\ndescription: Helps\n---\nHelp.",
+        "helper",
+        AgentSource::User,
+    )
+    .unwrap();
+
+    assert_eq!(agent.name, "helper");
+    assert_eq!(agent.tools, None);
+}
+
+#[test]
+fn markdown_agent_requires_description() {
+    let err = AgentDefinition::from_markdown("---\nname: x\n---\nPrompt", "x", AgentSource::User)
+        .unwrap_err();
+
+    assert!(err.contains("description"));
+}
+
+#[test]
+fn allows_tool_checks_allowlist() {
+    let mut agent = AgentDefinition::from_markdown(
+        "---\ndescription: d\ntools: Read, Bash(git:*)\n---\n",
+        "a",
+        AgentSource::User,
+    )
+    .unwrap();
+
+    assert!(agent.allows_tool("Read"));
+    assert!(agent.allows_tool("Bash"));
+    assert!(!agent.allows_tool("Write"));
+
+    agent.tools = None;
+    assert!(agent.allows_tool("Write"));
+}
+
+#[test]
+fn parses_agents_json() {
+    let agents = parse_agents_json(
+        r#"{"reviewer": {"description": "Reviews code", "prompt": "You review", "tools": ["Read"], "model": "sonnet"}}"#,
+    )
+    .unwrap();
+
+    assert_eq!(agents.len(), 1);
+    assert_eq!(agents[0].name, "reviewer");
+    assert_eq!(agents[0].tools, Some(vec!["Read".into()]));
+    assert_eq!(agents[0].source, AgentSource::Cli);
+}
+
+#[test]
+fn parse_agents_json_rejects_missing_prompt() {
+    assert!(parse_agents_json(r#"{"reviewer": {"description": "Reviews code"}}"#).is_err());
+}
+
+#[test]
+fn loader_applies_precedence() {
+    let state_dir = TempDir::new().unwrap();
+    let work_dir = TempDir::new().unwrap();
+    let plugin_dir = TempDir::new().unwrap();
+    let user_agents = state_dir.path().join("agents");
+    let project_agents = work_dir.path().join(".claude").join("agents");
+
+    write_agent(&user_agents, "shared.md", "---\ndescription: user\n---\n");
+    write_agent(
+        &user_agents,
+        "mine.md",
+        "---\ndescription: user only\n---\n",
+    );
+    write_agent(
+        &project_agents,
+        "shared.md",
+        "---\ndescription: project\n---\n",
+    );
+    write_agent(&project_agents, "notes.txt", "not an agent");
+    write_agent(
+        &plugin_dir.path().join("agents"),
+        "linter.md",
+        "---\ndescription: lints\n---\n",
+    );
+    write_agent(
+        &plugin_dir.path().join(".claude-plugin"),
+        "plugin.json",
+        r#"{"name": "tools"}"#,
+    );
+
+    let agents = AgentsLoader::resolve(Some(state_dir.path()), work_dir.path(), None)
+        .with_plugin_dirs(&[plugin_dir.path().to_string_lossy().into_owned()])
+        .with_cli_json(Some(
+            r#"{"shared": {"description": "cli", "prompt": "p"}, "flag": {"description": "cli only", "prompt": "p"}}"#,
+        ))
+        .load();
+
+    let described: Vec<_> = agents
+        .iter()
+        .map(|a| (a.name.as_str(), a.description.as_str()))
+        .collect();
+    assert_eq!(
+        described,
+        vec![
+            ("flag", "cli only"),
+            ("mine", "user only"),
+            ("shared", "project"),
+            ("tools:linter", "lints"),
+        ]
+    );
+    assert_eq!(agents[3].source, AgentSource::Plugin("tools".into()));
+}
+
+#[test]
+fn loader_respects_setting_sources() {
+    let state_dir = TempDir::new().unwrap();
+    let work_dir = TempDir::new().unwrap();
+    write_agent(
+        &state_dir.path().join("agents"),
+        "mine.md",
+        "---\ndescription: user\n---\n",
+    );
+    write_agent(
+        &work_dir.path().join(".claude").join("agents"),
+        "ours.md",
+        "---\ndescription: project\n---\n",
+    );
+
+    let agents = AgentsLoader::resolve(
+        Some(state_dir.path()),
+        work_dir.path(),
+        Some(&[SettingSource::Project]),
+    )
+    .load();
+
+    assert_eq!(agents.len(), 1);
+    assert_eq!(agents[0].name, "ours");
+}
+
+#[test]
+fn loader_skips_invalid_definitions() {
+    let work_dir = TempDir::new().unwrap();
+    let project_agents = work_dir.path().join(".claude").join("agents");
+    write_agent(&project_agents, "broken.md", "---\nname: broken\n");
+    write_agent(&project_agents, "ok.md", "---\ndescription: fine\n---\n");
+
+    let agents = AgentsLoader::resolve(None, work_dir.path(), None).load();
+
+    assert_eq!(agents.len(), 1);
+    assert_eq!(agents[0].name, "ok");
+}
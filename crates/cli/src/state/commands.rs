@@ -0,0 +1,283 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Custom slash commands.
+//!
+//! Each `<name>.md` file defines `/<name>`, found in two places, project
+//! commands overriding user commands with the same name:
+//! 1. User (`~/.claude/commands/<name>.md`)
+//! 2. Project (`.claude/commands/<name>.md`)
+//!
+//! The body is the prompt sent when the command runs. `$ARGUMENTS` is
+//! replaced by everything typed after the command and `$1`..`$9` by single
+//! arguments. `` !`cmd` `` is replaced by the output of running `cmd`, and
+//! `@path` by the contents of the file at `path`. Frontmatter may set
+//! `description`, `argument-hint`, `allowed-tools` and `model`.
+
+use super::frontmatter::Frontmatter;
+use super::settings_source::SettingSource;
+use crate::output::print_warning;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Where a custom command was defined.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommandSource {
+    /// `~/.claude/commands/`
+    User,
+    /// `.claude/commands/`
+    Project,
+}
+
+impl CommandSource {
+    /// Label shown after the command's description in the slash menu.
+    pub fn label(self) -> &'static str {
+        match self {
+            CommandSource::User => "user",
+            CommandSource::Project => "project",
+        }
+    }
+}
+
+/// A custom slash command.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CustomCommand {
+    /// Command name without the leading `/`.
+    pub name: String,
+    /// Description shown in the slash menu.
+    pub description: String,
+    /// Hint for the arguments the command takes (e.g. `<file>`).
+    pub argument_hint: Option<String>,
+    /// Tools the command may use without asking (`Bash(git status:*)`).
+    pub allowed_tools: Vec<String>,
+    /// Model the command runs with.
+    pub model: Option<String>,
+    /// Prompt template.
+    pub body: String,
+    /// Where the command was defined.
+    pub source: CommandSource,
+}
+
+impl CustomCommand {
+    /// Parse a command file. The description defaults to the first line of
+    /// the body.
+    pub fn from_markdown(content: &str, name: &str, source: CommandSource) -> Result<Self, String> {
+        let doc = Frontmatter::parse(content)?;
+        let description = doc.text("description").map_or_else(
+            || {
+                doc.body
+                    .lines()
+                    .map(str::trim)
+                    .find(|line| !line.is_empty())
+                    .unwrap_or_default()
+                    .to_string()
+            },
+            str::to_string,
+        );
+        Ok(Self {
+            name: name.to_string(),
+            description,
+            argument_hint: doc.text("argument-hint").map(str::to_string),
+            allowed_tools: doc.list("allowed-tools").unwrap_or_default(),
+            model: doc.text("model").map(str::to_string),
+            body: doc.body,
+            source,
+        })
+    }
+
+    /// Description with the command's source, as shown in the slash menu.
+    pub fn menu_description(&self) -> String {
+        format!("{} ({})", self.description, self.source.label())
+    }
+
+    /// The body with `$ARGUMENTS` and `$1`..`$9` replaced by `args`.
+    ///
+    /// A body without placeholders gets non-empty arguments appended.
+    pub fn substitute_arguments(&self, args: &str) -> String {
+        let positional: Vec<&str> = args.split_whitespace().collect();
+        let mut out = String::with_capacity(self.body.len());
+        let mut substituted = false;
+        let mut rest = self.body.as_str();
+        while let Some(i) = rest.find('$') {
+            out.push_str(&rest[..i]);
+            let after = &rest[i + 1..];
+            let digit = after
+                .chars()
+                .next()
+                .and_then(|c| c.to_digit(10))
+                .filter(|d| *d > 0);
+            if let Some(tail) = after.strip_prefix("ARGUMENTS") {
+                out.push_str(args);
+                rest = tail;
+                substituted = true;
+            } else if let Some(d) = digit {
+                out.push_str(positional.get(d as usize - 1).copied().unwrap_or_default());
+                rest = &after[1..];
+                substituted = true;
+            } else {
+                out.push('$');
+                rest = after;
+            }
+        }
+        out.push_str(rest);
+
+        if !substituted && !args.is_empty() {
+            out.push_str(&format!("\n\nARGUMENTS: {}", args));
+        }
+        out
+    }
+}
+
+/// Split `/name args` into the command name and its arguments.
+pub fn parse_invocation(prompt: &str) -> Option<(&str, &str)> {
+    let rest = prompt.trim().strip_prefix('/')?;
+    let (name, args) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+    (!name.is_empty()).then(|| (name, args.trim()))
+}
+
+/// Replace each `` !`cmd` `` in `text` with the output `run` gives for `cmd`.
+pub fn expand_bash(
+    text: &str,
+    mut run: impl FnMut(&str) -> Result<String, String>,
+) -> Result<String, String> {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(i) = rest.find("!`") {
+        let after = &rest[i + 2..];
+        let Some(end) = after.find('`') else {
+            break;
+        };
+        out.push_str(&rest[..i]);
+        out.push_str(run(&after[..end])?.trim_end());
+        rest = &after[end + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+/// The command of each `` !`cmd` `` in `text`, in order.
+pub fn bash_commands(text: &str) -> Vec<String> {
+    let mut commands = Vec::new();
+    let _ = expand_bash(text, |cmd| {
+        commands.push(cmd.to_string());
+        Ok(String::new())
+    });
+    commands
+}
+
+/// Replace each `@path` word in `text` with what `read` returns for `path`.
+///
+/// Words `read` returns nothing for are left as they are.
+pub fn expand_file_references(text: &str, read: impl Fn(&str) -> Option<String>) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(i) = rest.find('@') {
+        out.push_str(&rest[..i]);
+        let at_word_start = out.chars().next_back().is_none_or(char::is_whitespace);
+        let after = &rest[i + 1..];
+        let end = after.find(char::is_whitespace).unwrap_or(after.len());
+        let path = &after[..end];
+        let contents = if at_word_start && !path.is_empty() {
+            read(path)
+        } else {
+            None
+        };
+        match contents {
+            Some(contents) => {
+                out.push_str(contents.trim_end());
+                rest = &after[end..];
+            }
+            None => {
+                out.push('@');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Directories to load custom commands from.
+#[derive(Clone, Debug, Default)]
+pub struct CommandsLoader {
+    /// User commands directory (~/.claude/commands)
+    pub user: Option<PathBuf>,
+    /// Project commands directory (.claude/commands)
+    pub project: Option<PathBuf>,
+}
+
+impl CommandsLoader {
+    /// Resolve command locations, loading user and project commands only
+    /// for the given setting sources (all when `None`).
+    pub fn resolve(
+        state_dir: Option<&Path>,
+        working_dir: &Path,
+        sources: Option<&[SettingSource]>,
+    ) -> Self {
+        let sources = sources.unwrap_or(SettingSource::all());
+        Self {
+            user: state_dir
+                .filter(|_| sources.contains(&SettingSource::User))
+                .map(|dir| dir.join("commands")),
+            project: sources
+                .contains(&SettingSource::Project)
+                .then(|| working_dir.join(".claude").join("commands")),
+        }
+    }
+
+    /// Load all commands, sorted by name.
+    ///
+    /// Invalid definitions are skipped with a warning.
+    pub fn load(&self) -> Vec<CustomCommand> {
+        let mut commands = BTreeMap::new();
+        for (dir, source) in [
+            (&self.user, CommandSource::User),
+            (&self.project, CommandSource::Project),
+        ] {
+            let Some(dir) = dir else {
+                continue;
+            };
+            for command in load_dir(dir, source) {
+                commands.insert(command.name.clone(), command);
+            }
+        }
+        commands.into_values().collect()
+    }
+}
+
+/// Load the `.md` files in `dir`.
+fn load_dir(dir: &Path, source: CommandSource) -> Vec<CustomCommand> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "md"))
+        .collect();
+    paths.sort();
+
+    paths
+        .iter()
+        .filter_map(|path| {
+            let name = path.file_stem()?.to_string_lossy().into_owned();
+            let loaded = std::fs::read_to_string(path)
+                .map_err(|e| e.to_string())
+                .and_then(|content| CustomCommand::from_markdown(&content, &name, source));
+            match loaded {
+                Ok(command) => Some(command),
+                Err(e) => {
+                    print_warning(format_args!(
+                        "Failed to load command from {}: {}",
+                        path.display(),
+                        e
+                    ));
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "commands_tests.rs"]
+mod tests;
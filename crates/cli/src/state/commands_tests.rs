@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+use std::fs;
+use tempfile::TempDir;
+use yare::parameterized;
+
+fn command(body: &str) -> CustomCommand {
+    CustomCommand::from_markdown(body, "cmd", CommandSource::Project).unwrap()
+}
+
+#[test]
+fn command_reads_frontmatter() {
+    let command = CustomCommand::from_markdown(
+        "---\ndescription: Review a file\nargument-hint: <file>\nallowed-tools: Bash(git diff:*), Read\nmodel: haiku\n---\nReview $ARGUMENTS.\n",
+        "review-file",
+        CommandSource::User,
+    )
+    .unwrap();
+
+    assert_eq!(command.name, "review-file");
+    assert_eq!(command.description, "Review a file");
+    assert_eq!(command.argument_hint.as_deref(), Some("<file>"));
+    assert_eq!(command.allowed_tools, vec!["Bash(git diff:*)", "Read"]);
+    assert_eq!(command.model.as_deref(), Some("haiku"));
+    assert_eq!(command.body, "Review $ARGUMENTS.");
+    assert_eq!(command.menu_description(), "Review a file (user)");
+}
+
+#[test]
+fn description_defaults_to_first_body_line() {
+    let command = command("\nFix the failing tests.\nThen commit.\n");
+
+    assert_eq!(command.description, "Fix the failing tests.");
+    assert!(command.allowed_tools.is_empty());
+}
+
+#[parameterized(
+    arguments = { "Fix issue $ARGUMENTS now", "12 urgent", "Fix issue 12 urgent now" },
+    positional = { "Move $1 to $2", "a.rs b.rs", "Move a.rs to b.rs" },
+    missing_positional = { "Tag $1 as $2", "v1", "Tag v1 as " },
+    plain_dollar = { "Costs $5 or $$", "", "Costs  or $$" },
+    appended = { "Summarize.", "the diff", "Summarize.\n\nARGUMENTS: the diff" },
+    no_arguments = { "Summarize.", "", "Summarize." },
+)]
+fn substitutes_arguments(body: &str, args: &str, expected: &str) {
+    assert_eq!(command(body).substitute_arguments(args), expected);
+}
+
+#[parameterized(
+    with_args = { "/fix 12 now", Some(("fix", "12 now")) },
+    no_args = { "/fix", Some(("fix", "")) },
+    padded = { "  /fix   12 ", Some(("fix", "12")) },
+    bare_slash = { "/", None },
+    not_a_command = { "fix 12", None },
+)]
+fn parses_invocation(prompt: &str, expected: Option<(&str, &str)>) {
+    assert_eq!(parse_invocation(prompt), expected);
+}
+
+#[test]
+fn expand_bash_replaces_commands_with_output() {
+    let expanded = expand_bash("Status:\n!`git status`\nBranch: !`git branch`", |cmd| {
+        Ok(format!("<{}>\n", cmd))
+    })
+    .unwrap();
+
+    assert_eq!(expanded, "Status:\n<git status>\nBranch: <git branch>");
+}
+
+#[test]
+fn expand_bash_stops_at_first_failure() {
+    let err = expand_bash("!`ok` !`fail`", |cmd| match cmd {
+        "fail" => Err("denied".to_string()),
+        _ => Ok(String::new()),
+    })
+    .unwrap_err();
+
+    assert_eq!(err, "denied");
+}
+
+#[test]
+fn bash_commands_lists_commands_in_order() {
+    assert_eq!(
+        bash_commands("!`git status` then !`git branch` and !`unclosed"),
+        vec!["git status", "git branch"]
+    );
+}
+
+#[test]
+fn expand_file_references_inlines_readable_files() {
+    let read = |path: &str| (path == "src/lib.rs").then(|| "pub fn lib() {}\n".to_string());
+
+    let expanded = expand_file_references("Review @src/lib.rs and @missing.rs, mail a@b.c", read);
+
+    assert_eq!(
+        expanded,
+        "Review pub fn lib() {} and @missing.rs, mail a@b.c"
+    );
+}
+
+#[test]
+fn loader_prefers_project_commands() {
+    let state_dir = TempDir::new().unwrap();
+    let work_dir = TempDir::new().unwrap();
+    let user_dir = state_dir.path().join("commands");
+    let project_dir = work_dir.path().join(".claude").join("commands");
+    fs::create_dir_all(&user_dir).unwrap();
+    fs::create_dir_all(&project_dir).unwrap();
+    fs::write(user_dir.join("shared.md"), "User version").unwrap();
+    fs::write(user_dir.join("mine.md"), "Mine").unwrap();
+    fs::write(project_dir.join("shared.md"), "Project version").unwrap();
+    fs::write(project_dir.join("notes.txt"), "Not a command").unwrap();
+
+    let commands = CommandsLoader::resolve(Some(state_dir.path()), work_dir.path(), None).load();
+
+    let names: Vec<_> = commands.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, vec!["mine", "shared"]);
+    assert_eq!(commands[1].body, "Project version");
+    assert_eq!(commands[1].source, CommandSource::Project);
+}
+
+#[test]
+fn loader_respects_setting_sources() {
+    let state_dir = TempDir::new().unwrap();
+    let work_dir = TempDir::new().unwrap();
+    let user_dir = state_dir.path().join("commands");
+    fs::create_dir_all(&user_dir).unwrap();
+    fs::write(user_dir.join("mine.md"), "Mine").unwrap();
+
+    let commands = CommandsLoader::resolve(
+        Some(state_dir.path()),
+        work_dir.path(),
+        Some(&[SettingSource::Project]),
+    )
+    .load();
+
+    assert!(commands.is_empty());
+}
+
+#[test]
+fn loader_skips_invalid_commands() {
+    let work_dir = TempDir::new().unwrap();
+    let project_dir = work_dir.path().join(".claude").join("commands");
+    fs::create_dir_all(&project_dir).unwrap();
+    fs::write(project_dir.join("broken.md"), "---\ndescription: x\n").unwrap();
+    fs::write(project_dir.join("ok.md"), "Fine").unwrap();
+
+    let commands = CommandsLoader::resolve(None, work_dir.path(), None).load();
+
+    assert_eq!(commands.len(), 1);
+    assert_eq!(commands[0].name, "ok");
+}
@@ -103,6 +103,10 @@ impl StateDirectory {
         paths::todos_dir(&self.root)
     }
 
+    pub fn tasks_dir(&self) -> PathBuf {
+        paths::tasks_dir(&self.root)
+    }
+
     pub fn projects_dir(&self) -> PathBuf {
         paths::projects_dir(&self.root)
     }
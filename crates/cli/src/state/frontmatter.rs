@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Markdown files with YAML frontmatter.
+//!
+//! Agent definitions (and similar `.claude/` markdown files) start with a
+//! `---` delimited header of `key: value` lines. Only the subset of YAML those
+//! headers use is understood: plain or quoted scalars, inline `[a, b]` lists
+//! and block lists of `- item` lines.
+
+use std::collections::HashMap;
+
+/// A frontmatter value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum FrontmatterValue {
+    Text(String),
+    List(Vec<String>),
+}
+
+/// A markdown document split into its frontmatter fields and body.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Frontmatter {
+    fields: HashMap<String, FrontmatterValue>,
+    /// Markdown following the frontmatter, trimmed.
+    pub body: String,
+}
+
+impl Frontmatter {
+    /// Parse a markdown document. A document without frontmatter is all body.
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+        let mut lines = content.lines();
+        if lines.next().map(str::trim_end) != Some("---") {
+            return Ok(Self {
+                fields: HashMap::new(),
+                body: content.trim().to_string(),
+            });
+        }
+
+        let mut fields = HashMap::new();
+        let mut list_key: Option<String> = None;
+        let mut closed = false;
+        for line in lines.by_ref() {
+            if line.trim_end() == "---" {
+                closed = true;
+                break;
+            }
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            if let (Some(item), Some(key)) = (trimmed.strip_prefix("- "), list_key.as_ref()) {
+                if let Some(FrontmatterValue::List(items)) = fields.get_mut(key) {
+                    items.push(unquote(item));
+                }
+                continue;
+            }
+            let Some((key, value)) = line.split_once(':') else {
+                return Err(format!("Invalid frontmatter line: {}", trimmed));
+            };
+            let key = key.trim().to_string();
+            let value = value.trim();
+            if value.is_empty() {
+                // A block list may follow
+                fields.insert(key.clone(), FrontmatterValue::List(Vec::new()));
+                list_key = Some(key);
+                continue;
+            }
+            list_key = None;
+            let value = match value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+                Some(items) => FrontmatterValue::List(split_list(items)),
+                None => FrontmatterValue::Text(unquote(value)),
+            };
+            fields.insert(key, value);
+        }
+        if !closed {
+            return Err("Frontmatter is missing its closing ---".to_string());
+        }
+
+        Ok(Self {
+            fields,
+            body: lines.collect::<Vec<_>>().join("\n").trim().to_string(),
+        })
+    }
+
+    /// A scalar field.
+    pub fn text(&self, key: &str) -> Option<&str> {
+        match self.fields.get(key)? {
+            FrontmatterValue::Text(text) => Some(text),
+            FrontmatterValue::List(_) => None,
+        }
+    }
+
+    /// A list field, also accepting a comma-separated scalar.
+    pub fn list(&self, key: &str) -> Option<Vec<String>> {
+        match self.fields.get(key)? {
+            FrontmatterValue::Text(text) => Some(split_list(text)),
+            FrontmatterValue::List(items) => Some(items.clone()),
+        }
+    }
+}
+
+/// Split a comma-separated list, dropping empty items.
+fn split_list(items: &str) -> Vec<String> {
+    items
+        .split(',')
+        .map(unquote)
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+/// Strip surrounding whitespace and matching quotes.
+fn unquote(value: &str) -> String {
+    let value = value.trim();
+    ['"', '\'']
+        .iter()
+        .find_map(|q| value.strip_prefix(*q).and_then(|v| v.strip_suffix(*q)))
+        .unwrap_or(value)
+        .to_string()
+}
+
+#[cfg(test)]
+#[path = "frontmatter_tests.rs"]
+mod tests;
@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+use yare::parameterized;
+
+#[test]
+fn parses_fields_and_body() {
+    let doc = Frontmatter::parse(
+        "---\nname: reviewer\ndescription: \"Reviews code: carefully\"\nmodel: sonnet\n---\n\nYou are a reviewer.\n",
+    )
+    .unwrap();
+
+    assert_eq!(doc.text("name"), Some("reviewer"));
+    assert_eq!(doc.text("description"), Some("Reviews code: carefully"));
+    assert_eq!(doc.text("model"), Some("sonnet"));
+    assert_eq!(doc.text("tools"), None);
+    assert_eq!(doc.body, "You are a reviewer.");
+}
+
+#[parameterized(
+    comma_separated = { "tools: Read, Grep, Glob" },
+    inline_list = { "tools: [Read, \"Grep\", 'Glob']" },
+    block_list = { "tools:\n  - Read\n  - Grep\n  - Glob" },
+)]
+fn parses_lists(field: &str) {
+    let doc = Frontmatter::parse(&format!("---\n{}\n---\nbody", field)).unwrap();
+
+    assert_eq!(doc.list("tools").unwrap(), vec!["Read", "Grep", "Glob"]);
+}
+
+#[test]
+fn document_without_frontmatter_is_all_body() {
+    let doc = Frontmatter::parse("Just a prompt\n").unwrap();
+
+    assert_eq!(doc.text("name"), None);
+    assert_eq!(doc.body, "Just a prompt");
+}
+
+#[parameterized(
+    unclosed = { "---\nname: x\nbody" },
+    not_a_field = { "---\nname x\n---\nbody" },
+)]
+fn rejects_malformed_frontmatter(content: &str) {
+    assert!(Frontmatter::parse(content).is_err());
+}
@@ -4,22 +4,29 @@
 //! State management module for Claudeless.
 //!
 //! This module provides emulation of Claude Code's `~/.claude` directory structure,
-//! including todos, projects, plans, settings, and session state.
+//! including todos, tasks, projects, plans, settings, and session state.
 
+pub mod agents;
+pub mod commands;
 pub mod directory;
 pub mod session;
 pub mod settings;
 pub mod settings_loader;
 pub mod settings_source;
+pub mod skills;
+pub mod tasks;
 pub mod todos;
 pub mod words;
 
+pub(crate) mod frontmatter;
 pub(crate) mod index;
 pub(crate) mod io;
 pub(crate) mod paths;
 pub(crate) mod persistence;
 pub(crate) mod plans;
 
+pub use agents::{AgentDefinition, AgentSource, AgentsLoader};
+pub use commands::{CommandSource, CommandsLoader, CustomCommand};
 pub use directory::{StateDirectory, StateError};
 pub use persistence::ContentBlock;
 pub use settings::{
@@ -27,10 +34,12 @@ pub use settings::{
 };
 pub use settings_loader::{SettingsLoader, SettingsPaths};
 pub use settings_source::SettingSource;
+pub use skills::{Skill, SkillSource, SkillsLoader};
+pub use tasks::{Task, TaskError, TaskStatus, TaskStore, TaskUpdate, TaskUpdateOutcome};
 pub use todos::{TodoItem, TodoStatus};
 
 pub(crate) use index::{get_git_branch, SessionIndexEntry, SessionsIndex};
-pub(crate) use io::to_io_json;
+pub(crate) use io::{to_io_error, to_io_json};
 pub(crate) use persistence::{
     append_api_error_jsonl, append_assistant_message_jsonl, append_result_jsonl, append_turn_jsonl,
     append_user_message_jsonl, write_queue_operation, AssistantMessageParams, ErrorMessageParams,
@@ -59,6 +68,8 @@ pub struct StateWriter {
     cwd: PathBuf,
     first_prompt: Option<String>,
     message_count: u32,
+    /// Subagent whose sidechain messages are being recorded.
+    agent_id: Option<String>,
 }
 
 impl StateWriter {
@@ -81,6 +92,7 @@ impl StateWriter {
             cwd: cwd.into(),
             first_prompt: None,
             message_count: 0,
+            agent_id: None,
         })
     }
 
@@ -113,6 +125,7 @@ impl StateWriter {
             cwd: cwd.into(),
             first_prompt,
             message_count,
+            agent_id: None,
         })
     }
 
@@ -160,6 +173,19 @@ impl StateWriter {
         ))
     }
 
+    /// Get the task list directory (Claude format: `tasks/{sessionId}/`).
+    pub fn tasks_dir(&self) -> PathBuf {
+        self.dir.tasks_dir().join(&self.session_id)
+    }
+
+    /// Get the directory oversized tool results are saved in
+    /// (Claude format: `{sessionId}/tool-results/` in the project directory).
+    pub fn tool_results_dir(&self) -> PathBuf {
+        self.project_dir()
+            .join(&self.session_id)
+            .join("tool-results")
+    }
+
     fn on_message_written(&mut self, prompt: Option<&str>) {
         if let Some(p) = prompt {
             if self.first_prompt.is_none() {
@@ -169,6 +195,12 @@ impl StateWriter {
         self.message_count += 1;
     }
 
+    /// Record subsequent messages to a subagent's sidechain, or back to the
+    /// main conversation with `None`.
+    pub fn set_sidechain(&mut self, agent_id: Option<String>) {
+        self.agent_id = agent_id;
+    }
+
     /// Write initial session state files (JSONL, empty todo).
     ///
     /// Real Claude CLI creates these on startup before any user interaction.
@@ -207,6 +239,16 @@ impl StateWriter {
 
     /// Record a conversation turn.
     pub fn record_turn(&mut self, prompt: &str, response: &str) -> std::io::Result<()> {
+        self.record_turn_with_thinking(prompt, &[], response)
+    }
+
+    /// Record a conversation turn whose assistant message starts with thinking blocks.
+    pub fn record_turn_with_thinking(
+        &mut self,
+        prompt: &str,
+        thinking: &[ContentBlock],
+        response: &str,
+    ) -> std::io::Result<()> {
         let project_dir = self.project_dir();
         std::fs::create_dir_all(&project_dir)?;
 
@@ -227,6 +269,7 @@ impl StateWriter {
             assistant_uuid: &assistant_uuid,
             request_id: &request_id,
             prompt,
+            thinking,
             response,
             model: &self.model,
             cwd: &cwd,
@@ -246,6 +289,16 @@ impl StateWriter {
 
     /// Record a user message. Returns the user message UUID.
     pub fn record_user_message(&mut self, prompt: &str) -> std::io::Result<String> {
+        self.record_user_message_with_parent(prompt, None)
+    }
+
+    /// Record a user message that follows `parent_uuid` (e.g. a subagent's
+    /// prompt following the Task call). Returns the user message UUID.
+    pub fn record_user_message_with_parent(
+        &mut self,
+        prompt: &str,
+        parent_uuid: Option<&str>,
+    ) -> std::io::Result<String> {
         let project_dir = self.project_dir();
         std::fs::create_dir_all(&project_dir)?;
 
@@ -259,7 +312,8 @@ impl StateWriter {
         let params = UserMessageParams {
             session_id: &self.session_id,
             user_uuid: &uuid,
-            parent_uuid: None,
+            parent_uuid,
+            agent_id: self.agent_id.as_deref(),
             content: UserMessageContent::Text(prompt),
             cwd: &cwd,
             version,
@@ -268,7 +322,9 @@ impl StateWriter {
         };
         append_user_message_jsonl(&jsonl_path, &params)?;
 
-        self.on_message_written(Some(prompt));
+        // A subagent's prompt never becomes the session's first prompt
+        let is_sidechain = self.agent_id.is_some();
+        self.on_message_written(Some(prompt).filter(|_| !is_sidechain));
         Ok(uuid)
     }
 
@@ -281,6 +337,24 @@ impl StateWriter {
         self.record_assistant_response_inner(parent_user_uuid, response, None)
     }
 
+    /// Record an assistant message with arbitrary content blocks (e.g. thinking).
+    pub fn record_assistant_content(
+        &mut self,
+        parent_user_uuid: &str,
+        content: Vec<ContentBlock>,
+    ) -> std::io::Result<String> {
+        self.record_assistant_message(parent_user_uuid, content, None)
+    }
+
+    /// Record a final assistant message with arbitrary content blocks (end of turn).
+    pub fn record_assistant_content_final(
+        &mut self,
+        parent_user_uuid: &str,
+        content: Vec<ContentBlock>,
+    ) -> std::io::Result<String> {
+        self.record_assistant_message(parent_user_uuid, content, Some("end_turn"))
+    }
+
     /// Record a final assistant response (end of turn).
     pub fn record_assistant_response_final(
         &mut self,
@@ -295,6 +369,18 @@ impl StateWriter {
         parent_user_uuid: &str,
         response: &str,
         stop_reason: Option<&str>,
+    ) -> std::io::Result<String> {
+        let content = vec![ContentBlock::Text {
+            text: response.to_string(),
+        }];
+        self.record_assistant_message(parent_user_uuid, content, stop_reason)
+    }
+
+    fn record_assistant_message(
+        &mut self,
+        parent_user_uuid: &str,
+        content: Vec<ContentBlock>,
+        stop_reason: Option<&str>,
     ) -> std::io::Result<String> {
         let project_dir = self.project_dir();
         std::fs::create_dir_all(&project_dir)?;
@@ -313,11 +399,10 @@ impl StateWriter {
             session_id: &self.session_id,
             assistant_uuid: &uuid,
             parent_uuid: parent_user_uuid,
+            agent_id: self.agent_id.as_deref(),
             request_id: &request_id,
             message_id: &message_id,
-            content: vec![ContentBlock::Text {
-                text: response.to_string(),
-            }],
+            content,
             model: &self.model,
             stop_reason,
             cwd: &cwd,
@@ -356,6 +441,7 @@ impl StateWriter {
             session_id: &self.session_id,
             assistant_uuid: &uuid,
             parent_uuid: parent_user_uuid,
+            agent_id: self.agent_id.as_deref(),
             request_id: &request_id,
             message_id: &message_id,
             content,
@@ -394,6 +480,7 @@ impl StateWriter {
             session_id: &self.session_id,
             user_uuid: &uuid,
             parent_uuid: Some(assistant_uuid),
+            agent_id: self.agent_id.as_deref(),
             content: UserMessageContent::ToolResult {
                 tool_use_id,
                 content: result_content,
@@ -414,6 +501,42 @@ impl StateWriter {
         Ok(uuid)
     }
 
+    /// Record a meta message following the result of `source_tool_use_id`
+    /// (e.g. a skill's instructions). Returns the message UUID.
+    pub fn record_meta_message(
+        &mut self,
+        text: &str,
+        parent_uuid: &str,
+        source_tool_use_id: &str,
+    ) -> std::io::Result<String> {
+        let project_dir = self.project_dir();
+        std::fs::create_dir_all(&project_dir)?;
+
+        let jsonl_path = self.session_jsonl_path();
+        let git_branch = get_git_branch();
+        let cwd = self.cwd.to_string_lossy().into_owned();
+        let uuid = Uuid::new_v4().to_string();
+
+        let params = UserMessageParams {
+            session_id: &self.session_id,
+            user_uuid: &uuid,
+            parent_uuid: Some(parent_uuid),
+            agent_id: self.agent_id.as_deref(),
+            content: UserMessageContent::Meta {
+                text,
+                source_tool_use_id,
+            },
+            cwd: &cwd,
+            version: env!("CARGO_PKG_VERSION"),
+            git_branch: &git_branch,
+            timestamp: Utc::now(),
+        };
+        append_user_message_jsonl(&jsonl_path, &params)?;
+
+        self.on_message_written(None);
+        Ok(uuid)
+    }
+
     fn update_sessions_index(&self) -> std::io::Result<()> {
         let project_dir = self.project_dir();
         let index_path = project_dir.join("sessions-index.json");
@@ -163,6 +163,34 @@ fn test_state_writer_record_assistant_response_final_sets_stop_reason() {
     assert!(lines[1].contains("\"stop_reason\":\"end_turn\""));
 }
 
+#[test]
+fn test_state_writer_record_turn_with_thinking() {
+    let mut writer = StateWriter::new(
+        Uuid::new_v4().to_string(),
+        "/tmp/test-project",
+        Utc::now(),
+        "claude-sonnet-4-20250514",
+        "/tmp/test-project",
+    )
+    .unwrap();
+
+    let thinking = vec![ContentBlock::Thinking {
+        thinking: "Considering options".to_string(),
+        signature: "sig".to_string(),
+    }];
+    writer
+        .record_turn_with_thinking("Hello", &thinking, "Hi there!")
+        .unwrap();
+
+    let content = std::fs::read_to_string(writer.session_jsonl_path()).unwrap();
+    let assistant: serde_json::Value =
+        serde_json::from_str(content.lines().nth(1).unwrap()).unwrap();
+    let blocks = &assistant["message"]["content"];
+    assert_eq!(blocks[0]["type"], "thinking");
+    assert_eq!(blocks[0]["thinking"], "Considering options");
+    assert_eq!(blocks[1]["type"], "text");
+}
+
 #[test]
 fn test_state_writer_record_assistant_tool_use_sets_stop_reason() {
     let mut writer = StateWriter::new(
@@ -31,6 +31,10 @@ pub fn todos_dir(root: &Path) -> PathBuf {
     root.join("todos")
 }
 
+pub fn tasks_dir(root: &Path) -> PathBuf {
+    root.join("tasks")
+}
+
 pub fn projects_dir(root: &Path) -> PathBuf {
     root.join("projects")
 }
@@ -24,6 +24,7 @@ fn test_path_functions() {
     let root = Path::new("/home/user/.claude");
 
     assert_eq!(todos_dir(root), PathBuf::from("/home/user/.claude/todos"));
+    assert_eq!(tasks_dir(root), PathBuf::from("/home/user/.claude/tasks"));
     assert_eq!(
         projects_dir(root),
         PathBuf::from("/home/user/.claude/projects")
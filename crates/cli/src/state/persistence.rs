@@ -27,6 +27,9 @@ pub struct MessageEnvelope {
     pub parent_uuid: Option<String>,
     pub is_sidechain: bool,
     pub user_type: String,
+    /// Subagent that wrote this line (sidechain lines only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_id: Option<String>,
 }
 
 /// User message content for JSONL.
@@ -45,6 +48,26 @@ pub struct UserMessageLine {
     pub message: UserMessage,
 }
 
+/// User message with content blocks (meta messages).
+#[derive(Clone, Debug, Serialize)]
+pub struct BlockUserMessage {
+    pub role: &'static str,
+    pub content: Vec<ContentBlock>,
+}
+
+/// Meta message line in JSONL format: text the CLI adds to the conversation
+/// on the user's behalf, such as a loaded skill's instructions.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetaMessageLine {
+    #[serde(flatten)]
+    pub envelope: MessageEnvelope,
+    pub message: BlockUserMessage,
+    pub is_meta: bool,
+    #[serde(rename = "sourceToolUseID")]
+    pub source_tool_use_id: String,
+}
+
 /// Tool result content block.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ToolResultContent {
@@ -85,6 +108,10 @@ pub enum ContentBlock {
         name: String,
         input: serde_json::Value,
     },
+    #[serde(rename = "thinking")]
+    Thinking { thinking: String, signature: String },
+    #[serde(rename = "redacted_thinking")]
+    RedactedThinking { data: String },
 }
 
 /// Cache creation breakdown for usage statistics.
@@ -233,6 +260,7 @@ pub struct TurnParams<'a> {
     pub assistant_uuid: &'a str,
     pub request_id: &'a str,
     pub prompt: &'a str,
+    pub thinking: &'a [ContentBlock],
     pub response: &'a str,
     pub model: &'a str,
     pub cwd: &'a str,
@@ -258,6 +286,7 @@ pub fn append_turn_jsonl(path: &Path, params: &TurnParams) -> std::io::Result<()
         parent_uuid,
         is_sidechain: false,
         user_type: user_type::EXTERNAL.to_string(),
+        agent_id: None,
     };
 
     let user_line = UserMessageLine {
@@ -280,9 +309,14 @@ pub fn append_turn_jsonl(path: &Path, params: &TurnParams) -> std::io::Result<()
             id: params.message_id.to_string(),
             message_type: message_type::MESSAGE,
             role: role::ASSISTANT,
-            content: vec![ContentBlock::Text {
-                text: params.response.to_string(),
-            }],
+            content: params
+                .thinking
+                .iter()
+                .cloned()
+                .chain([ContentBlock::Text {
+                    text: params.response.to_string(),
+                }])
+                .collect(),
             stop_reason: None,
             stop_sequence: None,
             usage: Usage::new(2, 1),
@@ -298,6 +332,8 @@ pub struct UserMessageParams<'a> {
     pub session_id: &'a str,
     pub user_uuid: &'a str,
     pub parent_uuid: Option<&'a str>,
+    /// Subagent writing a sidechain message (None for the main conversation).
+    pub agent_id: Option<&'a str>,
     pub content: UserMessageContent<'a>,
     pub cwd: &'a str,
     pub version: &'a str,
@@ -315,6 +351,11 @@ pub enum UserMessageContent<'a> {
         tool_use_result: serde_json::Value,
         source_tool_assistant_uuid: &'a str,
     },
+    /// Meta text added after the result of `source_tool_use_id`.
+    Meta {
+        text: &'a str,
+        source_tool_use_id: &'a str,
+    },
 }
 
 /// Append a user message to a JSONL file.
@@ -334,8 +375,9 @@ pub fn append_user_message_jsonl(path: &Path, params: &UserMessageParams) -> std
                     version: params.version.to_string(),
                     git_branch: params.git_branch.to_string(),
                     parent_uuid: params.parent_uuid.map(String::from),
-                    is_sidechain: false,
+                    is_sidechain: params.agent_id.is_some(),
                     user_type: user_type::EXTERNAL.to_string(),
+                    agent_id: params.agent_id.map(String::from),
                 },
                 message: UserMessage {
                     role: role::USER,
@@ -364,8 +406,9 @@ pub fn append_user_message_jsonl(path: &Path, params: &UserMessageParams) -> std
                     version: params.version.to_string(),
                     git_branch: params.git_branch.to_string(),
                     parent_uuid: Some(parent),
-                    is_sidechain: false,
+                    is_sidechain: params.agent_id.is_some(),
                     user_type: user_type::EXTERNAL.to_string(),
+                    agent_id: params.agent_id.map(String::from),
                 },
                 message: ToolResultUserMessage {
                     role: role::USER,
@@ -380,6 +423,35 @@ pub fn append_user_message_jsonl(path: &Path, params: &UserMessageParams) -> std
             };
             write_jsonl_line(&mut file, &tool_result_line)?;
         }
+        UserMessageContent::Meta {
+            text,
+            source_tool_use_id,
+        } => {
+            let meta_line = MetaMessageLine {
+                envelope: MessageEnvelope {
+                    line_type: line_type::USER.to_string(),
+                    uuid: params.user_uuid.to_string(),
+                    timestamp: timestamp_str,
+                    session_id: params.session_id.to_string(),
+                    cwd: params.cwd.to_string(),
+                    version: params.version.to_string(),
+                    git_branch: params.git_branch.to_string(),
+                    parent_uuid: params.parent_uuid.map(String::from),
+                    is_sidechain: params.agent_id.is_some(),
+                    user_type: user_type::EXTERNAL.to_string(),
+                    agent_id: params.agent_id.map(String::from),
+                },
+                message: BlockUserMessage {
+                    role: role::USER,
+                    content: vec![ContentBlock::Text {
+                        text: (*text).to_string(),
+                    }],
+                },
+                is_meta: true,
+                source_tool_use_id: (*source_tool_use_id).to_string(),
+            };
+            write_jsonl_line(&mut file, &meta_line)?;
+        }
     }
 
     Ok(())
@@ -391,6 +463,8 @@ pub struct AssistantMessageParams<'a> {
     pub session_id: &'a str,
     pub assistant_uuid: &'a str,
     pub parent_uuid: &'a str,
+    /// Subagent writing a sidechain message (None for the main conversation).
+    pub agent_id: Option<&'a str>,
     pub request_id: &'a str,
     pub message_id: &'a str,
     pub content: Vec<ContentBlock>,
@@ -490,8 +564,9 @@ pub fn append_assistant_message_jsonl(
             version: params.version.to_string(),
             git_branch: params.git_branch.to_string(),
             parent_uuid: Some(params.parent_uuid.to_string()),
-            is_sidechain: false,
+            is_sidechain: params.agent_id.is_some(),
             user_type: user_type::EXTERNAL.to_string(),
+            agent_id: params.agent_id.map(String::from),
         },
         message: AssistantMessage {
             model: params.model.to_string(),
@@ -528,6 +603,7 @@ pub fn append_api_error_jsonl(path: &Path, params: &ErrorMessageParams) -> std::
             parent_uuid: params.parent_uuid.map(String::from),
             is_sidechain: false,
             user_type: user_type::EXTERNAL.to_string(),
+            agent_id: None,
         },
         message: ApiErrorAssistantMessage {
             id: params.message_id.to_string(),
@@ -60,6 +60,7 @@ fn test_assistant_message_with_stop_reason_end_turn() {
         session_id: "session_123",
         assistant_uuid: "assistant_456",
         parent_uuid: "user_789",
+        agent_id: None,
         request_id: "req_abc",
         message_id: "msg_def",
         content: vec![ContentBlock::Text {
@@ -90,6 +91,7 @@ fn test_assistant_message_with_stop_reason_tool_use() {
         session_id: "session_123",
         assistant_uuid: "assistant_456",
         parent_uuid: "user_789",
+        agent_id: None,
         request_id: "req_abc",
         message_id: "msg_def",
         content: vec![ContentBlock::ToolUse {
@@ -124,6 +126,7 @@ fn test_dual_record_strategy() {
         session_id: "session_123",
         user_uuid: "user_456",
         parent_uuid: Some("assistant_789"),
+        agent_id: None,
         content: UserMessageContent::ToolResult {
             tool_use_id: "toolu_abc",
             content: "hello\n\nExit code: 0",
@@ -149,3 +152,53 @@ fn test_dual_record_strategy() {
     assert!(lines[0].contains("\"type\":\"user\""));
     assert!(lines[1].contains("\"type\":\"result\""));
 }
+
+#[test]
+fn test_sidechain_messages_carry_agent_id() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.jsonl");
+    let timestamp = chrono::Utc::now();
+
+    let user_params = UserMessageParams {
+        session_id: "session_123",
+        user_uuid: "user_456",
+        parent_uuid: Some("assistant_123"),
+        agent_id: Some("a1b2c3d4"),
+        content: UserMessageContent::Text("Find the config loader"),
+        cwd: "/tmp",
+        version: "0.1.0",
+        git_branch: "main",
+        timestamp,
+    };
+    append_user_message_jsonl(&path, &user_params).unwrap();
+
+    let assistant_params = AssistantMessageParams {
+        session_id: "session_123",
+        assistant_uuid: "assistant_789",
+        parent_uuid: "user_456",
+        agent_id: None,
+        request_id: "req_abc",
+        message_id: "msg_def",
+        content: vec![ContentBlock::Text {
+            text: "Done.".to_string(),
+        }],
+        model: "claude-sonnet-4-20250514",
+        stop_reason: Some("end_turn"),
+        cwd: "/tmp",
+        version: "0.1.0",
+        git_branch: "main",
+        timestamp,
+    };
+    append_assistant_message_jsonl(&path, &assistant_params).unwrap();
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    let lines: Vec<serde_json::Value> = content
+        .lines()
+        .map(|l| serde_json::from_str(l).unwrap())
+        .collect();
+    assert_eq!(lines[0]["isSidechain"], true);
+    assert_eq!(lines[0]["agentId"], "a1b2c3d4");
+    assert_eq!(lines[0]["parentUuid"], "assistant_123");
+    assert_eq!(lines[1]["isSidechain"], false);
+    assert!(lines[1].get("agentId").is_none());
+}
@@ -130,7 +130,7 @@ impl PlansManager {
             .filter_map(|path| Plan::load(&path).ok())
             .collect();
         // Sort by modified time descending (most recent first)
-        plans.sort_by(|a, b| b.modified_at_ms.cmp(&a.modified_at_ms));
+        plans.sort_by_key(|p| std::cmp::Reverse(p.modified_at_ms));
         Ok(plans)
     }
 
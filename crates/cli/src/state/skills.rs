@@ -0,0 +1,182 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Skills loaded by the Skill tool.
+//!
+//! Each skill is a directory holding a `SKILL.md`, found in three places,
+//! later sources overriding earlier ones with the same name:
+//! 1. Plugins (`<plugin>/skills/<skill>/SKILL.md`), named `<plugin>:<skill>`
+//! 2. User (`~/.claude/skills/<skill>/SKILL.md`)
+//! 3. Project (`.claude/skills/<skill>/SKILL.md`)
+//!
+//! `SKILL.md` carries `name` and `description` in its frontmatter and the
+//! instructions injected when the skill is loaded as its body.
+
+use super::agents::plugin_name;
+use super::frontmatter::Frontmatter;
+use super::settings_source::SettingSource;
+use crate::output::print_warning;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// File holding a skill's definition.
+const SKILL_FILE: &str = "SKILL.md";
+
+/// Where a skill was defined.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SkillSource {
+    /// A `--plugin-dir` plugin, by plugin name.
+    Plugin(String),
+    /// `~/.claude/skills/`
+    User,
+    /// `.claude/skills/`
+    Project,
+}
+
+/// A skill.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Skill {
+    /// Name the Skill tool's `skill` input refers to.
+    pub name: String,
+    /// When to use the skill.
+    pub description: String,
+    /// Instructions injected into the conversation when the skill loads.
+    pub content: String,
+    /// Directory holding `SKILL.md` and any files it refers to.
+    pub dir: PathBuf,
+    /// Where the skill was defined.
+    pub source: SkillSource,
+}
+
+impl Skill {
+    /// Parse a `SKILL.md`. The name defaults to the skill's directory name.
+    pub fn from_markdown(content: &str, dir: &Path, source: SkillSource) -> Result<Self, String> {
+        let doc = Frontmatter::parse(content)?;
+        let Some(description) = doc.text("description") else {
+            return Err("missing required 'description' field".to_string());
+        };
+        let dir_name = dir
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Ok(Self {
+            name: doc.text("name").map_or(dir_name, str::to_string),
+            description: description.to_string(),
+            content: doc.body,
+            dir: dir.to_path_buf(),
+            source,
+        })
+    }
+}
+
+/// The Skill tool's result text when `name` loads.
+pub fn launch_message(name: &str) -> String {
+    format!("Launching skill: {}", name)
+}
+
+/// Directories to load skills from.
+#[derive(Clone, Debug, Default)]
+pub struct SkillsLoader {
+    /// Plugin roots (`--plugin-dir`).
+    pub plugin_dirs: Vec<PathBuf>,
+    /// User skills directory (~/.claude/skills)
+    pub user: Option<PathBuf>,
+    /// Project skills directory (.claude/skills)
+    pub project: Option<PathBuf>,
+}
+
+impl SkillsLoader {
+    /// Resolve skill locations, loading user and project skills only for
+    /// the given setting sources (all when `None`).
+    pub fn resolve(
+        state_dir: Option<&Path>,
+        working_dir: &Path,
+        sources: Option<&[SettingSource]>,
+    ) -> Self {
+        let sources = sources.unwrap_or(SettingSource::all());
+        Self {
+            plugin_dirs: Vec::new(),
+            user: state_dir
+                .filter(|_| sources.contains(&SettingSource::User))
+                .map(|dir| dir.join("skills")),
+            project: sources
+                .contains(&SettingSource::Project)
+                .then(|| working_dir.join(".claude").join("skills")),
+        }
+    }
+
+    /// Also load skills from these plugin directories.
+    pub fn with_plugin_dirs(mut self, plugin_dirs: &[String]) -> Self {
+        self.plugin_dirs = plugin_dirs.iter().map(PathBuf::from).collect();
+        self
+    }
+
+    /// Load all skills, sorted by name.
+    ///
+    /// Invalid definitions are skipped with a warning.
+    pub fn load(&self) -> Vec<Skill> {
+        let mut skills = BTreeMap::new();
+        let mut add = |found: Vec<Skill>| {
+            for skill in found {
+                skills.insert(skill.name.clone(), skill);
+            }
+        };
+
+        for plugin_dir in &self.plugin_dirs {
+            let plugin = plugin_name(plugin_dir);
+            let mut found = load_dir(
+                &plugin_dir.join("skills"),
+                SkillSource::Plugin(plugin.clone()),
+            );
+            for skill in &mut found {
+                skill.name = format!("{}:{}", plugin, skill.name);
+            }
+            add(found);
+        }
+        if let Some(ref dir) = self.user {
+            add(load_dir(dir, SkillSource::User));
+        }
+        if let Some(ref dir) = self.project {
+            add(load_dir(dir, SkillSource::Project));
+        }
+
+        skills.into_values().collect()
+    }
+}
+
+/// Load the skills in the subdirectories of `dir` that hold a `SKILL.md`.
+fn load_dir(dir: &Path, source: SkillSource) -> Vec<Skill> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut skill_dirs: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.join(SKILL_FILE).is_file())
+        .collect();
+    skill_dirs.sort();
+
+    skill_dirs
+        .iter()
+        .filter_map(|skill_dir| {
+            let path = skill_dir.join(SKILL_FILE);
+            let loaded = std::fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|content| Skill::from_markdown(&content, skill_dir, source.clone()));
+            match loaded {
+                Ok(skill) => Some(skill),
+                Err(e) => {
+                    print_warning(format_args!(
+                        "Failed to load skill from {}: {}",
+                        path.display(),
+                        e
+                    ));
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "skills_tests.rs"]
+mod tests;
@@ -0,0 +1,135 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+use std::fs;
+use tempfile::TempDir;
+
+fn write_skill(skills_dir: &Path, dir: &str, content: &str) {
+    let dir = skills_dir.join(dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join(SKILL_FILE), content).unwrap();
+}
+
+#[test]
+fn skill_reads_frontmatter_and_instructions() {
+    let skill = Skill::from_markdown(
+        "---\nname: deploy\ndescription: Deploys the app\n---\nRun make deploy.\n",
+        Path::new("/skills/deploy-dir"),
+        SkillSource::Project,
+    )
+    .unwrap();
+
+    assert_eq!(skill.name, "deploy");
+    assert_eq!(skill.description, "Deploys the app");
+    assert_eq!(skill.content, "Run make deploy.");
+    assert_eq!(skill.dir, PathBuf::from("/skills/deploy-dir"));
+}
+
+#[test]
+fn skill_name_defaults_to_directory_name() {
+    let skill = Skill::from_markdown(
+        "---\ndescription: Lints\n---\nLint.",
+        Path::new("/skills/lint"),
+        SkillSource::User,
+    )
+    .unwrap();
+
+    assert_eq!(skill.name, "lint");
+}
+
+#[test]
+fn skill_requires_description() {
+    let err = Skill::from_markdown("---\nname: x\n---\n", Path::new("/x"), SkillSource::User)
+        .unwrap_err();
+
+    assert!(err.contains("description"));
+}
+
+#[test]
+fn loader_applies_precedence() {
+    let state_dir = TempDir::new().unwrap();
+    let work_dir = TempDir::new().unwrap();
+    let plugin_dir = TempDir::new().unwrap();
+    let user_skills = state_dir.path().join("skills");
+    let project_skills = work_dir.path().join(".claude").join("skills");
+
+    write_skill(&user_skills, "shared", "---\ndescription: user\n---\n");
+    write_skill(&user_skills, "mine", "---\ndescription: user only\n---\n");
+    write_skill(
+        &project_skills,
+        "shared",
+        "---\ndescription: project\n---\n",
+    );
+    fs::create_dir_all(project_skills.join("empty")).unwrap();
+    write_skill(
+        &plugin_dir.path().join("skills"),
+        "release",
+        "---\ndescription: releases\n---\n",
+    );
+
+    let plugin_name = plugin_dir
+        .path()
+        .file_name()
+        .unwrap()
+        .to_string_lossy()
+        .into_owned();
+    let skills = SkillsLoader::resolve(Some(state_dir.path()), work_dir.path(), None)
+        .with_plugin_dirs(&[plugin_dir.path().to_string_lossy().into_owned()])
+        .load();
+
+    let described: Vec<_> = skills
+        .iter()
+        .map(|s| (s.name.as_str(), s.description.as_str()))
+        .collect();
+    let plugin_skill = format!("{}:release", plugin_name);
+    let mut expected = vec![
+        ("mine", "user only"),
+        ("shared", "project"),
+        (plugin_skill.as_str(), "releases"),
+    ];
+    expected.sort();
+    assert_eq!(described, expected);
+}
+
+#[test]
+fn loader_respects_setting_sources() {
+    let state_dir = TempDir::new().unwrap();
+    let work_dir = TempDir::new().unwrap();
+    write_skill(
+        &state_dir.path().join("skills"),
+        "mine",
+        "---\ndescription: user\n---\n",
+    );
+    write_skill(
+        &work_dir.path().join(".claude").join("skills"),
+        "ours",
+        "---\ndescription: project\n---\n",
+    );
+
+    let skills = SkillsLoader::resolve(
+        Some(state_dir.path()),
+        work_dir.path(),
+        Some(&[SettingSource::User]),
+    )
+    .load();
+
+    assert_eq!(skills.len(), 1);
+    assert_eq!(skills[0].name, "mine");
+    assert_eq!(skills[0].source, SkillSource::User);
+}
+
+#[test]
+fn loader_skips_invalid_skills() {
+    let work_dir = TempDir::new().unwrap();
+    let project_skills = work_dir.path().join(".claude").join("skills");
+    write_skill(&project_skills, "broken", "---\nname: broken\n");
+    write_skill(&project_skills, "ok", "---\ndescription: fine\n---\n");
+
+    let skills = SkillsLoader::resolve(None, work_dir.path(), None).load();
+
+    assert_eq!(skills.len(), 1);
+    assert_eq!(skills[0].name, "ok");
+}
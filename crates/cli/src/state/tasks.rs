@@ -0,0 +1,376 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Task list managed by TaskCreate, TaskUpdate, TaskList and TaskGet.
+//!
+//! Claude CLI stores each task as `~/.claude/tasks/{sessionId}/{id}.json`,
+//! with numeric IDs that are never reused: `.highwatermark` keeps the last
+//! ID handed out so deleted tasks leave gaps. Tasks form a dependency graph
+//! through `blocks` and `blockedBy`, which are kept symmetric.
+
+use super::io::{ensure_parent_exists, files_in, JsonLoad};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Name of the file holding the last task ID handed out.
+const HIGH_WATER_MARK_FILE: &str = ".highwatermark";
+
+/// Status of a task.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskStatus {
+    Pending,
+    InProgress,
+    Completed,
+}
+
+impl TaskStatus {
+    /// Name used in tool inputs and results.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::InProgress => "in_progress",
+            Self::Completed => "completed",
+        }
+    }
+
+    /// Parse a status name.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "pending" => Some(Self::Pending),
+            "in_progress" => Some(Self::InProgress),
+            "completed" => Some(Self::Completed),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for TaskStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A task in Claude CLI format.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Task {
+    pub id: String,
+    /// Short imperative title.
+    pub subject: String,
+    pub description: String,
+    /// Display form while in progress (e.g., "Running tests").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_form: Option<String>,
+    pub status: TaskStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// Tasks that cannot start until this one completes.
+    #[serde(default)]
+    pub blocks: Vec<String>,
+    /// Tasks that must complete before this one can start.
+    #[serde(default)]
+    pub blocked_by: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+}
+
+impl JsonLoad for Task {}
+
+/// Errors from changing the task list.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum TaskError {
+    #[error("Task #{0} not found")]
+    NotFound(String),
+
+    #[error("Task #{0} cannot depend on itself")]
+    SelfDependency(String),
+
+    #[error("Failed to save task: {0}")]
+    Io(String),
+}
+
+/// Changes requested by TaskUpdate. `None` fields are left alone.
+#[derive(Clone, Debug, Default)]
+pub struct TaskUpdate {
+    pub subject: Option<String>,
+    pub description: Option<String>,
+    pub active_form: Option<String>,
+    pub status: Option<TaskStatus>,
+    pub owner: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+    /// Tasks this one should block.
+    pub add_blocks: Vec<String>,
+    /// Tasks this one should wait for.
+    pub add_blocked_by: Vec<String>,
+}
+
+/// What a TaskUpdate changed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TaskUpdateOutcome {
+    /// Names of the fields that changed, in Claude CLI's spelling.
+    pub updated_fields: Vec<&'static str>,
+    /// Previous and new status, when the status changed.
+    pub status_change: Option<(TaskStatus, TaskStatus)>,
+}
+
+#[derive(Debug, Default)]
+struct Tasks {
+    /// Tasks in creation order.
+    items: Vec<Task>,
+    /// Last ID handed out.
+    high_water_mark: u64,
+}
+
+impl Tasks {
+    fn index(&self, id: &str) -> Result<usize, TaskError> {
+        self.items
+            .iter()
+            .position(|task| task.id == id)
+            .ok_or_else(|| TaskError::NotFound(id.to_string()))
+    }
+}
+
+/// The session's task list, saved to disk after every change when it has a
+/// directory.
+#[derive(Debug, Default)]
+pub struct TaskStore {
+    dir: Option<PathBuf>,
+    tasks: Mutex<Tasks>,
+}
+
+impl TaskStore {
+    /// Create a task list that is not saved.
+    pub fn in_memory() -> Self {
+        Self::default()
+    }
+
+    /// Open the task list stored in `dir`, loading any saved tasks.
+    pub fn open(dir: impl Into<PathBuf>) -> Self {
+        let dir = dir.into();
+        let mut items: Vec<Task> = files_in(&dir)
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .filter_map(|path| Task::load(&path).ok())
+            .collect();
+        items.sort_by_key(|task| numeric_id(&task.id));
+        let saved_mark = std::fs::read_to_string(dir.join(HIGH_WATER_MARK_FILE))
+            .ok()
+            .and_then(|s| s.trim().parse().ok())
+            .unwrap_or(0);
+        let high_water_mark = items
+            .iter()
+            .map(|task| numeric_id(&task.id))
+            .fold(saved_mark, u64::max);
+        Self {
+            dir: Some(dir),
+            tasks: Mutex::new(Tasks {
+                items,
+                high_water_mark,
+            }),
+        }
+    }
+
+    /// Directory the tasks are saved in, if any.
+    pub fn dir(&self) -> Option<&Path> {
+        self.dir.as_deref()
+    }
+
+    /// Add a pending task.
+    pub fn create(
+        &self,
+        subject: &str,
+        description: &str,
+        active_form: Option<&str>,
+        metadata: Option<serde_json::Value>,
+    ) -> Result<Task, TaskError> {
+        let mut tasks = self.tasks.lock();
+        let id = tasks.high_water_mark + 1;
+        let task = Task {
+            id: id.to_string(),
+            subject: subject.to_string(),
+            description: description.to_string(),
+            active_form: active_form.map(str::to_string),
+            status: TaskStatus::Pending,
+            owner: None,
+            blocks: Vec::new(),
+            blocked_by: Vec::new(),
+            metadata,
+        };
+        self.save(&task)?;
+        self.save_high_water_mark(id)?;
+        tasks.high_water_mark = id;
+        tasks.items.push(task.clone());
+        Ok(task)
+    }
+
+    /// A task by ID.
+    pub fn get(&self, id: &str) -> Option<Task> {
+        let tasks = self.tasks.lock();
+        tasks.items.iter().find(|task| task.id == id).cloned()
+    }
+
+    /// All tasks in ID order.
+    pub fn list(&self) -> Vec<Task> {
+        self.tasks.lock().items.clone()
+    }
+
+    /// Whether there are no tasks.
+    pub fn is_empty(&self) -> bool {
+        self.tasks.lock().items.is_empty()
+    }
+
+    /// IDs of the tasks blocking `task` that are not yet completed.
+    pub fn open_blockers(&self, task: &Task) -> Vec<String> {
+        let tasks = self.tasks.lock();
+        task.blocked_by
+            .iter()
+            .filter(|id| {
+                tasks
+                    .items
+                    .iter()
+                    .any(|t| t.id == **id && t.status != TaskStatus::Completed)
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Apply a TaskUpdate, linking both ends of any new dependency.
+    pub fn update(&self, id: &str, update: TaskUpdate) -> Result<TaskUpdateOutcome, TaskError> {
+        let mut tasks = self.tasks.lock();
+        let index = tasks.index(id)?;
+        for other in update.add_blocks.iter().chain(&update.add_blocked_by) {
+            if other == id {
+                return Err(TaskError::SelfDependency(id.to_string()));
+            }
+            tasks.index(other)?;
+        }
+
+        let mut outcome = TaskUpdateOutcome::default();
+        let mut changed = vec![index];
+        let task = &mut tasks.items[index];
+        if let Some(subject) = update.subject.filter(|s| *s != task.subject) {
+            task.subject = subject;
+            outcome.updated_fields.push("subject");
+        }
+        if let Some(description) = update.description.filter(|d| *d != task.description) {
+            task.description = description;
+            outcome.updated_fields.push("description");
+        }
+        if let Some(active_form) = update
+            .active_form
+            .filter(|a| task.active_form.as_ref() != Some(a))
+        {
+            task.active_form = Some(active_form);
+            outcome.updated_fields.push("activeForm");
+        }
+        if let Some(status) = update.status.filter(|s| *s != task.status) {
+            outcome.status_change = Some((task.status, status));
+            task.status = status;
+            outcome.updated_fields.push("status");
+        }
+        if let Some(owner) = update.owner.filter(|o| task.owner.as_ref() != Some(o)) {
+            task.owner = Some(owner);
+            outcome.updated_fields.push("owner");
+        }
+        if let Some(metadata) = update.metadata {
+            task.metadata = Some(metadata);
+            outcome.updated_fields.push("metadata");
+        }
+
+        let mut edges = Vec::new();
+        for other in update.add_blocks {
+            edges.push((id.to_string(), other));
+        }
+        for other in update.add_blocked_by {
+            edges.push((other, id.to_string()));
+        }
+        for (blocker, blocked) in edges {
+            let blocker_index = tasks.index(&blocker)?;
+            let blocked_index = tasks.index(&blocked)?;
+            let added = push_unique(&mut tasks.items[blocker_index].blocks, &blocked)
+                | push_unique(&mut tasks.items[blocked_index].blocked_by, &blocker);
+            if added {
+                let field = if blocker == id { "blocks" } else { "blockedBy" };
+                if !outcome.updated_fields.contains(&field) {
+                    outcome.updated_fields.push(field);
+                }
+                changed.extend([blocker_index, blocked_index]);
+            }
+        }
+
+        changed.sort_unstable();
+        changed.dedup();
+        for index in changed {
+            self.save(&tasks.items[index])?;
+        }
+        Ok(outcome)
+    }
+
+    /// Remove a task and every dependency on it.
+    pub fn delete(&self, id: &str) -> Result<Task, TaskError> {
+        let mut tasks = self.tasks.lock();
+        let index = tasks.index(id)?;
+        let task = tasks.items.remove(index);
+        if let Some(ref dir) = self.dir {
+            let _ = std::fs::remove_file(task_path(dir, id));
+        }
+        let mut changed = Vec::new();
+        for other in &mut tasks.items {
+            let before = other.blocks.len() + other.blocked_by.len();
+            other.blocks.retain(|t| t != id);
+            other.blocked_by.retain(|t| t != id);
+            if other.blocks.len() + other.blocked_by.len() != before {
+                changed.push(other.clone());
+            }
+        }
+        for other in &changed {
+            self.save(other)?;
+        }
+        Ok(task)
+    }
+
+    fn save(&self, task: &Task) -> Result<(), TaskError> {
+        let Some(ref dir) = self.dir else {
+            return Ok(());
+        };
+        let path = task_path(dir, &task.id);
+        let json = serde_json::to_string_pretty(task).map_err(|e| TaskError::Io(e.to_string()))?;
+        ensure_parent_exists(&path)
+            .and_then(|_| std::fs::write(&path, json))
+            .map_err(|e| TaskError::Io(e.to_string()))
+    }
+
+    fn save_high_water_mark(&self, id: u64) -> Result<(), TaskError> {
+        let Some(ref dir) = self.dir else {
+            return Ok(());
+        };
+        std::fs::create_dir_all(dir)
+            .and_then(|_| std::fs::write(dir.join(HIGH_WATER_MARK_FILE), id.to_string()))
+            .map_err(|e| TaskError::Io(e.to_string()))
+    }
+}
+
+/// File a task is saved in.
+fn task_path(dir: &Path, id: &str) -> PathBuf {
+    dir.join(format!("{}.json", id))
+}
+
+/// Numeric value of a task ID, for ordering.
+fn numeric_id(id: &str) -> u64 {
+    id.parse().unwrap_or(0)
+}
+
+/// Push `value` unless present, returning whether it was added.
+fn push_unique(list: &mut Vec<String>, value: &str) -> bool {
+    if list.iter().any(|v| v == value) {
+        return false;
+    }
+    list.push(value.to_string());
+    true
+}
+
+#[cfg(test)]
+#[path = "tasks_tests.rs"]
+mod tests;
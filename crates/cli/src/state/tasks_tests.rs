@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+use tempfile::TempDir;
+
+fn blocked_by(ids: &[&str]) -> TaskUpdate {
+    TaskUpdate {
+        add_blocked_by: ids.iter().map(|id| id.to_string()).collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn create_assigns_sequential_ids() {
+    let store = TaskStore::in_memory();
+
+    let first = store
+        .create("Write code", "Implement it", None, None)
+        .unwrap();
+    let second = store
+        .create("Test code", "Run tests", Some("Testing code"), None)
+        .unwrap();
+
+    assert_eq!(first.id, "1");
+    assert_eq!(second.id, "2");
+    assert_eq!(second.status, TaskStatus::Pending);
+    assert_eq!(second.active_form.as_deref(), Some("Testing code"));
+    assert_eq!(store.list().len(), 2);
+}
+
+#[test]
+fn update_reports_changed_fields_and_status_change() {
+    let store = TaskStore::in_memory();
+    store
+        .create("Write code", "Implement it", None, None)
+        .unwrap();
+
+    let outcome = store
+        .update(
+            "1",
+            TaskUpdate {
+                subject: Some("Write code".into()),
+                status: Some(TaskStatus::InProgress),
+                owner: Some("main".into()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+    assert_eq!(outcome.updated_fields, vec!["status", "owner"]);
+    assert_eq!(
+        outcome.status_change,
+        Some((TaskStatus::Pending, TaskStatus::InProgress))
+    );
+    assert_eq!(store.get("1").unwrap().status, TaskStatus::InProgress);
+}
+
+#[test]
+fn dependencies_are_linked_both_ways() {
+    let store = TaskStore::in_memory();
+    store.create("Build", "", None, None).unwrap();
+    store.create("Deploy", "", None, None).unwrap();
+
+    let outcome = store.update("2", blocked_by(&["1"])).unwrap();
+
+    assert_eq!(outcome.updated_fields, vec!["blockedBy"]);
+    assert_eq!(store.get("1").unwrap().blocks, vec!["2"]);
+    assert_eq!(store.get("2").unwrap().blocked_by, vec!["1"]);
+
+    // Adding the same dependency again changes nothing
+    let outcome = store.update("2", blocked_by(&["1"])).unwrap();
+    assert!(outcome.updated_fields.is_empty());
+}
+
+#[test]
+fn open_blockers_skip_completed_tasks() {
+    let store = TaskStore::in_memory();
+    store.create("Build", "", None, None).unwrap();
+    store.create("Deploy", "", None, None).unwrap();
+    store.update("2", blocked_by(&["1"])).unwrap();
+    let deploy = store.get("2").unwrap();
+
+    assert_eq!(store.open_blockers(&deploy), vec!["1"]);
+
+    store
+        .update(
+            "1",
+            TaskUpdate {
+                status: Some(TaskStatus::Completed),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    assert!(store.open_blockers(&deploy).is_empty());
+}
+
+#[test]
+fn update_rejects_unknown_and_self_dependencies() {
+    let store = TaskStore::in_memory();
+    store.create("Build", "", None, None).unwrap();
+
+    assert_eq!(
+        store.update("9", TaskUpdate::default()),
+        Err(TaskError::NotFound("9".into()))
+    );
+    assert_eq!(
+        store.update("1", blocked_by(&["9"])),
+        Err(TaskError::NotFound("9".into()))
+    );
+    assert_eq!(
+        store.update("1", blocked_by(&["1"])),
+        Err(TaskError::SelfDependency("1".into()))
+    );
+}
+
+#[test]
+fn delete_removes_dependencies_and_keeps_ids_unique() {
+    let store = TaskStore::in_memory();
+    store.create("Build", "", None, None).unwrap();
+    store.create("Deploy", "", None, None).unwrap();
+    store.update("2", blocked_by(&["1"])).unwrap();
+
+    store.delete("1").unwrap();
+
+    assert!(store.get("1").is_none());
+    assert!(store.get("2").unwrap().blocked_by.is_empty());
+    assert_eq!(store.create("Verify", "", None, None).unwrap().id, "3");
+}
+
+#[test]
+fn persisted_tasks_survive_reopening() {
+    let dir = TempDir::new().unwrap();
+    let tasks_dir = dir.path().join("session");
+    {
+        let store = TaskStore::open(&tasks_dir);
+        store.create("Build", "Compile", None, None).unwrap();
+        store.create("Deploy", "Ship", None, None).unwrap();
+        store.update("2", blocked_by(&["1"])).unwrap();
+        store.create("Scratch", "", None, None).unwrap();
+        store.delete("3").unwrap();
+    }
+
+    let saved: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(tasks_dir.join("2.json")).unwrap()).unwrap();
+    assert_eq!(saved["subject"], "Deploy");
+    assert_eq!(saved["status"], "pending");
+    assert_eq!(saved["blockedBy"], serde_json::json!(["1"]));
+    assert!(!tasks_dir.join("3.json").exists());
+
+    let store = TaskStore::open(&tasks_dir);
+    let ids: Vec<_> = store.list().into_iter().map(|t| t.id).collect();
+    assert_eq!(ids, vec!["1", "2"]);
+    assert_eq!(store.create("Verify", "", None, None).unwrap().id, "4");
+}
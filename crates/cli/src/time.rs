@@ -151,7 +151,7 @@ impl Clock for FakeClock {
 }
 
 /// Clock handle that can be either real or fake
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub enum ClockHandle {
     System(SystemClock),
     Fake(FakeClock),
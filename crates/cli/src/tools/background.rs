@@ -0,0 +1,306 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Background shells started by `Bash` with `run_in_background`.
+//!
+//! The registry lives for the session. `BashOutput` reads what a shell has
+//! printed since the last read, `KillShell` stops it, and the TUI `/tasks`
+//! dialog lists every shell with its status. Shells still running when the
+//! registry is dropped are killed.
+
+use std::io::{self, Read};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+
+use parking_lot::Mutex;
+use thiserror::Error;
+
+/// Errors from looking up or killing a background shell.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum BackgroundShellError {
+    /// No shell has the given ID.
+    #[error("No shell found with ID: {0}")]
+    NotFound(String),
+
+    /// The shell already exited or was killed.
+    #[error("Shell {id} is not running, so cannot be killed (status: {status})")]
+    NotRunning { id: String, status: ShellStatus },
+}
+
+/// Lifecycle of a background shell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShellStatus {
+    Running,
+    Completed,
+    Failed,
+    Killed,
+}
+
+impl ShellStatus {
+    /// Lowercase name used in tool results.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Running => "running",
+            Self::Completed => "completed",
+            Self::Failed => "failed",
+            Self::Killed => "killed",
+        }
+    }
+}
+
+impl std::fmt::Display for ShellStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Output printed since the previous read, with the shell's current status.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShellOutput {
+    pub command: String,
+    pub status: ShellStatus,
+    /// Exit code once the shell has finished on its own.
+    pub exit_code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// A background shell as listed by the `/tasks` dialog.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShellSummary {
+    pub id: String,
+    pub command: String,
+    /// The `description` input, or the command when none was given.
+    pub description: String,
+    pub status: ShellStatus,
+}
+
+/// Bytes a reader thread has collected from one pipe.
+pub(crate) type Buffer = Arc<Mutex<Vec<u8>>>;
+
+/// One running or finished background shell.
+struct BackgroundShell {
+    id: String,
+    command: String,
+    description: String,
+    child: Child,
+    stdout: Buffer,
+    stderr: Buffer,
+    /// Threads filling `stdout` and `stderr`.
+    readers: [JoinHandle<()>; 2],
+    /// Exit status, once the process has exited.
+    exit: Option<ExitStatus>,
+    /// Bytes of stdout/stderr already returned by [`BackgroundShells::output`].
+    stdout_read: usize,
+    stderr_read: usize,
+    status: ShellStatus,
+    exit_code: Option<i32>,
+}
+
+impl BackgroundShell {
+    /// Mark the shell finished once it has exited and all its output has
+    /// been collected.
+    fn refresh(&mut self) {
+        if self.status != ShellStatus::Running {
+            return;
+        }
+        if self.exit.is_none() {
+            self.exit = self.child.try_wait().ok().flatten();
+        }
+        let Some(exit) = self.exit else {
+            return;
+        };
+        if self.readers.iter().all(JoinHandle::is_finished) {
+            self.status = if exit.success() {
+                ShellStatus::Completed
+            } else {
+                ShellStatus::Failed
+            };
+            self.exit_code = exit.code();
+        }
+    }
+
+    /// Text appended to `buffer` since `read`, advancing `read`.
+    ///
+    /// A character cut off at the end stays unread until the rest of it
+    /// arrives, unless the pipe is closed.
+    fn take_new(buffer: &Buffer, read: &mut usize, closed: bool) -> String {
+        let buffer = buffer.lock();
+        let new = &buffer[*read..];
+        let end = match std::str::from_utf8(new) {
+            Err(e) if e.error_len().is_none() && !closed => e.valid_up_to(),
+            _ => new.len(),
+        };
+        *read += end;
+        String::from_utf8_lossy(&new[..end]).into_owned()
+    }
+}
+
+/// Session-scoped registry of background shells.
+#[derive(Default)]
+pub struct BackgroundShells {
+    shells: Mutex<Vec<BackgroundShell>>,
+    next_id: Mutex<usize>,
+}
+
+impl std::fmt::Debug for BackgroundShells {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BackgroundShells")
+            .field("shells", &self.shells.lock().len())
+            .finish()
+    }
+}
+
+impl BackgroundShells {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start `cmd` in the background and return its shell ID.
+    ///
+    /// `command` is the command line as the model wrote it, kept for
+    /// results and the `/tasks` dialog.
+    pub fn spawn(
+        &self,
+        mut cmd: Command,
+        command: &str,
+        description: Option<&str>,
+    ) -> io::Result<String> {
+        cmd.stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::CommandExt;
+            cmd.process_group(0);
+        }
+
+        let mut child = cmd.spawn()?;
+        let (stdout, stdout_reader) = collect(child.stdout.take());
+        let (stderr, stderr_reader) = collect(child.stderr.take());
+
+        let id = {
+            let mut next_id = self.next_id.lock();
+            *next_id += 1;
+            format!("bash_{}", next_id)
+        };
+        self.shells.lock().push(BackgroundShell {
+            id: id.clone(),
+            command: command.to_string(),
+            description: description.unwrap_or(command).to_string(),
+            child,
+            stdout,
+            stderr,
+            readers: [stdout_reader, stderr_reader],
+            exit: None,
+            stdout_read: 0,
+            stderr_read: 0,
+            status: ShellStatus::Running,
+            exit_code: None,
+        });
+        Ok(id)
+    }
+
+    /// Output the shell has printed since the last call.
+    pub fn output(&self, id: &str) -> Result<ShellOutput, BackgroundShellError> {
+        let mut shells = self.shells.lock();
+        let shell = shells
+            .iter_mut()
+            .find(|s| s.id == id)
+            .ok_or_else(|| BackgroundShellError::NotFound(id.to_string()))?;
+        shell.refresh();
+        let [stdout_closed, stderr_closed] = shell.readers.each_ref().map(JoinHandle::is_finished);
+        Ok(ShellOutput {
+            command: shell.command.clone(),
+            status: shell.status,
+            exit_code: shell.exit_code,
+            stdout: BackgroundShell::take_new(&shell.stdout, &mut shell.stdout_read, stdout_closed),
+            stderr: BackgroundShell::take_new(&shell.stderr, &mut shell.stderr_read, stderr_closed),
+        })
+    }
+
+    /// Kill a running shell and everything it started, returning its command.
+    pub fn kill(&self, id: &str) -> Result<String, BackgroundShellError> {
+        let mut shells = self.shells.lock();
+        let shell = shells
+            .iter_mut()
+            .find(|s| s.id == id)
+            .ok_or_else(|| BackgroundShellError::NotFound(id.to_string()))?;
+        shell.refresh();
+        if shell.status != ShellStatus::Running {
+            return Err(BackgroundShellError::NotRunning {
+                id: id.to_string(),
+                status: shell.status,
+            });
+        }
+        kill_process_group(&mut shell.child);
+        let _ = shell.child.wait();
+        shell.status = ShellStatus::Killed;
+        Ok(shell.command.clone())
+    }
+
+    /// Every shell started this session, oldest first.
+    pub fn list(&self) -> Vec<ShellSummary> {
+        let mut shells = self.shells.lock();
+        shells
+            .iter_mut()
+            .map(|shell| {
+                shell.refresh();
+                ShellSummary {
+                    id: shell.id.clone(),
+                    command: shell.command.clone(),
+                    description: shell.description.clone(),
+                    status: shell.status,
+                }
+            })
+            .collect()
+    }
+}
+
+impl Drop for BackgroundShells {
+    fn drop(&mut self) {
+        for shell in self.shells.get_mut() {
+            shell.refresh();
+            if shell.status == ShellStatus::Running {
+                kill_process_group(&mut shell.child);
+                let _ = shell.child.wait();
+            }
+        }
+    }
+}
+
+/// Append everything read from `pipe` to a shared buffer on a background thread.
+pub(crate) fn collect(pipe: Option<impl Read + Send + 'static>) -> (Buffer, JoinHandle<()>) {
+    let buffer = Buffer::default();
+    let sink = Arc::clone(&buffer);
+    let reader = thread::spawn(move || {
+        let Some(mut pipe) = pipe else {
+            return;
+        };
+        let mut chunk = [0u8; 8192];
+        while let Ok(n) = pipe.read(&mut chunk) {
+            if n == 0 {
+                break;
+            }
+            sink.lock().extend_from_slice(&chunk[..n]);
+        }
+    });
+    (buffer, reader)
+}
+
+/// Kill a process started in its own process group, and everything in it.
+pub(crate) fn kill_process_group(child: &mut Child) {
+    #[cfg(unix)]
+    {
+        use nix::sys::signal::{killpg, Signal};
+        use nix::unistd::Pid;
+        let _ = killpg(Pid::from_raw(child.id() as i32), Signal::SIGKILL);
+    }
+    let _ = child.kill();
+}
+
+#[cfg(test)]
+#[path = "background_tests.rs"]
+mod tests;
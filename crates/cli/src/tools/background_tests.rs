@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use std::time::{Duration, Instant};
+
+fn sh(script: &str) -> Command {
+    let mut cmd = Command::new("sh");
+    cmd.arg("-c").arg(script);
+    cmd
+}
+
+/// Poll until the shell leaves the running state.
+fn wait_for_exit(shells: &BackgroundShells, id: &str) {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while shells
+        .list()
+        .iter()
+        .any(|s| s.id == id && s.status == ShellStatus::Running)
+    {
+        assert!(Instant::now() < deadline, "shell {id} did not exit");
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[test]
+#[cfg(unix)]
+fn output_is_returned_once() {
+    let shells = BackgroundShells::new();
+    let id = shells
+        .spawn(sh("echo out; echo err >&2"), "echo", None)
+        .unwrap();
+    assert_eq!(id, "bash_1");
+    wait_for_exit(&shells, &id);
+
+    let first = shells.output(&id).unwrap();
+    assert_eq!(first.status, ShellStatus::Completed);
+    assert_eq!(first.exit_code, Some(0));
+    assert_eq!(first.stdout, "out\n");
+    assert_eq!(first.stderr, "err\n");
+
+    let second = shells.output(&id).unwrap();
+    assert_eq!(second.stdout, "");
+    assert_eq!(second.stderr, "");
+}
+
+#[test]
+#[cfg(unix)]
+fn failed_shell_reports_exit_code() {
+    let shells = BackgroundShells::new();
+    let id = shells.spawn(sh("exit 3"), "exit 3", None).unwrap();
+    wait_for_exit(&shells, &id);
+
+    let output = shells.output(&id).unwrap();
+    assert_eq!(output.status, ShellStatus::Failed);
+    assert_eq!(output.exit_code, Some(3));
+}
+
+#[test]
+#[cfg(unix)]
+fn kill_stops_running_shell() {
+    let shells = BackgroundShells::new();
+    let id = shells
+        .spawn(sh("sleep 30"), "sleep 30", Some("Wait a while"))
+        .unwrap();
+
+    assert_eq!(shells.kill(&id), Ok("sleep 30".to_string()));
+    assert_eq!(
+        shells.list(),
+        vec![ShellSummary {
+            id: id.clone(),
+            command: "sleep 30".to_string(),
+            description: "Wait a while".to_string(),
+            status: ShellStatus::Killed,
+        }]
+    );
+    assert_eq!(
+        shells.kill(&id),
+        Err(BackgroundShellError::NotRunning {
+            id: id.clone(),
+            status: ShellStatus::Killed,
+        })
+    );
+}
+
+#[test]
+fn unknown_id_is_not_found() {
+    let shells = BackgroundShells::new();
+    let err = shells.output("bash_9").unwrap_err();
+    assert_eq!(err.to_string(), "No shell found with ID: bash_9");
+    assert!(matches!(
+        shells.kill("bash_9"),
+        Err(BackgroundShellError::NotFound(_))
+    ));
+}
+
+#[test]
+fn split_character_is_kept_until_complete() {
+    let buffer = Buffer::default();
+    let mut read = 0;
+    buffer
+        .lock()
+        .extend_from_slice(&"a\u{2192}".as_bytes()[..2]);
+    assert_eq!(BackgroundShell::take_new(&buffer, &mut read, false), "a");
+
+    buffer
+        .lock()
+        .extend_from_slice(&"\u{2192}b".as_bytes()[1..]);
+    assert_eq!(
+        BackgroundShell::take_new(&buffer, &mut read, false),
+        "\u{2192}b"
+    );
+    assert_eq!(read, 5);
+}
+
+#[test]
+fn split_character_is_replaced_once_pipe_closes() {
+    let buffer = Buffer::default();
+    let mut read = 0;
+    buffer.lock().extend_from_slice(&"\u{2192}".as_bytes()[..2]);
+    assert_eq!(
+        BackgroundShell::take_new(&buffer, &mut read, true),
+        "\u{FFFD}"
+    );
+}
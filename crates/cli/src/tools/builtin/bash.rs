@@ -2,59 +2,365 @@
 // Copyright (c) 2026 Alfred Jean LLC
 
 //! Bash command executor.
+//!
+//! Commands run in `sh` with the session's saved directory and exports
+//! (see [`super::shell`]), are killed with their process group when the
+//! `timeout` expires, and have very long output truncated like the real
+//! tool before the result size limits (see [`crate::tools::limits`])
+//! apply. With `run_in_background` the command is handed to the session's
+//! [`BackgroundShells`] and the call returns its shell ID at once. In a
+//! sandbox, commands that name paths outside the copy are refused.
 
-use std::process::Command;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde_json::json;
 
 use crate::config::ToolCallSpec;
+use crate::tools::background::{collect, kill_process_group, BackgroundShells};
 use crate::tools::result::ToolExecutionResult;
 
-use super::{extract_str, require_field, BuiltinContext, BuiltinToolExecutor};
+use super::{
+    extract_bool, extract_str, extract_usize, require_field, BuiltinContext, BuiltinToolExecutor,
+};
 use crate::tools::tool_name::ToolName;
 
+/// Timeout when the input has none (`BASH_DEFAULT_TIMEOUT_MS` overrides).
+const DEFAULT_TIMEOUT_MS: u64 = 120_000;
+
+/// Largest accepted timeout (`BASH_MAX_TIMEOUT_MS` overrides).
+const MAX_TIMEOUT_MS: u64 = 600_000;
+
+/// Characters kept from stdout and stderr (`BASH_MAX_OUTPUT_LENGTH` overrides).
+const MAX_OUTPUT_LENGTH: usize = 30_000;
+
+/// How often a running command is polled for exit and timeout.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// How long to keep reading output after the shell exits, when jobs it
+/// started keep the pipes open.
+const OUTPUT_SETTLE: Duration = Duration::from_millis(100);
+
+/// Wrapper run by `sh -c`: replays the saved exports, runs the command and
+/// saves the final directory and exports on exit.
+///
+/// Arguments: `$1` state directory, `$2` command, `$3` saved exports.
+const SHELL_SCRIPT: &str = r#"__claudeless_state=$1
+__claudeless_command=$2
+eval "$3"
+shift 3
+trap 'pwd -P > "$__claudeless_state/cwd"; export -p > "$__claudeless_state/env"' EXIT
+eval "$__claudeless_command"
+"#;
+
+/// Wrapper for background commands: replays the saved exports but leaves
+/// the session's directory and exports alone.
+///
+/// Arguments: `$1` command, `$2` saved exports.
+const BACKGROUND_SCRIPT: &str = r#"__claudeless_command=$1
+eval "$2"
+shift 2
+eval "$__claudeless_command"
+"#;
+
 /// Executor for Bash commands.
 #[derive(Clone, Debug, Default)]
 pub struct BashExecutor;
 
+/// Captured output of a finished or killed command.
+struct CommandOutput {
+    stdout: String,
+    stderr: String,
+    exit_code: i32,
+    interrupted: bool,
+}
+
 impl BashExecutor {
-    /// Execute command for real.
-    fn execute_real(command: &str, ctx: &BuiltinContext, tool_use_id: &str) -> ToolExecutionResult {
-        let mut cmd = Command::new("sh");
-        cmd.arg("-c").arg(command);
+    /// A millisecond limit from the settings env or process env.
+    fn env_limit(ctx: &BuiltinContext, key: &str) -> Option<u64> {
+        ctx.env
+            .get(key)
+            .cloned()
+            .or_else(|| std::env::var(key).ok())
+            .and_then(|v| v.trim().parse().ok())
+    }
 
-        // Set working directory if specified
-        if let Some(ref cwd) = ctx.cwd {
-            cmd.current_dir(cwd);
+    /// Timeout for this call, capped at the maximum.
+    fn timeout(input: &serde_json::Value, ctx: &BuiltinContext) -> Duration {
+        let max = Self::env_limit(ctx, "BASH_MAX_TIMEOUT_MS").unwrap_or(MAX_TIMEOUT_MS);
+        let default = Self::env_limit(ctx, "BASH_DEFAULT_TIMEOUT_MS").unwrap_or(DEFAULT_TIMEOUT_MS);
+        let ms = extract_usize(input, "timeout").map_or(default, |t| t as u64);
+        Duration::from_millis(ms.min(max))
+    }
+
+    /// Keep the first `max_len` characters, noting how many lines were cut.
+    fn truncate_output(output: &str, max_len: usize) -> String {
+        match output.char_indices().nth(max_len) {
+            Some((cut, _)) => {
+                let (kept, rest) = output.split_at(cut);
+                format!(
+                    "{}\n\n... [{} lines truncated] ...",
+                    kept,
+                    rest.lines().count()
+                )
+            }
+            None => output.to_string(),
+        }
+    }
+
+    /// Run the command, killing it once `timeout` passes.
+    ///
+    /// The command counts as finished once the shell has exited. Jobs it
+    /// left running in the background may hold the pipes open; what they
+    /// print after [`OUTPUT_SETTLE`] is not read.
+    fn run(mut cmd: Command, timeout: Duration) -> std::io::Result<CommandOutput> {
+        cmd.stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::CommandExt;
+            cmd.process_group(0);
         }
 
-        match cmd.output() {
-            Ok(output) => {
-                let stdout = String::from_utf8_lossy(&output.stdout);
-                let stderr = String::from_utf8_lossy(&output.stderr);
-                let exit_code = output.status.code().unwrap_or(-1);
-
-                // Always include exit code in output for log extraction
-                let result_text = if output.status.success() {
-                    format!("{}\n\nExit code: {}", stdout.trim(), exit_code)
-                } else {
-                    let error_content = if stderr.is_empty() {
-                        stdout.trim().to_string()
-                    } else {
-                        format!("{}\n{}", stdout.trim(), stderr.trim())
-                    };
-                    format!("{}\n\nExit code: {}", error_content.trim(), exit_code)
-                };
+        let mut child = cmd.spawn()?;
+        let (stdout, stdout_reader) = collect(child.stdout.take());
+        let (stderr, stderr_reader) = collect(child.stderr.take());
+        let deadline = Instant::now() + timeout;
 
-                if output.status.success() {
-                    ToolExecutionResult::success(tool_use_id, result_text)
-                } else {
-                    ToolExecutionResult::error(tool_use_id, result_text)
+        let mut status = None;
+        let mut exited_at = None;
+        let interrupted = loop {
+            if status.is_none() {
+                status = child.try_wait()?;
+                exited_at = status.map(|_| Instant::now());
+            }
+            if let Some(exited_at) = exited_at {
+                let closed = stdout_reader.is_finished() && stderr_reader.is_finished();
+                if closed || exited_at.elapsed() >= OUTPUT_SETTLE {
+                    break false;
                 }
             }
+            if Instant::now() >= deadline {
+                kill_process_group(&mut child);
+                break true;
+            }
+            thread::sleep(POLL_INTERVAL);
+        };
+        let status = match status {
+            Some(status) => status,
+            None => child.wait()?,
+        };
+
+        let stdout = String::from_utf8_lossy(&stdout.lock()).into_owned();
+        let stderr = String::from_utf8_lossy(&stderr.lock()).into_owned();
+        Ok(CommandOutput {
+            stdout,
+            stderr,
+            exit_code: status.code().unwrap_or(-1),
+            interrupted,
+        })
+    }
+
+    /// `export` lines for the settings env, replayed after the saved exports
+    /// so a command cannot override it for later calls.
+    fn settings_exports(ctx: &BuiltinContext) -> String {
+        let mut vars: Vec<_> = ctx
+            .env
+            .iter()
+            .filter(|(key, _)| is_shell_name(key))
+            .collect();
+        vars.sort();
+        vars.into_iter()
+            .map(|(key, value)| export_line(key, value))
+            .collect()
+    }
+
+    /// Directory the next command starts in: where the last command left
+    /// off, else the working directory.
+    fn start_dir(ctx: &BuiltinContext) -> Option<PathBuf> {
+        ctx.shell
+            .as_ref()
+            .and_then(|s| s.snapshot().cwd)
+            .filter(|dir| dir.is_dir())
+            .or_else(|| ctx.cwd.clone())
+    }
+
+    /// An `sh -c` command with the settings env, starting where the last
+    /// command left off (else in the working directory).
+    ///
+    /// `args` adds the script and its arguments, given the exports to
+    /// replay: the saved ones, then the settings env. In a sandbox, `HOME`
+    /// is the copy's root.
+    fn shell_command(ctx: &BuiltinContext, args: impl FnOnce(&mut Command, &str)) -> Command {
+        let snapshot = ctx.shell.as_ref().map(|s| s.snapshot()).unwrap_or_default();
+        let mut exports = format!("{}\n{}", snapshot.exports, Self::settings_exports(ctx));
+        if let Some(ref sandbox) = ctx.sandbox {
+            exports.push_str(&export_line("HOME", &sandbox.root().to_string_lossy()));
+        }
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c");
+        args(&mut cmd, &exports);
+        cmd.envs(&ctx.env);
+
+        if let Some(ref cwd) = Self::start_dir(ctx) {
+            cmd.current_dir(cwd);
+        }
+        cmd
+    }
+
+    /// Start the command as a background shell.
+    fn execute_background(
+        command: &str,
+        description: Option<&str>,
+        shells: &BackgroundShells,
+        ctx: &BuiltinContext,
+        tool_use_id: &str,
+    ) -> ToolExecutionResult {
+        let cmd = Self::shell_command(ctx, |cmd, exports| {
+            cmd.arg(BACKGROUND_SCRIPT)
+                .arg("sh")
+                .arg(command)
+                .arg(exports);
+        });
+        match shells.spawn(cmd, command, description) {
+            Ok(id) => ToolExecutionResult::success_with_result(
+                tool_use_id,
+                format!("Command running in background with ID: {}", id),
+                json!({
+                    "stdout": "",
+                    "stderr": "",
+                    "interrupted": false,
+                    "isImage": false,
+                    "backgroundTaskId": id,
+                }),
+            ),
             Err(e) => {
                 ToolExecutionResult::error(tool_use_id, format!("Failed to execute command: {}", e))
             }
         }
     }
+
+    /// Execute command for real.
+    fn execute_real(
+        command: &str,
+        timeout: Duration,
+        ctx: &BuiltinContext,
+        tool_use_id: &str,
+    ) -> ToolExecutionResult {
+        let state_dir = match tempfile::tempdir() {
+            Ok(dir) => dir,
+            Err(e) => {
+                return ToolExecutionResult::error(
+                    tool_use_id,
+                    format!("Failed to execute command: {}", e),
+                )
+            }
+        };
+        let cmd = Self::shell_command(ctx, |cmd, exports| {
+            cmd.arg(SHELL_SCRIPT)
+                .arg("sh")
+                .arg(state_dir.path())
+                .arg(command)
+                .arg(exports);
+        });
+
+        let output = match Self::run(cmd, timeout) {
+            Ok(output) => output,
+            Err(e) => {
+                return ToolExecutionResult::error(
+                    tool_use_id,
+                    format!("Failed to execute command: {}", e),
+                )
+            }
+        };
+
+        let max_len = Self::env_limit(ctx, "BASH_MAX_OUTPUT_LENGTH")
+            .map_or(MAX_OUTPUT_LENGTH, |n| n as usize);
+        let stdout = Self::truncate_output(output.stdout.trim(), max_len);
+        let mut stderr = Self::truncate_output(output.stderr.trim(), max_len);
+        if output.interrupted {
+            stderr = format!(
+                "{}\nCommand timed out after {}ms",
+                stderr,
+                timeout.as_millis()
+            )
+            .trim()
+            .to_string();
+        } else if let Some(note) = Self::save_state(ctx, state_dir.path()) {
+            stderr = format!("{}\n{}", stderr, note).trim().to_string();
+        }
+
+        let success = !output.interrupted && output.exit_code == 0;
+
+        // Always include exit code in output for log extraction
+        let result_text = if output.interrupted {
+            format!("{}\n{}", stdout, stderr).trim().to_string()
+        } else {
+            let content = if success || stderr.is_empty() {
+                stdout.clone()
+            } else {
+                format!("{}\n{}", stdout, stderr)
+            };
+            format!("{}\n\nExit code: {}", content.trim(), output.exit_code)
+        };
+
+        let tool_use_result = json!({
+            "stdout": stdout,
+            "stderr": stderr,
+            "interrupted": output.interrupted,
+            "isImage": false,
+        });
+        let mut result = if success {
+            ToolExecutionResult::success(tool_use_id, result_text)
+        } else {
+            ToolExecutionResult::error(tool_use_id, result_text)
+        };
+        result.tool_use_result = Some(tool_use_result);
+        result
+    }
+
+    /// Save the directory and exports the command finished with.
+    ///
+    /// In a sandbox, a directory outside the copy is reset to the working
+    /// directory and the returned note says so.
+    fn save_state(ctx: &BuiltinContext, state_dir: &Path) -> Option<String> {
+        let shell = ctx.shell.as_ref()?;
+        let cwd = std::fs::read_to_string(state_dir.join("cwd")).ok()?;
+        let exports = std::fs::read_to_string(state_dir.join("env")).unwrap_or_default();
+        let cwd = Path::new(cwd.trim_end_matches('\n'));
+        shell.update(cwd, exports);
+
+        let sandbox = ctx.sandbox.as_ref()?;
+        if cwd.starts_with(sandbox.root()) {
+            return None;
+        }
+        let reset = ctx
+            .cwd
+            .clone()
+            .unwrap_or_else(|| sandbox.root().to_path_buf());
+        shell.reset_cwd(&reset);
+        Some(format!(
+            "Shell cwd was reset to {}",
+            sandbox.display_path(&reset).display()
+        ))
+    }
+}
+
+/// An `export` line setting `key` to `value`, quoted for `sh`.
+fn export_line(key: &str, value: &str) -> String {
+    format!("export {}='{}'\n", key, value.replace('\'', r"'\''"))
+}
+
+/// Whether `name` can be exported by `sh`.
+fn is_shell_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
 }
 
 impl BuiltinToolExecutor for BashExecutor {
@@ -66,7 +372,39 @@ impl BuiltinToolExecutor for BashExecutor {
     ) -> ToolExecutionResult {
         let command = require_field!(call.input, "command", extract_str, tool_use_id, call.tool);
 
-        Self::execute_real(command, ctx, tool_use_id)
+        if ctx.vfs.is_some() {
+            return ToolExecutionResult::error(
+                tool_use_id,
+                "Bash is not available in vfs mode; provide a canned result",
+            );
+        }
+
+        let confined;
+        let command = match ctx.sandbox {
+            Some(ref sandbox) => {
+                let start = Self::start_dir(ctx).unwrap_or_else(|| sandbox.root().to_path_buf());
+                confined = match sandbox.confine_command(command, &start) {
+                    Ok(confined) => confined,
+                    Err(e) => return ToolExecutionResult::error(tool_use_id, e.to_string()),
+                };
+                confined.as_str()
+            }
+            None => command,
+        };
+
+        if extract_bool(&call.input, "run_in_background", false) {
+            let Some(ref shells) = ctx.background_shells else {
+                return ToolExecutionResult::error(
+                    tool_use_id,
+                    "Background shells are not available in this session",
+                );
+            };
+            let description = extract_str(&call.input, "description");
+            return Self::execute_background(command, description, shells, ctx, tool_use_id);
+        }
+
+        let timeout = Self::timeout(&call.input, ctx);
+        Self::execute_real(command, timeout, ctx, tool_use_id)
     }
 
     fn tool_name(&self) -> ToolName {
@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Background shell output executor.
+//!
+//! Returns what a background shell printed since the previous `BashOutput`
+//! call, in the real tool's tagged format. With `filter`, only matching
+//! lines are returned; the rest are discarded.
+
+use chrono::{DateTime, SecondsFormat};
+use regex::Regex;
+use serde_json::json;
+
+use crate::config::ToolCallSpec;
+use crate::time::Clock;
+use crate::tools::result::ToolExecutionResult;
+
+use super::{extract_str, require_field, BuiltinContext, BuiltinToolExecutor};
+use crate::tools::tool_name::ToolName;
+
+/// Executor for reading background shell output.
+#[derive(Clone, Debug, Default)]
+pub struct BashOutputExecutor;
+
+impl BashOutputExecutor {
+    /// Keep only the lines matching `filter`.
+    fn filter_lines(output: &str, filter: Option<&Regex>) -> String {
+        match filter {
+            Some(re) => output
+                .lines()
+                .filter(|line| re.is_match(line))
+                .map(|line| format!("{}\n", line))
+                .collect(),
+            None => output.to_string(),
+        }
+    }
+}
+
+impl BuiltinToolExecutor for BashOutputExecutor {
+    fn execute(
+        &self,
+        call: &ToolCallSpec,
+        tool_use_id: &str,
+        ctx: &BuiltinContext,
+    ) -> ToolExecutionResult {
+        let bash_id = require_field!(call.input, "bash_id", extract_str, tool_use_id, call.tool);
+        let filter = match extract_str(&call.input, "filter").map(Regex::new) {
+            Some(Ok(re)) => Some(re),
+            Some(Err(e)) => {
+                return ToolExecutionResult::error(
+                    tool_use_id,
+                    format!("Invalid filter regex: {}", e),
+                )
+            }
+            None => None,
+        };
+
+        let output = match ctx.background_shells.as_ref().map(|s| s.output(bash_id)) {
+            Some(Ok(output)) => output,
+            Some(Err(e)) => return ToolExecutionResult::error(tool_use_id, e.to_string()),
+            None => {
+                return ToolExecutionResult::error(
+                    tool_use_id,
+                    format!("No shell found with ID: {}", bash_id),
+                )
+            }
+        };
+
+        let stdout = Self::filter_lines(&output.stdout, filter.as_ref());
+        let stderr = Self::filter_lines(&output.stderr, filter.as_ref());
+        let stdout = stdout.trim_end();
+        let stderr = stderr.trim_end();
+        let timestamp = DateTime::from_timestamp_millis(ctx.clock.now_millis() as i64)
+            .unwrap_or_default()
+            .to_rfc3339_opts(SecondsFormat::Millis, true);
+
+        let mut sections = vec![format!("<status>{}</status>", output.status)];
+        if let Some(code) = output.exit_code {
+            sections.push(format!("<exit_code>{}</exit_code>", code));
+        }
+        if !stdout.is_empty() {
+            sections.push(format!("<stdout>\n{}\n</stdout>", stdout));
+        }
+        if !stderr.is_empty() {
+            sections.push(format!("<stderr>\n{}\n</stderr>", stderr));
+        }
+        sections.push(format!("<timestamp>{}</timestamp>", timestamp));
+
+        ToolExecutionResult::success_with_result(
+            tool_use_id,
+            sections.join("\n\n"),
+            json!({
+                "shellId": bash_id,
+                "command": output.command,
+                "status": output.status.as_str(),
+                "exitCode": output.exit_code,
+                "stdout": stdout,
+                "stderr": stderr,
+                "stdoutLines": stdout.lines().count(),
+                "stderrLines": stderr.lines().count(),
+                "timestamp": timestamp,
+            }),
+        )
+    }
+
+    fn tool_name(&self) -> ToolName {
+        ToolName::BashOutput
+    }
+}
+
+#[cfg(test)]
+#[path = "bash_output_tests.rs"]
+mod tests;
@@ -0,0 +1,110 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::super::test_helpers::{assert_tool_error_contains, execute, tool_call};
+use super::*;
+use crate::tools::background::BackgroundShells;
+use std::process::Command;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use yare::parameterized;
+
+/// A context with a registry holding one finished shell that ran `script`.
+fn finished_shell(script: &str) -> BuiltinContext {
+    let shells = Arc::new(BackgroundShells::new());
+    let mut cmd = Command::new("sh");
+    cmd.arg("-c").arg(script);
+    let id = shells.spawn(cmd, script, None).unwrap();
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while shells.list()[0].status == crate::tools::ShellStatus::Running {
+        assert!(Instant::now() < deadline, "shell {id} did not exit");
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    BuiltinContext {
+        background_shells: Some(shells),
+        ..Default::default()
+    }
+}
+
+fn read(ctx: &BuiltinContext, input: serde_json::Value) -> ToolExecutionResult {
+    BashOutputExecutor.execute(&tool_call("BashOutput", input), "test_id", ctx)
+}
+
+#[test]
+#[cfg(unix)]
+fn test_bash_output_reports_status_and_new_output() {
+    let ctx = finished_shell("echo one; echo two >&2");
+
+    let result = read(&ctx, json!({ "bash_id": "bash_1" }));
+    assert!(!result.is_error);
+    let text = result.text().unwrap();
+    assert!(text.starts_with(
+        "<status>completed</status>\n\n<exit_code>0</exit_code>\n\n\
+         <stdout>\none\n</stdout>\n\n<stderr>\ntwo\n</stderr>\n\n<timestamp>"
+    ));
+    let meta = result.tool_use_result().unwrap();
+    assert_eq!(meta["status"], "completed");
+    assert_eq!(meta["exitCode"], 0);
+    assert_eq!(meta["stdout"], "one");
+
+    // Output already returned is not repeated
+    let again = read(&ctx, json!({ "bash_id": "bash_1" }));
+    assert!(!again.text().unwrap().contains("<stdout>"));
+}
+
+#[test]
+#[cfg(unix)]
+fn test_bash_output_filter_keeps_matching_lines() {
+    let ctx = finished_shell("printf 'error: a\\nok\\nerror: b\\n'");
+    let result = read(&ctx, json!({ "bash_id": "bash_1", "filter": "^error" }));
+    assert_eq!(
+        result.tool_use_result().unwrap()["stdout"],
+        "error: a\nerror: b"
+    );
+}
+
+#[parameterized(
+    unknown_id = { json!({ "bash_id": "bash_7" }), "No shell found with ID: bash_7" },
+    missing_id = { json!({}), "Missing 'bash_id' field" },
+)]
+fn bash_output_errors(input: serde_json::Value, expected: &str) {
+    assert_tool_error_contains(&execute::<BashOutputExecutor>(input), expected);
+}
+
+#[test]
+fn test_bash_output_invalid_filter() {
+    let ctx = BuiltinContext {
+        background_shells: Some(Arc::new(BackgroundShells::new())),
+        ..Default::default()
+    };
+    assert_tool_error_contains(
+        &read(&ctx, json!({ "bash_id": "bash_1", "filter": "(" })),
+        "Invalid filter regex",
+    );
+}
+
+#[test]
+fn test_tool_name() {
+    assert_eq!(BashOutputExecutor.tool_name(), ToolName::BashOutput);
+}
+
+#[test]
+#[cfg(unix)]
+fn test_bash_output_timestamp_uses_context_clock() {
+    let ctx = BuiltinContext {
+        clock: crate::time::ClockHandle::fake_at(1_736_937_000_000),
+        ..finished_shell("true")
+    };
+
+    let result = read(&ctx, json!({ "bash_id": "bash_1" }));
+    assert!(result
+        .text()
+        .unwrap()
+        .ends_with("<timestamp>2025-01-15T10:30:00.000Z</timestamp>"));
+    assert_eq!(
+        result.tool_use_result().unwrap()["timestamp"],
+        "2025-01-15T10:30:00.000Z"
+    );
+}
@@ -1,11 +1,15 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
-use super::super::test_helpers::execute;
+use super::super::test_helpers::{execute, tool_call};
+use super::super::ShellState;
 use super::*;
 use crate::tools::builtin::extract_str;
+use crate::tools::sandbox::Sandbox;
 use crate::tools::tool_name::ToolName;
 use serde_json::json;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use yare::parameterized;
 
 #[test]
@@ -44,3 +48,224 @@ fn bash_failed_commands(command: &str, expected: &str) {
 fn test_tool_name() {
     assert_eq!(BashExecutor.tool_name(), ToolName::Bash);
 }
+
+/// Run a command with the given context.
+fn run(command: &str, ctx: &BuiltinContext) -> ToolExecutionResult {
+    let call = tool_call("Bash", json!({ "command": command }));
+    BashExecutor.execute(&call, "test_id", ctx)
+}
+
+#[test]
+#[cfg(unix)]
+fn test_bash_reports_stdout_and_stderr() {
+    let result = execute::<BashExecutor>(json!({ "command": "echo out; echo err >&2" }));
+    assert_eq!(
+        result.tool_use_result().unwrap(),
+        json!({ "stdout": "out", "stderr": "err", "interrupted": false, "isImage": false })
+    );
+}
+
+#[test]
+#[cfg(unix)]
+fn test_bash_timeout_kills_command() {
+    let start = Instant::now();
+    let result = execute::<BashExecutor>(json!({ "command": "sleep 5 & sleep 5", "timeout": 200 }));
+    assert!(start.elapsed() < Duration::from_secs(4));
+    assert!(result.is_error);
+    assert!(result
+        .text()
+        .unwrap()
+        .contains("Command timed out after 200ms"));
+    assert_eq!(result.tool_use_result().unwrap()["interrupted"], true);
+}
+
+#[parameterized(
+    default = { json!({}), &[], 120_000 },
+    explicit = { json!({ "timeout": 5000 }), &[], 5000 },
+    capped = { json!({ "timeout": 9_000_000 }), &[], 600_000 },
+    settings_default = { json!({}), &[("BASH_DEFAULT_TIMEOUT_MS", "3000")], 3000 },
+    settings_max = { json!({ "timeout": 9000 }), &[("BASH_MAX_TIMEOUT_MS", "4000")], 4000 },
+)]
+fn bash_timeout(input: serde_json::Value, env: &[(&str, &str)], expected_ms: u64) {
+    let ctx = BuiltinContext {
+        env: env
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+        ..Default::default()
+    };
+    assert_eq!(
+        BashExecutor::timeout(&input, &ctx),
+        Duration::from_millis(expected_ms)
+    );
+}
+
+#[parameterized(
+    short = { "a\nb", 10, "a\nb" },
+    exact = { "abc", 3, "abc" },
+    one_over = { "abcd", 3, "abc\n\n... [1 lines truncated] ..." },
+    long = { "abcd\ne\nf", 2, "ab\n\n... [3 lines truncated] ..." },
+    multibyte = { "\u{2192}\u{2192}x", 1, "\u{2192}\n\n... [1 lines truncated] ..." },
+)]
+fn bash_truncate_output(output: &str, max_len: usize, expected: &str) {
+    assert_eq!(BashExecutor::truncate_output(output, max_len), expected);
+}
+
+#[test]
+#[cfg(unix)]
+fn test_bash_keeps_cwd_and_exports_between_calls() {
+    let dir = tempfile::TempDir::new().unwrap();
+    std::fs::create_dir(dir.path().join("sub")).unwrap();
+    let ctx = BuiltinContext {
+        cwd: Some(dir.path().to_path_buf()),
+        shell: Some(Arc::new(ShellState::new())),
+        ..Default::default()
+    };
+
+    assert!(!run("cd sub && export GREETING=hi", &ctx).is_error);
+    let result = run("pwd; echo $GREETING", &ctx);
+    let expected = dir.path().canonicalize().unwrap().join("sub");
+    assert_eq!(
+        result.tool_use_result().unwrap()["stdout"],
+        format!("{}\nhi", expected.display())
+    );
+}
+
+#[test]
+#[cfg(unix)]
+fn test_bash_without_shell_state_starts_fresh() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let ctx = BuiltinContext {
+        cwd: Some(dir.path().to_path_buf()),
+        ..Default::default()
+    };
+
+    run("cd / && export GREETING=hi", &ctx);
+    let result = run("echo \"$GREETING\"", &ctx);
+    assert_eq!(result.tool_use_result().unwrap()["stdout"], "");
+}
+
+#[test]
+#[cfg(unix)]
+fn test_bash_applies_settings_env() {
+    let ctx = BuiltinContext {
+        env: [(
+            "CLAUDELESS_TEST_VAR".to_string(),
+            "from-settings".to_string(),
+        )]
+        .into(),
+        ..Default::default()
+    };
+    let result = run("echo $CLAUDELESS_TEST_VAR", &ctx);
+    assert_eq!(result.tool_use_result().unwrap()["stdout"], "from-settings");
+}
+
+#[test]
+#[cfg(unix)]
+fn test_bash_run_in_background_returns_shell_id() {
+    let shells = Arc::new(crate::tools::BackgroundShells::new());
+    let ctx = BuiltinContext {
+        background_shells: Some(Arc::clone(&shells)),
+        ..Default::default()
+    };
+    let call = tool_call(
+        "Bash",
+        json!({ "command": "sleep 30", "run_in_background": true, "description": "Nap" }),
+    );
+
+    let start = Instant::now();
+    let result = BashExecutor.execute(&call, "test_id", &ctx);
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert_eq!(
+        result.text(),
+        Some("Command running in background with ID: bash_1")
+    );
+    assert_eq!(
+        result.tool_use_result().unwrap()["backgroundTaskId"],
+        "bash_1"
+    );
+
+    let listed = shells.list();
+    assert_eq!(listed[0].description, "Nap");
+    assert_eq!(listed[0].status, crate::tools::ShellStatus::Running);
+    shells.kill("bash_1").unwrap();
+}
+
+#[test]
+fn test_bash_run_in_background_needs_registry() {
+    let result = execute::<BashExecutor>(json!({ "command": "true", "run_in_background": true }));
+    assert!(result.is_error);
+}
+
+#[test]
+#[cfg(unix)]
+fn test_bash_returns_when_shell_exits_despite_background_job() {
+    let start = Instant::now();
+    let result = execute::<BashExecutor>(json!({ "command": "sleep 5 & echo started" }));
+    assert!(
+        start.elapsed() < Duration::from_secs(3),
+        "{:?}",
+        start.elapsed()
+    );
+    assert!(!result.is_error, "{:?}", result);
+    assert_eq!(result.tool_use_result().unwrap()["stdout"], "started");
+}
+
+#[test]
+#[cfg(unix)]
+fn test_bash_settings_env_wins_over_saved_exports() {
+    let ctx = BuiltinContext {
+        shell: Some(Arc::new(ShellState::new())),
+        env: [("GREETING".to_string(), "it's settings".to_string())].into(),
+        ..Default::default()
+    };
+
+    assert!(!run("export GREETING=shell", &ctx).is_error);
+    let result = run("echo $GREETING", &ctx);
+    assert_eq!(result.tool_use_result().unwrap()["stdout"], "it's settings");
+}
+
+#[test]
+#[cfg(unix)]
+fn test_bash_in_sandbox_refuses_outside_paths() {
+    let project = tempfile::TempDir::new().unwrap();
+    let outside = tempfile::TempDir::new().unwrap();
+    let target = outside.path().join("escaped.txt");
+    let sandbox = Arc::new(Sandbox::new(project.path()).unwrap());
+    let ctx = BuiltinContext {
+        cwd: Some(sandbox.root().to_path_buf()),
+        sandbox: Some(Arc::clone(&sandbox)),
+        ..Default::default()
+    };
+
+    let result = run(&format!("echo x > {}", target.display()), &ctx);
+    assert!(result.is_error);
+    assert!(result.text().unwrap().contains("is outside the sandbox"));
+    assert!(!target.exists());
+
+    let command = format!("echo x > {}/inside.txt", sandbox.source().display());
+    assert!(!run(&command, &ctx).is_error);
+    assert!(sandbox.root().join("inside.txt").exists());
+    assert!(!project.path().join("inside.txt").exists());
+}
+
+#[test]
+#[cfg(unix)]
+fn test_bash_in_sandbox_sets_home_to_the_copy() {
+    let project = tempfile::TempDir::new().unwrap();
+    let sandbox = Arc::new(Sandbox::new(project.path()).unwrap());
+    let ctx = BuiltinContext {
+        cwd: Some(sandbox.root().to_path_buf()),
+        shell: Some(Arc::new(ShellState::new())),
+        sandbox: Some(Arc::clone(&sandbox)),
+        env: [("HOME".to_string(), "/".to_string())].into(),
+        ..Default::default()
+    };
+
+    assert!(!run("export HOME=\"$PWD/..\"", &ctx).is_error);
+    let result = run("cd ~ && pwd", &ctx);
+    assert_eq!(
+        result.tool_use_result().unwrap()["stdout"],
+        sandbox.root().display().to_string()
+    );
+}
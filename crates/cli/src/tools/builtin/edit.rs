@@ -3,11 +3,14 @@
 
 //! File edit executor.
 
-use std::fs;
+use std::path::Path;
+
+use serde_json::json;
 
 use crate::config::ToolCallSpec;
 use crate::tools::result::ToolExecutionResult;
 
+use super::patch::structured_patch;
 use super::{
     extract_bool, extract_file_path, extract_str, require_field, BuiltinContext,
     BuiltinToolExecutor,
@@ -18,12 +21,45 @@ use crate::tools::tool_name::ToolName;
 #[derive(Clone, Debug, Default)]
 pub struct EditExecutor;
 
+/// Replace `old_string` with `new_string` in `content`.
+///
+/// Without `replace_all`, `old_string` must occur exactly once. Returns the
+/// new content and the number of replacements made.
+pub(super) fn apply_edit(
+    content: &str,
+    old_string: &str,
+    new_string: &str,
+    replace_all: bool,
+    shown_path: &Path,
+) -> Result<(String, usize), String> {
+    let count = content.matches(old_string).count();
+    if count == 0 {
+        return Err(format!(
+            "old_string not found in file '{}'. Make sure it matches exactly.",
+            shown_path.display()
+        ));
+    }
+
+    if replace_all {
+        return Ok((content.replace(old_string, new_string), count));
+    }
+    if count > 1 {
+        return Err(format!(
+            "old_string is not unique in file '{}'. Found {} occurrences. \
+             Use replace_all=true to replace all, or provide more context.",
+            shown_path.display(),
+            count
+        ));
+    }
+    Ok((content.replacen(old_string, new_string, 1), 1))
+}
+
 impl BuiltinToolExecutor for EditExecutor {
     fn execute(
         &self,
         call: &ToolCallSpec,
         tool_use_id: &str,
-        _ctx: &BuiltinContext,
+        ctx: &BuiltinContext,
     ) -> ToolExecutionResult {
         let path = require_field!(
             call.input,
@@ -46,67 +82,60 @@ impl BuiltinToolExecutor for EditExecutor {
             call.tool
         );
 
-        let resolved_path = std::path::PathBuf::from(path);
+        let resolved_path = match ctx.resolve_path(path) {
+            Ok(p) => p,
+            Err(e) => return ToolExecutionResult::error(tool_use_id, e),
+        };
+        let shown_path = ctx.display_path(&resolved_path);
 
         // Read the file
-        let content = match fs::read_to_string(&resolved_path) {
+        let content = match ctx.read_file(&resolved_path) {
             Ok(c) => c,
             Err(e) => {
                 return ToolExecutionResult::error(
                     tool_use_id,
-                    format!("Failed to read file '{}': {}", resolved_path.display(), e),
+                    format!("Failed to read file '{}': {}", shown_path.display(), e),
                 )
             }
         };
 
-        // Check if old_string exists
-        if !content.contains(old_string) {
-            return ToolExecutionResult::error(
-                tool_use_id,
-                format!(
-                    "old_string not found in file '{}'. Make sure it matches exactly.",
-                    resolved_path.display()
-                ),
-            );
+        if let Err(e) = ctx.check_file_fresh(&resolved_path, content.as_bytes()) {
+            return ToolExecutionResult::error(tool_use_id, e);
         }
 
         // Perform replacement
         let replace_all = extract_bool(&call.input, "replace_all", false);
-        let (new_content, count) = if replace_all {
-            let count = content.matches(old_string).count();
-            (content.replace(old_string, new_string), count)
-        } else {
-            // Replace first occurrence only
-            let count = if content.contains(old_string) { 1 } else { 0 };
-            (content.replacen(old_string, new_string, 1), count)
-        };
-
-        // Check for uniqueness when not using replace_all
-        if !replace_all && content.matches(old_string).count() > 1 {
-            return ToolExecutionResult::error(
-                tool_use_id,
-                format!(
-                    "old_string is not unique in file '{}'. Found {} occurrences. \
-                     Use replace_all=true to replace all, or provide more context.",
-                    resolved_path.display(),
-                    content.matches(old_string).count()
-                ),
-            );
-        }
+        let (new_content, count) =
+            match apply_edit(&content, old_string, new_string, replace_all, &shown_path) {
+                Ok(edited) => edited,
+                Err(e) => return ToolExecutionResult::error(tool_use_id, e),
+            };
 
         // Write the modified content
-        match fs::write(&resolved_path, &new_content) {
-            Ok(()) => ToolExecutionResult::success(
-                tool_use_id,
-                format!(
-                    "Successfully edited {}: replaced {} occurrence(s)",
-                    resolved_path.display(),
-                    count
-                ),
-            ),
+        match ctx.write_file(&resolved_path, &new_content) {
+            Ok(()) => {
+                ctx.record_file(&resolved_path, new_content.as_bytes());
+                ToolExecutionResult::success_with_result(
+                    tool_use_id,
+                    format!(
+                        "Successfully edited {}: replaced {} occurrence(s)",
+                        shown_path.display(),
+                        count
+                    ),
+                    json!({
+                        "filePath": shown_path.display().to_string(),
+                        "oldString": old_string,
+                        "newString": new_string,
+                        "originalFile": content,
+                        "structuredPatch": structured_patch(&content, &new_content),
+                        "userModified": false,
+                        "replaceAll": replace_all,
+                    }),
+                )
+            }
             Err(e) => ToolExecutionResult::error(
                 tool_use_id,
-                format!("Failed to write file '{}': {}", resolved_path.display(), e),
+                format!("Failed to write file '{}': {}", shown_path.display(), e),
             ),
         }
     }
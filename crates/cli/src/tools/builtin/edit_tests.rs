@@ -7,6 +7,7 @@ use super::super::test_helpers::{
 use super::*;
 use crate::tools::builtin::{extract_bool, extract_file_path, extract_str};
 use serde_json::json;
+use std::fs;
 
 #[test]
 fn test_extract_fields() {
@@ -62,3 +63,45 @@ fn test_edit_successful() {
         .unwrap()
         .contains("Hello, Rust!"));
 }
+
+#[test]
+fn test_edit_records_structured_patch() {
+    let file = TestFile::new("test.txt").with_content("one\ntwo\nthree\n");
+    let result = execute::<EditExecutor>(json!({
+        "file_path": file.path_str(),
+        "old_string": "two",
+        "new_string": "2"
+    }));
+
+    let meta = result.tool_use_result().unwrap();
+    assert_eq!(meta["filePath"], file.path_str());
+    assert_eq!(meta["oldString"], "two");
+    assert_eq!(meta["newString"], "2");
+    assert_eq!(meta["originalFile"], "one\ntwo\nthree\n");
+    assert_eq!(meta["replaceAll"], false);
+    assert_eq!(meta["userModified"], false);
+    assert_eq!(
+        meta["structuredPatch"],
+        json!([{
+            "oldStart": 1,
+            "oldLines": 3,
+            "newStart": 1,
+            "newLines": 3,
+            "lines": [" one", "-two", "+2", " three"],
+        }])
+    );
+}
+
+#[test]
+fn test_edit_not_unique_without_replace_all() {
+    let file = TestFile::new("test.txt").with_content("a a\n");
+    assert_tool_error_contains(
+        &execute::<EditExecutor>(json!({
+            "file_path": file.path_str(),
+            "old_string": "a",
+            "new_string": "b"
+        })),
+        "Found 2 occurrences",
+    );
+    assert_eq!(fs::read_to_string(&file.path).unwrap(), "a a\n");
+}
@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Per-session record of files read by tools.
+//!
+//! Write, Edit and NotebookEdit refuse to touch an existing file that has
+//! not been read in the session, or that changed since it was last read,
+//! with the same messages as the real tools.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use parking_lot::RwLock;
+use sha2::{Digest, Sha256};
+
+/// Error for writing a file that was never read.
+pub const FILE_NOT_READ: &str = "File has not been read yet. Read it first before writing to it.";
+
+/// Error for writing a file that changed after it was read.
+pub const FILE_MODIFIED_SINCE_READ: &str = "File has been modified since read, either by the user \
+    or by a linter. Read it again before attempting to write it.";
+
+/// A file as it was when last read or written by a tool.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct FileSnapshot {
+    /// Modification time on disk (`None` in the in-memory filesystem).
+    mtime: Option<SystemTime>,
+    /// SHA-256 of the contents.
+    hash: [u8; 32],
+}
+
+/// Files read during the session, keyed by resolved path.
+#[derive(Debug, Default)]
+pub struct FileReadState {
+    files: RwLock<HashMap<PathBuf, FileSnapshot>>,
+}
+
+impl FileReadState {
+    /// Create an empty record.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remember a file's current contents.
+    pub fn record(&self, path: &Path, content: &[u8], mtime: Option<SystemTime>) {
+        let snapshot = FileSnapshot {
+            mtime,
+            hash: Sha256::digest(content).into(),
+        };
+        self.files.write().insert(path.to_path_buf(), snapshot);
+    }
+
+    /// Check that an existing file was read and has not changed since.
+    ///
+    /// A newer modification time alone is not enough to reject the write:
+    /// the contents must differ too, so touching a file is harmless. Files
+    /// without a modification time are compared by contents only.
+    pub fn check_fresh(
+        &self,
+        path: &Path,
+        content: &[u8],
+        mtime: Option<SystemTime>,
+    ) -> Result<(), &'static str> {
+        let files = self.files.read();
+        let snapshot = files.get(path).ok_or(FILE_NOT_READ)?;
+        if mtime.is_some() && snapshot.mtime == mtime {
+            return Ok(());
+        }
+        let hash: [u8; 32] = Sha256::digest(content).into();
+        if snapshot.hash == hash {
+            Ok(())
+        } else {
+            Err(FILE_MODIFIED_SINCE_READ)
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "file_reads_tests.rs"]
+mod tests;
@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use std::time::Duration;
+
+fn later(time: SystemTime) -> Option<SystemTime> {
+    Some(time + Duration::from_secs(1))
+}
+
+#[test]
+fn unread_file_is_rejected() {
+    let state = FileReadState::new();
+    assert_eq!(
+        state.check_fresh(Path::new("/a.txt"), b"x", None),
+        Err(FILE_NOT_READ)
+    );
+}
+
+#[test]
+fn unchanged_file_is_fresh() {
+    let state = FileReadState::new();
+    let now = SystemTime::now();
+    state.record(Path::new("/a.txt"), b"x", Some(now));
+    assert_eq!(
+        state.check_fresh(Path::new("/a.txt"), b"x", Some(now)),
+        Ok(())
+    );
+}
+
+#[test]
+fn touched_file_with_same_content_is_fresh() {
+    let state = FileReadState::new();
+    let now = SystemTime::now();
+    state.record(Path::new("/a.txt"), b"x", Some(now));
+    assert_eq!(
+        state.check_fresh(Path::new("/a.txt"), b"x", later(now)),
+        Ok(())
+    );
+}
+
+#[test]
+fn modified_file_is_stale() {
+    let state = FileReadState::new();
+    let now = SystemTime::now();
+    state.record(Path::new("/a.txt"), b"x", Some(now));
+    assert_eq!(
+        state.check_fresh(Path::new("/a.txt"), b"y", later(now)),
+        Err(FILE_MODIFIED_SINCE_READ)
+    );
+}
+
+#[test]
+fn in_memory_files_compare_by_content() {
+    let state = FileReadState::new();
+    state.record(Path::new("/a.txt"), b"x", None);
+    assert_eq!(state.check_fresh(Path::new("/a.txt"), b"x", None), Ok(()));
+    assert_eq!(
+        state.check_fresh(Path::new("/a.txt"), b"y", None),
+        Err(FILE_MODIFIED_SINCE_READ)
+    );
+}
@@ -2,21 +2,47 @@
 // Copyright (c) 2026 Alfred Jean LLC
 
 //! Glob pattern matching executor.
+//!
+//! Mirrors the ripgrep-backed Glob tool: ignore files are honored, matches
+//! are sorted by modification time (oldest first), and at most
+//! [`RESULT_LIMIT`] paths are returned with a truncation notice.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use glob::glob as glob_match;
+use serde_json::json;
 
 use crate::config::ToolCallSpec;
+use crate::time::Clock;
 use crate::tools::result::ToolExecutionResult;
 
+use super::walk::{list_files, sort_by_mtime, MtimeOrder};
 use super::{extract_directory, extract_str, require_field, BuiltinContext, BuiltinToolExecutor};
 use crate::tools::tool_name::ToolName;
 
+/// Maximum number of paths returned.
+const RESULT_LIMIT: usize = 100;
+
 /// Executor for glob pattern matching.
 #[derive(Clone, Debug, Default)]
 pub struct GlobExecutor;
 
+impl GlobExecutor {
+    /// Split an absolute pattern into the directory before the first
+    /// wildcard and the pattern relative to it.
+    fn split_absolute(pattern: &str) -> (PathBuf, String) {
+        let mut base = PathBuf::from("/");
+        let mut rest = Vec::new();
+        for part in pattern.split('/').filter(|p| !p.is_empty()) {
+            if rest.is_empty() && !part.contains(['*', '?', '[', '{']) {
+                base.push(part);
+            } else {
+                rest.push(part);
+            }
+        }
+        (base, rest.join("/"))
+    }
+}
+
 impl BuiltinToolExecutor for GlobExecutor {
     fn execute(
         &self,
@@ -24,42 +50,87 @@ impl BuiltinToolExecutor for GlobExecutor {
         tool_use_id: &str,
         ctx: &BuiltinContext,
     ) -> ToolExecutionResult {
+        let start = ctx.clock.now_millis();
         let pattern = require_field!(call.input, "pattern", extract_str, tool_use_id, call.tool);
 
         // Get the base directory
-        let base_dir = extract_directory(&call.input)
-            .map(PathBuf::from)
-            .or_else(|| ctx.cwd.clone())
-            .unwrap_or_else(|| PathBuf::from("."));
-
-        // Construct full pattern
-        let full_pattern = if pattern.starts_with('/') || pattern.contains(':') {
-            // Absolute pattern or Windows path
-            pattern.to_string()
-        } else {
-            // Relative pattern
-            base_dir.join(pattern).to_string_lossy().to_string()
+        let base_dir = match extract_directory(&call.input) {
+            Some(dir) => match ctx.resolve_path(dir) {
+                Ok(p) => p,
+                Err(e) => return ToolExecutionResult::error(tool_use_id, e),
+            },
+            None => ctx.cwd.clone().unwrap_or_else(|| PathBuf::from(".")),
         };
 
-        // Execute glob
-        match glob_match(&full_pattern) {
-            Ok(paths) => {
-                let matches: Vec<String> = paths
-                    .filter_map(|entry| entry.ok())
-                    .map(|path| path.to_string_lossy().to_string())
-                    .collect();
-
-                if matches.is_empty() {
-                    ToolExecutionResult::success(tool_use_id, "No matches found")
-                } else {
-                    ToolExecutionResult::success(tool_use_id, matches.join("\n"))
-                }
+        // Absolute patterns search from their literal prefix
+        let (search_dir, relative) = if Path::new(pattern).is_absolute() {
+            let (base, relative) = Self::split_absolute(pattern);
+            match ctx.resolve_path(&base.to_string_lossy()) {
+                Ok(p) => (p, relative),
+                Err(e) => return ToolExecutionResult::error(tool_use_id, e),
             }
-            Err(e) => ToolExecutionResult::error(
+        } else {
+            (base_dir, pattern.to_string())
+        };
+
+        let exists = match ctx.vfs {
+            Some(ref vfs) => vfs.is_dir(&search_dir) || vfs.is_file(&search_dir),
+            None => search_dir.exists(),
+        };
+        if !exists {
+            return ToolExecutionResult::error(
                 tool_use_id,
-                format!("Invalid glob pattern '{}': {}", pattern, e),
-            ),
+                format!(
+                    "Directory does not exist: {}",
+                    ctx.display_path(&search_dir).display()
+                ),
+            );
         }
+
+        let globs: Vec<&str> = if relative.is_empty() {
+            Vec::new()
+        } else {
+            vec![&relative]
+        };
+        let mut paths = match list_files(ctx, &search_dir, &globs, None) {
+            Ok(paths) => paths,
+            Err(e) => {
+                return ToolExecutionResult::error(
+                    tool_use_id,
+                    format!("Invalid glob pattern '{}': {}", pattern, e),
+                )
+            }
+        };
+        sort_by_mtime(ctx, &mut paths, MtimeOrder::OldestFirst);
+
+        let truncated = paths.len() > RESULT_LIMIT;
+        let filenames: Vec<String> = paths
+            .iter()
+            .take(RESULT_LIMIT)
+            .map(|path| ctx.display_path(path).to_string_lossy().to_string())
+            .collect();
+
+        let text = if filenames.is_empty() {
+            "No files found".to_string()
+        } else if truncated {
+            format!(
+                "{}\n(Results are truncated. Consider using a more specific path or pattern.)",
+                filenames.join("\n")
+            )
+        } else {
+            filenames.join("\n")
+        };
+
+        ToolExecutionResult::success_with_result(
+            tool_use_id,
+            text,
+            json!({
+                "filenames": filenames,
+                "durationMs": ctx.clock.now_millis().saturating_sub(start),
+                "numFiles": filenames.len(),
+                "truncated": truncated,
+            }),
+        )
     }
 
     fn tool_name(&self) -> ToolName {
@@ -1,8 +1,11 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
-use super::super::test_helpers::{assert_tool_success_contains, execute, TestDir};
+use super::super::test_helpers::{
+    assert_tool_error_contains, assert_tool_success_contains, execute, tool_call, TestDir,
+};
 use super::*;
+use crate::time::ClockHandle;
 use crate::tools::builtin::{extract_directory, extract_str};
 use serde_json::json;
 use yare::parameterized;
@@ -31,7 +34,7 @@ fn test_glob_no_matches() {
             "pattern": "*.nonexistent",
             "path": dir.path_str()
         })),
-        "No matches",
+        "No files found",
     );
 }
 
@@ -52,3 +55,84 @@ fn test_glob_with_matches() {
     assert!(text.contains("file2.txt"));
     assert!(!text.contains("other.rs"));
 }
+
+#[test]
+fn test_glob_sorts_oldest_first_and_reports_metadata() {
+    let dir = TestDir::new()
+        .with_file("new.txt", "")
+        .with_file("old.txt", "");
+    let old = std::path::Path::new(dir.path_str()).join("old.txt");
+    let past = std::time::SystemTime::now() - std::time::Duration::from_secs(3600);
+    std::fs::File::options()
+        .write(true)
+        .open(&old)
+        .unwrap()
+        .set_modified(past)
+        .unwrap();
+
+    let call = tool_call(
+        "Glob",
+        json!({ "pattern": "*.txt", "path": dir.path_str() }),
+    );
+    let ctx = BuiltinContext {
+        clock: ClockHandle::fake_at_epoch(),
+        ..Default::default()
+    };
+    let result = GlobExecutor.execute(&call, "test_id", &ctx);
+    let text = result.text().unwrap();
+    assert_eq!(text, format!("{0}/old.txt\n{0}/new.txt", dir.path_str()));
+
+    let meta = result.tool_use_result().unwrap();
+    assert_eq!(meta["numFiles"], 2);
+    assert_eq!(meta["truncated"], false);
+    assert_eq!(meta["durationMs"], 0);
+    assert_eq!(meta["filenames"][0], format!("{}/old.txt", dir.path_str()));
+}
+
+#[test]
+fn test_glob_truncates_results() {
+    let dir = (0..=RESULT_LIMIT).fold(TestDir::new(), |dir, i| {
+        dir.with_file(&format!("f{i}.txt"), "")
+    });
+
+    let result = execute::<GlobExecutor>(json!({ "pattern": "*.txt", "path": dir.path_str() }));
+    assert!(result
+        .text()
+        .unwrap()
+        .ends_with("(Results are truncated. Consider using a more specific path or pattern.)"));
+    let meta = result.tool_use_result().unwrap();
+    assert_eq!(meta["numFiles"], RESULT_LIMIT);
+    assert_eq!(meta["truncated"], true);
+}
+
+#[test]
+fn test_glob_absolute_pattern() {
+    let dir = TestDir::new().with_file("a.rs", "").with_file("b.txt", "");
+    let result = execute::<GlobExecutor>(json!({
+        "pattern": format!("{}/*.rs", dir.path_str())
+    }));
+    assert_eq!(
+        result.text(),
+        Some(format!("{}/a.rs", dir.path_str()).as_str())
+    );
+}
+
+#[test]
+fn test_glob_missing_directory() {
+    assert_tool_error_contains(
+        &execute::<GlobExecutor>(json!({ "pattern": "*", "path": "/nonexistent/dir" })),
+        "Directory does not exist",
+    );
+}
+
+#[parameterized(
+    literal_prefix = { "/work/src/*.rs", "/work/src", "*.rs" },
+    recursive = { "/work/**/test_*.py", "/work", "**/test_*.py" },
+    no_wildcard = { "/work/file.txt", "/work/file.txt", "" },
+)]
+fn split_absolute_pattern(pattern: &str, base: &str, relative: &str) {
+    assert_eq!(
+        GlobExecutor::split_absolute(pattern),
+        (std::path::PathBuf::from(base), relative.to_string())
+    );
+}
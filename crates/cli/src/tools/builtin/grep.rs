@@ -2,56 +2,153 @@
 // Copyright (c) 2026 Alfred Jean LLC
 
 //! Content search (grep) executor.
+//!
+//! Mirrors the ripgrep-backed Grep tool: `files_with_matches`, `content`
+//! and `count` output modes, context lines, `head_limit`/`offset`
+//! pagination, and gitignore-aware file listing.
 
-use std::fs;
+use std::collections::BTreeSet;
 use std::path::PathBuf;
 
-use regex::Regex;
+use regex::{Regex, RegexBuilder};
+use serde_json::{json, Value};
 
 use crate::config::ToolCallSpec;
 use crate::tools::result::ToolExecutionResult;
 
-use super::{extract_bool, extract_str, require_field, BuiltinContext, BuiltinToolExecutor};
+use super::walk::{list_files, sort_by_mtime, split_globs, MtimeOrder};
+use super::{
+    extract_bool, extract_str, extract_usize, require_field, BuiltinContext, BuiltinToolExecutor,
+};
 use crate::tools::tool_name::ToolName;
 
+/// Entries returned when no `head_limit` is given; `0` means unlimited.
+const DEFAULT_HEAD_LIMIT: usize = 250;
+
+/// Longer lines are omitted from content output, like `rg --max-columns 500`.
+const MAX_COLUMNS: usize = 500;
+
+/// What the Grep tool reports.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum OutputMode {
+    /// Paths of files with at least one match (default).
+    FilesWithMatches,
+    /// Matching lines, with optional context.
+    Content,
+    /// Number of matching lines per file.
+    Count,
+}
+
+impl OutputMode {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "files_with_matches" => Some(Self::FilesWithMatches),
+            "content" => Some(Self::Content),
+            "count" => Some(Self::Count),
+            _ => None,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::FilesWithMatches => "files_with_matches",
+            Self::Content => "content",
+            Self::Count => "count",
+        }
+    }
+}
+
+/// How matching lines are printed in content mode.
+struct ContentFormat {
+    before: usize,
+    after: usize,
+    line_numbers: bool,
+    with_filename: bool,
+}
+
+/// A searched file and the lines that matched.
+struct FileMatches {
+    path: PathBuf,
+    content: String,
+    /// Zero-based indices of matching lines.
+    matched: BTreeSet<usize>,
+}
+
 /// Executor for content search (grep-like functionality).
 #[derive(Clone, Debug, Default)]
 pub struct GrepExecutor;
 
 impl GrepExecutor {
-    /// Recursively collect files from a directory.
-    fn collect_files(path: &PathBuf, glob_pattern: Option<&str>) -> Vec<PathBuf> {
-        let mut files = Vec::new();
-
-        if path.is_file() {
-            files.push(path.clone());
-        } else if path.is_dir() {
-            if let Ok(entries) = fs::read_dir(path) {
-                for entry in entries.filter_map(|e| e.ok()) {
-                    let entry_path = entry.path();
-                    if entry_path.is_file() {
-                        // Apply glob filter if provided
-                        if let Some(pattern) = glob_pattern {
-                            let file_name = entry_path
-                                .file_name()
-                                .and_then(|n| n.to_str())
-                                .unwrap_or("");
-                            if let Ok(glob) = glob::Pattern::new(pattern) {
-                                if glob.matches(file_name) {
-                                    files.push(entry_path);
-                                }
-                            }
-                        } else {
-                            files.push(entry_path);
-                        }
-                    } else if entry_path.is_dir() {
-                        files.extend(Self::collect_files(&entry_path, glob_pattern));
-                    }
+    /// Lines that contain a match. In multiline mode a match marks every
+    /// line it spans.
+    fn matching_lines(regex: &Regex, content: &str, multiline: bool) -> BTreeSet<usize> {
+        if !multiline {
+            return content
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| regex.is_match(line))
+                .map(|(i, _)| i)
+                .collect();
+        }
+
+        let starts: Vec<usize> = std::iter::once(0)
+            .chain(content.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        let line_of = |byte: usize| starts.partition_point(|&s| s <= byte).saturating_sub(1);
+        regex
+            .find_iter(content)
+            .flat_map(|m| line_of(m.start())..=line_of(m.end().max(m.start() + 1) - 1))
+            .collect()
+    }
+
+    /// Format matching lines (and their context) like ripgrep does.
+    fn content_lines(
+        out: &mut Vec<String>,
+        file: &FileMatches,
+        regex: &Regex,
+        format: &ContentFormat,
+    ) {
+        let lines: Vec<&str> = file.content.lines().collect();
+        let shown: BTreeSet<usize> = file
+            .matched
+            .iter()
+            .flat_map(|&i| i.saturating_sub(format.before)..=(i + format.after))
+            .filter(|&i| i < lines.len())
+            .collect();
+
+        let has_context = format.before > 0 || format.after > 0;
+        let name = file.path.display().to_string();
+        let mut previous: Option<usize> = None;
+        for i in shown {
+            let gap = previous.map_or(!out.is_empty(), |p| i > p + 1);
+            if has_context && gap {
+                out.push("--".to_string());
+            }
+            previous = Some(i);
+
+            let is_match = file.matched.contains(&i);
+            let sep = if is_match { ':' } else { '-' };
+            let mut line = String::new();
+            if format.with_filename {
+                line.push_str(&name);
+                line.push(sep);
+            }
+            if format.line_numbers {
+                line.push_str(&format!("{}{}", i + 1, sep));
+            }
+            let text = lines[i];
+            if text.len() > MAX_COLUMNS {
+                if is_match {
+                    let count = regex.find_iter(text).count();
+                    line.push_str(&format!("[Omitted long line with {} matches]", count));
+                } else {
+                    line.push_str("[Omitted long context line]");
                 }
+            } else {
+                line.push_str(text);
             }
+            out.push(line);
         }
-
-        files
     }
 }
 
@@ -62,17 +159,32 @@ impl BuiltinToolExecutor for GrepExecutor {
         tool_use_id: &str,
         ctx: &BuiltinContext,
     ) -> ToolExecutionResult {
-        let pattern_str =
-            require_field!(call.input, "pattern", extract_str, tool_use_id, call.tool);
+        let input = &call.input;
+        let pattern_str = require_field!(input, "pattern", extract_str, tool_use_id, call.tool);
 
-        // Build regex
-        let case_insensitive = extract_bool(&call.input, "-i", false);
-        let regex = if case_insensitive {
-            Regex::new(&format!("(?i){}", pattern_str))
-        } else {
-            Regex::new(pattern_str)
+        let mode = match extract_str(input, "output_mode") {
+            None => OutputMode::FilesWithMatches,
+            Some(value) => match OutputMode::parse(value) {
+                Some(mode) => mode,
+                None => {
+                    return ToolExecutionResult::error(
+                        tool_use_id,
+                        format!(
+                            "Invalid output_mode '{}': expected 'content', 'files_with_matches' or 'count'",
+                            value
+                        ),
+                    )
+                }
+            },
         };
 
+        // Build regex
+        let multiline = extract_bool(input, "multiline", false);
+        let regex = RegexBuilder::new(pattern_str)
+            .case_insensitive(extract_bool(input, "-i", false))
+            .multi_line(true)
+            .dot_matches_new_line(multiline)
+            .build();
         let regex = match regex {
             Ok(r) => r,
             Err(e) => {
@@ -84,33 +196,159 @@ impl BuiltinToolExecutor for GrepExecutor {
         };
 
         // Get search path
-        let search_path = extract_str(&call.input, "path")
-            .map(PathBuf::from)
-            .or_else(|| ctx.cwd.clone())
-            .unwrap_or_else(|| PathBuf::from("."));
-
-        // Get glob filter
-        let glob_pattern = extract_str(&call.input, "glob");
+        let search_path = match extract_str(input, "path") {
+            Some(path) => match ctx.resolve_path(path) {
+                Ok(p) => p,
+                Err(e) => return ToolExecutionResult::error(tool_use_id, e),
+            },
+            None => ctx.cwd.clone().unwrap_or_else(|| PathBuf::from(".")),
+        };
+        let exists = match ctx.vfs {
+            Some(ref vfs) => vfs.is_file(&search_path) || vfs.is_dir(&search_path),
+            None => search_path.exists(),
+        };
+        if !exists {
+            return ToolExecutionResult::error(
+                tool_use_id,
+                format!(
+                    "Path does not exist: {}",
+                    ctx.display_path(&search_path).display()
+                ),
+            );
+        }
 
         // Collect files to search
-        let files = Self::collect_files(&search_path, glob_pattern);
-
-        // Search through files
-        let mut matches = Vec::new();
-        for file in files {
-            if let Ok(content) = fs::read_to_string(&file) {
-                for (line_num, line) in content.lines().enumerate() {
-                    if regex.is_match(line) {
-                        matches.push(format!("{}:{}:{}", file.display(), line_num + 1, line));
-                    }
-                }
-            }
+        let globs = extract_str(input, "glob")
+            .map(split_globs)
+            .unwrap_or_default();
+        let file_type = extract_str(input, "type");
+        let mut files = match list_files(ctx, &search_path, &globs, file_type) {
+            Ok(files) => files,
+            Err(e) => return ToolExecutionResult::error(tool_use_id, e),
+        };
+        let single_file = files.len() == 1 && files[0] == search_path;
+        if mode == OutputMode::FilesWithMatches {
+            sort_by_mtime(ctx, &mut files, MtimeOrder::NewestFirst);
+        } else {
+            files.sort();
         }
 
-        if matches.is_empty() {
-            ToolExecutionResult::success(tool_use_id, "No matches found")
-        } else {
-            ToolExecutionResult::success(tool_use_id, matches.join("\n"))
+        // Search through files, skipping anything that is not text
+        let matches: Vec<FileMatches> = files
+            .into_iter()
+            .filter_map(|path| {
+                let content = ctx.read_file(&path).ok().filter(|c| !c.contains('\0'))?;
+                let matched = Self::matching_lines(&regex, &content, multiline);
+                (!matched.is_empty()).then(|| FileMatches {
+                    path: ctx.display_path(&path),
+                    content,
+                    matched,
+                })
+            })
+            .collect();
+
+        let head_limit = extract_usize(input, "head_limit").unwrap_or(DEFAULT_HEAD_LIMIT);
+        let offset = extract_usize(input, "offset").unwrap_or(0);
+
+        match mode {
+            OutputMode::FilesWithMatches => {
+                let names = matches.iter().map(|f| f.path.display().to_string());
+                let (filenames, applied_limit) = paginate(names.collect(), head_limit, offset);
+                let limit_info = limit_info(applied_limit, offset);
+                let text = if filenames.is_empty() {
+                    "No files found".to_string()
+                } else {
+                    format!(
+                        "Found {} {}{}\n{}",
+                        filenames.len(),
+                        plural(filenames.len(), "file", "files"),
+                        limit_info.map(|i| format!(" {}", i)).unwrap_or_default(),
+                        filenames.join("\n")
+                    )
+                };
+                let mut result = json!({
+                    "mode": mode.as_str(),
+                    "filenames": filenames,
+                    "numFiles": filenames.len(),
+                });
+                add_pagination(&mut result, applied_limit, offset);
+                ToolExecutionResult::success_with_result(tool_use_id, text, result)
+            }
+            OutputMode::Content => {
+                let context = extract_usize(input, "-C").unwrap_or(0);
+                let format = ContentFormat {
+                    before: extract_usize(input, "-B").unwrap_or(context),
+                    after: extract_usize(input, "-A").unwrap_or(context),
+                    line_numbers: extract_bool(input, "-n", true),
+                    with_filename: !single_file,
+                };
+                let mut lines = Vec::new();
+                for file in &matches {
+                    Self::content_lines(&mut lines, file, &regex, &format);
+                }
+                let (lines, applied_limit) = paginate(lines, head_limit, offset);
+                let content = lines.join("\n");
+                let mut text = if content.is_empty() {
+                    "No matches found".to_string()
+                } else {
+                    content.clone()
+                };
+                if let Some(info) = limit_info(applied_limit, offset) {
+                    text.push_str(&format!("\n\n[Showing results with pagination = {}]", info));
+                }
+                let mut result = json!({
+                    "mode": mode.as_str(),
+                    "numFiles": 0,
+                    "filenames": [],
+                    "content": content,
+                    "numLines": lines.len(),
+                });
+                add_pagination(&mut result, applied_limit, offset);
+                ToolExecutionResult::success_with_result(tool_use_id, text, result)
+            }
+            OutputMode::Count => {
+                let entries: Vec<(String, usize)> = matches
+                    .iter()
+                    .map(|f| (f.path.display().to_string(), f.matched.len()))
+                    .collect();
+                let (entries, applied_limit) = paginate(entries, head_limit, offset);
+                let num_matches: usize = entries.iter().map(|(_, n)| n).sum();
+                let content = entries
+                    .iter()
+                    .map(|(name, n)| {
+                        if single_file {
+                            n.to_string()
+                        } else {
+                            format!("{}:{}", name, n)
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let text = format!(
+                    "{}\n\nFound {} total {} across {} {}.{}",
+                    if content.is_empty() {
+                        "No matches found"
+                    } else {
+                        &content
+                    },
+                    num_matches,
+                    plural(num_matches, "occurrence", "occurrences"),
+                    entries.len(),
+                    plural(entries.len(), "file", "files"),
+                    limit_info(applied_limit, offset)
+                        .map(|i| format!(" with pagination = {}", i))
+                        .unwrap_or_default()
+                );
+                let mut result = json!({
+                    "mode": mode.as_str(),
+                    "numFiles": entries.len(),
+                    "filenames": [],
+                    "content": content,
+                    "numMatches": num_matches,
+                });
+                add_pagination(&mut result, applied_limit, offset);
+                ToolExecutionResult::success_with_result(tool_use_id, text, result)
+            }
         }
     }
 
@@ -119,6 +357,46 @@ impl BuiltinToolExecutor for GrepExecutor {
     }
 }
 
+/// Skip `offset` items and keep at most `limit` (`0` keeps all).
+///
+/// Returns the limit only when it actually cut results off.
+fn paginate<T>(items: Vec<T>, limit: usize, offset: usize) -> (Vec<T>, Option<usize>) {
+    let remaining: Vec<T> = items.into_iter().skip(offset).collect();
+    if limit == 0 || remaining.len() <= limit {
+        return (remaining, None);
+    }
+    (remaining.into_iter().take(limit).collect(), Some(limit))
+}
+
+/// `limit: N, offset: M` summary of applied pagination.
+fn limit_info(applied_limit: Option<usize>, offset: usize) -> Option<String> {
+    let mut parts = Vec::new();
+    if let Some(limit) = applied_limit {
+        parts.push(format!("limit: {}", limit));
+    }
+    if offset > 0 {
+        parts.push(format!("offset: {}", offset));
+    }
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+fn add_pagination(result: &mut Value, applied_limit: Option<usize>, offset: usize) {
+    if let Some(limit) = applied_limit {
+        result["appliedLimit"] = json!(limit);
+    }
+    if offset > 0 {
+        result["appliedOffset"] = json!(offset);
+    }
+}
+
+fn plural(n: usize, one: &'static str, many: &'static str) -> &'static str {
+    if n == 1 {
+        one
+    } else {
+        many
+    }
+}
+
 #[cfg(test)]
 #[path = "grep_tests.rs"]
 mod tests;
@@ -32,7 +32,7 @@ fn test_grep_no_matches() {
             "pattern": "nonexistent",
             "path": dir.path_str()
         })),
-        "No matches",
+        "No files found",
     );
 }
 
@@ -43,11 +43,12 @@ fn test_grep_with_matches() {
 
     let result = execute::<GrepExecutor>(json!({
         "pattern": "Hello",
-        "path": dir.path_str()
+        "path": dir.path_str(),
+        "output_mode": "content"
     }));
     assert!(!result.is_error);
     let text = result.text().unwrap();
-    assert!(text.contains("Hello, World!"));
+    assert!(text.contains("test.txt:1:Hello, World!"));
     assert!(text.contains("Hello again!"));
     assert!(!text.contains("Goodbye"));
 }
@@ -59,6 +60,7 @@ fn test_grep_case_insensitive() {
     let result = execute::<GrepExecutor>(json!({
         "pattern": "hello",
         "path": dir.path_str(),
+        "output_mode": "content",
         "-i": true
     }));
     assert!(!result.is_error);
@@ -67,3 +69,164 @@ fn test_grep_case_insensitive() {
     assert!(text.contains("hello"));
     assert!(text.contains("Hello"));
 }
+
+fn run(input: serde_json::Value) -> ToolExecutionResult {
+    let result = execute::<GrepExecutor>(input);
+    assert!(!result.is_error, "{:?}", result);
+    result
+}
+
+#[test]
+fn test_grep_files_with_matches_lists_files() {
+    let dir = TestDir::new()
+        .with_file("a.txt", "needle\n")
+        .with_file("b.txt", "hay\n")
+        .with_file("c.txt", "needle needle\n");
+
+    let result = run(json!({ "pattern": "needle", "path": dir.path_str() }));
+    let text = result.text().unwrap();
+    assert!(text.starts_with("Found 2 files\n"), "{}", text);
+    assert!(!text.contains("b.txt"));
+
+    let meta = result.tool_use_result().unwrap();
+    assert_eq!(meta["mode"], "files_with_matches");
+    assert_eq!(meta["numFiles"], 2);
+    assert_eq!(meta["filenames"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn test_grep_content_with_context_and_separators() {
+    let dir = TestDir::new().with_file("f.txt", "a\nmatch\nb\nc\nd\nmatch\n");
+
+    let result = run(json!({
+        "pattern": "match",
+        "path": format!("{}/f.txt", dir.path_str()),
+        "output_mode": "content",
+        "-C": 1
+    }));
+    assert_eq!(result.text(), Some("1-a\n2:match\n3-b\n--\n5-d\n6:match"));
+    let meta = result.tool_use_result().unwrap();
+    assert_eq!(meta["mode"], "content");
+    assert_eq!(meta["numLines"], 6);
+}
+
+#[test]
+fn test_grep_content_without_line_numbers() {
+    let dir = TestDir::new().with_file("f.txt", "one\ntwo\n");
+    let result = run(json!({
+        "pattern": "two",
+        "path": format!("{}/f.txt", dir.path_str()),
+        "output_mode": "content",
+        "-n": false
+    }));
+    assert_eq!(result.text(), Some("two"));
+}
+
+#[test]
+fn test_grep_count_mode_summarizes() {
+    let dir = TestDir::new()
+        .with_file("a.txt", "x\nx\n")
+        .with_file("b.txt", "x\n");
+
+    let result = run(json!({
+        "pattern": "x",
+        "path": dir.path_str(),
+        "output_mode": "count"
+    }));
+    let text = result.text().unwrap();
+    assert!(text.contains("a.txt:2"), "{}", text);
+    assert!(text.contains("b.txt:1"), "{}", text);
+    assert!(text.ends_with("Found 3 total occurrences across 2 files."));
+
+    let meta = result.tool_use_result().unwrap();
+    assert_eq!(meta["numMatches"], 3);
+    assert_eq!(meta["numFiles"], 2);
+}
+
+#[test]
+fn test_grep_head_limit_and_offset_paginate() {
+    let dir = TestDir::new().with_file("f.txt", "m1\nm2\nm3\nm4\n");
+
+    let result = run(json!({
+        "pattern": "m",
+        "path": format!("{}/f.txt", dir.path_str()),
+        "output_mode": "content",
+        "head_limit": 2,
+        "offset": 1
+    }));
+    assert_eq!(
+        result.text(),
+        Some("2:m2\n3:m3\n\n[Showing results with pagination = limit: 2, offset: 1]")
+    );
+    let meta = result.tool_use_result().unwrap();
+    assert_eq!(meta["appliedLimit"], 2);
+    assert_eq!(meta["appliedOffset"], 1);
+}
+
+#[test]
+fn test_grep_type_filter() {
+    let dir = TestDir::new()
+        .with_file("main.rs", "fn target() {}\n")
+        .with_file("app.py", "def target(): pass\n");
+
+    let result = run(json!({
+        "pattern": "target",
+        "path": dir.path_str(),
+        "type": "py"
+    }));
+    let filenames = result.tool_use_result().unwrap()["filenames"].clone();
+    assert_eq!(filenames.as_array().unwrap().len(), 1);
+    assert!(filenames[0].as_str().unwrap().ends_with("app.py"));
+}
+
+#[test]
+fn test_grep_multiline_spans_lines() {
+    let dir = TestDir::new().with_file("f.txt", "struct A {\n    x: u8,\n}\nother\n");
+
+    let single = run(json!({
+        "pattern": "A \\{.*x",
+        "path": dir.path_str()
+    }));
+    assert_eq!(single.text(), Some("No files found"));
+
+    let multi = run(json!({
+        "pattern": "A \\{.*x",
+        "path": format!("{}/f.txt", dir.path_str()),
+        "output_mode": "content",
+        "multiline": true
+    }));
+    assert_eq!(multi.text(), Some("1:struct A {\n2:    x: u8,"));
+}
+
+#[test]
+fn test_grep_omits_long_lines() {
+    let long = format!("needle {}", "x".repeat(MAX_COLUMNS));
+    let dir = TestDir::new().with_file("f.txt", &long);
+    let result = run(json!({
+        "pattern": "needle",
+        "path": format!("{}/f.txt", dir.path_str()),
+        "output_mode": "content"
+    }));
+    assert_eq!(result.text(), Some("1:[Omitted long line with 1 matches]"));
+}
+
+#[test]
+fn test_grep_missing_path_is_error() {
+    assert_tool_error_contains(
+        &execute::<GrepExecutor>(json!({ "pattern": "x", "path": "/nonexistent/dir" })),
+        "Path does not exist",
+    );
+}
+
+#[test]
+fn test_grep_invalid_output_mode() {
+    let dir = TestDir::new();
+    assert_tool_error_contains(
+        &execute::<GrepExecutor>(json!({
+            "pattern": "x",
+            "path": dir.path_str(),
+            "output_mode": "lines"
+        })),
+        "Invalid output_mode",
+    );
+}
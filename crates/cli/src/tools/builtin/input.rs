@@ -71,11 +71,24 @@ pub(crate) fn extract_str<'a>(input: &'a Value, key: &str) -> Option<&'a str> {
     input.get(key).and_then(|v| v.as_str())
 }
 
+/// Extract an array field by name.
+pub(crate) fn extract_array<'a>(input: &'a Value, key: &str) -> Option<&'a Vec<Value>> {
+    input.get(key).and_then(|v| v.as_array())
+}
+
 /// Extract a boolean field with default.
 pub(crate) fn extract_bool(input: &Value, key: &str, default: bool) -> bool {
     input.get(key).and_then(|v| v.as_bool()).unwrap_or(default)
 }
 
+/// Extract a non-negative integer field by name.
+pub(crate) fn extract_usize(input: &Value, key: &str) -> Option<usize> {
+    input
+        .get(key)
+        .and_then(|v| v.as_u64())
+        .and_then(|n| usize::try_from(n).ok())
+}
+
 #[cfg(test)]
 #[path = "input_tests.rs"]
 mod tests;
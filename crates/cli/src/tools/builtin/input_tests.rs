@@ -88,3 +88,27 @@ fn extract_bool_returns_default_when_not_bool() {
     assert!(extract_bool(&input, "not_bool", true));
     assert!(!extract_bool(&input, "not_bool", false));
 }
+
+#[test]
+fn extract_usize_accepts_only_non_negative_integers() {
+    let input = json!({
+        "limit": 20,
+        "negative": -1,
+        "text": "20"
+    });
+    assert_eq!(extract_usize(&input, "limit"), Some(20));
+    assert_eq!(extract_usize(&input, "negative"), None);
+    assert_eq!(extract_usize(&input, "text"), None);
+    assert_eq!(extract_usize(&input, "missing"), None);
+}
+
+#[test]
+fn extract_array_works() {
+    let input = json!({
+        "edits": [{ "old_string": "a" }],
+        "text": "not an array"
+    });
+    assert_eq!(extract_array(&input, "edits").map(Vec::len), Some(1));
+    assert_eq!(extract_array(&input, "text"), None);
+    assert_eq!(extract_array(&input, "missing"), None);
+}
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Background shell kill executor.
+
+use serde_json::json;
+
+use crate::config::ToolCallSpec;
+use crate::tools::result::ToolExecutionResult;
+
+use super::{extract_str, require_field, BuiltinContext, BuiltinToolExecutor};
+use crate::tools::tool_name::ToolName;
+
+/// Executor for stopping a background shell.
+#[derive(Clone, Debug, Default)]
+pub struct KillShellExecutor;
+
+impl BuiltinToolExecutor for KillShellExecutor {
+    fn execute(
+        &self,
+        call: &ToolCallSpec,
+        tool_use_id: &str,
+        ctx: &BuiltinContext,
+    ) -> ToolExecutionResult {
+        let shell_id = require_field!(call.input, "shell_id", extract_str, tool_use_id, call.tool);
+
+        match ctx.background_shells.as_ref().map(|s| s.kill(shell_id)) {
+            Some(Ok(command)) => {
+                let message = format!("Successfully killed shell: {} ({})", shell_id, command);
+                ToolExecutionResult::success_with_result(
+                    tool_use_id,
+                    message.clone(),
+                    json!({
+                        "success": true,
+                        "message": message,
+                        "shell_id": shell_id,
+                    }),
+                )
+            }
+            Some(Err(e)) => ToolExecutionResult::error(tool_use_id, e.to_string()),
+            None => ToolExecutionResult::error(
+                tool_use_id,
+                format!("No shell found with ID: {}", shell_id),
+            ),
+        }
+    }
+
+    fn tool_name(&self) -> ToolName {
+        ToolName::KillShell
+    }
+}
+
+#[cfg(test)]
+#[path = "kill_shell_tests.rs"]
+mod tests;
@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::super::test_helpers::{assert_tool_error_contains, execute, tool_call};
+use super::*;
+use crate::tools::background::BackgroundShells;
+use std::process::Command;
+use std::sync::Arc;
+
+#[test]
+#[cfg(unix)]
+fn test_kill_shell_stops_running_shell() {
+    let shells = Arc::new(BackgroundShells::new());
+    let mut cmd = Command::new("sh");
+    cmd.arg("-c").arg("sleep 30");
+    let id = shells.spawn(cmd, "sleep 30", None).unwrap();
+    let ctx = BuiltinContext {
+        background_shells: Some(Arc::clone(&shells)),
+        ..Default::default()
+    };
+    let call = tool_call("KillShell", json!({ "shell_id": id }));
+
+    let result = KillShellExecutor.execute(&call, "test_id", &ctx);
+    assert!(!result.is_error);
+    assert_eq!(
+        result.text(),
+        Some("Successfully killed shell: bash_1 (sleep 30)")
+    );
+    assert_eq!(result.tool_use_result().unwrap()["shell_id"], "bash_1");
+
+    assert_tool_error_contains(
+        &KillShellExecutor.execute(&call, "test_id", &ctx),
+        "Shell bash_1 is not running, so cannot be killed (status: killed)",
+    );
+}
+
+#[test]
+fn test_kill_shell_unknown_id() {
+    assert_tool_error_contains(
+        &execute::<KillShellExecutor>(json!({ "shell_id": "bash_3" })),
+        "No shell found with ID: bash_3",
+    );
+}
+
+#[test]
+fn test_tool_name() {
+    assert_eq!(KillShellExecutor.tool_name(), ToolName::KillShell);
+}
@@ -4,51 +4,96 @@
 //! Built-in tool executors for live mode.
 //!
 //! This module provides executors for Claude's built-in tools:
-//! - Bash - Command execution
+//! - Bash - Command execution in a session-persistent shell
 //! - Read - File reading
 //! - Write - File writing
 //! - Edit - File editing
+//! - MultiEdit - Several edits to one file, applied atomically
+//! - NotebookEdit - Replace, insert or delete a Jupyter notebook cell
 //! - Glob - Pattern matching
 //! - Grep - Content search
+//! - BashOutput - New output from a background shell
+//! - KillShell - Stop a background shell
+//! - WebFetch - Fetch a page from the scenario's web fixtures
+//! - WebSearch - Search results from the scenario's web fixtures
+//! - TaskCreate, TaskGet, TaskUpdate, TaskList - The session's task list
 
 mod bash;
+mod bash_output;
 mod edit;
+mod file_reads;
 mod glob;
 mod grep;
 mod input;
+mod kill_shell;
+mod multi_edit;
+mod notebook;
+mod notebook_edit;
+mod patch;
 mod read;
+mod shell;
 pub mod stateful;
+mod task_create;
+mod task_get;
+mod task_list;
+mod task_update;
+pub(crate) mod walk;
+mod web_fetch;
+mod web_search;
 mod write;
 
 pub(crate) use input::{
-    extract_bool, extract_directory, extract_file_path, extract_str, require_field,
+    extract_array, extract_bool, extract_directory, extract_file_path, extract_str, extract_usize,
+    require_field,
 };
 
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::SystemTime;
 
 use crate::config::ToolCallSpec;
-use crate::state::StateWriter;
-use crate::tools::executor::{ExecutionContext, ToolExecutor};
+use crate::state::{StateWriter, TaskStore};
+use crate::time::ClockHandle;
+use crate::tools::background::BackgroundShells;
+use crate::tools::executor::{ExecutionContext, ToolExecutor, ToolFuture};
 use crate::tools::result::ToolExecutionResult;
+use crate::tools::sandbox::{normalize, Sandbox};
 use crate::tools::tool_name::ToolName;
+use crate::tools::vfs::Vfs;
+use crate::tools::web::Web;
 
 pub use bash::BashExecutor;
+pub use bash_output::BashOutputExecutor;
 pub use edit::EditExecutor;
+pub use file_reads::FileReadState;
 pub use glob::GlobExecutor;
 pub use grep::GrepExecutor;
+pub use kill_shell::KillShellExecutor;
+pub use multi_edit::MultiEditExecutor;
+pub use notebook_edit::NotebookEditExecutor;
 pub use read::ReadExecutor;
+pub use shell::ShellState;
 pub use stateful::{
     execute_ask_user_question, execute_enter_plan_mode, execute_exit_plan_mode, execute_todo_write,
 };
+pub use task_create::TaskCreateExecutor;
+pub use task_get::TaskGetExecutor;
+pub use task_list::TaskListExecutor;
+pub use task_update::TaskUpdateExecutor;
+pub use web_fetch::WebFetchExecutor;
+pub use web_search::WebSearchExecutor;
 pub use write::WriteExecutor;
 
 /// Registry of built-in tool executors.
 pub struct BuiltinExecutor {
-    executors: HashMap<String, Box<dyn BuiltinToolExecutor>>,
+    executors: HashMap<String, Arc<dyn BuiltinToolExecutor>>,
     /// Optional state writer for TodoWrite/ExitPlanMode tools.
     state_writer: Option<Arc<parking_lot::RwLock<StateWriter>>>,
+    /// Files read this session, for Write/Edit freshness checks.
+    file_reads: Arc<FileReadState>,
+    /// Directory and exports carried between Bash calls.
+    shell: Arc<ShellState>,
 }
 
 impl Default for BuiltinExecutor {
@@ -60,13 +105,23 @@ impl Default for BuiltinExecutor {
 impl BuiltinExecutor {
     /// Create a new builtin executor with default configuration.
     pub fn new() -> Self {
-        let all_executors: [Box<dyn BuiltinToolExecutor>; 6] = [
-            Box::new(BashExecutor),
-            Box::new(ReadExecutor),
-            Box::new(WriteExecutor),
-            Box::new(EditExecutor),
-            Box::new(GlobExecutor),
-            Box::new(GrepExecutor),
+        let all_executors: [Arc<dyn BuiltinToolExecutor>; 16] = [
+            Arc::new(BashExecutor),
+            Arc::new(ReadExecutor),
+            Arc::new(WriteExecutor),
+            Arc::new(EditExecutor),
+            Arc::new(MultiEditExecutor),
+            Arc::new(NotebookEditExecutor),
+            Arc::new(GlobExecutor),
+            Arc::new(GrepExecutor),
+            Arc::new(BashOutputExecutor),
+            Arc::new(KillShellExecutor),
+            Arc::new(WebFetchExecutor),
+            Arc::new(WebSearchExecutor),
+            Arc::new(TaskCreateExecutor),
+            Arc::new(TaskGetExecutor),
+            Arc::new(TaskUpdateExecutor),
+            Arc::new(TaskListExecutor),
         ];
 
         let executors = all_executors
@@ -77,6 +132,8 @@ impl BuiltinExecutor {
         Self {
             executors,
             state_writer: None,
+            file_reads: Arc::new(FileReadState::new()),
+            shell: Arc::new(ShellState::new()),
         }
     }
 
@@ -87,8 +144,9 @@ impl BuiltinExecutor {
     }
 }
 
-impl ToolExecutor for BuiltinExecutor {
-    fn execute(
+impl BuiltinExecutor {
+    /// Run a built-in tool call.
+    async fn run(
         &self,
         call: &ToolCallSpec,
         tool_use_id: &str,
@@ -136,10 +194,40 @@ impl ToolExecutor for BuiltinExecutor {
 
         // Look up the tool executor
         if let Some(executor) = self.executors.get(&call.tool) {
-            let builtin_ctx = BuiltinContext {
-                cwd: ctx.cwd.clone(),
+            let mut builtin_ctx = match (&ctx.vfs, &ctx.sandbox) {
+                (Some(vfs), _) => BuiltinContext {
+                    cwd: Some(ctx.cwd.clone().unwrap_or_else(|| vfs.cwd().to_path_buf())),
+                    vfs: Some(Arc::clone(vfs)),
+                    ..Default::default()
+                },
+                (None, Some(sandbox)) => BuiltinContext {
+                    cwd: Some(sandbox.working_dir(ctx.cwd.as_deref())),
+                    sandbox: Some(Arc::clone(sandbox)),
+                    ..Default::default()
+                },
+                (None, None) => BuiltinContext {
+                    cwd: ctx.cwd.clone(),
+                    ..Default::default()
+                },
             };
-            executor.execute(call, tool_use_id, &builtin_ctx)
+            builtin_ctx.file_reads = Some(Arc::clone(&self.file_reads));
+            builtin_ctx.shell = Some(Arc::clone(&self.shell));
+            builtin_ctx.env = ctx.env.clone();
+            builtin_ctx.background_shells = ctx.background_shells.clone();
+            builtin_ctx.web = ctx.web.clone();
+            builtin_ctx.task_store = ctx.task_store.clone();
+            builtin_ctx.clock = ctx.clock.clone();
+
+            // Tools block on the disk and on processes, so they run on the
+            // blocking pool where concurrent calls overlap
+            let executor = Arc::clone(executor);
+            let owned_call = call.clone();
+            let id = tool_use_id.to_string();
+            tokio::task::spawn_blocking(move || executor.execute(&owned_call, &id, &builtin_ctx))
+                .await
+                .unwrap_or_else(|e| {
+                    ToolExecutionResult::error(tool_use_id, format!("{} failed: {}", call.tool, e))
+                })
         } else {
             // Return mock result for unknown stateful tools
             let is_stateful = matches!(
@@ -165,6 +253,17 @@ impl ToolExecutor for BuiltinExecutor {
             }
         }
     }
+}
+
+impl ToolExecutor for BuiltinExecutor {
+    fn execute<'a>(
+        &'a self,
+        call: &'a ToolCallSpec,
+        tool_use_id: &'a str,
+        ctx: &'a ExecutionContext,
+    ) -> ToolFuture<'a> {
+        Box::pin(self.run(call, tool_use_id, ctx))
+    }
 
     fn name(&self) -> &'static str {
         "builtin"
@@ -176,6 +275,105 @@ impl ToolExecutor for BuiltinExecutor {
 pub struct BuiltinContext {
     /// Working directory.
     pub cwd: Option<PathBuf>,
+    /// Sandbox that paths are confined to.
+    pub sandbox: Option<Arc<Sandbox>>,
+    /// In-memory filesystem that replaces the disk.
+    pub vfs: Option<Arc<Vfs>>,
+    /// Files read this session; `None` skips read-before-write checks.
+    pub file_reads: Option<Arc<FileReadState>>,
+    /// Bash state carried between calls; `None` starts every command fresh.
+    pub shell: Option<Arc<ShellState>>,
+    /// Extra environment for commands (`settings.env`).
+    pub env: HashMap<String, String>,
+    /// Background shells started this session.
+    pub background_shells: Option<Arc<BackgroundShells>>,
+    /// Web fixtures for WebFetch and WebSearch; `None` means no pages exist.
+    pub web: Option<Arc<Web>>,
+    /// The session's task list.
+    pub task_store: Option<Arc<TaskStore>>,
+    /// Clock for timestamps and durations in results.
+    pub clock: ClockHandle,
+}
+
+impl BuiltinContext {
+    /// Resolve a tool path, confining it to the sandbox when one is set.
+    ///
+    /// On disk, relative paths are joined to the working directory (else
+    /// the process's) and the result is normalized lexically, so one file
+    /// has one path however a call spells it.
+    pub fn resolve_path(&self, path: &str) -> Result<PathBuf, String> {
+        if let Some(ref vfs) = self.vfs {
+            return Ok(vfs.resolve(path, self.cwd.as_deref()));
+        }
+        match self.sandbox {
+            Some(ref sandbox) => sandbox
+                .resolve(path, self.cwd.as_deref())
+                .map_err(|e| e.to_string()),
+            None => {
+                let joined = match self.cwd {
+                    Some(ref cwd) => cwd.join(path),
+                    None => std::path::absolute(path).map_err(|e| e.to_string())?,
+                };
+                Ok(normalize(&joined))
+            }
+        }
+    }
+
+    /// Read a file from the in-memory filesystem or disk.
+    pub fn read_file(&self, path: &Path) -> std::io::Result<String> {
+        match self.vfs {
+            Some(ref vfs) => vfs.read_to_string(path),
+            None => std::fs::read_to_string(path),
+        }
+    }
+
+    /// Read a file's raw bytes from the in-memory filesystem or disk.
+    pub fn read_bytes(&self, path: &Path) -> std::io::Result<Vec<u8>> {
+        match self.vfs {
+            Some(ref vfs) => vfs.read(path),
+            None => std::fs::read(path),
+        }
+    }
+
+    /// Write a file to the in-memory filesystem or disk.
+    pub fn write_file(&self, path: &Path, content: &str) -> std::io::Result<()> {
+        match self.vfs {
+            Some(ref vfs) => vfs.write(path, content),
+            None => std::fs::write(path, content),
+        }
+    }
+
+    /// Modification time of a file on disk (`None` in the in-memory filesystem).
+    fn modified(&self, path: &Path) -> Option<SystemTime> {
+        if self.vfs.is_some() {
+            return None;
+        }
+        std::fs::metadata(path).and_then(|m| m.modified()).ok()
+    }
+
+    /// Remember a file's contents after a tool read or wrote it.
+    pub fn record_file(&self, path: &Path, content: &[u8]) {
+        if let Some(ref file_reads) = self.file_reads {
+            file_reads.record(path, content, self.modified(path));
+        }
+    }
+
+    /// Check that an existing file was read this session and has not
+    /// changed since.
+    pub fn check_file_fresh(&self, path: &Path, content: &[u8]) -> Result<(), &'static str> {
+        match self.file_reads {
+            Some(ref file_reads) => file_reads.check_fresh(path, content, self.modified(path)),
+            None => Ok(()),
+        }
+    }
+
+    /// Path as reported in tool results (the project path for sandbox files).
+    pub fn display_path(&self, path: &Path) -> PathBuf {
+        match self.sandbox {
+            Some(ref sandbox) => sandbox.display_path(path),
+            None => path.to_path_buf(),
+        }
+    }
 }
 
 /// Trait for individual built-in tool executors.
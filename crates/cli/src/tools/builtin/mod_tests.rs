@@ -4,8 +4,8 @@
 use super::*;
 use serde_json::json;
 
-#[test]
-fn test_builtin_executor_with_mock_result() {
+#[tokio::test]
+async fn test_builtin_executor_with_mock_result() {
     let executor = BuiltinExecutor::new();
     let call = ToolCallSpec {
         tool: "Bash".to_string(),
@@ -13,14 +13,14 @@ fn test_builtin_executor_with_mock_result() {
         result: Some("mock output".to_string()),
     };
     let ctx = ExecutionContext::default();
-    let result = executor.execute(&call, "toolu_123", &ctx);
+    let result = executor.execute(&call, "toolu_123", &ctx).await;
 
     assert!(!result.is_error);
     assert_eq!(result.text(), Some("mock output"));
 }
 
-#[test]
-fn test_builtin_executor_unknown_tool() {
+#[tokio::test]
+async fn test_builtin_executor_unknown_tool() {
     let executor = BuiltinExecutor::new();
     let call = ToolCallSpec {
         tool: "UnknownTool".to_string(),
@@ -28,8 +28,173 @@ fn test_builtin_executor_unknown_tool() {
         result: None,
     };
     let ctx = ExecutionContext::default();
-    let result = executor.execute(&call, "toolu_123", &ctx);
+    let result = executor.execute(&call, "toolu_123", &ctx).await;
 
     assert!(result.is_error);
     assert!(result.text().unwrap().contains("Unknown built-in tool"));
 }
+
+async fn run(
+    executor: &BuiltinExecutor,
+    tool: &str,
+    input: serde_json::Value,
+) -> ToolExecutionResult {
+    let call = ToolCallSpec {
+        tool: tool.to_string(),
+        input,
+        result: None,
+    };
+    executor
+        .execute(&call, "toolu_123", &ExecutionContext::default())
+        .await
+}
+
+#[tokio::test]
+async fn test_write_and_edit_require_prior_read() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("notes.txt");
+    std::fs::write(&path, "original").unwrap();
+    let file_path = path.to_str().unwrap();
+    let executor = BuiltinExecutor::new();
+
+    let write = run(
+        &executor,
+        "Write",
+        json!({ "file_path": file_path, "content": "x" }),
+    )
+    .await;
+    assert!(write.is_error);
+    assert_eq!(write.text(), Some(file_reads::FILE_NOT_READ));
+
+    let edit = run(
+        &executor,
+        "Edit",
+        json!({ "file_path": file_path, "old_string": "original", "new_string": "x" }),
+    )
+    .await;
+    assert_eq!(edit.text(), Some(file_reads::FILE_NOT_READ));
+
+    assert!(
+        !run(&executor, "Read", json!({ "file_path": file_path }))
+            .await
+            .is_error
+    );
+    let edit = run(
+        &executor,
+        "Edit",
+        json!({ "file_path": file_path, "old_string": "original", "new_string": "edited" }),
+    )
+    .await;
+    assert!(!edit.is_error, "{:?}", edit);
+
+    // The tool's own edit keeps the file fresh for the next write
+    let write = run(
+        &executor,
+        "Write",
+        json!({ "file_path": file_path, "content": "rewritten" }),
+    )
+    .await;
+    assert!(!write.is_error, "{:?}", write);
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "rewritten");
+}
+
+#[tokio::test]
+async fn test_edit_after_read_with_another_spelling_of_the_path() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("notes.txt");
+    std::fs::write(&path, "original").unwrap();
+    let executor = BuiltinExecutor::new();
+    let ctx = ExecutionContext::default().with_cwd(dir.path());
+    let call = |tool: &str, input| ToolCallSpec {
+        tool: tool.to_string(),
+        input,
+        result: None,
+    };
+
+    let read = call("Read", json!({ "file_path": "./notes.txt" }));
+    let read = executor.execute(&read, "toolu_1", &ctx).await;
+    assert!(!read.is_error, "{:?}", read);
+
+    let edit = call(
+        "Edit",
+        json!({ "file_path": path.to_str().unwrap(), "old_string": "original", "new_string": "edited" }),
+    );
+    let edit = executor.execute(&edit, "toolu_2", &ctx).await;
+    assert!(!edit.is_error, "{:?}", edit);
+
+    let write = call(
+        "Write",
+        json!({ "file_path": "sub/../notes.txt", "content": "rewritten" }),
+    );
+    let write = executor.execute(&write, "toolu_3", &ctx).await;
+    assert!(!write.is_error, "{:?}", write);
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "rewritten");
+}
+
+#[tokio::test]
+async fn test_write_rejects_file_changed_since_read() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("notes.txt");
+    std::fs::write(&path, "original").unwrap();
+    let file_path = path.to_str().unwrap();
+    let executor = BuiltinExecutor::new();
+
+    assert!(
+        !run(&executor, "Read", json!({ "file_path": file_path }))
+            .await
+            .is_error
+    );
+    std::fs::write(&path, "changed by someone else").unwrap();
+    let future = std::time::SystemTime::now() + std::time::Duration::from_secs(5);
+    std::fs::File::options()
+        .write(true)
+        .open(&path)
+        .unwrap()
+        .set_modified(future)
+        .unwrap();
+
+    let write = run(
+        &executor,
+        "Write",
+        json!({ "file_path": file_path, "content": "x" }),
+    )
+    .await;
+    assert!(write.is_error);
+    assert_eq!(write.text(), Some(file_reads::FILE_MODIFIED_SINCE_READ));
+}
+
+#[tokio::test]
+async fn test_write_new_file_needs_no_read() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("new.txt");
+    let executor = BuiltinExecutor::new();
+
+    let write = run(
+        &executor,
+        "Write",
+        json!({ "file_path": path.to_str().unwrap(), "content": "x" }),
+    )
+    .await;
+    assert!(!write.is_error, "{:?}", write);
+}
+
+#[tokio::test]
+#[cfg(unix)]
+async fn test_bash_state_and_env_persist_across_calls() {
+    let executor = BuiltinExecutor::new();
+    let ctx = ExecutionContext::default()
+        .with_env([("FROM_SETTINGS".to_string(), "s".to_string())].into());
+    let call = |command: &str| ToolCallSpec {
+        tool: "Bash".to_string(),
+        input: json!({ "command": command }),
+        result: None,
+    };
+
+    executor
+        .execute(&call("export FROM_SHELL=x"), "toolu_1", &ctx)
+        .await;
+    let result = executor
+        .execute(&call("echo $FROM_SETTINGS$FROM_SHELL"), "toolu_2", &ctx)
+        .await;
+    assert_eq!(result.tool_use_result().unwrap()["stdout"], "sx");
+}
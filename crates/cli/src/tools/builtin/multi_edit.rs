@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Multi-edit executor.
+//!
+//! Applies an ordered list of edits to one file. Each edit sees the result
+//! of the previous ones, and the file is only written if every edit applies.
+
+use serde_json::json;
+
+use crate::config::ToolCallSpec;
+use crate::tools::result::ToolExecutionResult;
+
+use super::edit::apply_edit;
+use super::patch::structured_patch;
+use super::{
+    extract_array, extract_bool, extract_file_path, extract_str, require_field, BuiltinContext,
+    BuiltinToolExecutor,
+};
+use crate::tools::tool_name::ToolName;
+
+/// Executor for applying several edits to one file atomically.
+#[derive(Clone, Debug, Default)]
+pub struct MultiEditExecutor;
+
+impl BuiltinToolExecutor for MultiEditExecutor {
+    fn execute(
+        &self,
+        call: &ToolCallSpec,
+        tool_use_id: &str,
+        ctx: &BuiltinContext,
+    ) -> ToolExecutionResult {
+        let path = require_field!(
+            call.input,
+            extract_file_path => "'file_path' or 'path'",
+            tool_use_id,
+            call.tool
+        );
+        let edits = require_field!(call.input, "edits", extract_array, tool_use_id, call.tool);
+        if edits.is_empty() {
+            return ToolExecutionResult::error(tool_use_id, "At least one edit is required");
+        }
+
+        let resolved_path = match ctx.resolve_path(path) {
+            Ok(p) => p,
+            Err(e) => return ToolExecutionResult::error(tool_use_id, e),
+        };
+        let shown_path = ctx.display_path(&resolved_path);
+
+        let original = match ctx.read_file(&resolved_path) {
+            Ok(c) => c,
+            Err(e) => {
+                return ToolExecutionResult::error(
+                    tool_use_id,
+                    format!("Failed to read file '{}': {}", shown_path.display(), e),
+                )
+            }
+        };
+
+        if let Err(e) = ctx.check_file_fresh(&resolved_path, original.as_bytes()) {
+            return ToolExecutionResult::error(tool_use_id, e);
+        }
+
+        // Apply every edit in memory first; any failure leaves the file untouched
+        let mut content = original.clone();
+        let mut applied = Vec::with_capacity(edits.len());
+        for (i, edit) in edits.iter().enumerate() {
+            let (Some(old_string), Some(new_string)) = (
+                extract_str(edit, "old_string"),
+                extract_str(edit, "new_string"),
+            ) else {
+                return ToolExecutionResult::error(
+                    tool_use_id,
+                    format!(
+                        "Edit {} is missing 'old_string' or 'new_string' in {} tool input",
+                        i + 1,
+                        call.tool
+                    ),
+                );
+            };
+            let replace_all = extract_bool(edit, "replace_all", false);
+            content = match apply_edit(&content, old_string, new_string, replace_all, &shown_path) {
+                Ok((edited, _)) => edited,
+                Err(e) => {
+                    return ToolExecutionResult::error(
+                        tool_use_id,
+                        format!("Edit {} of {} failed: {}", i + 1, edits.len(), e),
+                    )
+                }
+            };
+            applied.push(json!({
+                "old_string": old_string,
+                "new_string": new_string,
+                "replace_all": replace_all,
+            }));
+        }
+
+        match ctx.write_file(&resolved_path, &content) {
+            Ok(()) => {
+                ctx.record_file(&resolved_path, content.as_bytes());
+                ToolExecutionResult::success_with_result(
+                    tool_use_id,
+                    format!("Applied {} edits to {}", edits.len(), shown_path.display()),
+                    json!({
+                        "filePath": shown_path.display().to_string(),
+                        "edits": applied,
+                        "originalFileContents": original,
+                        "structuredPatch": structured_patch(&original, &content),
+                        "userModified": false,
+                    }),
+                )
+            }
+            Err(e) => ToolExecutionResult::error(
+                tool_use_id,
+                format!("Failed to write file '{}': {}", shown_path.display(), e),
+            ),
+        }
+    }
+
+    fn tool_name(&self) -> ToolName {
+        ToolName::MultiEdit
+    }
+}
+
+#[cfg(test)]
+#[path = "multi_edit_tests.rs"]
+mod tests;
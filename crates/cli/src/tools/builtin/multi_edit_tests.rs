@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::super::test_helpers::{assert_tool_error_contains, execute, TestFile};
+use super::*;
+use std::fs;
+
+#[test]
+fn test_multi_edit_applies_edits_in_order() {
+    let file = TestFile::new("main.rs").with_content("fn main() {\n    old();\n}\n");
+    let result = execute::<MultiEditExecutor>(json!({
+        "file_path": file.path_str(),
+        "edits": [
+            { "old_string": "old", "new_string": "new" },
+            { "old_string": "new();", "new_string": "new();\n    more();" },
+        ]
+    }));
+
+    assert!(!result.is_error, "{:?}", result);
+    assert_eq!(
+        fs::read_to_string(&file.path).unwrap(),
+        "fn main() {\n    new();\n    more();\n}\n"
+    );
+
+    let meta = result.tool_use_result().unwrap();
+    assert_eq!(meta["filePath"], file.path_str());
+    assert_eq!(meta["edits"].as_array().unwrap().len(), 2);
+    assert_eq!(meta["edits"][0]["replace_all"], false);
+    assert_eq!(meta["originalFileContents"], "fn main() {\n    old();\n}\n");
+    assert_eq!(
+        meta["structuredPatch"][0]["lines"],
+        json!([
+            " fn main() {",
+            "-    old();",
+            "+    new();",
+            "+    more();",
+            " }"
+        ])
+    );
+}
+
+#[test]
+fn test_multi_edit_is_all_or_nothing() {
+    let file = TestFile::new("notes.txt").with_content("alpha beta\n");
+    let result = execute::<MultiEditExecutor>(json!({
+        "file_path": file.path_str(),
+        "edits": [
+            { "old_string": "alpha", "new_string": "gamma" },
+            { "old_string": "missing", "new_string": "x" },
+        ]
+    }));
+
+    assert_tool_error_contains(&result, "Edit 2 of 2 failed: old_string not found");
+    assert_eq!(fs::read_to_string(&file.path).unwrap(), "alpha beta\n");
+}
+
+#[test]
+fn test_multi_edit_later_edits_see_earlier_changes() {
+    let file = TestFile::new("notes.txt").with_content("a a\n");
+    let result = execute::<MultiEditExecutor>(json!({
+        "file_path": file.path_str(),
+        "edits": [
+            { "old_string": "a", "new_string": "b", "replace_all": true },
+            { "old_string": "b b", "new_string": "c" },
+        ]
+    }));
+
+    assert!(!result.is_error, "{:?}", result);
+    assert_eq!(fs::read_to_string(&file.path).unwrap(), "c\n");
+}
+
+#[test]
+fn test_multi_edit_requires_edits() {
+    let file = TestFile::new("notes.txt").with_content("x");
+    assert_tool_error_contains(
+        &execute::<MultiEditExecutor>(json!({ "file_path": file.path_str() })),
+        "Missing 'edits'",
+    );
+    assert_tool_error_contains(
+        &execute::<MultiEditExecutor>(json!({ "file_path": file.path_str(), "edits": [] })),
+        "At least one edit",
+    );
+    assert_tool_error_contains(
+        &execute::<MultiEditExecutor>(json!({
+            "file_path": file.path_str(),
+            "edits": [{ "old_string": "x" }]
+        })),
+        "Edit 1 is missing",
+    );
+}
@@ -0,0 +1,233 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Jupyter notebook parsing and rendering, shared by Read and NotebookEdit.
+//!
+//! Cells are addressed by their `id`, or by `cell-N` (0-based) for
+//! notebooks that predate cell IDs. Read renders each cell as
+//! `<cell id="...">source</cell id="...">`, followed by its outputs.
+
+use serde_json::{json, Map, Value};
+
+use crate::tools::result::ToolResultContent;
+
+/// Language assumed when the notebook metadata names none.
+const DEFAULT_LANGUAGE: &str = "python";
+
+/// Image output types returned as image blocks, in order of preference.
+const IMAGE_MIME_TYPES: [&str; 2] = ["image/png", "image/jpeg"];
+
+/// Parse notebook JSON, requiring a `cells` array.
+pub(super) fn parse(content: &str) -> Result<Value, String> {
+    let notebook: Value =
+        serde_json::from_str(content).map_err(|_| "Notebook is not valid JSON.".to_string())?;
+    if !notebook.get("cells").is_some_and(Value::is_array) {
+        return Err("Notebook is missing a cells array.".to_string());
+    }
+    Ok(notebook)
+}
+
+/// Serialize a notebook the way Jupyter does: one-space indent, sorted keys
+/// and a trailing newline.
+pub(super) fn serialize(notebook: &Value) -> String {
+    let mut sorted = notebook.clone();
+    sorted.sort_all_objects();
+    let mut out = Vec::new();
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(b" ");
+    let mut serializer = serde_json::Serializer::with_formatter(&mut out, formatter);
+    if serde::Serialize::serialize(&sorted, &mut serializer).is_err() {
+        return notebook.to_string();
+    }
+    out.push(b'\n');
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// The notebook's kernel language.
+pub(super) fn language(notebook: &Value) -> &str {
+    notebook
+        .pointer("/metadata/language_info/name")
+        .and_then(Value::as_str)
+        .unwrap_or(DEFAULT_LANGUAGE)
+}
+
+/// Whether new cells should get an `id` (nbformat 4.5 and later).
+pub(super) fn uses_cell_ids(notebook: &Value) -> bool {
+    let major = notebook["nbformat"].as_u64().unwrap_or(0);
+    let minor = notebook["nbformat_minor"].as_u64().unwrap_or(0);
+    major > 4 || (major == 4 && minor >= 5)
+}
+
+/// A fresh cell ID.
+pub(super) fn new_cell_id() -> String {
+    std::iter::repeat_with(|| fastrand::digit(36))
+        .take(13)
+        .collect()
+}
+
+/// The ID a cell is addressed by.
+pub(super) fn cell_id(cell: &Value, index: usize) -> String {
+    cell["id"]
+        .as_str()
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("cell-{}", index))
+}
+
+/// Index of the cell with `id`, falling back to a `cell-N` index.
+pub(super) fn find_cell(cells: &[Value], id: &str) -> Option<usize> {
+    cells
+        .iter()
+        .position(|cell| cell["id"].as_str() == Some(id))
+        .or_else(|| {
+            id.strip_prefix("cell-")
+                .and_then(|n| n.parse::<usize>().ok())
+                .filter(|&n| n < cells.len())
+        })
+}
+
+/// A multiline notebook string, stored either whole or as a list of lines.
+pub(super) fn text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Array(lines) => lines.iter().filter_map(Value::as_str).collect(),
+        _ => String::new(),
+    }
+}
+
+/// One cell output reduced to the text and image the model sees.
+fn render_output(output: &Value) -> Value {
+    let (text, image) = match output["output_type"].as_str() {
+        Some("stream") => (Some(text(&output["text"])), None),
+        Some("execute_result" | "display_data") => {
+            let data = &output["data"];
+            let text = data.get("text/plain").map(text);
+            let image = IMAGE_MIME_TYPES.iter().find_map(|mime| {
+                data.get(*mime).map(|image| {
+                    json!({
+                        "image_data": text_without_newlines(image),
+                        "media_type": mime,
+                    })
+                })
+            });
+            (text, image)
+        }
+        Some("error") => {
+            let traceback = output["traceback"]
+                .as_array()
+                .map(|lines| {
+                    lines
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                })
+                .unwrap_or_default();
+            let text = format!(
+                "{}: {}\n{}",
+                output["ename"].as_str().unwrap_or_default(),
+                output["evalue"].as_str().unwrap_or_default(),
+                traceback
+            );
+            (Some(text), None)
+        }
+        _ => (None, None),
+    };
+
+    let mut rendered = Map::new();
+    rendered.insert("output_type".to_string(), output["output_type"].clone());
+    if let Some(text) = text {
+        rendered.insert("text".to_string(), Value::String(text));
+    }
+    if let Some(image) = image {
+        rendered.insert("image".to_string(), image);
+    }
+    Value::Object(rendered)
+}
+
+/// Base64 image data, which notebooks may split across lines.
+fn text_without_newlines(value: &Value) -> String {
+    text(value).replace('\n', "")
+}
+
+/// Cells as returned in Read's `toolUseResult`.
+pub(super) fn cells(notebook: &Value) -> Vec<Value> {
+    let language = language(notebook);
+    notebook["cells"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .enumerate()
+        .map(|(index, cell)| {
+            let mut rendered = json!({
+                "cellType": cell["cell_type"],
+                "source": text(&cell["source"]),
+                "cell_id": cell_id(cell, index),
+            });
+            if cell["cell_type"] == "code" {
+                rendered["execution_count"] = cell["execution_count"].clone();
+                rendered["language"] = json!(language);
+                if let Some(outputs) = cell["outputs"].as_array().filter(|o| !o.is_empty()) {
+                    rendered["outputs"] = outputs.iter().map(render_output).collect();
+                }
+            }
+            rendered
+        })
+        .collect()
+}
+
+/// Content blocks the model sees for the rendered `cells`.
+///
+/// Adjacent text blocks are joined with a newline, so a notebook without
+/// image outputs reads as a single text block.
+pub(super) fn content(cells: &[Value]) -> Vec<ToolResultContent> {
+    let mut blocks = Vec::new();
+    for cell in cells {
+        let id = cell["cell_id"].as_str().unwrap_or_default();
+        let cell_type = cell["cellType"].as_str().unwrap_or_default();
+        let metadata = match cell["language"].as_str() {
+            _ if cell_type != "code" => format!("<cell_type>{}</cell_type>", cell_type),
+            Some(language) if language != DEFAULT_LANGUAGE => {
+                format!("<language>{}</language>", language)
+            }
+            _ => String::new(),
+        };
+        push_text(
+            &mut blocks,
+            format!(
+                "<cell id=\"{id}\">{metadata}{}</cell id=\"{id}\">",
+                cell["source"].as_str().unwrap_or_default()
+            ),
+        );
+
+        for output in cell["outputs"]
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+        {
+            if let Some(text) = output["text"].as_str() {
+                push_text(&mut blocks, format!("\n{}", text));
+            }
+            if let Some(image) = output.get("image") {
+                blocks.push(ToolResultContent::image(
+                    image["media_type"].as_str().unwrap_or_default(),
+                    image["image_data"].as_str().unwrap_or_default(),
+                ));
+            }
+        }
+    }
+    blocks
+}
+
+/// Append text, joining it onto a preceding text block.
+fn push_text(blocks: &mut Vec<ToolResultContent>, text: String) {
+    if let Some(ToolResultContent::Text { text: last }) = blocks.last_mut() {
+        last.push('\n');
+        last.push_str(&text);
+    } else {
+        blocks.push(ToolResultContent::Text { text });
+    }
+}
+
+#[cfg(test)]
+#[path = "notebook_tests.rs"]
+mod tests;
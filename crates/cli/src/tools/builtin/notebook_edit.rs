@@ -0,0 +1,248 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Notebook edit executor.
+//!
+//! Replaces, inserts or deletes one cell of a Jupyter notebook. Like Write
+//! and Edit, the notebook must have been read in the session first.
+
+use serde_json::{json, Map, Value};
+
+use crate::config::ToolCallSpec;
+use crate::tools::result::ToolExecutionResult;
+
+use super::notebook;
+use super::read::is_notebook;
+use super::{extract_str, require_field, BuiltinContext, BuiltinToolExecutor};
+use crate::tools::tool_name::ToolName;
+
+/// Executor for editing notebook cells.
+#[derive(Clone, Debug, Default)]
+pub struct NotebookEditExecutor;
+
+/// What to do with the addressed cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum EditMode {
+    Replace,
+    Insert,
+    Delete,
+}
+
+impl EditMode {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "replace" => Some(Self::Replace),
+            "insert" => Some(Self::Insert),
+            "delete" => Some(Self::Delete),
+            _ => None,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Replace => "replace",
+            Self::Insert => "insert",
+            Self::Delete => "delete",
+        }
+    }
+}
+
+/// Set a cell's type, adding or dropping the fields only code cells have.
+fn set_cell_type(cell: &mut Map<String, Value>, cell_type: &str) {
+    cell.insert("cell_type".to_string(), json!(cell_type));
+    if cell_type == "code" {
+        cell.insert("execution_count".to_string(), Value::Null);
+        cell.insert("outputs".to_string(), json!([]));
+    } else {
+        cell.remove("execution_count");
+        cell.remove("outputs");
+    }
+}
+
+/// Apply one edit to the notebook's cells, returning the message and the
+/// ID and type of the affected cell.
+fn apply(
+    notebook: &mut Value,
+    mode: EditMode,
+    cell_id: Option<&str>,
+    cell_type: Option<&str>,
+    new_source: &str,
+) -> Result<(String, String, String), String> {
+    let with_ids = notebook::uses_cell_ids(notebook);
+    let Some(cells) = notebook["cells"].as_array_mut() else {
+        return Err("Notebook is missing a cells array.".to_string());
+    };
+    let index = match cell_id {
+        Some(id) => Some(
+            notebook::find_cell(cells, id)
+                .ok_or_else(|| format!("Cell with ID \"{}\" not found in notebook.", id))?,
+        ),
+        None if mode == EditMode::Insert => None,
+        None => return Err("Cell ID must be specified when not inserting a new cell.".to_string()),
+    };
+
+    match (mode, index) {
+        (EditMode::Insert, _) => {
+            let cell_type = cell_type.unwrap_or("code");
+            let mut cell = Map::new();
+            let new_id = if with_ids {
+                let id = notebook::new_cell_id();
+                cell.insert("id".to_string(), json!(id));
+                Some(id)
+            } else {
+                None
+            };
+            cell.insert("metadata".to_string(), json!({}));
+            cell.insert("source".to_string(), json!(new_source));
+            set_cell_type(&mut cell, cell_type);
+
+            let at = index.map_or(0, |i| i + 1);
+            cells.insert(at, Value::Object(cell));
+            let id = new_id.unwrap_or_else(|| format!("cell-{}", at));
+            Ok((
+                format!("Inserted cell {} with {}", id, new_source),
+                id,
+                cell_type.to_string(),
+            ))
+        }
+        (EditMode::Replace, Some(i)) => {
+            let id = notebook::cell_id(&cells[i], i);
+            let Some(cell) = cells[i].as_object_mut() else {
+                return Err(format!("Cell with ID \"{}\" is not a valid cell.", id));
+            };
+            cell.insert("source".to_string(), json!(new_source));
+            let cell_type = cell_type
+                .or_else(|| cell.get("cell_type").and_then(Value::as_str))
+                .unwrap_or("code")
+                .to_string();
+            // Edited code loses its stale outputs
+            set_cell_type(cell, &cell_type);
+            Ok((
+                format!("Updated cell {} with {}", id, new_source),
+                id,
+                cell_type,
+            ))
+        }
+        (EditMode::Delete, Some(i)) => {
+            let id = notebook::cell_id(&cells[i], i);
+            let removed = cells.remove(i);
+            let cell_type = removed["cell_type"].as_str().unwrap_or("code").to_string();
+            Ok((format!("Deleted cell {}", id), id, cell_type))
+        }
+        (_, None) => Err("Cell ID must be specified when not inserting a new cell.".to_string()),
+    }
+}
+
+impl BuiltinToolExecutor for NotebookEditExecutor {
+    fn execute(
+        &self,
+        call: &ToolCallSpec,
+        tool_use_id: &str,
+        ctx: &BuiltinContext,
+    ) -> ToolExecutionResult {
+        let path = require_field!(
+            call.input,
+            "notebook_path",
+            extract_str,
+            tool_use_id,
+            call.tool
+        );
+        let mode = match extract_str(&call.input, "edit_mode").map(EditMode::parse) {
+            None => EditMode::Replace,
+            Some(Some(mode)) => mode,
+            Some(None) => {
+                return ToolExecutionResult::error(
+                    tool_use_id,
+                    "Edit mode must be replace, insert, or delete.",
+                )
+            }
+        };
+        let new_source = match mode {
+            EditMode::Delete => extract_str(&call.input, "new_source").unwrap_or_default(),
+            _ => require_field!(
+                call.input,
+                "new_source",
+                extract_str,
+                tool_use_id,
+                call.tool
+            ),
+        };
+        let cell_id = extract_str(&call.input, "cell_id");
+        let cell_type = extract_str(&call.input, "cell_type");
+        if let Some(cell_type) = cell_type.filter(|t| !matches!(*t, "code" | "markdown")) {
+            return ToolExecutionResult::error(
+                tool_use_id,
+                format!("Cell type must be code or markdown, got '{}'.", cell_type),
+            );
+        }
+        if mode == EditMode::Insert && cell_type.is_none() {
+            return ToolExecutionResult::error(
+                tool_use_id,
+                "Cell type is required when using edit_mode=insert.",
+            );
+        }
+
+        let resolved_path = match ctx.resolve_path(path) {
+            Ok(p) => p,
+            Err(e) => return ToolExecutionResult::error(tool_use_id, e),
+        };
+        let shown_path = ctx.display_path(&resolved_path);
+        if !is_notebook(&resolved_path) {
+            return ToolExecutionResult::error(
+                tool_use_id,
+                "File must be a Jupyter notebook (.ipynb file). \
+                 For editing other file types, use the Edit tool.",
+            );
+        }
+
+        let Ok(original) = ctx.read_file(&resolved_path) else {
+            return ToolExecutionResult::error(tool_use_id, "Notebook file does not exist.");
+        };
+        if let Err(e) = ctx.check_file_fresh(&resolved_path, original.as_bytes()) {
+            return ToolExecutionResult::error(tool_use_id, e);
+        }
+        let mut notebook = match notebook::parse(&original) {
+            Ok(notebook) => notebook,
+            Err(e) => return ToolExecutionResult::error(tool_use_id, e),
+        };
+
+        let (message, cell_id, cell_type) =
+            match apply(&mut notebook, mode, cell_id, cell_type, new_source) {
+                Ok(applied) => applied,
+                Err(e) => return ToolExecutionResult::error(tool_use_id, e),
+            };
+        let updated = notebook::serialize(&notebook);
+
+        if let Err(e) = ctx.write_file(&resolved_path, &updated) {
+            return ToolExecutionResult::error(
+                tool_use_id,
+                format!("Failed to write file '{}': {}", shown_path.display(), e),
+            );
+        }
+        ctx.record_file(&resolved_path, updated.as_bytes());
+
+        ToolExecutionResult::success_with_result(
+            tool_use_id,
+            message,
+            json!({
+                "new_source": new_source,
+                "cell_id": cell_id,
+                "cell_type": cell_type,
+                "language": notebook::language(&notebook),
+                "edit_mode": mode.as_str(),
+                "error": "",
+                "notebook_path": shown_path.display().to_string(),
+                "original_file": original,
+                "updated_file": updated,
+            }),
+        )
+    }
+
+    fn tool_name(&self) -> ToolName {
+        ToolName::NotebookEdit
+    }
+}
+
+#[cfg(test)]
+#[path = "notebook_edit_tests.rs"]
+mod tests;
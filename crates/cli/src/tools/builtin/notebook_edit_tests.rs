@@ -0,0 +1,159 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::super::test_helpers::{assert_tool_error_contains, execute, TestFile};
+use super::*;
+use crate::tools::builtin::FileReadState;
+use std::sync::Arc;
+use yare::parameterized;
+
+const NOTEBOOK: &str = r#"{
+ "cells": [
+  { "cell_type": "markdown", "id": "intro", "metadata": {}, "source": ["Intro"] },
+  {
+   "cell_type": "code",
+   "id": "calc",
+   "metadata": {},
+   "execution_count": 3,
+   "source": ["1 + 1"],
+   "outputs": [{ "output_type": "execute_result", "data": { "text/plain": ["2"] } }]
+  }
+ ],
+ "metadata": { "language_info": { "name": "python" } },
+ "nbformat": 4,
+ "nbformat_minor": 5
+}
+"#;
+
+fn notebook_file() -> TestFile {
+    TestFile::new("analysis.ipynb").with_content(NOTEBOOK)
+}
+
+fn edit(file: &TestFile, input: Value) -> ToolExecutionResult {
+    let mut input = input;
+    input["notebook_path"] = json!(file.path_str());
+    execute::<NotebookEditExecutor>(input)
+}
+
+fn cells(file: &TestFile) -> Vec<Value> {
+    let content = std::fs::read_to_string(&file.path).unwrap();
+    let notebook: Value = serde_json::from_str(&content).unwrap();
+    notebook["cells"].as_array().unwrap().clone()
+}
+
+#[test]
+fn test_replace_clears_outputs() {
+    let file = notebook_file();
+    let result = edit(&file, json!({ "cell_id": "calc", "new_source": "2 + 2" }));
+    assert!(!result.is_error, "{:?}", result);
+    assert_eq!(result.text(), Some("Updated cell calc with 2 + 2"));
+
+    let cells = cells(&file);
+    assert_eq!(cells[1]["source"], "2 + 2");
+    assert_eq!(cells[1]["execution_count"], Value::Null);
+    assert_eq!(cells[1]["outputs"], json!([]));
+
+    let meta = result.tool_use_result().unwrap();
+    assert_eq!(meta["edit_mode"], "replace");
+    assert_eq!(meta["cell_type"], "code");
+    assert_eq!(meta["language"], "python");
+    assert_eq!(meta["original_file"], NOTEBOOK);
+    assert!(meta["updated_file"].as_str().unwrap().contains("\"2 + 2\""));
+}
+
+#[test]
+fn test_replace_can_change_cell_type() {
+    let file = notebook_file();
+    edit(
+        &file,
+        json!({ "cell_id": "calc", "new_source": "Notes", "cell_type": "markdown" }),
+    );
+    let cells = cells(&file);
+    assert_eq!(cells[1]["cell_type"], "markdown");
+    assert!(cells[1].get("outputs").is_none());
+}
+
+#[test]
+fn test_insert_after_cell_with_new_id() {
+    let file = notebook_file();
+    let result = edit(
+        &file,
+        json!({ "cell_id": "intro", "new_source": "x = 1", "cell_type": "code", "edit_mode": "insert" }),
+    );
+    let cells = cells(&file);
+    assert_eq!(cells.len(), 3);
+    assert_eq!(cells[1]["source"], "x = 1");
+    let id = cells[1]["id"].as_str().unwrap();
+    assert_eq!(id.len(), 13);
+    assert_eq!(
+        result.text(),
+        Some(format!("Inserted cell {} with x = 1", id).as_str())
+    );
+}
+
+#[test]
+fn test_insert_without_cell_id_goes_first() {
+    let file = notebook_file();
+    edit(
+        &file,
+        json!({ "new_source": "# Top", "cell_type": "markdown", "edit_mode": "insert" }),
+    );
+    assert_eq!(cells(&file)[0]["source"], "# Top");
+}
+
+#[test]
+fn test_delete_by_index() {
+    let file = notebook_file();
+    let result = edit(&file, json!({ "cell_id": "cell-0", "edit_mode": "delete" }));
+    assert_eq!(result.text(), Some("Deleted cell intro"));
+    assert_eq!(cells(&file).len(), 1);
+}
+
+#[parameterized(
+    unknown_cell = { json!({ "cell_id": "nope", "new_source": "x" }), "Cell with ID \"nope\" not found in notebook." },
+    missing_cell_id = { json!({ "new_source": "x" }), "Cell ID must be specified when not inserting a new cell." },
+    insert_without_type = { json!({ "new_source": "x", "edit_mode": "insert" }), "Cell type is required when using edit_mode=insert." },
+    bad_mode = { json!({ "cell_id": "calc", "new_source": "x", "edit_mode": "append" }), "Edit mode must be replace, insert, or delete." },
+    bad_type = { json!({ "cell_id": "calc", "new_source": "x", "cell_type": "raw" }), "Cell type must be code or markdown" },
+)]
+fn notebook_edit_errors(input: Value, expected: &str) {
+    let file = notebook_file();
+    assert_tool_error_contains(&edit(&file, input), expected);
+    assert_eq!(std::fs::read_to_string(&file.path).unwrap(), NOTEBOOK);
+}
+
+#[test]
+fn test_rejects_other_files_and_missing_notebooks() {
+    let file = TestFile::new("notes.txt").with_content("text");
+    assert_tool_error_contains(
+        &edit(&file, json!({ "cell_id": "cell-0", "new_source": "x" })),
+        "File must be a Jupyter notebook (.ipynb file).",
+    );
+    let missing = TestFile::new("missing.ipynb");
+    assert_tool_error_contains(
+        &edit(&missing, json!({ "cell_id": "cell-0", "new_source": "x" })),
+        "Notebook file does not exist.",
+    );
+}
+
+#[test]
+fn test_requires_read_first() {
+    let file = notebook_file();
+    let ctx = BuiltinContext {
+        file_reads: Some(Arc::new(FileReadState::new())),
+        ..Default::default()
+    };
+    let call = super::super::test_helpers::tool_call(
+        "NotebookEdit",
+        json!({ "notebook_path": file.path_str(), "cell_id": "calc", "new_source": "x" }),
+    );
+    assert_tool_error_contains(
+        &NotebookEditExecutor.execute(&call, "test_id", &ctx),
+        "File has not been read yet.",
+    );
+}
+
+#[test]
+fn test_tool_name() {
+    assert_eq!(NotebookEditExecutor.tool_name(), ToolName::NotebookEdit);
+}
@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use yare::parameterized;
+
+fn sample() -> Value {
+    json!({
+        "cells": [
+            { "cell_type": "markdown", "id": "intro", "metadata": {}, "source": ["# Title\n", "Text"] },
+            {
+                "cell_type": "code",
+                "id": "calc",
+                "metadata": {},
+                "execution_count": 1,
+                "source": "1 + 1",
+                "outputs": [
+                    { "output_type": "execute_result", "data": { "text/plain": ["2"] } },
+                    { "output_type": "display_data", "data": { "image/png": "iVBO\nRw0=" } },
+                ],
+            },
+            {
+                "cell_type": "code",
+                "metadata": {},
+                "execution_count": 2,
+                "source": "1 / 0",
+                "outputs": [{
+                    "output_type": "error",
+                    "ename": "ZeroDivisionError",
+                    "evalue": "division by zero",
+                    "traceback": ["Traceback", "  line 1"],
+                }],
+            },
+        ],
+        "metadata": { "language_info": { "name": "python" } },
+        "nbformat": 4,
+        "nbformat_minor": 5,
+    })
+}
+
+#[parameterized(
+    by_id = { "calc", Some(1) },
+    by_index = { "cell-2", Some(2) },
+    index_past_end = { "cell-3", None },
+    unknown = { "nope", None },
+)]
+fn find_cell_by_id_or_index(id: &str, expected: Option<usize>) {
+    let notebook = sample();
+    assert_eq!(
+        find_cell(notebook["cells"].as_array().unwrap(), id),
+        expected
+    );
+}
+
+#[test]
+fn parse_rejects_invalid_notebooks() {
+    assert_eq!(parse("{").unwrap_err(), "Notebook is not valid JSON.");
+    assert_eq!(
+        parse("{}").unwrap_err(),
+        "Notebook is missing a cells array."
+    );
+}
+
+#[test]
+fn serialize_uses_one_space_indent_and_trailing_newline() {
+    let out = serialize(&json!({ "nbformat": 4, "cells": [] }));
+    assert_eq!(out, "{\n \"cells\": [],\n \"nbformat\": 4\n}\n");
+}
+
+#[test]
+fn cells_render_outputs() {
+    let cells = cells(&sample());
+    assert_eq!(cells[0]["cellType"], "markdown");
+    assert_eq!(cells[0]["source"], "# Title\nText");
+    assert_eq!(cells[1]["execution_count"], 1);
+    assert_eq!(cells[1]["outputs"][0]["text"], "2");
+    assert_eq!(cells[1]["outputs"][1]["image"]["image_data"], "iVBORw0=");
+    assert_eq!(cells[2]["cell_id"], "cell-2");
+    assert_eq!(
+        cells[2]["outputs"][0]["text"],
+        "ZeroDivisionError: division by zero\nTraceback\n  line 1"
+    );
+}
+
+#[test]
+fn content_joins_text_and_keeps_images() {
+    let blocks = content(&cells(&sample()));
+    assert_eq!(blocks.len(), 3);
+    let ToolResultContent::Text { text } = &blocks[0] else {
+        panic!("expected text: {:?}", blocks[0]);
+    };
+    assert_eq!(
+        text,
+        "<cell id=\"intro\"><cell_type>markdown</cell_type># Title\nText</cell id=\"intro\">\n\
+         <cell id=\"calc\">1 + 1</cell id=\"calc\">\n\n2"
+    );
+    assert!(matches!(&blocks[1], ToolResultContent::Image { source } if source.data == "iVBORw0="));
+    let ToolResultContent::Text { text } = &blocks[2] else {
+        panic!("expected text: {:?}", blocks[2]);
+    };
+    assert!(text.starts_with("<cell id=\"cell-2\">1 / 0</cell id=\"cell-2\">\n\nZeroDivisionError"));
+}
+
+#[test]
+fn content_marks_non_python_code() {
+    let mut notebook = sample();
+    notebook["metadata"]["language_info"]["name"] = json!("r");
+    let blocks = content(&cells(&notebook));
+    let ToolResultContent::Text { text } = &blocks[0] else {
+        panic!("expected text");
+    };
+    assert!(text.contains("<cell id=\"calc\"><language>r</language>1 + 1"));
+}
@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Structured patches for Write/Edit results.
+//!
+//! The real CLI records each file change as a list of unified-diff hunks
+//! (`structuredPatch`) in `toolUseResult`.
+
+use serde_json::{json, Value};
+use similar::{ChangeTag, TextDiff};
+
+/// Unchanged lines kept around each hunk.
+const CONTEXT_LINES: usize = 3;
+
+/// Hunks turning `old` into `new`, each with `oldStart`, `oldLines`,
+/// `newStart`, `newLines` and prefixed `lines`.
+pub(crate) fn structured_patch(old: &str, new: &str) -> Vec<Value> {
+    let diff = TextDiff::from_lines(old, new);
+    diff.grouped_ops(CONTEXT_LINES)
+        .iter()
+        .filter_map(|group| {
+            let (first, last) = (group.first()?, group.last()?);
+            let old_range = first.old_range().start..last.old_range().end;
+            let new_range = first.new_range().start..last.new_range().end;
+            let lines: Vec<String> = group
+                .iter()
+                .flat_map(|op| diff.iter_changes(op))
+                .map(|change| {
+                    let prefix = match change.tag() {
+                        ChangeTag::Equal => ' ',
+                        ChangeTag::Delete => '-',
+                        ChangeTag::Insert => '+',
+                    };
+                    let text = change.value();
+                    format!("{}{}", prefix, text.strip_suffix('\n').unwrap_or(text))
+                })
+                .collect();
+            Some(json!({
+                "oldStart": old_range.start + 1,
+                "oldLines": old_range.len(),
+                "newStart": new_range.start + 1,
+                "newLines": new_range.len(),
+                "lines": lines,
+            }))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "patch_tests.rs"]
+mod tests;
@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+#[test]
+fn identical_contents_have_no_hunks() {
+    assert!(structured_patch("a\nb\n", "a\nb\n").is_empty());
+}
+
+#[test]
+fn single_change_includes_context() {
+    let old = "1\n2\n3\n4\n5\n6\n7\n8\n9\n";
+    let new = "1\n2\n3\n4\nfive\n6\n7\n8\n9\n";
+    assert_eq!(
+        structured_patch(old, new),
+        vec![json!({
+            "oldStart": 2,
+            "oldLines": 7,
+            "newStart": 2,
+            "newLines": 7,
+            "lines": [" 2", " 3", " 4", "-5", "+five", " 6", " 7", " 8"],
+        })]
+    );
+}
+
+#[test]
+fn distant_changes_form_separate_hunks() {
+    let old: String = (1..=20).map(|n| format!("{n}\n")).collect();
+    let new: String = (1..=20)
+        .map(|n| match n {
+            2 => "two\n".to_string(),
+            19 => "nineteen\n".to_string(),
+            n => format!("{n}\n"),
+        })
+        .collect();
+    let hunks = structured_patch(&old, &new);
+    assert_eq!(hunks.len(), 2);
+    assert_eq!(hunks[0]["oldStart"], 1);
+    assert_eq!(hunks[1]["newStart"], 16);
+}
+
+#[test]
+fn new_file_is_one_insertion_hunk() {
+    let hunks = structured_patch("", "a\nb");
+    assert_eq!(
+        hunks,
+        vec![json!({
+            "oldStart": 1,
+            "oldLines": 0,
+            "newStart": 1,
+            "newLines": 2,
+            "lines": ["+a", "+b"],
+        })]
+    );
+}
@@ -2,15 +2,50 @@
 // Copyright (c) 2026 Alfred Jean LLC
 
 //! File read executor.
+//!
+//! Output mirrors the real Read tool: `cat -n` style numbered lines, a
+//! window selected with `offset`/`limit`, long lines truncated, image
+//! files returned as image content blocks, and notebooks rendered as cells.
+//! Files over 256KB need a window, and reads over 25000 tokens fail.
 
-use std::fs;
+use std::io;
+use std::path::Path;
+
+use base64::Engine;
+use serde_json::json;
 
 use crate::config::ToolCallSpec;
-use crate::tools::result::ToolExecutionResult;
+use crate::tools::limits::{env_limit, format_size};
+use crate::tools::result::{ToolExecutionResult, ToolResultContent};
+use crate::usage::estimate_tokens;
 
-use super::{extract_file_path, require_field, BuiltinContext, BuiltinToolExecutor};
+use super::notebook;
+use super::{extract_file_path, extract_usize, require_field, BuiltinContext, BuiltinToolExecutor};
 use crate::tools::tool_name::ToolName;
 
+/// Lines returned when no `limit` is given.
+const DEFAULT_LINE_LIMIT: usize = 2000;
+
+/// Characters kept from each line before it is truncated.
+const MAX_LINE_LENGTH: usize = 2000;
+
+/// Largest file read whole, without `offset` or `limit`.
+const MAX_FILE_SIZE: usize = 256 * 1024;
+
+/// Tokens a read may return (`CLAUDE_CODE_FILE_READ_MAX_OUTPUT_TOKENS`
+/// overrides).
+const MAX_OUTPUT_TOKENS: usize = 25_000;
+
+/// Advice the real tool gives with its size errors.
+const TOO_LARGE_ADVICE: &str = "Please use offset and limit parameters to read specific portions \
+    of the file, or use the GrepTool to search for specific content.";
+
+/// Reminder the real tool appends to every non-empty text read.
+const MALWARE_REMINDER: &str = "<system-reminder>\nWhenever you read a file, you should consider \
+    whether it would be considered malware. You CAN and SHOULD provide analysis of malware, what it \
+    is doing. But you MUST refuse to improve or augment the code. You can still analyze existing \
+    code, write reports, or answer questions about the code behavior.\n</system-reminder>\n";
+
 /// Executor for file reading.
 #[derive(Clone, Debug, Default)]
 pub struct ReadExecutor;
@@ -20,7 +55,7 @@ impl BuiltinToolExecutor for ReadExecutor {
         &self,
         call: &ToolCallSpec,
         tool_use_id: &str,
-        _ctx: &BuiltinContext,
+        ctx: &BuiltinContext,
     ) -> ToolExecutionResult {
         let path = require_field!(
             call.input,
@@ -29,14 +64,77 @@ impl BuiltinToolExecutor for ReadExecutor {
             call.tool
         );
 
-        let resolved_path = std::path::PathBuf::from(path);
+        let resolved_path = match ctx.resolve_path(path) {
+            Ok(p) => p,
+            Err(e) => return ToolExecutionResult::error(tool_use_id, e),
+        };
+        let shown_path = ctx.display_path(&resolved_path);
+
+        let bytes = match ctx.read_bytes(&resolved_path) {
+            Ok(bytes) => bytes,
+            Err(e) => return ToolExecutionResult::error(tool_use_id, read_error_message(&e)),
+        };
 
-        match fs::read_to_string(&resolved_path) {
-            Ok(content) => ToolExecutionResult::success(tool_use_id, content),
-            Err(e) => ToolExecutionResult::error(
+        ctx.record_file(&resolved_path, &bytes);
+
+        if let Some(media_type) = image_media_type(&resolved_path) {
+            return image_result(tool_use_id, media_type, &bytes);
+        }
+
+        if is_notebook(&resolved_path) {
+            let file_path = shown_path.to_string_lossy();
+            return notebook_result(tool_use_id, &file_path, &bytes);
+        }
+
+        let content = match String::from_utf8(bytes) {
+            Ok(content) if !content.contains('\0') => content,
+            _ => {
+                let extension = resolved_path
+                    .extension()
+                    .map(|ext| format!(" .{}", ext.to_string_lossy()))
+                    .unwrap_or_default();
+                return ToolExecutionResult::error(
+                    tool_use_id,
+                    format!(
+                        "This tool cannot read binary files. The file appears to be a binary{} file. \
+                         Please use appropriate tools for binary file analysis.",
+                        extension
+                    ),
+                );
+            }
+        };
+
+        let windowed = call.input.get("offset").is_some() || call.input.get("limit").is_some();
+        if !windowed && content.len() > MAX_FILE_SIZE {
+            return ToolExecutionResult::error(
                 tool_use_id,
-                format!("Failed to read file '{}': {}", resolved_path.display(), e),
+                format!(
+                    "File content ({}) exceeds maximum allowed size ({}). {}",
+                    format_size(content.len()),
+                    format_size(MAX_FILE_SIZE),
+                    TOO_LARGE_ADVICE
+                ),
+            );
+        }
+
+        let offset = extract_usize(&call.input, "offset").unwrap_or(1).max(1);
+        let limit = extract_usize(&call.input, "limit")
+            .filter(|&n| n > 0)
+            .unwrap_or(DEFAULT_LINE_LIMIT);
+        let file_path = shown_path.to_string_lossy();
+        let result = text_result(tool_use_id, &file_path, &content, offset, limit);
+
+        let max_tokens = env_limit(&ctx.env, "CLAUDE_CODE_FILE_READ_MAX_OUTPUT_TOKENS")
+            .unwrap_or(MAX_OUTPUT_TOKENS);
+        match result.text().map(|text| estimate_tokens(text) as usize) {
+            Some(tokens) if tokens > max_tokens => ToolExecutionResult::error(
+                tool_use_id,
+                format!(
+                    "File content ({} tokens) exceeds maximum allowed tokens ({}). {}",
+                    tokens, max_tokens, TOO_LARGE_ADVICE
+                ),
             ),
+            _ => result,
         }
     }
 
@@ -45,6 +143,137 @@ impl BuiltinToolExecutor for ReadExecutor {
     }
 }
 
+/// Message for a file that could not be read.
+fn read_error_message(e: &io::Error) -> String {
+    match e.kind() {
+        io::ErrorKind::NotFound => "File does not exist.".to_string(),
+        io::ErrorKind::IsADirectory => "EISDIR: illegal operation on a directory, read".to_string(),
+        _ => format!("Failed to read file: {}", e),
+    }
+}
+
+/// MIME type for files the Read tool returns as images.
+fn image_media_type(path: &Path) -> Option<&'static str> {
+    let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+    match ext.as_str() {
+        "png" => Some("image/png"),
+        "jpg" | "jpeg" => Some("image/jpeg"),
+        "gif" => Some("image/gif"),
+        "webp" => Some("image/webp"),
+        _ => None,
+    }
+}
+
+/// Whether the file is a Jupyter notebook.
+pub(super) fn is_notebook(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "ipynb")
+}
+
+fn notebook_result(tool_use_id: &str, file_path: &str, bytes: &[u8]) -> ToolExecutionResult {
+    let parsed = notebook::parse(&String::from_utf8_lossy(bytes));
+    let notebook = match parsed {
+        Ok(notebook) => notebook,
+        Err(e) => return ToolExecutionResult::error(tool_use_id, e),
+    };
+    let cells = notebook::cells(&notebook);
+    ToolExecutionResult {
+        content: notebook::content(&cells),
+        tool_use_result: Some(json!({
+            "type": "notebook",
+            "file": {
+                "filePath": file_path,
+                "cells": cells,
+            }
+        })),
+        ..ToolExecutionResult::success(tool_use_id, "")
+    }
+}
+
+fn image_result(tool_use_id: &str, media_type: &str, bytes: &[u8]) -> ToolExecutionResult {
+    let data = base64::engine::general_purpose::STANDARD.encode(bytes);
+    ToolExecutionResult {
+        content: vec![ToolResultContent::image(media_type, data.clone())],
+        tool_use_result: Some(json!({
+            "type": "image",
+            "file": {
+                "base64": data,
+                "type": media_type,
+                "originalSize": bytes.len(),
+            }
+        })),
+        ..ToolExecutionResult::success(tool_use_id, "")
+    }
+}
+
+fn text_result(
+    tool_use_id: &str,
+    file_path: &str,
+    content: &str,
+    offset: usize,
+    limit: usize,
+) -> ToolExecutionResult {
+    // Split on every newline like the real tool, so a trailing newline
+    // yields a final empty line.
+    let lines: Vec<&str> = if content.is_empty() {
+        Vec::new()
+    } else {
+        content
+            .split('\n')
+            .map(|line| line.strip_suffix('\r').unwrap_or(line))
+            .collect()
+    };
+    let total_lines = lines.len();
+    let selected: Vec<&str> = lines.iter().skip(offset - 1).take(limit).copied().collect();
+
+    let text = if total_lines == 0 {
+        "<system-reminder>Warning: the file exists but the contents are empty.</system-reminder>"
+            .to_string()
+    } else if selected.is_empty() {
+        format!(
+            "<system-reminder>Warning: the file exists but is shorter than the provided offset ({}). \
+             The file has {} lines.</system-reminder>",
+            offset, total_lines
+        )
+    } else {
+        format!(
+            "{}\n\n{}",
+            number_lines(&selected, offset),
+            MALWARE_REMINDER
+        )
+    };
+
+    ToolExecutionResult::success_with_result(
+        tool_use_id,
+        text,
+        json!({
+            "type": "text",
+            "file": {
+                "filePath": file_path,
+                "content": selected.join("\n"),
+                "numLines": selected.len(),
+                "startLine": offset,
+                "totalLines": total_lines,
+            }
+        }),
+    )
+}
+
+/// Format lines like `cat -n`, truncating any that are too long.
+fn number_lines(lines: &[&str], first: usize) -> String {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let line = match line.char_indices().nth(MAX_LINE_LENGTH) {
+                Some((end, _)) => format!("{}... (line truncated)", &line[..end]),
+                None => line.to_string(),
+            };
+            format!("{:>6}\u{2192}{}", first + i, line)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 #[cfg(test)]
 #[path = "read_tests.rs"]
 mod tests;
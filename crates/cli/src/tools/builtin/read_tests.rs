@@ -2,7 +2,7 @@
 // Copyright (c) 2026 Alfred Jean LLC
 
 use super::super::test_helpers::{
-    assert_tool_error_contains, assert_tool_success_contains, execute, TestFile,
+    assert_tool_error_contains, assert_tool_success_contains, execute, TestDir, TestFile,
 };
 use super::*;
 use crate::tools::builtin::extract_file_path;
@@ -22,7 +22,7 @@ fn extract_path(input: serde_json::Value, expected: Option<&str>) {
 fn test_read_nonexistent_file() {
     assert_tool_error_contains(
         &execute::<ReadExecutor>(json!({ "file_path": "/nonexistent/file.txt" })),
-        "Failed to read",
+        "File does not exist.",
     );
 }
 
@@ -31,6 +31,158 @@ fn test_read_real_file() {
     let file = TestFile::new("test.txt").with_content("Hello, World!\n");
     assert_tool_success_contains(
         &execute::<ReadExecutor>(json!({ "file_path": file.path_str() })),
-        "Hello, World!",
+        "     1\u{2192}Hello, World!",
     );
 }
+
+#[test]
+fn test_read_offset_and_limit_select_window() {
+    let file = TestFile::new("lines.txt").with_content("one\ntwo\nthree\nfour\n");
+    let result = execute::<ReadExecutor>(json!({
+        "file_path": file.path_str(),
+        "offset": 2,
+        "limit": 2
+    }));
+
+    let text = result.text().unwrap();
+    assert!(
+        text.starts_with("     2\u{2192}two\n     3\u{2192}three\n\n<system-reminder>"),
+        "{}",
+        text
+    );
+    let meta = result.tool_use_result().unwrap();
+    assert_eq!(meta["type"], "text");
+    assert_eq!(meta["file"]["filePath"], file.path_str());
+    assert_eq!(meta["file"]["content"], "two\nthree");
+    assert_eq!(meta["file"]["numLines"], 2);
+    assert_eq!(meta["file"]["startLine"], 2);
+    assert_eq!(meta["file"]["totalLines"], 5);
+}
+
+#[test]
+fn test_read_trailing_newline_counts_as_line() {
+    // Matches recorded transcripts: "stop\n" reads as two lines
+    let file = TestFile::new("stop.txt").with_content("stop\n");
+    let result = execute::<ReadExecutor>(json!({ "file_path": file.path_str() }));
+
+    assert!(result.text().unwrap().starts_with(
+        "     1\u{2192}stop\n     2\u{2192}\n\n<system-reminder>\nWhenever you read a file"
+    ));
+    let meta = result.tool_use_result().unwrap();
+    assert_eq!(meta["file"]["content"], "stop\n");
+    assert_eq!(meta["file"]["numLines"], 2);
+    assert_eq!(meta["file"]["totalLines"], 2);
+}
+
+#[test]
+fn test_read_truncates_long_lines() {
+    let long = "x".repeat(MAX_LINE_LENGTH + 10);
+    let file = TestFile::new("long.txt").with_content(&long);
+    let result = execute::<ReadExecutor>(json!({ "file_path": file.path_str() }));
+
+    let text = result.text().unwrap();
+    assert!(text.contains("... (line truncated)\n"), "{}", text);
+    assert!(!text.contains(&long));
+}
+
+#[test]
+fn test_read_large_file_needs_window() {
+    let content = format!("{}\n", "x".repeat(99)).repeat(3000);
+    let file = TestFile::new("big.txt").with_content(&content);
+
+    assert_tool_error_contains(
+        &execute::<ReadExecutor>(json!({ "file_path": file.path_str() })),
+        "File content (293KB) exceeds maximum allowed size (256KB). Please use offset and limit",
+    );
+    assert!(
+        !execute::<ReadExecutor>(json!({ "file_path": file.path_str(), "limit": 10 })).is_error
+    );
+}
+
+#[test]
+fn test_read_over_token_limit_is_error() {
+    let content = format!("{}\n", "x".repeat(99)).repeat(2000);
+    let file = TestFile::new("wide.txt").with_content(&content);
+
+    assert_tool_error_contains(
+        &execute::<ReadExecutor>(json!({ "file_path": file.path_str() })),
+        "tokens) exceeds maximum allowed tokens (25000)",
+    );
+    assert!(
+        !execute::<ReadExecutor>(json!({ "file_path": file.path_str(), "limit": 500 })).is_error
+    );
+}
+
+#[parameterized(
+    empty = { "", None, "the file exists but the contents are empty" },
+    past_end = { "a\nb", Some(5), "shorter than the provided offset (5). The file has 2 lines." },
+)]
+fn read_reports_nothing_to_show(content: &str, offset: Option<u64>, expected: &str) {
+    let file = TestFile::new("short.txt").with_content(content);
+    let mut input = json!({ "file_path": file.path_str() });
+    if let Some(offset) = offset {
+        input["offset"] = json!(offset);
+    }
+    assert_tool_success_contains(&execute::<ReadExecutor>(input), expected);
+}
+
+#[test]
+fn test_read_directory_is_error() {
+    let dir = TestDir::new();
+    assert_tool_error_contains(
+        &execute::<ReadExecutor>(json!({ "file_path": dir.path_str() })),
+        "EISDIR",
+    );
+}
+
+#[test]
+fn test_read_binary_file_is_error() {
+    let file = TestFile::new("data.bin");
+    std::fs::write(&file.path, [0u8, 159, 146, 150]).unwrap();
+    assert_tool_error_contains(
+        &execute::<ReadExecutor>(json!({ "file_path": file.path_str() })),
+        "cannot read binary files",
+    );
+}
+
+#[test]
+fn test_read_image_returns_image_block() {
+    let file = TestFile::new("pixel.png");
+    std::fs::write(&file.path, b"\x89PNG").unwrap();
+    let result = execute::<ReadExecutor>(json!({ "file_path": file.path_str() }));
+
+    assert!(!result.is_error);
+    match &result.content[..] {
+        [ToolResultContent::Image { source }] => {
+            assert_eq!(source.media_type, "image/png");
+            assert_eq!(source.data, "iVBORw==");
+        }
+        other => panic!("Expected image block, got {:?}", other),
+    }
+    let meta = result.tool_use_result().unwrap();
+    assert_eq!(meta["type"], "image");
+    assert_eq!(meta["file"]["originalSize"], 4);
+}
+
+#[test]
+fn test_read_notebook_renders_cells() {
+    let file = TestFile::new("analysis.ipynb").with_content(
+        r#"{"cells": [
+            {"cell_type": "markdown", "id": "intro", "metadata": {}, "source": ["Intro"]},
+            {"cell_type": "code", "id": "calc", "metadata": {}, "execution_count": 1,
+             "source": ["1 + 1"], "outputs": [{"output_type": "stream", "name": "stdout", "text": ["2\n"]}]}
+        ], "metadata": {}, "nbformat": 4, "nbformat_minor": 5}"#,
+    );
+    let result = execute::<ReadExecutor>(json!({ "file_path": file.path_str() }));
+    assert_eq!(
+        result.text(),
+        Some(
+            "<cell id=\"intro\"><cell_type>markdown</cell_type>Intro</cell id=\"intro\">\n\
+             <cell id=\"calc\">1 + 1</cell id=\"calc\">\n\n2\n"
+        )
+    );
+    let meta = result.tool_use_result().unwrap();
+    assert_eq!(meta["type"], "notebook");
+    assert_eq!(meta["file"]["filePath"], file.path_str());
+    assert_eq!(meta["file"]["cells"][1]["outputs"][0]["text"], "2\n");
+}
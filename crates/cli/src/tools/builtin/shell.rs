@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Per-session shell state for the Bash tool.
+//!
+//! The real Bash tool keeps one shell alive for the session, so `cd` and
+//! `export` carry over to later commands. Each call here runs a fresh `sh`,
+//! which saves its working directory and exported variables on exit; the
+//! next call starts from them.
+
+use std::path::{Path, PathBuf};
+
+use parking_lot::Mutex;
+
+/// Working directory and exports left behind by the last command.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ShellSnapshot {
+    /// Directory the last command finished in.
+    pub cwd: Option<PathBuf>,
+    /// Output of `export -p`, replayed before the next command.
+    pub exports: String,
+}
+
+/// Shell state shared by every Bash call in a session.
+#[derive(Debug, Default)]
+pub struct ShellState {
+    snapshot: Mutex<ShellSnapshot>,
+}
+
+impl ShellState {
+    /// Create a state with no saved directory or exports.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// State to start the next command from.
+    pub fn snapshot(&self) -> ShellSnapshot {
+        self.snapshot.lock().clone()
+    }
+
+    /// Save the state a command finished with.
+    pub fn update(&self, cwd: &Path, exports: String) {
+        *self.snapshot.lock() = ShellSnapshot {
+            cwd: Some(cwd.to_path_buf()),
+            exports,
+        };
+    }
+
+    /// Move the saved directory back to `cwd`, keeping the exports.
+    pub fn reset_cwd(&self, cwd: &Path) {
+        self.snapshot.lock().cwd = Some(cwd.to_path_buf());
+    }
+}
+
+#[cfg(test)]
+#[path = "shell_tests.rs"]
+mod tests;
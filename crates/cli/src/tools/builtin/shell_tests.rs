@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+#[test]
+fn new_state_is_empty() {
+    assert_eq!(ShellState::new().snapshot(), ShellSnapshot::default());
+}
+
+#[test]
+fn update_then_reset_cwd_keeps_exports() {
+    let state = ShellState::new();
+    state.update(Path::new("/tmp/a"), "export FOO='bar'\n".to_string());
+    state.reset_cwd(Path::new("/tmp"));
+
+    let snapshot = state.snapshot();
+    assert_eq!(snapshot.cwd.as_deref(), Some(Path::new("/tmp")));
+    assert_eq!(snapshot.exports, "export FOO='bar'\n");
+}
@@ -46,6 +46,8 @@ pub fn execute_todo_write(call: &ToolCallSpec, state_writer: &StateWriter) -> To
             is_error: false,
             tool_use_result: Some(tool_use_result),
             needs_prompt: false,
+            permission_denied: false,
+            meta_message: None,
         },
         Err(e) => ToolExecutionResult {
             tool_use_id: String::new(),
@@ -55,6 +57,8 @@ pub fn execute_todo_write(call: &ToolCallSpec, state_writer: &StateWriter) -> To
             is_error: true,
             tool_use_result: None,
             needs_prompt: false,
+            permission_denied: false,
+            meta_message: None,
         },
     }
 }
@@ -114,6 +118,8 @@ pub fn execute_enter_plan_mode() -> ToolExecutionResult {
             "message": "Entered plan mode. You are now exploring and designing an implementation approach."
         })),
         needs_prompt: false,
+        permission_denied: false,
+        meta_message: None,
     }
 }
 
@@ -151,6 +157,8 @@ pub fn execute_exit_plan_mode(
                     "plan_path": plan_path,
                 })),
                 needs_prompt: false,
+                permission_denied: false,
+                meta_message: None,
             }
         }
         Err(e) => ToolExecutionResult {
@@ -161,6 +169,8 @@ pub fn execute_exit_plan_mode(
             is_error: true,
             tool_use_result: None,
             needs_prompt: false,
+            permission_denied: false,
+            meta_message: None,
         },
     }
 }
@@ -211,6 +221,8 @@ pub fn execute_ask_user_question(call: &ToolCallSpec) -> ToolExecutionResult {
         is_error: false,
         tool_use_result: Some(result_json),
         needs_prompt: false,
+        permission_denied: false,
+        meta_message: None,
     }
 }
 
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Task creation executor.
+//!
+//! Adds a pending task to the session's task list.
+
+use serde_json::json;
+
+use crate::config::ToolCallSpec;
+use crate::tools::result::ToolExecutionResult;
+
+use super::{extract_str, require_field, BuiltinContext, BuiltinToolExecutor};
+use crate::tools::tool_name::ToolName;
+
+/// Executor for adding a task.
+#[derive(Clone, Debug, Default)]
+pub struct TaskCreateExecutor;
+
+impl BuiltinToolExecutor for TaskCreateExecutor {
+    fn execute(
+        &self,
+        call: &ToolCallSpec,
+        tool_use_id: &str,
+        ctx: &BuiltinContext,
+    ) -> ToolExecutionResult {
+        let subject = require_field!(call.input, "subject", extract_str, tool_use_id, call.tool);
+        let description = require_field!(
+            call.input,
+            "description",
+            extract_str,
+            tool_use_id,
+            call.tool
+        );
+        let active_form = extract_str(&call.input, "activeForm");
+        let metadata = call.input.get("metadata").cloned();
+
+        let Some(ref store) = ctx.task_store else {
+            return ToolExecutionResult::error(tool_use_id, "Task list is not available");
+        };
+        match store.create(subject, description, active_form, metadata) {
+            Ok(task) => ToolExecutionResult::success_with_result(
+                tool_use_id,
+                format!("Task #{} created successfully: {}", task.id, task.subject),
+                json!({
+                    "task": { "id": task.id, "subject": task.subject },
+                }),
+            ),
+            Err(e) => ToolExecutionResult::error(tool_use_id, e.to_string()),
+        }
+    }
+
+    fn tool_name(&self) -> ToolName {
+        ToolName::TaskCreate
+    }
+}
+
+#[cfg(test)]
+#[path = "task_create_tests.rs"]
+mod tests;
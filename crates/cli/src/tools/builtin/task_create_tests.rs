@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::super::test_helpers::{assert_tool_error_contains, execute, tool_call};
+use super::*;
+use crate::state::TaskStore;
+use std::sync::Arc;
+
+#[test]
+fn test_task_create_adds_pending_task() {
+    let store = Arc::new(TaskStore::in_memory());
+    let ctx = BuiltinContext {
+        task_store: Some(Arc::clone(&store)),
+        ..Default::default()
+    };
+    let call = tool_call(
+        "TaskCreate",
+        json!({ "subject": "Run tests", "description": "cargo test", "activeForm": "Running tests" }),
+    );
+
+    let result = TaskCreateExecutor.execute(&call, "test_id", &ctx);
+
+    assert!(!result.is_error);
+    assert_eq!(
+        result.text(),
+        Some("Task #1 created successfully: Run tests")
+    );
+    assert_eq!(
+        result.tool_use_result().unwrap(),
+        json!({ "task": { "id": "1", "subject": "Run tests" } })
+    );
+    let task = store.get("1").unwrap();
+    assert_eq!(task.description, "cargo test");
+    assert_eq!(task.active_form.as_deref(), Some("Running tests"));
+}
+
+#[test]
+fn test_task_create_requires_subject() {
+    assert_tool_error_contains(
+        &execute::<TaskCreateExecutor>(json!({ "description": "d" })),
+        "Missing 'subject' field",
+    );
+}
+
+#[test]
+fn test_tool_name() {
+    assert_eq!(TaskCreateExecutor.tool_name(), ToolName::TaskCreate);
+}
@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Task lookup executor.
+//!
+//! Returns one task with its description and dependencies.
+
+use serde_json::json;
+
+use crate::config::ToolCallSpec;
+use crate::tools::result::ToolExecutionResult;
+
+use super::{extract_str, require_field, BuiltinContext, BuiltinToolExecutor};
+use crate::tools::tool_name::ToolName;
+
+/// Executor for reading a task.
+#[derive(Clone, Debug, Default)]
+pub struct TaskGetExecutor;
+
+/// Task IDs as `#1, #2`.
+pub(super) fn task_refs(ids: &[String]) -> String {
+    ids.iter()
+        .map(|id| format!("#{}", id))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl BuiltinToolExecutor for TaskGetExecutor {
+    fn execute(
+        &self,
+        call: &ToolCallSpec,
+        tool_use_id: &str,
+        ctx: &BuiltinContext,
+    ) -> ToolExecutionResult {
+        let task_id = require_field!(call.input, "taskId", extract_str, tool_use_id, call.tool);
+
+        let Some(task) = ctx.task_store.as_ref().and_then(|store| store.get(task_id)) else {
+            return ToolExecutionResult::success_with_result(
+                tool_use_id,
+                "Task not found",
+                json!({ "task": null }),
+            );
+        };
+
+        let mut text = format!(
+            "Task #{}: {}\nStatus: {}\nDescription: {}",
+            task.id, task.subject, task.status, task.description
+        );
+        if !task.blocked_by.is_empty() {
+            text.push_str(&format!("\nBlocked by: {}", task_refs(&task.blocked_by)));
+        }
+        if !task.blocks.is_empty() {
+            text.push_str(&format!("\nBlocks: {}", task_refs(&task.blocks)));
+        }
+        ToolExecutionResult::success_with_result(
+            tool_use_id,
+            text,
+            json!({
+                "task": {
+                    "id": task.id,
+                    "subject": task.subject,
+                    "description": task.description,
+                    "status": task.status,
+                    "blocks": task.blocks,
+                    "blockedBy": task.blocked_by,
+                },
+            }),
+        )
+    }
+
+    fn tool_name(&self) -> ToolName {
+        ToolName::TaskGet
+    }
+}
+
+#[cfg(test)]
+#[path = "task_get_tests.rs"]
+mod tests;
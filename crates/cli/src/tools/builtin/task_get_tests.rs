@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::super::test_helpers::tool_call;
+use super::*;
+use crate::state::{TaskStore, TaskUpdate};
+use std::sync::Arc;
+
+fn context() -> BuiltinContext {
+    let store = TaskStore::in_memory();
+    store.create("Build", "Compile it", None, None).unwrap();
+    store.create("Deploy", "Ship it", None, None).unwrap();
+    store
+        .update(
+            "2",
+            TaskUpdate {
+                add_blocked_by: vec!["1".into()],
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    BuiltinContext {
+        task_store: Some(Arc::new(store)),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_task_get_shows_task_with_dependencies() {
+    let call = tool_call("TaskGet", json!({ "taskId": "2" }));
+
+    let result = TaskGetExecutor.execute(&call, "test_id", &context());
+
+    assert_eq!(
+        result.text(),
+        Some("Task #2: Deploy\nStatus: pending\nDescription: Ship it\nBlocked by: #1")
+    );
+    let task = &result.tool_use_result().unwrap()["task"];
+    assert_eq!(task["blockedBy"], json!(["1"]));
+    assert_eq!(task["blocks"], json!([]));
+}
+
+#[test]
+fn test_task_get_unknown_task() {
+    let call = tool_call("TaskGet", json!({ "taskId": "7" }));
+
+    let result = TaskGetExecutor.execute(&call, "test_id", &context());
+
+    assert!(!result.is_error);
+    assert_eq!(result.text(), Some("Task not found"));
+    assert_eq!(result.tool_use_result().unwrap(), json!({ "task": null }));
+}
+
+#[test]
+fn test_tool_name() {
+    assert_eq!(TaskGetExecutor.tool_name(), ToolName::TaskGet);
+}
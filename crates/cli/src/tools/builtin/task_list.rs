@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Task list executor.
+//!
+//! Summarizes every task, with the unfinished tasks blocking each one.
+
+use serde_json::json;
+
+use crate::config::ToolCallSpec;
+use crate::tools::result::ToolExecutionResult;
+
+use super::task_get::task_refs;
+use super::{BuiltinContext, BuiltinToolExecutor};
+use crate::tools::tool_name::ToolName;
+
+/// Executor for listing tasks.
+#[derive(Clone, Debug, Default)]
+pub struct TaskListExecutor;
+
+impl BuiltinToolExecutor for TaskListExecutor {
+    fn execute(
+        &self,
+        _call: &ToolCallSpec,
+        tool_use_id: &str,
+        ctx: &BuiltinContext,
+    ) -> ToolExecutionResult {
+        let Some(ref store) = ctx.task_store else {
+            return ToolExecutionResult::success_with_result(
+                tool_use_id,
+                "No tasks found",
+                json!({ "tasks": [] }),
+            );
+        };
+
+        let mut lines = Vec::new();
+        let mut tasks = Vec::new();
+        for task in store.list() {
+            let blocked_by = store.open_blockers(&task);
+            let mut line = format!("#{} [{}] {}", task.id, task.status, task.subject);
+            if let Some(ref owner) = task.owner {
+                line.push_str(&format!(" ({})", owner));
+            }
+            if !blocked_by.is_empty() {
+                line.push_str(&format!(" [blocked by {}]", task_refs(&blocked_by)));
+            }
+            lines.push(line);
+            tasks.push(json!({
+                "id": task.id,
+                "subject": task.subject,
+                "status": task.status,
+                "owner": task.owner,
+                "blockedBy": blocked_by,
+            }));
+        }
+
+        let text = if lines.is_empty() {
+            "No tasks found".to_string()
+        } else {
+            lines.join("\n")
+        };
+        ToolExecutionResult::success_with_result(tool_use_id, text, json!({ "tasks": tasks }))
+    }
+
+    fn tool_name(&self) -> ToolName {
+        ToolName::TaskList
+    }
+}
+
+#[cfg(test)]
+#[path = "task_list_tests.rs"]
+mod tests;
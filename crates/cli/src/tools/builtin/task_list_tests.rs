@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::super::test_helpers::{execute, tool_call};
+use super::*;
+use crate::state::{TaskStatus, TaskStore, TaskUpdate};
+use std::sync::Arc;
+
+#[test]
+fn test_task_list_summarizes_tasks() {
+    let store = TaskStore::in_memory();
+    for subject in ["Build", "Test", "Deploy"] {
+        store.create(subject, "", None, None).unwrap();
+    }
+    store
+        .update(
+            "1",
+            TaskUpdate {
+                status: Some(TaskStatus::Completed),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    store
+        .update(
+            "3",
+            TaskUpdate {
+                owner: Some("main".into()),
+                add_blocked_by: vec!["1".into(), "2".into()],
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    let ctx = BuiltinContext {
+        task_store: Some(Arc::new(store)),
+        ..Default::default()
+    };
+
+    let result = TaskListExecutor.execute(&tool_call("TaskList", json!({})), "test_id", &ctx);
+
+    // Completed blockers no longer count
+    assert_eq!(
+        result.text(),
+        Some("#1 [completed] Build\n#2 [pending] Test\n#3 [pending] Deploy (main) [blocked by #2]")
+    );
+    let tasks = &result.tool_use_result().unwrap()["tasks"];
+    assert_eq!(tasks[2]["blockedBy"], json!(["2"]));
+    assert_eq!(tasks[2]["owner"], "main");
+}
+
+#[test]
+fn test_task_list_empty() {
+    let result = execute::<TaskListExecutor>(json!({}));
+
+    assert_eq!(result.text(), Some("No tasks found"));
+    assert_eq!(result.tool_use_result().unwrap(), json!({ "tasks": [] }));
+}
+
+#[test]
+fn test_tool_name() {
+    assert_eq!(TaskListExecutor.tool_name(), ToolName::TaskList);
+}
@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Task update executor.
+//!
+//! Changes a task's fields or status, adds dependencies, or deletes it
+//! with `status: "deleted"`.
+
+use serde_json::{json, Value};
+
+use crate::config::ToolCallSpec;
+use crate::state::{TaskStatus, TaskUpdate};
+use crate::tools::result::ToolExecutionResult;
+
+use super::{extract_array, extract_str, require_field, BuiltinContext, BuiltinToolExecutor};
+use crate::tools::tool_name::ToolName;
+
+/// Status value that deletes the task.
+const DELETED: &str = "deleted";
+
+/// Executor for changing a task.
+#[derive(Clone, Debug, Default)]
+pub struct TaskUpdateExecutor;
+
+/// Task IDs in an array field.
+fn ids(input: &Value, key: &str) -> Vec<String> {
+    extract_array(input, key)
+        .map(|ids| {
+            ids.iter()
+                .filter_map(|id| match id {
+                    Value::String(s) => Some(s.clone()),
+                    Value::Number(n) => Some(n.to_string()),
+                    _ => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+impl BuiltinToolExecutor for TaskUpdateExecutor {
+    fn execute(
+        &self,
+        call: &ToolCallSpec,
+        tool_use_id: &str,
+        ctx: &BuiltinContext,
+    ) -> ToolExecutionResult {
+        let task_id = require_field!(call.input, "taskId", extract_str, tool_use_id, call.tool);
+        let Some(ref store) = ctx.task_store else {
+            return ToolExecutionResult::error(tool_use_id, "Task list is not available");
+        };
+
+        let status = extract_str(&call.input, "status");
+        if status == Some(DELETED) {
+            return match store.delete(task_id) {
+                Ok(_) => ToolExecutionResult::success_with_result(
+                    tool_use_id,
+                    format!("Updated task #{} deleted", task_id),
+                    json!({
+                        "success": true,
+                        "taskId": task_id,
+                        "updatedFields": ["deleted"],
+                    }),
+                ),
+                Err(e) => ToolExecutionResult::error(tool_use_id, e.to_string()),
+            };
+        }
+        let status = match status.map(TaskStatus::parse) {
+            None => None,
+            Some(Some(status)) => Some(status),
+            Some(None) => {
+                return ToolExecutionResult::error(
+                    tool_use_id,
+                    format!(
+                        "Invalid status '{}'. Expected pending, in_progress, completed or deleted.",
+                        status.unwrap_or_default()
+                    ),
+                )
+            }
+        };
+
+        let update = TaskUpdate {
+            subject: extract_str(&call.input, "subject").map(str::to_string),
+            description: extract_str(&call.input, "description").map(str::to_string),
+            active_form: extract_str(&call.input, "activeForm").map(str::to_string),
+            status,
+            owner: extract_str(&call.input, "owner").map(str::to_string),
+            metadata: call.input.get("metadata").cloned(),
+            add_blocks: ids(&call.input, "addBlocks"),
+            add_blocked_by: ids(&call.input, "addBlockedBy"),
+        };
+        let outcome = match store.update(task_id, update) {
+            Ok(outcome) => outcome,
+            Err(e) => return ToolExecutionResult::error(tool_use_id, e.to_string()),
+        };
+
+        let mut result = json!({
+            "success": true,
+            "taskId": task_id,
+            "updatedFields": outcome.updated_fields,
+        });
+        if let Some((from, to)) = outcome.status_change {
+            result["statusChange"] = json!({ "from": from, "to": to });
+        }
+        let text = if outcome.updated_fields.is_empty() {
+            format!("Updated task #{}", task_id)
+        } else {
+            format!(
+                "Updated task #{} {}",
+                task_id,
+                outcome.updated_fields.join(", ")
+            )
+        };
+        ToolExecutionResult::success_with_result(tool_use_id, text, result)
+    }
+
+    fn tool_name(&self) -> ToolName {
+        ToolName::TaskUpdate
+    }
+}
+
+#[cfg(test)]
+#[path = "task_update_tests.rs"]
+mod tests;
@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::super::test_helpers::{assert_tool_error_contains, tool_call};
+use super::*;
+use crate::state::TaskStore;
+use std::sync::Arc;
+
+fn context() -> (Arc<TaskStore>, BuiltinContext) {
+    let store = Arc::new(TaskStore::in_memory());
+    store.create("Build", "", None, None).unwrap();
+    store.create("Deploy", "", None, None).unwrap();
+    let ctx = BuiltinContext {
+        task_store: Some(Arc::clone(&store)),
+        ..Default::default()
+    };
+    (store, ctx)
+}
+
+fn update(ctx: &BuiltinContext, input: Value) -> ToolExecutionResult {
+    TaskUpdateExecutor.execute(&tool_call("TaskUpdate", input), "test_id", ctx)
+}
+
+#[test]
+fn test_task_update_status_records_transition() {
+    let (store, ctx) = context();
+
+    let result = update(&ctx, json!({ "taskId": "1", "status": "in_progress" }));
+
+    assert_eq!(result.text(), Some("Updated task #1 status"));
+    assert_eq!(
+        result.tool_use_result().unwrap(),
+        json!({
+            "success": true,
+            "taskId": "1",
+            "updatedFields": ["status"],
+            "statusChange": { "from": "pending", "to": "in_progress" },
+        })
+    );
+    assert_eq!(store.get("1").unwrap().status, TaskStatus::InProgress);
+}
+
+#[test]
+fn test_task_update_adds_dependencies() {
+    let (store, ctx) = context();
+
+    let result = update(&ctx, json!({ "taskId": "1", "addBlocks": ["2"] }));
+
+    assert_eq!(result.text(), Some("Updated task #1 blocks"));
+    assert_eq!(store.get("2").unwrap().blocked_by, vec!["1"]);
+}
+
+#[test]
+fn test_task_update_deletes_task() {
+    let (store, ctx) = context();
+
+    let result = update(&ctx, json!({ "taskId": "2", "status": "deleted" }));
+
+    assert_eq!(result.text(), Some("Updated task #2 deleted"));
+    assert!(store.get("2").is_none());
+}
+
+#[test]
+fn test_task_update_errors() {
+    let (_, ctx) = context();
+
+    assert_tool_error_contains(
+        &update(&ctx, json!({ "taskId": "9", "status": "completed" })),
+        "Task #9 not found",
+    );
+    assert_tool_error_contains(
+        &update(&ctx, json!({ "taskId": "1", "status": "done" })),
+        "Invalid status 'done'",
+    );
+    assert_tool_error_contains(
+        &update(&ctx, json!({ "taskId": "1", "addBlockedBy": ["1"] })),
+        "Task #1 cannot depend on itself",
+    );
+}
+
+#[test]
+fn test_tool_name() {
+    assert_eq!(TaskUpdateExecutor.tool_name(), ToolName::TaskUpdate);
+}
@@ -111,14 +111,16 @@ pub fn assert_tool_success_contains(result: &ToolExecutionResult, expected: &str
 }
 
 /// Execute a builtin tool by name with the given input.
-pub fn execute_tool(tool: &str, input: serde_json::Value) -> ToolExecutionResult {
+pub async fn execute_tool(tool: &str, input: serde_json::Value) -> ToolExecutionResult {
     let executor = BuiltinExecutor::new();
     let call = tool_call(tool, input);
-    executor.execute(
-        &call,
-        "test_id",
-        &crate::tools::executor::ExecutionContext::default(),
-    )
+    executor
+        .execute(
+            &call,
+            "test_id",
+            &crate::tools::executor::ExecutionContext::default(),
+        )
+        .await
 }
 
 #[cfg(test)]
@@ -17,8 +17,12 @@ use yare::parameterized;
     glob_pattern = { "Glob", json!({}), "pattern" },
     grep_pattern = { "Grep", json!({}), "pattern" },
 )]
-fn missing_field_returns_error(tool: &str, input: serde_json::Value, field: &str) {
-    assert_tool_error_contains(&execute_tool(tool, input), &format!("Missing '{field}'"));
+#[test_macro(tokio::test)]
+async fn missing_field_returns_error(tool: &str, input: serde_json::Value, field: &str) {
+    assert_tool_error_contains(
+        &execute_tool(tool, input).await,
+        &format!("Missing '{field}'"),
+    );
 }
 
 /// Consolidated tests for tool name accessors.
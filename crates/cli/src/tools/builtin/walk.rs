@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! File listing shared by the search tools.
+//!
+//! Follows the rules the real tools get from ripgrep: hidden files are
+//! included, `.gitignore`/`.ignore` rules apply, and version control
+//! directories are skipped.
+
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use ignore::overrides::OverrideBuilder;
+use ignore::types::{Types, TypesBuilder};
+use ignore::WalkBuilder;
+
+use super::BuiltinContext;
+
+/// Version control directories never searched.
+pub(crate) const VCS_DIRECTORIES: [&str; 6] = [".git", ".svn", ".hg", ".bzr", ".jj", ".sl"];
+
+/// Split a `glob` parameter into individual globs.
+///
+/// Globs are separated by whitespace; commas also separate globs unless the
+/// glob uses a `{a,b}` alternation.
+pub(crate) fn split_globs(glob: &str) -> Vec<&str> {
+    glob.split_whitespace()
+        .flat_map(|part| {
+            if part.contains('{') && part.contains('}') {
+                vec![part]
+            } else {
+                part.split(',').filter(|p| !p.is_empty()).collect()
+            }
+        })
+        .collect()
+}
+
+/// List the files under `root` that match the globs and file type.
+///
+/// An explicit file `root` is returned as-is, like ripgrep does.
+pub(crate) fn list_files(
+    ctx: &BuiltinContext,
+    root: &Path,
+    globs: &[&str],
+    file_type: Option<&str>,
+) -> Result<Vec<PathBuf>, String> {
+    let mut overrides = OverrideBuilder::new(root);
+    for glob in globs {
+        overrides.add(glob).map_err(|e| e.to_string())?;
+    }
+    let overrides = overrides.build().map_err(|e| e.to_string())?;
+    let types = build_types(file_type)?;
+
+    if let Some(ref vfs) = ctx.vfs {
+        if vfs.is_file(root) {
+            return Ok(vec![root.to_path_buf()]);
+        }
+        return Ok(vfs
+            .files_under(root)
+            .into_iter()
+            .filter(|file| !in_vcs_directory(file))
+            .filter(|file| !overrides.matched(file, false).is_ignore())
+            .filter(|file| !types.matched(file, false).is_ignore())
+            .collect());
+    }
+
+    if root.is_file() {
+        return Ok(vec![root.to_path_buf()]);
+    }
+
+    let mut files = Vec::new();
+    let walker = WalkBuilder::new(root)
+        .hidden(false)
+        .overrides(overrides)
+        .types(types)
+        .filter_entry(|entry| !VCS_DIRECTORIES.iter().any(|dir| entry.file_name() == *dir))
+        .build();
+    for entry in walker.filter_map(Result::ok) {
+        if entry.file_type().is_some_and(|t| t.is_file()) {
+            files.push(entry.into_path());
+        }
+    }
+    Ok(files)
+}
+
+/// Order for [`sort_by_mtime`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum MtimeOrder {
+    /// Most recently modified first (Grep).
+    NewestFirst,
+    /// Least recently modified first, like `rg --sort=modified` (Glob).
+    OldestFirst,
+}
+
+/// Sort files by modification time, breaking ties by path.
+///
+/// Files in the in-memory filesystem have no modification time and are
+/// sorted by path alone.
+pub(crate) fn sort_by_mtime(ctx: &BuiltinContext, files: &mut [PathBuf], order: MtimeOrder) {
+    let mtime = |path: &Path| -> Option<SystemTime> {
+        if ctx.vfs.is_some() {
+            return None;
+        }
+        std::fs::metadata(path).and_then(|m| m.modified()).ok()
+    };
+    match order {
+        MtimeOrder::NewestFirst => {
+            files.sort_by_cached_key(|path| (std::cmp::Reverse(mtime(path)), path.clone()))
+        }
+        MtimeOrder::OldestFirst => files.sort_by_cached_key(|path| (mtime(path), path.clone())),
+    }
+}
+
+fn build_types(file_type: Option<&str>) -> Result<Types, String> {
+    let mut builder = TypesBuilder::new();
+    builder.add_defaults();
+    if let Some(name) = file_type {
+        builder.select(name);
+    }
+    builder.build().map_err(|e| e.to_string())
+}
+
+fn in_vcs_directory(path: &Path) -> bool {
+    path.components()
+        .any(|c| VCS_DIRECTORIES.iter().any(|dir| c.as_os_str() == *dir))
+}
+
+#[cfg(test)]
+#[path = "walk_tests.rs"]
+mod tests;
@@ -0,0 +1,110 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use std::fs;
+use std::sync::Arc;
+use tempfile::TempDir;
+use yare::parameterized;
+
+use crate::tools::vfs::Vfs;
+
+#[parameterized(
+    single = { "*.rs", &["*.rs"] },
+    whitespace = { "*.rs *.toml", &["*.rs", "*.toml"] },
+    commas = { "*.rs,*.toml", &["*.rs", "*.toml"] },
+    braces = { "*.{rs,toml}", &["*.{rs,toml}"] },
+)]
+fn split_globs_separates_patterns(glob: &str, expected: &[&str]) {
+    assert_eq!(split_globs(glob), expected);
+}
+
+fn project() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir_all(dir.path().join(".git")).unwrap();
+    fs::create_dir_all(dir.path().join("src")).unwrap();
+    fs::create_dir_all(dir.path().join("target")).unwrap();
+    fs::write(dir.path().join(".gitignore"), "target/\n").unwrap();
+    fs::write(dir.path().join(".env"), "KEY=1").unwrap();
+    fs::write(dir.path().join(".git/config"), "[core]").unwrap();
+    fs::write(dir.path().join("src/main.rs"), "fn main() {}").unwrap();
+    fs::write(dir.path().join("src/app.py"), "print()").unwrap();
+    fs::write(dir.path().join("target/out.rs"), "// built").unwrap();
+    dir
+}
+
+fn names(root: &Path, files: Vec<PathBuf>) -> Vec<String> {
+    let mut names: Vec<String> = files
+        .iter()
+        .map(|f| f.strip_prefix(root).unwrap().display().to_string())
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn list_files_honors_gitignore_and_includes_hidden_files() {
+    let dir = project();
+    let files = list_files(&BuiltinContext::default(), dir.path(), &[], None).unwrap();
+    assert_eq!(
+        names(dir.path(), files),
+        vec![".env", ".gitignore", "src/app.py", "src/main.rs"]
+    );
+}
+
+#[test]
+fn list_files_filters_by_glob_and_type() {
+    let dir = project();
+    let ctx = BuiltinContext::default();
+
+    let by_glob = list_files(&ctx, dir.path(), &["*.py"], None).unwrap();
+    assert_eq!(names(dir.path(), by_glob), vec!["src/app.py"]);
+
+    let by_type = list_files(&ctx, dir.path(), &[], Some("rust")).unwrap();
+    assert_eq!(names(dir.path(), by_type), vec!["src/main.rs"]);
+
+    let unknown = list_files(&ctx, dir.path(), &[], Some("nope")).unwrap_err();
+    assert!(unknown.contains("nope"), "{}", unknown);
+}
+
+#[test]
+fn list_files_in_vfs_skips_vcs_directories() {
+    let vfs = Vfs::new("/work");
+    vfs.insert(".git/HEAD", "ref");
+    vfs.insert("src/main.rs", "fn main() {}");
+    vfs.insert("README.md", "# Readme");
+    let ctx = BuiltinContext {
+        vfs: Some(Arc::new(vfs)),
+        ..Default::default()
+    };
+
+    let files = list_files(&ctx, Path::new("/work"), &["*.rs"], None).unwrap();
+    assert_eq!(files, vec![PathBuf::from("/work/src/main.rs")]);
+
+    let all = list_files(&ctx, Path::new("/work"), &[], None).unwrap();
+    assert_eq!(all.len(), 2);
+}
+
+#[test]
+fn sort_by_mtime_orders_both_ways() {
+    let dir = TempDir::new().unwrap();
+    let old = dir.path().join("old.txt");
+    let new = dir.path().join("new.txt");
+    fs::write(&old, "").unwrap();
+    fs::write(&new, "").unwrap();
+    let past = SystemTime::now() - std::time::Duration::from_secs(3600);
+    fs::File::options()
+        .write(true)
+        .open(&old)
+        .unwrap()
+        .set_modified(past)
+        .unwrap();
+
+    let ctx = BuiltinContext::default();
+    let mut files = vec![old.clone(), new.clone()];
+    sort_by_mtime(&ctx, &mut files, MtimeOrder::NewestFirst);
+    assert_eq!(files, vec![new.clone(), old.clone()]);
+
+    sort_by_mtime(&ctx, &mut files, MtimeOrder::OldestFirst);
+    assert_eq!(files, vec![old, new]);
+}
@@ -0,0 +1,151 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Web fetch executor.
+//!
+//! Fetches a page from the scenario's web fixtures (see
+//! [`crate::tools::web`]). Redirects within the same host are followed;
+//! a redirect to another host is reported with the real tool's notice so
+//! the model can fetch the new URL itself. There is no model to apply the
+//! `prompt`, so the page body is returned as the result.
+
+use serde_json::json;
+use url::Url;
+
+use crate::config::ToolCallSpec;
+use crate::time::Clock;
+use crate::tools::result::ToolExecutionResult;
+use crate::tools::web::Web;
+
+use super::{extract_str, require_field, BuiltinContext, BuiltinToolExecutor};
+use crate::tools::tool_name::ToolName;
+
+/// Redirects followed before giving up.
+const MAX_REDIRECTS: usize = 10;
+
+/// Executor for fetching web pages.
+#[derive(Clone, Debug, Default)]
+pub struct WebFetchExecutor;
+
+impl WebFetchExecutor {
+    /// Whether a redirect stays on the same site (`www.` is ignored).
+    fn same_host(from: &Url, to: &Url) -> bool {
+        let host = |url: &Url| {
+            let host = url.host_str().unwrap_or_default();
+            host.strip_prefix("www.").unwrap_or(host).to_string()
+        };
+        from.scheme() == to.scheme() && from.port() == to.port() && host(from) == host(to)
+    }
+
+    /// Notice returned when a page redirects to another host.
+    fn redirect_notice(
+        original: &Url,
+        target: &str,
+        status: u16,
+        status_text: &str,
+        prompt: &str,
+    ) -> String {
+        format!(
+            "REDIRECT DETECTED: The URL redirects to a different host.\n\n\
+             Original URL: {original}\n\
+             Redirect URL: {target}\n\
+             Status: {status} {status_text}\n\n\
+             To complete your request, I need to fetch content from the redirected URL. \
+             Please use WebFetch again with these parameters:\n\
+             - url: \"{target}\"\n\
+             - prompt: \"{prompt}\""
+        )
+    }
+}
+
+impl BuiltinToolExecutor for WebFetchExecutor {
+    fn execute(
+        &self,
+        call: &ToolCallSpec,
+        tool_use_id: &str,
+        ctx: &BuiltinContext,
+    ) -> ToolExecutionResult {
+        let start = ctx.clock.now_millis();
+        let url = require_field!(call.input, "url", extract_str, tool_use_id, call.tool);
+        let prompt = require_field!(call.input, "prompt", extract_str, tool_use_id, call.tool);
+
+        let Ok(original) = Url::parse(url) else {
+            return ToolExecutionResult::error(tool_use_id, "Invalid URL");
+        };
+        let no_fixtures = Web::default();
+        let web = ctx.web.as_deref().unwrap_or(&no_fixtures);
+
+        let mut current = original.clone();
+        let mut redirects = 0;
+        let response = loop {
+            let response = match web.fetch(&current) {
+                Ok(response) => response,
+                Err(e) => return ToolExecutionResult::error(tool_use_id, e),
+            };
+            let Some(target) = response
+                .location
+                .as_deref()
+                .filter(|_| response.is_redirect())
+            else {
+                break response;
+            };
+            let Ok(target_url) = current.join(target) else {
+                return ToolExecutionResult::error(tool_use_id, "Invalid URL");
+            };
+            if !Self::same_host(&current, &target_url) {
+                let notice = Self::redirect_notice(
+                    &original,
+                    target_url.as_str(),
+                    response.status,
+                    response.status_text(),
+                    prompt,
+                );
+                return ToolExecutionResult::success_with_result(
+                    tool_use_id,
+                    notice.clone(),
+                    json!({
+                        "bytes": notice.len(),
+                        "code": response.status,
+                        "codeText": response.status_text(),
+                        "result": notice,
+                        "durationMs": ctx.clock.now_millis().saturating_sub(start),
+                        "url": url,
+                    }),
+                );
+            }
+            redirects += 1;
+            if redirects > MAX_REDIRECTS {
+                return ToolExecutionResult::error(tool_use_id, "Too many redirects");
+            }
+            current = target_url;
+        };
+
+        if response.status >= 400 {
+            return ToolExecutionResult::error(
+                tool_use_id,
+                format!("Request failed with status code {}", response.status),
+            );
+        }
+
+        ToolExecutionResult::success_with_result(
+            tool_use_id,
+            response.body.clone(),
+            json!({
+                "bytes": response.body.len(),
+                "code": response.status,
+                "codeText": response.status_text(),
+                "result": response.body,
+                "durationMs": ctx.clock.now_millis().saturating_sub(start),
+                "url": url,
+            }),
+        )
+    }
+
+    fn tool_name(&self) -> ToolName {
+        ToolName::WebFetch
+    }
+}
+
+#[cfg(test)]
+#[path = "web_fetch_tests.rs"]
+mod tests;
@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::super::test_helpers::{assert_tool_error_contains, execute, tool_call};
+use super::*;
+use crate::time::ClockHandle;
+use std::sync::Arc;
+use yare::parameterized;
+
+const PAGES: &str = r#"
+[pages."https://example.com/docs"]
+body = "Docs"
+
+[pages."https://example.com/moved"]
+redirect = "https://www.example.com/docs"
+
+[pages."https://www.example.com/docs"]
+body = "Docs (www)"
+
+[pages."https://example.com/away"]
+redirect = "https://other.org/page"
+
+[pages."https://example.com/gone"]
+status = 404
+body = "Not here"
+"#;
+
+fn fetch(input: serde_json::Value) -> ToolExecutionResult {
+    let ctx = BuiltinContext {
+        web: Some(Arc::new(Web::new(&toml::from_str(PAGES).unwrap()))),
+        clock: ClockHandle::fake_at_epoch(),
+        ..Default::default()
+    };
+    WebFetchExecutor.execute(&tool_call("WebFetch", input), "test_id", &ctx)
+}
+
+#[test]
+fn test_web_fetch_returns_page() {
+    let result = fetch(json!({ "url": "https://example.com/docs", "prompt": "Summarize" }));
+    assert!(!result.is_error);
+    assert_eq!(result.text(), Some("Docs"));
+    let meta = result.tool_use_result().unwrap();
+    assert_eq!(meta["code"], 200);
+    assert_eq!(meta["codeText"], "OK");
+    assert_eq!(meta["bytes"], 4);
+    assert_eq!(meta["url"], "https://example.com/docs");
+    assert_eq!(meta["durationMs"], 0);
+}
+
+#[test]
+fn test_web_fetch_follows_same_host_redirect() {
+    let result = fetch(json!({ "url": "https://example.com/moved", "prompt": "p" }));
+    assert_eq!(result.text(), Some("Docs (www)"));
+}
+
+#[test]
+fn test_web_fetch_reports_cross_host_redirect() {
+    let result = fetch(json!({ "url": "https://example.com/away", "prompt": "Find the API" }));
+    assert!(!result.is_error);
+    assert_eq!(
+        result.text(),
+        Some(
+            "REDIRECT DETECTED: The URL redirects to a different host.\n\n\
+             Original URL: https://example.com/away\n\
+             Redirect URL: https://other.org/page\n\
+             Status: 301 Moved Permanently\n\n\
+             To complete your request, I need to fetch content from the redirected URL. \
+             Please use WebFetch again with these parameters:\n\
+             - url: \"https://other.org/page\"\n\
+             - prompt: \"Find the API\""
+        )
+    );
+}
+
+#[parameterized(
+    error_status = { json!({ "url": "https://example.com/gone", "prompt": "p" }), "Request failed with status code 404" },
+    no_fixture = { json!({ "url": "https://example.com/none", "prompt": "p" }), "no web fixture for this URL" },
+    invalid_url = { json!({ "url": "not a url", "prompt": "p" }), "Invalid URL" },
+    missing_prompt = { json!({ "url": "https://example.com/docs" }), "Missing 'prompt' field" },
+)]
+fn web_fetch_errors(input: serde_json::Value, expected: &str) {
+    assert_tool_error_contains(&fetch(input), expected);
+}
+
+#[test]
+fn test_web_fetch_without_fixtures() {
+    assert_tool_error_contains(
+        &execute::<WebFetchExecutor>(json!({ "url": "https://example.com/", "prompt": "p" })),
+        "no web fixture for this URL",
+    );
+}
+
+#[test]
+fn test_tool_name() {
+    assert_eq!(WebFetchExecutor.tool_name(), ToolName::WebFetch);
+}
@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Web search executor.
+//!
+//! Results come from the scenario's `web.search` fixtures, keyed by the
+//! exact query. A query without a fixture returns no links.
+
+use serde_json::{json, Value};
+use url::Url;
+
+use crate::config::{ToolCallSpec, WebSearchResult};
+use crate::time::Clock;
+use crate::tools::result::ToolExecutionResult;
+
+use super::{extract_array, extract_str, require_field, BuiltinContext, BuiltinToolExecutor};
+use crate::tools::tool_name::ToolName;
+
+/// Executor for searching the web.
+#[derive(Clone, Debug, Default)]
+pub struct WebSearchExecutor;
+
+impl WebSearchExecutor {
+    /// Domains listed under `key`, lowercased.
+    fn domains(input: &Value, key: &str) -> Vec<String> {
+        extract_array(input, key)
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(str::to_ascii_lowercase)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Whether the result's host is `domain` or one of its subdomains.
+    fn on_domain(result: &WebSearchResult, domain: &str) -> bool {
+        let Some(host) = Url::parse(&result.url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_ascii_lowercase))
+        else {
+            return false;
+        };
+        host == domain || host.ends_with(&format!(".{}", domain))
+    }
+}
+
+impl BuiltinToolExecutor for WebSearchExecutor {
+    fn execute(
+        &self,
+        call: &ToolCallSpec,
+        tool_use_id: &str,
+        ctx: &BuiltinContext,
+    ) -> ToolExecutionResult {
+        let start = ctx.clock.now_millis();
+        let query = require_field!(call.input, "query", extract_str, tool_use_id, call.tool);
+
+        let allowed = Self::domains(&call.input, "allowed_domains");
+        let blocked = Self::domains(&call.input, "blocked_domains");
+        if !allowed.is_empty() && !blocked.is_empty() {
+            return ToolExecutionResult::error(
+                tool_use_id,
+                "Cannot specify both allowed_domains and blocked_domains in the same request",
+            );
+        }
+
+        let spec = ctx.web.as_ref().and_then(|web| web.search(query));
+        let links: Vec<&WebSearchResult> = spec
+            .map(|spec| spec.results.iter().collect::<Vec<_>>())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|r| allowed.is_empty() || allowed.iter().any(|d| Self::on_domain(r, d)))
+            .filter(|r| !blocked.iter().any(|d| Self::on_domain(r, d)))
+            .collect();
+        let summary = spec.and_then(|spec| spec.summary.clone());
+
+        let links_json = serde_json::to_string(&links).unwrap_or_else(|_| "[]".to_string());
+        let mut text = format!(
+            "Web search results for query: \"{}\"\n\nLinks: {}",
+            query, links_json
+        );
+        if let Some(ref summary) = summary {
+            text.push_str("\n\n");
+            text.push_str(summary);
+        }
+
+        let search_id = format!("srvtoolu_{}", tool_use_id.trim_start_matches("toolu_"));
+        let mut results = vec![json!({
+            "tool_use_id": search_id,
+            "content": links,
+        })];
+        if let Some(summary) = summary {
+            results.push(Value::String(summary));
+        }
+
+        ToolExecutionResult::success_with_result(
+            tool_use_id,
+            text,
+            json!({
+                "query": query,
+                "results": results,
+                "durationSeconds": ctx.clock.now_millis().saturating_sub(start) as f64 / 1000.0,
+            }),
+        )
+    }
+
+    fn tool_name(&self) -> ToolName {
+        ToolName::WebSearch
+    }
+}
+
+#[cfg(test)]
+#[path = "web_search_tests.rs"]
+mod tests;
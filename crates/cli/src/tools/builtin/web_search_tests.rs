@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::super::test_helpers::{assert_tool_error_contains, tool_call};
+use super::*;
+use crate::time::ClockHandle;
+use crate::tools::web::Web;
+use std::sync::Arc;
+
+const SEARCH: &str = r#"
+[search."rust async"]
+summary = "Tokio is the usual runtime."
+results = [
+    { title = "Tokio", url = "https://tokio.rs/" },
+    { title = "Async book", url = "https://rust-lang.github.io/async-book/" },
+]
+"#;
+
+fn search(input: Value) -> ToolExecutionResult {
+    let ctx = BuiltinContext {
+        web: Some(Arc::new(Web::new(&toml::from_str(SEARCH).unwrap()))),
+        clock: ClockHandle::fake_at_epoch(),
+        ..Default::default()
+    };
+    WebSearchExecutor.execute(&tool_call("WebSearch", input), "toolu_01", &ctx)
+}
+
+#[test]
+fn test_web_search_returns_links_and_summary() {
+    let result = search(json!({ "query": "rust async" }));
+    assert!(!result.is_error);
+    assert_eq!(
+        result.text(),
+        Some(
+            "Web search results for query: \"rust async\"\n\n\
+             Links: [{\"title\":\"Tokio\",\"url\":\"https://tokio.rs/\"},\
+             {\"title\":\"Async book\",\"url\":\"https://rust-lang.github.io/async-book/\"}]\n\n\
+             Tokio is the usual runtime."
+        )
+    );
+    let meta = result.tool_use_result().unwrap();
+    assert_eq!(meta["query"], "rust async");
+    assert_eq!(meta["results"][0]["tool_use_id"], "srvtoolu_01");
+    assert_eq!(meta["results"][0]["content"][1]["title"], "Async book");
+    assert_eq!(meta["results"][1], "Tokio is the usual runtime.");
+    assert_eq!(meta["durationSeconds"], 0.0);
+}
+
+#[test]
+fn test_web_search_domain_filters() {
+    let allowed = search(json!({ "query": "rust async", "allowed_domains": ["tokio.rs"] }));
+    let content = &allowed.tool_use_result().unwrap()["results"][0]["content"];
+    assert_eq!(content.as_array().unwrap().len(), 1);
+    assert_eq!(content[0]["title"], "Tokio");
+
+    let blocked = search(json!({ "query": "rust async", "blocked_domains": ["github.io"] }));
+    let content = &blocked.tool_use_result().unwrap()["results"][0]["content"];
+    assert_eq!(content.as_array().unwrap().len(), 1);
+    assert_eq!(content[0]["title"], "Tokio");
+}
+
+#[test]
+fn test_web_search_unknown_query_has_no_links() {
+    let result = search(json!({ "query": "nothing" }));
+    assert_eq!(
+        result.text(),
+        Some("Web search results for query: \"nothing\"\n\nLinks: []")
+    );
+}
+
+#[test]
+fn test_web_search_rejects_both_domain_lists() {
+    assert_tool_error_contains(
+        &search(json!({
+            "query": "rust async",
+            "allowed_domains": ["a.com"],
+            "blocked_domains": ["b.com"],
+        })),
+        "Cannot specify both allowed_domains and blocked_domains",
+    );
+}
+
+#[test]
+fn test_tool_name() {
+    assert_eq!(WebSearchExecutor.tool_name(), ToolName::WebSearch);
+}
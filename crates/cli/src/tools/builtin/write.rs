@@ -3,12 +3,13 @@
 
 //! File write executor.
 
-use std::fs;
+use serde_json::json;
 
 use crate::config::ToolCallSpec;
 use crate::state::io::ensure_parent_exists;
 use crate::tools::result::ToolExecutionResult;
 
+use super::patch::structured_patch;
 use super::{extract_file_path, extract_str, require_field, BuiltinContext, BuiltinToolExecutor};
 use crate::tools::tool_name::ToolName;
 
@@ -21,7 +22,7 @@ impl BuiltinToolExecutor for WriteExecutor {
         &self,
         call: &ToolCallSpec,
         tool_use_id: &str,
-        _ctx: &BuiltinContext,
+        ctx: &BuiltinContext,
     ) -> ToolExecutionResult {
         let path = require_field!(
             call.input,
@@ -31,28 +32,66 @@ impl BuiltinToolExecutor for WriteExecutor {
         );
         let content = require_field!(call.input, "content", extract_str, tool_use_id, call.tool);
 
-        let resolved_path = std::path::PathBuf::from(path);
+        let resolved_path = match ctx.resolve_path(path) {
+            Ok(p) => p,
+            Err(e) => return ToolExecutionResult::error(tool_use_id, e),
+        };
+        let shown_path = ctx.display_path(&resolved_path);
 
-        // Create parent directories if needed
-        if let Err(e) = ensure_parent_exists(&resolved_path) {
-            return ToolExecutionResult::error(
-                tool_use_id,
-                format!("Failed to create parent directories: {}", e),
-            );
+        // Overwriting requires a fresh read; new files need none
+        let original = ctx.read_bytes(&resolved_path).ok();
+        if let Some(ref existing) = original {
+            if let Err(e) = ctx.check_file_fresh(&resolved_path, existing) {
+                return ToolExecutionResult::error(tool_use_id, e);
+            }
         }
 
-        match fs::write(&resolved_path, content) {
-            Ok(()) => ToolExecutionResult::success(
-                tool_use_id,
-                format!(
-                    "Successfully wrote {} bytes to {}",
-                    content.len(),
-                    resolved_path.display()
-                ),
-            ),
+        // Create parent directories if needed (implicit in the in-memory filesystem)
+        if ctx.vfs.is_none() {
+            if let Err(e) = ensure_parent_exists(&resolved_path) {
+                return ToolExecutionResult::error(
+                    tool_use_id,
+                    format!("Failed to create parent directories: {}", e),
+                );
+            }
+        }
+
+        match ctx.write_file(&resolved_path, content) {
+            Ok(()) => {
+                ctx.record_file(&resolved_path, content.as_bytes());
+                let file_path = shown_path.display().to_string();
+                let result = match original {
+                    Some(bytes) => {
+                        let original = String::from_utf8_lossy(&bytes);
+                        json!({
+                            "type": "update",
+                            "filePath": file_path,
+                            "content": content,
+                            "structuredPatch": structured_patch(&original, content),
+                            "originalFile": original,
+                        })
+                    }
+                    None => json!({
+                        "type": "create",
+                        "filePath": file_path,
+                        "content": content,
+                        "structuredPatch": [],
+                        "originalFile": null,
+                    }),
+                };
+                ToolExecutionResult::success_with_result(
+                    tool_use_id,
+                    format!(
+                        "Successfully wrote {} bytes to {}",
+                        content.len(),
+                        shown_path.display()
+                    ),
+                    result,
+                )
+            }
             Err(e) => ToolExecutionResult::error(
                 tool_use_id,
-                format!("Failed to write file '{}': {}", resolved_path.display(), e),
+                format!("Failed to write file '{}': {}", shown_path.display(), e),
             ),
         }
     }
@@ -5,6 +5,7 @@ use super::super::test_helpers::{assert_tool_success_contains, execute, TestFile
 use super::*;
 use crate::tools::builtin::{extract_file_path, extract_str};
 use serde_json::json;
+use std::fs;
 use yare::parameterized;
 
 #[parameterized(
@@ -48,3 +49,31 @@ fn test_write_creates_parent_dirs() {
     );
     assert_eq!(fs::read_to_string(&file.path).unwrap(), "nested content");
 }
+
+#[test]
+fn test_write_records_create_and_update() {
+    let file = TestFile::new("test.txt");
+    let created = execute::<WriteExecutor>(json!({
+        "file_path": file.path_str(),
+        "content": "a\n"
+    }));
+    assert_eq!(
+        created.tool_use_result().unwrap(),
+        json!({
+            "type": "create",
+            "filePath": file.path_str(),
+            "content": "a\n",
+            "structuredPatch": [],
+            "originalFile": null,
+        })
+    );
+
+    let updated = execute::<WriteExecutor>(json!({
+        "file_path": file.path_str(),
+        "content": "b\n"
+    }));
+    let meta = updated.tool_use_result().unwrap();
+    assert_eq!(meta["type"], "update");
+    assert_eq!(meta["originalFile"], "a\n");
+    assert_eq!(meta["structuredPatch"][0]["lines"], json!(["-a", "+b"]));
+}
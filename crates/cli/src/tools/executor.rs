@@ -6,18 +6,28 @@
 
 //! Tool execution engine trait and implementations.
 
+use std::collections::HashMap;
+use std::future::Future;
 use std::path::PathBuf;
+use std::pin::Pin;
 use std::sync::Arc;
 
 use parking_lot::RwLock;
 
-use crate::config::{ToolCallSpec, ToolExecutionMode};
+use crate::config::{ToolCallSpec, ToolConfig, ToolExecutionMode};
 use crate::mcp::McpManager;
-use crate::permission::{PermissionChecker, PermissionResult};
-use crate::state::StateWriter;
+use crate::permission::{PermissionChecker, PermissionResult, ToolPattern};
+use crate::state::{StateWriter, TaskStore};
+use crate::time::ClockHandle;
 
+use super::background::BackgroundShells;
+use super::external::ExternalToolExecutor;
+use super::limits::LimitedExecutor;
 use super::mcp_executor::{CompositeExecutor, McpToolExecutor};
 use super::result::ToolExecutionResult;
+use super::sandbox::Sandbox;
+use super::vfs::Vfs;
+use super::web::Web;
 
 /// Context for tool execution.
 #[derive(Clone, Debug, Default)]
@@ -27,6 +37,30 @@ pub struct ExecutionContext {
 
     /// Session ID for tracking.
     pub session_id: Option<String>,
+
+    /// Sandbox that built-in tools resolve paths in.
+    pub sandbox: Option<Arc<Sandbox>>,
+
+    /// In-memory filesystem for file tools (`vfs` mode).
+    pub vfs: Option<Arc<Vfs>>,
+
+    /// Environment variables for commands (`settings.env`).
+    pub env: HashMap<String, String>,
+
+    /// Background shells started this session.
+    pub background_shells: Option<Arc<BackgroundShells>>,
+
+    /// Web fixtures for WebFetch and WebSearch.
+    pub web: Option<Arc<Web>>,
+
+    /// The session's task list.
+    pub task_store: Option<Arc<TaskStore>>,
+
+    /// Tools the running slash command allows without asking.
+    pub allowed_tools: Vec<ToolPattern>,
+
+    /// Clock for timestamps in tool results.
+    pub clock: ClockHandle,
 }
 
 impl ExecutionContext {
@@ -41,17 +75,71 @@ impl ExecutionContext {
         self.session_id = Some(session_id.into());
         self
     }
+
+    /// Set the sandbox.
+    pub fn with_sandbox(mut self, sandbox: Arc<Sandbox>) -> Self {
+        self.sandbox = Some(sandbox);
+        self
+    }
+
+    /// Set the in-memory filesystem.
+    pub fn with_vfs(mut self, vfs: Arc<Vfs>) -> Self {
+        self.vfs = Some(vfs);
+        self
+    }
+
+    /// Set the environment variables for commands.
+    pub fn with_env(mut self, env: HashMap<String, String>) -> Self {
+        self.env = env;
+        self
+    }
+
+    /// Set the background shell registry.
+    pub fn with_background_shells(mut self, shells: Arc<BackgroundShells>) -> Self {
+        self.background_shells = Some(shells);
+        self
+    }
+
+    /// Set the web fixtures.
+    pub fn with_web(mut self, web: Arc<Web>) -> Self {
+        self.web = Some(web);
+        self
+    }
+
+    /// Set the task list.
+    pub fn with_task_store(mut self, task_store: Arc<TaskStore>) -> Self {
+        self.task_store = Some(task_store);
+        self
+    }
+
+    /// Set the clock.
+    pub fn with_clock(mut self, clock: ClockHandle) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Set the tools allowed without asking.
+    pub fn with_allowed_tools(mut self, allowed_tools: Vec<ToolPattern>) -> Self {
+        self.allowed_tools = allowed_tools;
+        self
+    }
 }
 
+/// A running tool call.
+pub type ToolFuture<'a> = Pin<Box<dyn Future<Output = ToolExecutionResult> + Send + 'a>>;
+
 /// Trait for tool execution engines.
+///
+/// Execution is async so the runtime can run independent read-only calls
+/// at the same time.
 pub trait ToolExecutor: Send + Sync {
     /// Execute a tool call and return the result.
-    fn execute(
-        &self,
-        call: &ToolCallSpec,
-        tool_use_id: &str,
-        ctx: &ExecutionContext,
-    ) -> ToolExecutionResult;
+    fn execute<'a>(
+        &'a self,
+        call: &'a ToolCallSpec,
+        tool_use_id: &'a str,
+        ctx: &'a ExecutionContext,
+    ) -> ToolFuture<'a>;
 
     /// Get the name of this executor for debugging.
     fn name(&self) -> &'static str;
@@ -69,16 +157,18 @@ impl MockExecutor {
 }
 
 impl ToolExecutor for MockExecutor {
-    fn execute(
-        &self,
-        call: &ToolCallSpec,
-        tool_use_id: &str,
-        _ctx: &ExecutionContext,
-    ) -> ToolExecutionResult {
-        match &call.result {
-            Some(result) => ToolExecutionResult::success(tool_use_id, result),
-            None => ToolExecutionResult::no_mock_result(tool_use_id, &call.tool),
-        }
+    fn execute<'a>(
+        &'a self,
+        call: &'a ToolCallSpec,
+        tool_use_id: &'a str,
+        _ctx: &'a ExecutionContext,
+    ) -> ToolFuture<'a> {
+        Box::pin(async move {
+            match &call.result {
+                Some(result) => ToolExecutionResult::success(tool_use_id, result),
+                None => ToolExecutionResult::no_mock_result(tool_use_id, &call.tool),
+            }
+        })
     }
 
     fn name(&self) -> &'static str {
@@ -106,22 +196,48 @@ impl PermissionCheckingExecutor {
             .map(|t| t.action())
             .unwrap_or("execute") // Default for MCP tools
     }
+
+    /// Input matched by argument patterns, for tools that support them.
+    ///
+    /// Bash rules name a command, as in `Bash(git status:*)`, and WebFetch
+    /// rules a host, as in `WebFetch(domain:example.com)`.
+    fn permission_input(call: &ToolCallSpec) -> Option<String> {
+        match super::tool_name::ToolName::parse(&call.tool)? {
+            super::tool_name::ToolName::Bash => {
+                call.input.get("command")?.as_str().map(str::to_string)
+            }
+            super::tool_name::ToolName::WebFetch => {
+                let url = call.input.get("url")?.as_str()?;
+                let host = url::Url::parse(url).ok()?.host_str()?.to_string();
+                Some(format!("domain:{}", host))
+            }
+            _ => None,
+        }
+    }
 }
 
 impl ToolExecutor for PermissionCheckingExecutor {
-    fn execute(
-        &self,
-        call: &ToolCallSpec,
-        tool_use_id: &str,
-        ctx: &ExecutionContext,
-    ) -> ToolExecutionResult {
+    fn execute<'a>(
+        &'a self,
+        call: &'a ToolCallSpec,
+        tool_use_id: &'a str,
+        ctx: &'a ExecutionContext,
+    ) -> ToolFuture<'a> {
         let action = self.get_action(&call.tool);
-        match self.checker.check(&call.tool, action) {
+        let input = Self::permission_input(call);
+        match self.checker.check_with_allowed(
+            &call.tool,
+            action,
+            input.as_deref(),
+            &ctx.allowed_tools,
+        ) {
             PermissionResult::Allowed => self.inner.execute(call, tool_use_id, ctx),
             PermissionResult::Denied { reason } => {
-                ToolExecutionResult::permission_denied(tool_use_id, reason)
+                Box::pin(async move { ToolExecutionResult::permission_denied(tool_use_id, reason) })
+            }
+            PermissionResult::NeedsPrompt { .. } => {
+                Box::pin(async move { ToolExecutionResult::needs_prompt(tool_use_id) })
             }
-            PermissionResult::NeedsPrompt { .. } => ToolExecutionResult::needs_prompt(tool_use_id),
         }
     }
 
@@ -134,7 +250,9 @@ impl ToolExecutor for PermissionCheckingExecutor {
 pub fn create_executor(mode: ToolExecutionMode) -> Box<dyn ToolExecutor> {
     match mode {
         ToolExecutionMode::Mock => Box::new(MockExecutor::new()),
-        ToolExecutionMode::Live => Box::new(super::builtin::BuiltinExecutor::new()),
+        ToolExecutionMode::Live | ToolExecutionMode::Vfs => {
+            Box::new(super::builtin::BuiltinExecutor::new())
+        }
     }
 }
 
@@ -154,14 +272,22 @@ pub fn create_executor_with_permissions(
 ///
 /// If `state_writer` is provided, stateful tools (TodoWrite, ExitPlanMode)
 /// will persist their results to the session state.
+///
+/// Tools in `tools` with a `command` run through that command in every mode.
+/// Results are capped by the size limits in `tools::limits`, with oversized
+/// ones saved in the session's `tool-results` directory.
 pub fn create_executor_with_mcp(
     mode: ToolExecutionMode,
     mcp_manager: Option<Arc<RwLock<McpManager>>>,
     state_writer: Option<Arc<RwLock<StateWriter>>>,
+    tools: &HashMap<String, ToolConfig>,
 ) -> Box<dyn ToolExecutor> {
-    match mode {
+    let results_dir = state_writer
+        .as_ref()
+        .map(|writer| writer.read().tool_results_dir());
+    let inner: Box<dyn ToolExecutor> = match mode {
         ToolExecutionMode::Mock => Box::new(MockExecutor::new()),
-        ToolExecutionMode::Live => {
+        ToolExecutionMode::Live | ToolExecutionMode::Vfs => {
             let mut builtin = super::builtin::BuiltinExecutor::new();
             if let Some(writer) = state_writer {
                 builtin = builtin.with_state_writer(writer);
@@ -169,7 +295,13 @@ pub fn create_executor_with_mcp(
             let mcp = mcp_manager.map(McpToolExecutor::new);
             Box::new(CompositeExecutor::new(mcp, builtin))
         }
-    }
+    };
+    let inner: Box<dyn ToolExecutor> = if tools.values().any(|tool| tool.command.is_some()) {
+        Box::new(ExternalToolExecutor::new(inner, tools))
+    } else {
+        inner
+    };
+    Box::new(LimitedExecutor::new(inner, tools, results_dir))
 }
 
 /// Create an executor with MCP and permission checking.
@@ -177,9 +309,10 @@ pub fn create_executor_with_mcp_and_permissions(
     mode: ToolExecutionMode,
     mcp_manager: Option<Arc<RwLock<McpManager>>>,
     state_writer: Option<Arc<RwLock<StateWriter>>>,
+    tools: &HashMap<String, ToolConfig>,
     checker: PermissionChecker,
 ) -> Box<dyn ToolExecutor> {
-    let inner = create_executor_with_mcp(mode, mcp_manager, state_writer);
+    let inner = create_executor_with_mcp(mode, mcp_manager, state_writer, tools);
     Box::new(PermissionCheckingExecutor::new(inner, checker))
 }
 
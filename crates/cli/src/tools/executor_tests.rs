@@ -4,8 +4,8 @@
 use super::*;
 use serde_json::json;
 
-#[test]
-fn test_mock_executor_with_result() {
+#[tokio::test]
+async fn test_mock_executor_with_result() {
     let executor = MockExecutor::new();
     let call = ToolCallSpec {
         tool: "Bash".to_string(),
@@ -13,15 +13,15 @@ fn test_mock_executor_with_result() {
         result: Some("file1.txt\nfile2.txt".to_string()),
     };
     let ctx = ExecutionContext::default();
-    let result = executor.execute(&call, "toolu_123", &ctx);
+    let result = executor.execute(&call, "toolu_123", &ctx).await;
 
     assert!(!result.is_error);
     assert_eq!(result.tool_use_id, "toolu_123");
     assert_eq!(result.text(), Some("file1.txt\nfile2.txt"));
 }
 
-#[test]
-fn test_mock_executor_without_result() {
+#[tokio::test]
+async fn test_mock_executor_without_result() {
     let executor = MockExecutor::new();
     let call = ToolCallSpec {
         tool: "Read".to_string(),
@@ -29,7 +29,7 @@ fn test_mock_executor_without_result() {
         result: None,
     };
     let ctx = ExecutionContext::default();
-    let result = executor.execute(&call, "toolu_456", &ctx);
+    let result = executor.execute(&call, "toolu_456", &ctx).await;
 
     assert!(result.is_error);
     assert!(result.text().unwrap().contains("No mock result"));
@@ -57,8 +57,8 @@ fn test_executor_name() {
     assert_eq!(MockExecutor::new().name(), "mock");
 }
 
-#[test]
-fn test_permission_checking_executor_allowed() {
+#[tokio::test]
+async fn test_permission_checking_executor_allowed() {
     use crate::permission::{PermissionBypass, PermissionMode};
 
     let inner = Box::new(MockExecutor::new());
@@ -74,14 +74,14 @@ fn test_permission_checking_executor_allowed() {
         result: Some("output".to_string()),
     };
     let ctx = ExecutionContext::default();
-    let result = executor.execute(&call, "toolu_123", &ctx);
+    let result = executor.execute(&call, "toolu_123", &ctx).await;
 
     assert!(!result.is_error);
     assert_eq!(result.text(), Some("output"));
 }
 
-#[test]
-fn test_permission_checking_executor_denied() {
+#[tokio::test]
+async fn test_permission_checking_executor_denied() {
     use crate::permission::{PermissionBypass, PermissionMode};
 
     let inner = Box::new(MockExecutor::new());
@@ -94,14 +94,14 @@ fn test_permission_checking_executor_denied() {
         result: Some("never executed".to_string()),
     };
     let ctx = ExecutionContext::default();
-    let result = executor.execute(&call, "toolu_456", &ctx);
+    let result = executor.execute(&call, "toolu_456", &ctx).await;
 
     assert!(result.is_error);
     assert!(result.text().unwrap().contains("Permission denied"));
 }
 
-#[test]
-fn test_permission_checking_executor_needs_prompt() {
+#[tokio::test]
+async fn test_permission_checking_executor_needs_prompt() {
     use crate::permission::{PermissionBypass, PermissionMode};
 
     let inner = Box::new(MockExecutor::new());
@@ -114,7 +114,7 @@ fn test_permission_checking_executor_needs_prompt() {
         result: Some("never executed".to_string()),
     };
     let ctx = ExecutionContext::default();
-    let result = executor.execute(&call, "toolu_789", &ctx);
+    let result = executor.execute(&call, "toolu_789", &ctx).await;
 
     // NeedsPrompt returns a marker result so the caller can show a permission dialog
     assert!(!result.is_error);
@@ -132,3 +132,42 @@ fn test_create_executor_with_permissions() {
     let executor = create_executor_with_permissions(ToolExecutionMode::Mock, checker);
     assert_eq!(executor.name(), "permission_checking");
 }
+
+#[tokio::test]
+async fn test_permission_checking_executor_web_fetch_domain_rules() {
+    use crate::permission::{PermissionBypass, PermissionMode, PermissionPatterns};
+    use crate::state::PermissionSettings;
+
+    let settings = PermissionSettings {
+        allow: vec!["WebFetch(domain:docs.rs)".to_string()],
+        deny: vec!["WebFetch(domain:evil.example)".to_string()],
+        ..Default::default()
+    };
+    let checker = PermissionChecker::with_patterns(
+        PermissionMode::Default,
+        PermissionBypass::default(),
+        PermissionPatterns::from_settings(&settings),
+    );
+    let executor = PermissionCheckingExecutor::new(Box::new(MockExecutor::new()), checker);
+    let fetch = |url: &str| ToolCallSpec {
+        tool: "WebFetch".to_string(),
+        input: json!({ "url": url, "prompt": "Summarize" }),
+        result: Some("page".to_string()),
+    };
+    let ctx = ExecutionContext::default();
+
+    let allowed = executor
+        .execute(&fetch("https://docs.rs/serde"), "toolu_1", &ctx)
+        .await;
+    assert_eq!(allowed.text(), Some("page"));
+
+    let denied = executor
+        .execute(&fetch("https://evil.example/x"), "toolu_2", &ctx)
+        .await;
+    assert!(denied.is_error);
+
+    let other = executor
+        .execute(&fetch("https://example.com/"), "toolu_3", &ctx)
+        .await;
+    assert!(other.needs_prompt);
+}
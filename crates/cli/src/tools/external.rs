@@ -0,0 +1,250 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Tools run by an external command.
+//!
+//! A scenario can hand a tool to an executable with
+//! `[tool_execution.tools.<Tool>] command = "./fake-tool.sh"`. The command
+//! gets the call as JSON on stdin:
+//!
+//! ```json
+//! {"tool_name": "Bash", "tool_input": {...}, "tool_use_id": "toolu_...",
+//!  "session_id": "...", "cwd": "/project"}
+//! ```
+//!
+//! and prints the result as JSON on stdout, with `content` either text or
+//! a list of content blocks:
+//!
+//! ```json
+//! {"content": "output", "is_error": false, "tool_use_result": {...}}
+//! ```
+
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::config::{ToolCallSpec, ToolConfig};
+
+use super::background::kill_process_group;
+use super::executor::{ExecutionContext, ToolExecutor, ToolFuture};
+use super::result::{ToolExecutionResult, ToolResultContent};
+
+/// How long a tool command may run before it is killed.
+const COMMAND_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// How often a running command is polled for exit and timeout.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// What a tool command prints.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CommandResponse {
+    content: CommandContent,
+    #[serde(default)]
+    is_error: bool,
+    #[serde(default)]
+    tool_use_result: Option<serde_json::Value>,
+}
+
+/// Result content: plain text or content blocks.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum CommandContent {
+    Text(String),
+    Blocks(Vec<ToolResultContent>),
+}
+
+/// Executor that runs tools with a scenario `command` through that
+/// command, and everything else through the inner executor.
+pub struct ExternalToolExecutor {
+    /// Command for each overridden tool.
+    commands: HashMap<String, PathBuf>,
+    /// Executor for the other tools.
+    inner: Box<dyn ToolExecutor>,
+}
+
+impl ExternalToolExecutor {
+    /// Wrap `inner`, running the tools in `tools` that set a `command`.
+    pub fn new(inner: Box<dyn ToolExecutor>, tools: &HashMap<String, ToolConfig>) -> Self {
+        let commands = tools
+            .iter()
+            .filter_map(|(name, config)| {
+                let command = config.command.as_ref()?;
+                Some((name.clone(), PathBuf::from(command)))
+            })
+            .collect();
+        Self { commands, inner }
+    }
+
+    /// Run `command` for the call and turn its output into a result.
+    fn run(
+        command: &Path,
+        call: &ToolCallSpec,
+        tool_use_id: &str,
+        ctx: &ExecutionContext,
+    ) -> ToolExecutionResult {
+        let cwd = ctx
+            .cwd
+            .clone()
+            .or_else(|| std::env::current_dir().ok())
+            .unwrap_or_default();
+        let request = json!({
+            "tool_name": call.tool,
+            "tool_input": call.input,
+            "tool_use_id": tool_use_id,
+            "session_id": ctx.session_id,
+            "cwd": cwd,
+        });
+
+        let mut cmd = Command::new(command);
+        cmd.current_dir(&cwd).envs(&ctx.env);
+        let output = match spawn_with_input(cmd, request.to_string()) {
+            Ok(output) => output,
+            Err(e) => {
+                return ToolExecutionResult::error(
+                    tool_use_id,
+                    format!("Failed to run tool command {}: {}", command.display(), e),
+                )
+            }
+        };
+        if !output.success {
+            return ToolExecutionResult::error(
+                tool_use_id,
+                format!(
+                    "Tool command {} failed: {}",
+                    command.display(),
+                    output.stderr.trim()
+                ),
+            );
+        }
+
+        let response: CommandResponse = match serde_json::from_str(&output.stdout) {
+            Ok(response) => response,
+            Err(e) => {
+                return ToolExecutionResult::error(
+                    tool_use_id,
+                    format!(
+                        "Tool command {} printed an invalid result: {}",
+                        command.display(),
+                        e
+                    ),
+                )
+            }
+        };
+        let content = match response.content {
+            CommandContent::Text(text) => vec![ToolResultContent::Text { text }],
+            CommandContent::Blocks(blocks) => blocks,
+        };
+        ToolExecutionResult {
+            is_error: response.is_error,
+            content,
+            tool_use_result: response.tool_use_result,
+            ..ToolExecutionResult::success(tool_use_id, "")
+        }
+    }
+}
+
+impl ToolExecutor for ExternalToolExecutor {
+    fn execute<'a>(
+        &'a self,
+        call: &'a ToolCallSpec,
+        tool_use_id: &'a str,
+        ctx: &'a ExecutionContext,
+    ) -> ToolFuture<'a> {
+        // A call's own canned result still wins over the tool's command
+        let command = match self.commands.get(&call.tool) {
+            Some(command) if call.result.is_none() => command.clone(),
+            _ => return self.inner.execute(call, tool_use_id, ctx),
+        };
+        let owned_call = call.clone();
+        let id = tool_use_id.to_string();
+        let owned_ctx = ctx.clone();
+        Box::pin(async move {
+            tokio::task::spawn_blocking(move || Self::run(&command, &owned_call, &id, &owned_ctx))
+                .await
+                .unwrap_or_else(|e| {
+                    ToolExecutionResult::error(
+                        tool_use_id,
+                        format!("Failed to run tool command: {}", e),
+                    )
+                })
+        })
+    }
+
+    fn name(&self) -> &'static str {
+        "external"
+    }
+}
+
+/// Output of a finished tool command.
+struct CommandOutput {
+    stdout: String,
+    stderr: String,
+    success: bool,
+}
+
+/// Run `cmd` with `input` on stdin, killing it after [`COMMAND_TIMEOUT`].
+fn spawn_with_input(mut cmd: Command, input: String) -> std::io::Result<CommandOutput> {
+    cmd.stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        cmd.process_group(0);
+    }
+
+    let mut child = cmd.spawn()?;
+    let stdin = child.stdin.take();
+    // Commands that ignore stdin may exit before reading it
+    let writer = thread::spawn(move || {
+        if let Some(mut stdin) = stdin {
+            let _ = stdin.write_all(input.as_bytes());
+        }
+    });
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+    let deadline = Instant::now() + COMMAND_TIMEOUT;
+
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            kill_process_group(&mut child);
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                format!("timed out after {}s", COMMAND_TIMEOUT.as_secs()),
+            ));
+        }
+        thread::sleep(POLL_INTERVAL);
+    };
+    let _ = writer.join();
+
+    Ok(CommandOutput {
+        stdout: String::from_utf8_lossy(&stdout.join().unwrap_or_default()).to_string(),
+        stderr: String::from_utf8_lossy(&stderr.join().unwrap_or_default()).to_string(),
+        success: status.success(),
+    })
+}
+
+/// Read a pipe to the end on a background thread.
+fn drain(pipe: Option<impl Read + Send + 'static>) -> JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buf);
+        }
+        buf
+    })
+}
+
+#[cfg(test)]
+#[path = "external_tests.rs"]
+mod tests;
@@ -0,0 +1,120 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+use crate::tools::executor::MockExecutor;
+use std::fs;
+use tempfile::TempDir;
+
+/// Write an executable script and an executor that runs `Bash` through it.
+fn executor(dir: &TempDir, script: &str) -> ExternalToolExecutor {
+    let path = dir.path().join("tool.sh");
+    fs::write(&path, format!("#!/bin/sh\n{}", script)).unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+    }
+    let tools = HashMap::from([(
+        "Bash".to_string(),
+        ToolConfig {
+            command: Some(path.to_string_lossy().into_owned()),
+            ..Default::default()
+        },
+    )]);
+    ExternalToolExecutor::new(Box::new(MockExecutor::new()), &tools)
+}
+
+fn call(tool: &str, result: Option<&str>) -> ToolCallSpec {
+    ToolCallSpec {
+        tool: tool.to_string(),
+        input: json!({ "command": "ls" }),
+        result: result.map(str::to_string),
+    }
+}
+
+#[tokio::test]
+async fn command_gets_request_and_returns_result() {
+    let dir = TempDir::new().unwrap();
+    let executor = executor(
+        &dir,
+        r#"input=$(cat)
+printf '{"content": %s, "tool_use_result": {"seen": true}}' "$(printf '%s' "$input" | sed 's/"/\\"/g; s/^/"/; s/$/"/')"
+"#,
+    );
+    let ctx = ExecutionContext::default()
+        .with_cwd(dir.path())
+        .with_session_id("session-1");
+
+    let result = executor.execute(&call("Bash", None), "toolu_1", &ctx).await;
+
+    assert!(!result.is_error, "{:?}", result);
+    let request: serde_json::Value = serde_json::from_str(result.text().unwrap()).unwrap();
+    assert_eq!(request["tool_name"], "Bash");
+    assert_eq!(request["tool_input"]["command"], "ls");
+    assert_eq!(request["tool_use_id"], "toolu_1");
+    assert_eq!(request["session_id"], "session-1");
+    assert_eq!(request["cwd"], dir.path().to_string_lossy().as_ref());
+    assert_eq!(result.tool_use_result, Some(json!({ "seen": true })));
+}
+
+#[tokio::test]
+async fn command_can_return_error_blocks() {
+    let dir = TempDir::new().unwrap();
+    let executor = executor(
+        &dir,
+        r#"echo '{"content": [{"type": "text", "text": "boom"}], "is_error": true}'"#,
+    );
+
+    let result = executor
+        .execute(&call("Bash", None), "toolu_1", &ExecutionContext::default())
+        .await;
+
+    assert!(result.is_error);
+    assert_eq!(result.text(), Some("boom"));
+}
+
+#[tokio::test]
+async fn failing_command_is_a_tool_error() {
+    let dir = TempDir::new().unwrap();
+    let executor = executor(&dir, "echo 'no luck' >&2\nexit 3\n");
+
+    let result = executor
+        .execute(&call("Bash", None), "toolu_1", &ExecutionContext::default())
+        .await;
+
+    assert!(result.is_error);
+    assert!(result.text().unwrap().ends_with("failed: no luck"));
+}
+
+#[tokio::test]
+async fn invalid_output_is_a_tool_error() {
+    let dir = TempDir::new().unwrap();
+    let executor = executor(&dir, "echo hello\n");
+
+    let result = executor
+        .execute(&call("Bash", None), "toolu_1", &ExecutionContext::default())
+        .await;
+
+    assert!(result.is_error);
+    assert!(result.text().unwrap().contains("printed an invalid result"));
+}
+
+#[tokio::test]
+async fn other_tools_and_canned_results_use_inner_executor() {
+    let dir = TempDir::new().unwrap();
+    let executor = executor(&dir, "exit 1\n");
+    let ctx = ExecutionContext::default();
+
+    let canned = executor
+        .execute(&call("Bash", Some("canned")), "toolu_1", &ctx)
+        .await;
+    let other = executor
+        .execute(&call("Read", Some("file")), "toolu_2", &ctx)
+        .await;
+
+    assert_eq!(canned.text(), Some("canned"));
+    assert_eq!(other.text(), Some("file"));
+}
@@ -0,0 +1,264 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Tool result size limits.
+//!
+//! Like the real CLI, results are capped before they reach the
+//! conversation:
+//!
+//! - MCP results over `MAX_MCP_OUTPUT_TOKENS` (default 25000) tokens are
+//!   truncated with the real notice, and results over 10000 tokens print a
+//!   warning.
+//! - Text results over a tool's size limit are saved to
+//!   `tool-results/<tool_use_id>.txt` in the session directory and replaced
+//!   by a `<persisted-output>` preview. The limit defaults to the real
+//!   tool's and can be set with `[tool_execution.tools.<Tool>]
+//!   max_result_chars`.
+//!
+//! Tokens are estimated like the usage counts, at four bytes each.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use serde_json::json;
+
+use crate::config::{ToolCallSpec, ToolConfig};
+use crate::output::print_warning;
+use crate::usage::estimate_tokens;
+
+use super::executor::{ExecutionContext, ToolExecutor, ToolFuture};
+use super::result::{ToolExecutionResult, ToolResultContent};
+use super::tool_name::ToolName;
+
+/// Bytes per estimated token, as in [`estimate_tokens`].
+const BYTES_PER_TOKEN: usize = 4;
+
+/// Tokens an MCP result may use (`MAX_MCP_OUTPUT_TOKENS` overrides).
+pub const MCP_MAX_TOKENS: usize = 25_000;
+
+/// Tokens above which an MCP result prints a warning.
+pub const MCP_WARNING_TOKENS: usize = 10_000;
+
+/// Result size limit for tools without their own.
+pub const DEFAULT_MAX_RESULT_CHARS: usize = 100_000;
+
+/// Bytes of a persisted result shown in its preview.
+const PREVIEW_BYTES: usize = 2000;
+
+/// Size in the real CLI's format: `512 bytes`, `12.5KB` or `1.2MB`.
+pub fn format_size(bytes: usize) -> String {
+    const KB: f64 = 1024.0;
+    let size = bytes as f64;
+    let (value, unit) = if size < KB {
+        return format!("{} bytes", bytes);
+    } else if size < KB * KB {
+        (size / KB, "KB")
+    } else {
+        (size / (KB * KB), "MB")
+    };
+    let value = format!("{:.1}", value);
+    format!("{}{}", value.strip_suffix(".0").unwrap_or(&value), unit)
+}
+
+/// Default result size limit for a tool.
+///
+/// Read bounds itself by tokens, so only its own limits apply. Bash
+/// truncates stdout and stderr itself (`BASH_MAX_OUTPUT_LENGTH`), so its
+/// truncated output stays under the general limit and is not persisted
+/// as well.
+pub fn default_max_result_chars(tool: &str) -> usize {
+    match ToolName::parse(tool) {
+        Some(ToolName::Read) => usize::MAX,
+        Some(ToolName::Grep) => 20_000,
+        _ => DEFAULT_MAX_RESULT_CHARS,
+    }
+}
+
+/// A limit from `settings.env` or the process environment.
+pub fn env_limit(env: &HashMap<String, String>, key: &str) -> Option<usize> {
+    env.get(key)
+        .cloned()
+        .or_else(|| std::env::var(key).ok())
+        .and_then(|v| v.trim().parse().ok())
+        .filter(|&n| n > 0)
+}
+
+/// Executor that applies result size limits to the inner executor's
+/// results.
+pub struct LimitedExecutor {
+    /// Size limits set in the scenario, by tool.
+    max_result_chars: HashMap<String, usize>,
+    /// Directory persisted results are saved in; `None` uses a temporary
+    /// directory per session.
+    results_dir: Option<PathBuf>,
+    /// Executor whose results are limited.
+    inner: Box<dyn ToolExecutor>,
+}
+
+impl LimitedExecutor {
+    /// Wrap `inner`, saving oversized results in `results_dir`.
+    pub fn new(
+        inner: Box<dyn ToolExecutor>,
+        tools: &HashMap<String, ToolConfig>,
+        results_dir: Option<PathBuf>,
+    ) -> Self {
+        let max_result_chars = tools
+            .iter()
+            .filter_map(|(name, config)| Some((name.clone(), config.max_result_chars?)))
+            .collect();
+        Self {
+            max_result_chars,
+            results_dir,
+            inner,
+        }
+    }
+
+    /// Size limit for a tool's results.
+    fn max_result_chars(&self, tool: &str) -> usize {
+        self.max_result_chars
+            .get(tool)
+            .copied()
+            .unwrap_or_else(|| default_max_result_chars(tool))
+    }
+
+    /// Directory persisted results are saved in.
+    fn results_dir(&self, ctx: &ExecutionContext) -> PathBuf {
+        self.results_dir.clone().unwrap_or_else(|| {
+            let session = ctx.session_id.as_deref().unwrap_or("session");
+            std::env::temp_dir()
+                .join("claudeless")
+                .join(session)
+                .join("tool-results")
+        })
+    }
+
+    /// Apply the limits to a finished call's result.
+    fn limit(
+        &self,
+        call: &ToolCallSpec,
+        ctx: &ExecutionContext,
+        mut result: ToolExecutionResult,
+    ) -> ToolExecutionResult {
+        if ToolName::parse(&call.tool).is_none() {
+            limit_mcp_tokens(&mut result, &ctx.env);
+        }
+        let max_chars = self.max_result_chars(&call.tool);
+        if let Some(text) = result.text() {
+            if text.chars().count() > max_chars {
+                let text = text.to_string();
+                persist(&mut result, &text, &self.results_dir(ctx));
+            }
+        }
+        result
+    }
+}
+
+impl ToolExecutor for LimitedExecutor {
+    fn execute<'a>(
+        &'a self,
+        call: &'a ToolCallSpec,
+        tool_use_id: &'a str,
+        ctx: &'a ExecutionContext,
+    ) -> ToolFuture<'a> {
+        Box::pin(async move {
+            let result = self.inner.execute(call, tool_use_id, ctx).await;
+            if result.needs_prompt || result.permission_denied {
+                return result;
+            }
+            self.limit(call, ctx, result)
+        })
+    }
+
+    fn name(&self) -> &'static str {
+        "limited"
+    }
+}
+
+/// Truncate an MCP result's text to the token limit, warning when it is
+/// large.
+fn limit_mcp_tokens(result: &mut ToolExecutionResult, env: &HashMap<String, String>) {
+    let max_tokens = env_limit(env, "MAX_MCP_OUTPUT_TOKENS").unwrap_or(MCP_MAX_TOKENS);
+    let tokens: usize = result
+        .content
+        .iter()
+        .map(|block| match block {
+            ToolResultContent::Text { text } => estimate_tokens(text) as usize,
+            ToolResultContent::Image { .. } => 0,
+        })
+        .sum();
+    if tokens > MCP_WARNING_TOKENS {
+        print_warning(format_args!(
+            "Large MCP response (~{}k tokens), this can fill up context quickly",
+            tokens / 1000
+        ));
+    }
+    if tokens <= max_tokens {
+        return;
+    }
+
+    let mut budget = max_tokens * BYTES_PER_TOKEN;
+    for block in &mut result.content {
+        if let ToolResultContent::Text { text } = block {
+            let mut end = budget.min(text.len());
+            while !text.is_char_boundary(end) {
+                end -= 1;
+            }
+            text.truncate(end);
+            budget -= end;
+        }
+    }
+    result.content.retain(|block| match block {
+        ToolResultContent::Text { text } => !text.is_empty(),
+        ToolResultContent::Image { .. } => true,
+    });
+    result.content.push(ToolResultContent::Text {
+        text: format!(
+            "\n\n[OUTPUT TRUNCATED - exceeded {} token limit]\n\n\
+             The tool output was truncated. If this MCP server provides pagination or filtering \
+             tools, use them to retrieve specific portions of the data. If pagination is not \
+             available, inform the user that you are returning truncated data.",
+            max_tokens
+        ),
+    });
+}
+
+/// Save `text` under `dir` and replace the result with a preview.
+fn persist(result: &mut ToolExecutionResult, text: &str, dir: &Path) {
+    let path = dir.join(format!("{}.txt", result.tool_use_id));
+    if let Err(e) = std::fs::create_dir_all(dir).and_then(|()| std::fs::write(&path, text)) {
+        print_warning(format_args!(
+            "Failed to save tool result to {}: {}",
+            path.display(),
+            e
+        ));
+        return;
+    }
+
+    let mut end = PREVIEW_BYTES.min(text.len());
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    // Cut the preview at the last line break when there is one
+    let preview = match text[..end].rfind('\n') {
+        Some(i) if i > 0 && end < text.len() => &text[..i],
+        _ => &text[..end],
+    };
+    result.content = vec![ToolResultContent::Text {
+        text: format!(
+            "<persisted-output>\nOutput too large ({}). Full output saved to: {}\n\n\
+             Preview (first {}):\n{}\n...\n</persisted-output>",
+            format_size(text.len()),
+            path.display(),
+            format_size(PREVIEW_BYTES),
+            preview
+        ),
+    }];
+    if let Some(serde_json::Value::Object(ref mut fields)) = result.tool_use_result {
+        fields.insert("persistedOutputPath".into(), json!(path));
+        fields.insert("persistedOutputSize".into(), json!(text.len()));
+    }
+}
+
+#[cfg(test)]
+#[path = "limits_tests.rs"]
+mod tests;
@@ -0,0 +1,161 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+use tempfile::TempDir;
+use yare::parameterized;
+
+/// Executor that returns the same result for every call.
+struct FixedExecutor(ToolExecutionResult);
+
+impl ToolExecutor for FixedExecutor {
+    fn execute<'a>(
+        &'a self,
+        _call: &'a ToolCallSpec,
+        tool_use_id: &'a str,
+        _ctx: &'a ExecutionContext,
+    ) -> ToolFuture<'a> {
+        let mut result = self.0.clone();
+        result.tool_use_id = tool_use_id.to_string();
+        Box::pin(async move { result })
+    }
+
+    fn name(&self) -> &'static str {
+        "fixed"
+    }
+}
+
+fn executor(
+    dir: &TempDir,
+    result: ToolExecutionResult,
+    limits: &[(&str, usize)],
+) -> LimitedExecutor {
+    let tools = limits
+        .iter()
+        .map(|&(name, max)| {
+            let config = ToolConfig {
+                max_result_chars: Some(max),
+                ..Default::default()
+            };
+            (name.to_string(), config)
+        })
+        .collect();
+    LimitedExecutor::new(
+        Box::new(FixedExecutor(result)),
+        &tools,
+        Some(dir.path().join("tool-results")),
+    )
+}
+
+fn call(tool: &str) -> ToolCallSpec {
+    ToolCallSpec {
+        tool: tool.to_string(),
+        input: json!({}),
+        result: None,
+    }
+}
+
+#[parameterized(
+    bytes = { 512, "512 bytes" },
+    whole_kb = { 2048, "2KB" },
+    fractional_kb = { 1536, "1.5KB" },
+    mb = { 3 * 1024 * 1024, "3MB" },
+)]
+fn formats_sizes(bytes: usize, expected: &str) {
+    assert_eq!(format_size(bytes), expected);
+}
+
+#[test]
+fn default_limits_match_real_tools() {
+    assert_eq!(default_max_result_chars("Bash"), DEFAULT_MAX_RESULT_CHARS);
+    assert_eq!(default_max_result_chars("Grep"), 20_000);
+    assert_eq!(default_max_result_chars("Read"), usize::MAX);
+    assert_eq!(
+        default_max_result_chars("mcp__db__query"),
+        DEFAULT_MAX_RESULT_CHARS
+    );
+}
+
+#[tokio::test]
+async fn oversized_result_is_persisted_with_preview() {
+    let dir = TempDir::new().unwrap();
+    let output = "line of output\n".repeat(200);
+    let result = ToolExecutionResult::success_with_result(
+        "",
+        output.clone(),
+        json!({ "stdout": output.clone() }),
+    );
+    let executor = executor(&dir, result, &[("Bash", 1000)]);
+
+    let result = executor
+        .execute(&call("Bash"), "toolu_1", &ExecutionContext::default())
+        .await;
+
+    let path = dir.path().join("tool-results").join("toolu_1.txt");
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), output);
+    let text = result.text().unwrap();
+    let header = format!(
+        "<persisted-output>\nOutput too large (2.9KB). Full output saved to: {}\n\n\
+         Preview (first 2KB):\nline of output\n",
+        path.display()
+    );
+    assert!(text.starts_with(&header), "{}", text);
+    assert!(
+        text.ends_with("line of output\n...\n</persisted-output>"),
+        "{}",
+        text
+    );
+    let meta = result.tool_use_result().unwrap();
+    assert_eq!(meta["persistedOutputPath"], path.to_string_lossy().as_ref());
+    assert_eq!(meta["persistedOutputSize"], output.len());
+}
+
+#[tokio::test]
+async fn results_within_limit_are_unchanged() {
+    let dir = TempDir::new().unwrap();
+    let executor = executor(
+        &dir,
+        ToolExecutionResult::success("", "x".repeat(1000)),
+        &[("Bash", 1000)],
+    );
+    let ctx = ExecutionContext::default();
+
+    let bash = executor.execute(&call("Bash"), "toolu_1", &ctx).await;
+    let read = executor.execute(&call("Read"), "toolu_2", &ctx).await;
+
+    assert_eq!(bash.text(), Some("x".repeat(1000).as_str()));
+    assert_eq!(read.text(), Some("x".repeat(1000).as_str()));
+    assert!(!dir.path().join("tool-results").exists());
+}
+
+#[tokio::test]
+async fn mcp_result_over_token_limit_is_truncated() {
+    let dir = TempDir::new().unwrap();
+    let executor = executor(&dir, ToolExecutionResult::success("", "y".repeat(100)), &[]);
+    let ctx = ExecutionContext::default().with_env(HashMap::from([(
+        "MAX_MCP_OUTPUT_TOKENS".into(),
+        "5".into(),
+    )]));
+
+    let mcp = executor
+        .execute(&call("mcp__db__query"), "toolu_1", &ctx)
+        .await;
+    let builtin = executor.execute(&call("Glob"), "toolu_2", &ctx).await;
+
+    let [ToolResultContent::Text { text: kept }, ToolResultContent::Text { text }] =
+        mcp.content.as_slice()
+    else {
+        panic!("expected two text blocks: {:?}", mcp.content);
+    };
+    assert_eq!(*kept, "y".repeat(20));
+    assert!(
+        text.starts_with(
+            "\n\n[OUTPUT TRUNCATED - exceeded 5 token limit]\n\nThe tool output was truncated."
+        ),
+        "{}",
+        text
+    );
+    assert_eq!(builtin.text(), Some("y".repeat(100).as_str()));
+}
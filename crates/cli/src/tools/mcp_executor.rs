@@ -12,7 +12,7 @@ use std::sync::Arc;
 use parking_lot::RwLock;
 
 use super::builtin::BuiltinExecutor;
-use super::executor::{ExecutionContext, ToolExecutor};
+use super::executor::{ExecutionContext, ToolExecutor, ToolFuture};
 use super::result::ToolExecutionResult;
 use crate::config::ToolCallSpec;
 use crate::mcp::config::McpToolDef;
@@ -53,61 +53,58 @@ impl McpToolExecutor {
 }
 
 impl ToolExecutor for McpToolExecutor {
-    fn execute(
-        &self,
-        call: &ToolCallSpec,
-        tool_use_id: &str,
-        _ctx: &ExecutionContext,
-    ) -> ToolExecutionResult {
+    fn execute<'a>(
+        &'a self,
+        call: &'a ToolCallSpec,
+        tool_use_id: &'a str,
+        _ctx: &'a ExecutionContext,
+    ) -> ToolFuture<'a> {
         // Extract raw tool name from potentially qualified name (mcp__server__tool)
         let raw_tool_name = Self::get_raw_tool_name(&call.tool);
 
-        // Check if we handle this tool
-        let manager = self.manager.read();
-        if !manager.has_tool(&raw_tool_name) {
-            return ToolExecutionResult::error(
-                tool_use_id,
-                format!("MCP tool not found: {}", call.tool),
-            );
-        }
-
-        // Bridge async to sync safely within an async runtime
-        // Use block_in_place to avoid "Cannot start a runtime from within a runtime" panic
-        let handle = match tokio::runtime::Handle::try_current() {
-            Ok(h) => h,
-            Err(_) => {
-                return ToolExecutionResult::error(
-                    tool_use_id,
-                    "No tokio runtime available for MCP execution",
-                );
-            }
-        };
-
-        // Execute the async call using block_in_place to safely block
         // Canonicalize path arguments to handle symlinks (e.g., /tmp -> /private/tmp on macOS)
         let input = canonicalize_path_arguments(call.input.clone());
-        let result = tokio::task::block_in_place(|| {
-            handle.block_on(async { manager.call_tool(&raw_tool_name, input).await })
-        });
-
-        // Convert McpToolResult to ToolExecutionResult
-        match result {
-            Ok(mcp_result) => {
-                if mcp_result.success {
-                    // Format content as string for tool result
-                    let text = format_mcp_content(&mcp_result.content);
-                    ToolExecutionResult::success(tool_use_id, text)
-                } else {
+
+        // Route the call while holding the manager lock, then await it
+        // without the lock so calls to other servers can run meanwhile
+        let pending = {
+            let manager = self.manager.read();
+            if !manager.has_tool(&raw_tool_name) {
+                return Box::pin(async move {
                     ToolExecutionResult::error(
                         tool_use_id,
-                        mcp_result
-                            .error
-                            .unwrap_or_else(|| "MCP tool execution failed".into()),
+                        format!("MCP tool not found: {}", call.tool),
                     )
+                });
+            }
+            manager.start_tool_call(&raw_tool_name, input)
+        };
+
+        Box::pin(async move {
+            let result = match pending {
+                Ok(pending) => pending.await,
+                Err(e) => Err(e),
+            };
+
+            // Convert McpToolResult to ToolExecutionResult
+            match result {
+                Ok(mcp_result) => {
+                    if mcp_result.success {
+                        // Format content as string for tool result
+                        let text = format_mcp_content(&mcp_result.content);
+                        ToolExecutionResult::success(tool_use_id, text)
+                    } else {
+                        ToolExecutionResult::error(
+                            tool_use_id,
+                            mcp_result
+                                .error
+                                .unwrap_or_else(|| "MCP tool execution failed".into()),
+                        )
+                    }
                 }
+                Err(e) => ToolExecutionResult::error(tool_use_id, e.to_string()),
             }
-            Err(e) => ToolExecutionResult::error(tool_use_id, e.to_string()),
-        }
+        })
     }
 
     fn name(&self) -> &'static str {
@@ -190,12 +187,12 @@ impl CompositeExecutor {
 }
 
 impl ToolExecutor for CompositeExecutor {
-    fn execute(
-        &self,
-        call: &ToolCallSpec,
-        tool_use_id: &str,
-        ctx: &ExecutionContext,
-    ) -> ToolExecutionResult {
+    fn execute<'a>(
+        &'a self,
+        call: &'a ToolCallSpec,
+        tool_use_id: &'a str,
+        ctx: &'a ExecutionContext,
+    ) -> ToolFuture<'a> {
         // Check if this is an MCP tool (qualified name or known raw name)
         if let Some(ref mcp) = self.mcp {
             // Check for qualified name (mcp__server__tool) or raw MCP tool name
@@ -18,6 +18,7 @@ fn mock_manager_with_tool(tool_name: &str) -> Arc<RwLock<McpManager>> {
         description: "Test tool".to_string(),
         input_schema: serde_json::json!({"type": "object"}),
         server_name: "test-server".to_string(),
+        read_only: false,
     });
 
     manager.add_server(server);
@@ -28,6 +29,7 @@ fn mock_manager_with_tool(tool_name: &str) -> Arc<RwLock<McpManager>> {
             description: "Test tool".to_string(),
             input_schema: serde_json::json!({"type": "object"}),
             server_name: "test-server".to_string(),
+            read_only: false,
         },
     );
 
@@ -57,8 +59,8 @@ fn composite_executor_name() {
     assert_eq!(composite.name(), "composite");
 }
 
-#[test]
-fn composite_routes_to_builtin_for_unknown_mcp_tool() {
+#[tokio::test]
+async fn composite_routes_to_builtin_for_unknown_mcp_tool() {
     let manager = mock_manager_with_tool("mcp_tool");
     let mcp = McpToolExecutor::new(manager);
     let builtin = BuiltinExecutor::new();
@@ -70,7 +72,9 @@ fn composite_routes_to_builtin_for_unknown_mcp_tool() {
         result: Some("test content".to_string()),
     };
 
-    let result = composite.execute(&call, "test-id", &ExecutionContext::default());
+    let result = composite
+        .execute(&call, "test-id", &ExecutionContext::default())
+        .await;
 
     // Should fall through to builtin (which uses mock result)
     assert!(!result.is_error);
@@ -88,8 +92,8 @@ fn composite_routes_mcp_tool_to_mcp() {
     assert!(composite.mcp.as_ref().unwrap().has_tool("custom_tool"));
 }
 
-#[test]
-fn composite_without_mcp_works() {
+#[tokio::test]
+async fn composite_without_mcp_works() {
     let builtin = BuiltinExecutor::new();
     let composite = CompositeExecutor::builtin_only(builtin);
 
@@ -99,7 +103,9 @@ fn composite_without_mcp_works() {
         result: Some("test content".to_string()),
     };
 
-    let result = composite.execute(&call, "test-id", &ExecutionContext::default());
+    let result = composite
+        .execute(&call, "test-id", &ExecutionContext::default())
+        .await;
     assert!(!result.is_error);
     assert_eq!(result.text(), Some("test content"));
 }
@@ -179,8 +185,8 @@ fn get_raw_tool_name_handles_builtin() {
     assert_eq!(raw, "Read");
 }
 
-#[test]
-fn composite_routes_qualified_mcp_tool_to_mcp() {
+#[tokio::test]
+async fn composite_routes_qualified_mcp_tool_to_mcp() {
     let manager = mock_manager_with_tool("read_file");
     let mcp = McpToolExecutor::new(manager);
     let builtin = BuiltinExecutor::new();
@@ -193,7 +199,9 @@ fn composite_routes_qualified_mcp_tool_to_mcp() {
         result: None,
     };
 
-    let result = composite.execute(&call, "test-id", &ExecutionContext::default());
+    let result = composite
+        .execute(&call, "test-id", &ExecutionContext::default())
+        .await;
 
     // Should route to MCP executor (not builtin)
     // It will fail because we don't have a live MCP connection, but it should
@@ -7,6 +7,7 @@
 //! - **Disabled** - No tool execution
 //! - **Mock** - Return pre-configured results from scenario config
 //! - **Live** - Execute built-in tools directly (Bash, Read, Write, etc.)
+//! - **Vfs** - Execute file tools against an in-memory filesystem
 //!
 //! # Example
 //!
@@ -15,17 +16,28 @@
 //!
 //! let executor = create_executor_with_mcp(mode, mcp_manager);
 //! let ctx = ExecutionContext::default();
-//! let result = executor.execute(&call, "toolu_123", &ctx);
+//! let result = executor.execute(&call, "toolu_123", &ctx).await;
 //! ```
 
+pub(crate) mod background;
 pub(crate) mod builtin;
 pub(crate) mod executor;
+pub(crate) mod external;
+pub(crate) mod limits;
 pub(crate) mod mcp_executor;
 pub(crate) mod result;
+pub(crate) mod sandbox;
+pub(crate) mod schema;
 pub(crate) mod tool_name;
+pub(crate) mod vfs;
+pub(crate) mod web;
 
+pub use background::{BackgroundShellError, BackgroundShells, ShellStatus};
 pub use executor::{
     create_executor_with_mcp, create_executor_with_mcp_and_permissions, ExecutionContext,
-    ToolExecutor,
+    ToolExecutor, ToolFuture,
 };
 pub use result::{ToolExecutionResult, ToolResultContent};
+pub use sandbox::{Sandbox, SandboxDiff, SandboxError};
+pub use vfs::Vfs;
+pub use web::Web;
@@ -53,10 +53,19 @@ pub struct ToolExecutionResult {
     /// Whether this tool needs an interactive permission prompt before executing.
     #[serde(skip)]
     pub needs_prompt: bool,
+
+    /// Whether permission checks denied this tool call.
+    #[serde(skip)]
+    pub permission_denied: bool,
+
+    /// Message added to the conversation after this result (e.g. a loaded
+    /// skill's instructions), recorded to JSONL with `isMeta`.
+    #[serde(skip)]
+    pub meta_message: Option<String>,
 }
 
 impl ToolExecutionResult {
-    impl_tool_result_factories!([ToolResultContent::Text], tool_use_result: None, needs_prompt: false);
+    impl_tool_result_factories!([ToolResultContent::Text], tool_use_result: None, needs_prompt: false, permission_denied: false, meta_message: None);
 
     /// Create a successful result with tool-specific result data.
     pub fn success_with_result(
@@ -70,6 +79,8 @@ impl ToolExecutionResult {
             content: vec![ToolResultContent::Text { text: text.into() }],
             tool_use_result: Some(tool_use_result),
             needs_prompt: false,
+            permission_denied: false,
+            meta_message: None,
         }
     }
 
@@ -81,6 +92,8 @@ impl ToolExecutionResult {
             content: vec![],
             tool_use_result: None,
             needs_prompt: true,
+            permission_denied: false,
+            meta_message: None,
         }
     }
 
@@ -99,7 +112,16 @@ impl ToolExecutionResult {
 
     /// Create a result indicating permission was denied.
     pub fn permission_denied(tool_use_id: impl Into<String>, reason: impl Into<String>) -> Self {
-        Self::error(tool_use_id, format!("Permission denied: {}", reason.into()))
+        Self {
+            permission_denied: true,
+            ..Self::error(tool_use_id, format!("Permission denied: {}", reason.into()))
+        }
+    }
+
+    /// Add a message to the conversation after this result.
+    pub fn with_meta_message(mut self, message: impl Into<String>) -> Self {
+        self.meta_message = Some(message.into());
+        self
     }
 
     /// Get the text content if this is a simple text result.
@@ -125,7 +147,32 @@ pub enum ToolResultContent {
     /// Text content
     Text { text: String },
     /// Image content (base64 encoded)
-    Image { data: String, media_type: String },
+    Image { source: ImageSource },
+}
+
+/// Source of an image content block.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ImageSource {
+    /// Encoding of `data`; always `"base64"`.
+    #[serde(rename = "type")]
+    pub source_type: String,
+    /// MIME type of the image, e.g. `image/png`.
+    pub media_type: String,
+    /// Base64-encoded image bytes.
+    pub data: String,
+}
+
+impl ToolResultContent {
+    /// Create a base64 image block.
+    pub fn image(media_type: impl Into<String>, data: impl Into<String>) -> Self {
+        Self::Image {
+            source: ImageSource {
+                source_type: "base64".to_string(),
+                media_type: media_type.into(),
+                data: data.into(),
+            },
+        }
+    }
 }
 
 #[cfg(test)]
@@ -30,6 +30,7 @@ fn test_no_mock_result() {
 fn test_disabled_result() {
     let result = ToolExecutionResult::disabled("toolu_abc");
     assert!(result.is_error);
+    assert!(!result.permission_denied);
     assert!(result.text().unwrap().contains("disabled"));
 }
 
@@ -39,6 +40,7 @@ fn test_permission_denied() {
     assert!(result.is_error);
     assert!(result.text().unwrap().contains("Permission denied"));
     assert!(result.text().unwrap().contains("DontAsk mode"));
+    assert!(result.permission_denied);
 }
 
 #[test]
@@ -49,3 +51,15 @@ fn test_serialization() {
     assert_eq!(parsed.tool_use_id, "toolu_123");
     assert!(!parsed.is_error);
 }
+
+#[test]
+fn test_image_content_serializes_as_api_block() {
+    let block = ToolResultContent::image("image/png", "aGVsbG8=");
+    assert_eq!(
+        serde_json::to_value(&block).unwrap(),
+        serde_json::json!({
+            "type": "image",
+            "source": { "type": "base64", "media_type": "image/png", "data": "aGVsbG8=" }
+        })
+    );
+}
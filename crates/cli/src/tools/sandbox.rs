@@ -0,0 +1,321 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Sandbox workspace for live tool execution.
+//!
+//! The project directory is copied into a temporary directory when the
+//! session starts, leaving out version control directories and files that
+//! `.gitignore` or `.ignore` rules exclude. Built-in tools resolve every
+//! path inside that copy, so a scenario can never modify the real project.
+//! Paths that escape the copy are refused, in tool inputs and in Bash
+//! commands alike, and the changes made during the session can be
+//! collected as a [`SandboxDiff`].
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+use ignore::{DirEntry, WalkBuilder};
+use serde::{Deserialize, Serialize};
+use tempfile::TempDir;
+use thiserror::Error;
+
+use super::builtin::walk::VCS_DIRECTORIES;
+
+/// Errors from creating or using a sandbox.
+#[derive(Debug, Error)]
+pub enum SandboxError {
+    /// A tool path resolved outside the sandbox.
+    #[error("Path '{0}' is outside the sandbox")]
+    Escape(String),
+
+    /// Copying or walking the workspace failed.
+    #[error("{0}")]
+    Io(#[from] io::Error),
+}
+
+/// Files changed in the sandbox relative to the original project.
+///
+/// Paths are relative to the project root and sorted.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SandboxDiff {
+    /// Files that exist only in the sandbox.
+    #[serde(default)]
+    pub added: Vec<String>,
+    /// Files whose contents differ from the original.
+    #[serde(default)]
+    pub modified: Vec<String>,
+    /// Files removed from the sandbox.
+    #[serde(default)]
+    pub deleted: Vec<String>,
+}
+
+impl SandboxDiff {
+    /// Whether no files changed.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.modified.is_empty() && self.deleted.is_empty()
+    }
+}
+
+/// A copy of the project directory that built-in tools operate on.
+#[derive(Debug)]
+pub struct Sandbox {
+    /// Original project directory (canonical).
+    source: PathBuf,
+    /// Root of the copy (canonical).
+    root: PathBuf,
+    /// Owns the temporary directory; removed on drop.
+    _dir: TempDir,
+}
+
+impl Sandbox {
+    /// Copy `source` into a new temporary sandbox.
+    pub fn new(source: impl AsRef<Path>) -> Result<Self, SandboxError> {
+        let source = source.as_ref().canonicalize()?;
+        let dir = tempfile::Builder::new()
+            .prefix("claudeless-sandbox-")
+            .tempdir()?;
+        let root = dir.path().canonicalize()?;
+        copy_tree(&source, &root)?;
+        Ok(Self {
+            source,
+            root,
+            _dir: dir,
+        })
+    }
+
+    /// Original project directory.
+    pub fn source(&self) -> &Path {
+        &self.source
+    }
+
+    /// Root of the sandbox copy.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Resolve a tool path to a location inside the sandbox.
+    ///
+    /// Absolute paths under the original project are mapped into the copy,
+    /// relative paths are joined to `cwd` (or the sandbox root). Anything
+    /// that ends up outside the copy, including through symlinks, is refused.
+    pub fn resolve(&self, path: &str, cwd: Option<&Path>) -> Result<PathBuf, SandboxError> {
+        let escape = || SandboxError::Escape(path.to_string());
+        let requested = Path::new(path);
+
+        let joined = if requested.is_absolute() {
+            let requested = normalize(requested);
+            match requested.strip_prefix(&self.source) {
+                Ok(rel) => self.root.join(rel),
+                Err(_) => requested,
+            }
+        } else {
+            cwd.unwrap_or(&self.root).join(requested)
+        };
+
+        let resolved = normalize(&joined);
+        if !resolved.starts_with(&self.root) {
+            return Err(escape());
+        }
+
+        // Follow symlinks on the deepest existing ancestor
+        let existing = resolved
+            .ancestors()
+            .find(|p| p.exists())
+            .ok_or_else(escape)?;
+        let canonical = existing.canonicalize()?;
+        if !canonical.starts_with(&self.root) {
+            return Err(escape());
+        }
+
+        Ok(resolved)
+    }
+
+    /// Check a shell command's paths against the sandbox.
+    ///
+    /// Project paths in the command are rewritten to the copy. Words that
+    /// name a path outside the copy (absolute, through `..` relative to
+    /// `cwd`, or `~`, which Bash sets to the copy's root) are refused.
+    ///
+    /// This is a guard against mistakes, not isolation. The check is
+    /// textual, so paths that only exist once the shell runs (`$VAR`,
+    /// `$(...)`, globs, `..` assembled from pieces) are not caught.
+    pub fn confine_command(&self, command: &str, cwd: &Path) -> Result<String, SandboxError> {
+        let source = self.source.to_string_lossy();
+        let root = self.root.to_string_lossy();
+        let mut confined = String::with_capacity(command.len());
+        let mut rest = command;
+        while let Some(at) = rest.find(source.as_ref()) {
+            let after = &rest[at + source.len()..];
+            let starts_word = rest[..at]
+                .chars()
+                .next_back()
+                .is_none_or(|c| is_word_break(c) || c == '=');
+            let ends_word = after
+                .chars()
+                .next()
+                .is_none_or(|c| c == '/' || is_word_break(c));
+            confined.push_str(&rest[..at]);
+            confined.push_str(if starts_word && ends_word {
+                &root
+            } else {
+                &source
+            });
+            rest = after;
+        }
+        confined.push_str(rest);
+
+        let words = confined
+            .split(is_word_break)
+            .flat_map(|word| [Some(word), word.split_once('=').map(|(_, value)| value)])
+            .flatten();
+        for word in words {
+            let escape = || SandboxError::Escape(word.to_string());
+            let expanded = match word.strip_prefix('~') {
+                Some(rest) if rest.is_empty() || rest.starts_with('/') => {
+                    format!("{}{}", root, rest)
+                }
+                // `~user` names another home directory
+                Some(_) => return Err(escape()),
+                None => word.to_string(),
+            };
+            let path = Path::new(&expanded);
+            let leaves = path.is_absolute() || path.components().any(|c| c == Component::ParentDir);
+            if leaves && !DEVICE_PATHS.contains(&word) {
+                self.resolve(&expanded, Some(cwd)).map_err(|_| escape())?;
+            }
+        }
+        Ok(confined)
+    }
+
+    /// Working directory inside the sandbox for the given session cwd.
+    pub fn working_dir(&self, cwd: Option<&Path>) -> PathBuf {
+        cwd.and_then(|cwd| self.resolve(&cwd.to_string_lossy(), None).ok())
+            .unwrap_or_else(|| self.root.clone())
+    }
+
+    /// Map a sandbox path back to the project path it stands for.
+    pub fn display_path(&self, path: &Path) -> PathBuf {
+        match path.strip_prefix(&self.root) {
+            Ok(rel) => self.source.join(rel),
+            Err(_) => path.to_path_buf(),
+        }
+    }
+
+    /// Compare the sandbox with the original project.
+    pub fn diff(&self) -> Result<SandboxDiff, SandboxError> {
+        let before = list_files(&self.source)?;
+        let after = list_files(&self.root)?;
+
+        let mut diff = SandboxDiff::default();
+        for (rel, path) in &after {
+            match before.get(rel) {
+                None => diff.added.push(rel.clone()),
+                Some(original) => {
+                    if fs::read(original)? != fs::read(path)? {
+                        diff.modified.push(rel.clone());
+                    }
+                }
+            }
+        }
+        diff.deleted = before
+            .keys()
+            .filter(|rel| !after.contains_key(*rel))
+            .cloned()
+            .collect();
+        Ok(diff)
+    }
+}
+
+/// Device files a sandboxed command may still name.
+const DEVICE_PATHS: &[&str] = &["/dev/null", "/dev/stdin", "/dev/stdout", "/dev/stderr"];
+
+/// Whether `c` separates words in a shell command.
+fn is_word_break(c: char) -> bool {
+    c.is_whitespace()
+        || matches!(
+            c,
+            ';' | '|' | '&' | '<' | '>' | '(' | ')' | '\'' | '"' | '`'
+        )
+}
+
+/// Lexically normalize a path, resolving `.` and `..` components.
+pub(crate) fn normalize(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                out.pop();
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// Entries under `root` that belong in the sandbox.
+///
+/// Only ignore files inside `root` apply, so the project and its copy are
+/// walked alike.
+fn walk(root: &Path) -> io::Result<Vec<DirEntry>> {
+    WalkBuilder::new(root)
+        .hidden(false)
+        .parents(false)
+        .require_git(false)
+        .git_global(false)
+        .git_exclude(false)
+        .filter_entry(|entry| !VCS_DIRECTORIES.iter().any(|dir| entry.file_name() == *dir))
+        .build()
+        .filter(|entry| entry.as_ref().map_or(true, |e| e.depth() > 0))
+        .map(|entry| entry.map_err(io::Error::other))
+        .collect()
+}
+
+/// Copy a directory tree, preserving symlinks as links.
+fn copy_tree(from: &Path, to: &Path) -> io::Result<()> {
+    for entry in walk(from)? {
+        let Ok(rel) = entry.path().strip_prefix(from) else {
+            continue;
+        };
+        let target = to.join(rel);
+        match entry.file_type() {
+            Some(t) if t.is_dir() => fs::create_dir_all(&target)?,
+            Some(t) if t.is_symlink() => copy_symlink(entry.path(), &target)?,
+            _ => {
+                fs::copy(entry.path(), &target)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn copy_symlink(from: &Path, to: &Path) -> io::Result<()> {
+    std::os::unix::fs::symlink(fs::read_link(from)?, to)
+}
+
+#[cfg(not(unix))]
+fn copy_symlink(from: &Path, to: &Path) -> io::Result<()> {
+    fs::copy(from, to).map(|_| ())
+}
+
+/// Regular files under `root`, keyed by their relative path.
+fn list_files(root: &Path) -> io::Result<BTreeMap<String, PathBuf>> {
+    let mut files = BTreeMap::new();
+    for entry in walk(root)? {
+        if !entry.file_type().is_some_and(|t| t.is_file()) {
+            continue;
+        }
+        if let Ok(rel) = entry.path().strip_prefix(root) {
+            files.insert(rel.to_string_lossy().into_owned(), entry.into_path());
+        }
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+#[path = "sandbox_tests.rs"]
+mod tests;
@@ -0,0 +1,201 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use tempfile::TempDir;
+use yare::parameterized;
+
+fn project() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir(dir.path().join("src")).unwrap();
+    fs::write(dir.path().join("src/main.rs"), "fn main() {}").unwrap();
+    fs::write(dir.path().join("README.md"), "readme").unwrap();
+    dir
+}
+
+#[test]
+fn new_copies_project_tree() {
+    let project = project();
+    let sandbox = Sandbox::new(project.path()).unwrap();
+
+    assert_ne!(sandbox.root(), sandbox.source());
+    assert_eq!(
+        fs::read_to_string(sandbox.root().join("src/main.rs")).unwrap(),
+        "fn main() {}"
+    );
+}
+
+#[test]
+fn resolve_maps_project_paths_into_sandbox() {
+    let project = project();
+    let sandbox = Sandbox::new(project.path()).unwrap();
+
+    let absolute = sandbox.source().join("src/main.rs");
+    let resolved = sandbox.resolve(&absolute.to_string_lossy(), None).unwrap();
+    assert_eq!(resolved, sandbox.root().join("src/main.rs"));
+
+    let relative = sandbox.resolve("src/new.rs", None).unwrap();
+    assert_eq!(relative, sandbox.root().join("src/new.rs"));
+}
+
+#[test]
+fn resolve_relative_to_cwd() {
+    let project = project();
+    let sandbox = Sandbox::new(project.path()).unwrap();
+    let cwd = sandbox.root().join("src");
+
+    let resolved = sandbox.resolve("main.rs", Some(&cwd)).unwrap();
+    assert_eq!(resolved, sandbox.root().join("src/main.rs"));
+}
+
+#[parameterized(
+    parent_dir = { "../outside.txt" },
+    nested_parent = { "src/../../outside.txt" },
+    absolute = { "/etc/passwd" },
+)]
+fn resolve_refuses_escapes(path: &str) {
+    let project = project();
+    let sandbox = Sandbox::new(project.path()).unwrap();
+
+    let err = sandbox.resolve(path, None).unwrap_err();
+    assert!(matches!(err, SandboxError::Escape(_)));
+}
+
+#[cfg(unix)]
+#[test]
+fn resolve_refuses_symlink_escape() {
+    let project = project();
+    let outside = TempDir::new().unwrap();
+    std::os::unix::fs::symlink(outside.path(), project.path().join("link")).unwrap();
+    let sandbox = Sandbox::new(project.path()).unwrap();
+
+    let err = sandbox.resolve("link/file.txt", None).unwrap_err();
+    assert!(matches!(err, SandboxError::Escape(_)));
+}
+
+#[test]
+fn display_path_maps_back_to_project() {
+    let project = project();
+    let sandbox = Sandbox::new(project.path()).unwrap();
+
+    let shown = sandbox.display_path(&sandbox.root().join("src/main.rs"));
+    assert_eq!(shown, sandbox.source().join("src/main.rs"));
+}
+
+#[test]
+fn diff_reports_added_modified_and_deleted() {
+    let project = project();
+    let sandbox = Sandbox::new(project.path()).unwrap();
+    assert!(sandbox.diff().unwrap().is_empty());
+
+    fs::write(sandbox.root().join("src/main.rs"), "fn main() { run() }").unwrap();
+    fs::write(sandbox.root().join("src/lib.rs"), "pub fn run() {}").unwrap();
+    fs::remove_file(sandbox.root().join("README.md")).unwrap();
+
+    let diff = sandbox.diff().unwrap();
+    assert_eq!(diff.added, vec!["src/lib.rs"]);
+    assert_eq!(diff.modified, vec!["src/main.rs"]);
+    assert_eq!(diff.deleted, vec!["README.md"]);
+
+    // The original project is untouched
+    assert_eq!(
+        fs::read_to_string(project.path().join("src/main.rs")).unwrap(),
+        "fn main() {}"
+    );
+    assert!(project.path().join("README.md").exists());
+}
+
+#[test]
+fn new_skips_vcs_and_ignored_files() {
+    let project = project();
+    fs::create_dir_all(project.path().join(".git/objects")).unwrap();
+    fs::write(project.path().join(".git/HEAD"), "ref: refs/heads/main").unwrap();
+    fs::write(project.path().join(".gitignore"), "target/\n*.log\n").unwrap();
+    fs::create_dir_all(project.path().join("target/debug")).unwrap();
+    fs::write(project.path().join("target/debug/app"), "binary").unwrap();
+    fs::write(project.path().join("build.log"), "log").unwrap();
+    fs::write(project.path().join(".env"), "KEY=value").unwrap();
+
+    let sandbox = Sandbox::new(project.path()).unwrap();
+
+    assert!(!sandbox.root().join(".git").exists());
+    assert!(!sandbox.root().join("target").exists());
+    assert!(!sandbox.root().join("build.log").exists());
+    assert!(sandbox.root().join(".gitignore").exists());
+    assert!(sandbox.root().join(".env").exists());
+    assert!(sandbox.root().join("src/main.rs").exists());
+    // Files left out of the copy are not reported as deleted
+    assert!(sandbox.diff().unwrap().is_empty());
+}
+
+#[test]
+fn confine_command_maps_project_paths_into_sandbox() {
+    let project = project();
+    let sandbox = Sandbox::new(project.path()).unwrap();
+    let source = sandbox.source().display().to_string();
+    let root = sandbox.root().display().to_string();
+
+    let command = format!("cat {source}/README.md > out.txt; ls \"{source}\" 2>/dev/null");
+    let confined = sandbox.confine_command(&command, sandbox.root()).unwrap();
+    assert_eq!(
+        confined,
+        format!("cat {root}/README.md > out.txt; ls \"{root}\" 2>/dev/null")
+    );
+
+    let sibling = format!("ls {source}-other");
+    let err = sandbox
+        .confine_command(&sibling, sandbox.root())
+        .unwrap_err();
+    assert!(matches!(err, SandboxError::Escape(_)));
+}
+
+#[test]
+fn confine_command_allows_paths_inside_sandbox() {
+    let project = project();
+    let sandbox = Sandbox::new(project.path()).unwrap();
+    let cwd = sandbox.root().join("src");
+
+    let command = "cat ../README.md main.rs ~/README.md | grep -c fn; git log HEAD..main";
+    assert_eq!(sandbox.confine_command(command, &cwd).unwrap(), command);
+}
+
+#[parameterized(
+    absolute = { "cat /etc/passwd" },
+    redirect = { "echo x >/tmp/out.txt" },
+    quoted = { "cat '/etc/passwd'" },
+    assignment = { "OUT=/tmp/out.txt make" },
+    option_value = { "tool --file=/etc/hosts" },
+    parent_dir = { "cat ../outside.txt" },
+    other_home = { "cat ~root/.ssh/id_rsa" },
+    above_home = { "cat ~/../outside.txt" },
+)]
+fn confine_command_refuses_escapes(command: &str) {
+    let project = project();
+    let sandbox = Sandbox::new(project.path()).unwrap();
+
+    let err = sandbox
+        .confine_command(command, sandbox.root())
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("is outside the sandbox"),
+        "{}",
+        err
+    );
+}
+
+/// Paths the shell assembles at run time get past the textual check.
+#[parameterized(
+    variable = { "cat $HOME/../outside.txt" },
+    substitution = { "cat $(printf '\\057etc/passwd')" },
+    parent_variable = { "D=.; cd $D$D && ls" },
+    parent_substitution = { "cd \"$(dirname \"$PWD\")\"" },
+)]
+fn confine_command_misses_runtime_paths(command: &str) {
+    let project = project();
+    let sandbox = Sandbox::new(project.path()).unwrap();
+
+    assert_eq!(
+        sandbox.confine_command(command, sandbox.root()).unwrap(),
+        command
+    );
+}
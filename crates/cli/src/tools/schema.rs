@@ -0,0 +1,592 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Input schemas for built-in tools.
+//!
+//! Each built-in tool's input is checked against the JSON schema the real
+//! CLI sends for it before the call runs. Problems are reported the way
+//! the real CLI reports its zod validation issues: missing parameters,
+//! unexpected parameters and type mismatches are summarized, and any other
+//! issue (enum values, lengths) is listed as the zod issue JSON. Issues
+//! follow the order the schema declares its properties in, as zod's do.
+
+use serde_json::{json, Map, Value};
+
+use crate::config::ToolCallSpec;
+
+use super::result::ToolExecutionResult;
+use super::tool_name::ToolName;
+
+/// JSON schema for a built-in tool's input.
+pub fn input_schema(tool: ToolName) -> Value {
+    match tool {
+        ToolName::Bash => object(
+            &[
+                ("command", string()),
+                ("timeout", number()),
+                ("description", string()),
+                ("run_in_background", boolean()),
+                ("dangerouslyDisableSandbox", boolean()),
+            ],
+            &["command"],
+        ),
+        ToolName::Read => object(
+            &[
+                ("file_path", string()),
+                ("offset", number()),
+                ("limit", number()),
+            ],
+            &["file_path"],
+        ),
+        ToolName::Write => object(
+            &[("file_path", string()), ("content", string())],
+            &["file_path", "content"],
+        ),
+        ToolName::Edit => object(
+            &[
+                ("file_path", string()),
+                ("old_string", string()),
+                ("new_string", string()),
+                ("replace_all", boolean()),
+            ],
+            &["file_path", "old_string", "new_string"],
+        ),
+        ToolName::MultiEdit => {
+            let edit = object(
+                &[
+                    ("old_string", string()),
+                    ("new_string", string()),
+                    ("replace_all", boolean()),
+                ],
+                &["old_string", "new_string"],
+            );
+            object(
+                &[
+                    ("file_path", string()),
+                    ("edits", array(edit, Some(1), None)),
+                ],
+                &["file_path", "edits"],
+            )
+        }
+        ToolName::Glob => object(&[("pattern", string()), ("path", string())], &["pattern"]),
+        ToolName::Grep => object(
+            &[
+                ("pattern", string()),
+                ("path", string()),
+                ("glob", string()),
+                (
+                    "output_mode",
+                    one_of(&["content", "files_with_matches", "count"]),
+                ),
+                ("-B", number()),
+                ("-A", number()),
+                ("-C", number()),
+                ("-n", boolean()),
+                ("-i", boolean()),
+                ("type", string()),
+                ("head_limit", number()),
+                ("offset", number()),
+                ("multiline", boolean()),
+            ],
+            &["pattern"],
+        ),
+        ToolName::BashOutput => {
+            object(&[("bash_id", string()), ("filter", string())], &["bash_id"])
+        }
+        ToolName::KillShell => object(&[("shell_id", string())], &["shell_id"]),
+        ToolName::TodoWrite => {
+            let todo = object(
+                &[
+                    ("content", min_length(1)),
+                    ("status", one_of(&["pending", "in_progress", "completed"])),
+                    ("activeForm", min_length(1)),
+                ],
+                &["content", "status", "activeForm"],
+            );
+            object(&[("todos", array(todo, None, None))], &["todos"])
+        }
+        ToolName::EnterPlanMode | ToolName::TaskList => object(&[], &[]),
+        // Plan mode passes extra keys through (e.g. `plan_content`)
+        ToolName::ExitPlanMode => json!({
+            "type": "object",
+            "properties": { "plan": string() },
+        }),
+        ToolName::AskUserQuestion => {
+            let option = object(
+                &[("label", string()), ("description", string())],
+                &["label", "description"],
+            );
+            let question = object(
+                &[
+                    ("question", string()),
+                    ("header", string()),
+                    ("options", array(option, Some(2), Some(4))),
+                    ("multiSelect", boolean()),
+                ],
+                &["question", "header", "options", "multiSelect"],
+            );
+            object(
+                &[
+                    ("questions", array(question, Some(1), Some(4))),
+                    (
+                        "answers",
+                        json!({ "type": "object", "additionalProperties": string() }),
+                    ),
+                ],
+                &["questions"],
+            )
+        }
+        ToolName::TaskCreate => object(
+            &[
+                ("subject", string()),
+                ("description", string()),
+                ("activeForm", string()),
+                ("metadata", any_object()),
+            ],
+            &["subject", "description"],
+        ),
+        ToolName::TaskGet => object(&[("taskId", string())], &["taskId"]),
+        ToolName::TaskUpdate => object(
+            &[
+                ("taskId", string()),
+                ("subject", string()),
+                ("description", string()),
+                ("activeForm", string()),
+                (
+                    "status",
+                    one_of(&["pending", "in_progress", "completed", "deleted"]),
+                ),
+                ("addBlocks", array(string(), None, None)),
+                ("addBlockedBy", array(string(), None, None)),
+                ("owner", string()),
+                ("metadata", any_object()),
+            ],
+            &["taskId"],
+        ),
+        ToolName::WebFetch => object(
+            &[("url", string()), ("prompt", string())],
+            &["url", "prompt"],
+        ),
+        ToolName::WebSearch => object(
+            &[
+                ("query", min_length(2)),
+                ("allowed_domains", array(string(), None, None)),
+                ("blocked_domains", array(string(), None, None)),
+            ],
+            &["query"],
+        ),
+        ToolName::NotebookEdit => object(
+            &[
+                ("notebook_path", string()),
+                ("cell_id", string()),
+                ("new_source", string()),
+                ("cell_type", one_of(&["code", "markdown"])),
+                ("edit_mode", one_of(&["replace", "insert", "delete"])),
+            ],
+            &["notebook_path", "new_source"],
+        ),
+        ToolName::Task => object(
+            &[
+                ("description", string()),
+                ("prompt", string()),
+                ("subagent_type", string()),
+                ("model", one_of(&["sonnet", "opus", "haiku"])),
+                ("resume", string()),
+                ("run_in_background", boolean()),
+            ],
+            &["description", "prompt", "subagent_type"],
+        ),
+        ToolName::Skill => object(&[("skill", string()), ("args", string())], &["skill"]),
+    }
+}
+
+/// The error result for a built-in call whose input does not match its
+/// tool's schema.
+///
+/// Calls with a canned `result` are checked too, so scenarios can exercise
+/// validation errors without running the tool.
+pub fn input_error(call: &ToolCallSpec, tool_use_id: &str) -> Option<ToolExecutionResult> {
+    let tool = ToolName::parse(&call.tool)?;
+    let message = validate_input(tool, &call.input).err()?;
+    Some(ToolExecutionResult::error(
+        tool_use_id,
+        format!("<tool_use_error>{}</tool_use_error>", message),
+    ))
+}
+
+/// Check a built-in tool's input against its schema.
+///
+/// Returns the `InputValidationError` message the real CLI reports for
+/// invalid input.
+pub fn validate_input(tool: ToolName, input: &Value) -> Result<(), String> {
+    let mut issues = Vec::new();
+    check(&input_schema(tool), input, &mut Vec::new(), &mut issues);
+    if issues.is_empty() {
+        return Ok(());
+    }
+
+    let summary: Vec<String> = issues.iter().filter_map(Issue::summary).collect();
+    let message = if summary.is_empty() {
+        issues_json(&issues)
+    } else {
+        format!(
+            "{} failed due to the following {}:\n{}",
+            tool,
+            if summary.len() > 1 { "issues" } else { "issue" },
+            summary.join("\n")
+        )
+    };
+    Err(format!("InputValidationError: {}", message))
+}
+
+fn string() -> Value {
+    json!({ "type": "string" })
+}
+
+fn min_length(min: usize) -> Value {
+    json!({ "type": "string", "minLength": min })
+}
+
+fn number() -> Value {
+    json!({ "type": "number" })
+}
+
+fn boolean() -> Value {
+    json!({ "type": "boolean" })
+}
+
+fn one_of(values: &[&str]) -> Value {
+    json!({ "type": "string", "enum": values })
+}
+
+fn any_object() -> Value {
+    json!({ "type": "object" })
+}
+
+fn array(items: Value, min: Option<usize>, max: Option<usize>) -> Value {
+    let mut schema = json!({ "type": "array", "items": items });
+    if let Some(min) = min {
+        schema["minItems"] = json!(min);
+    }
+    if let Some(max) = max {
+        schema["maxItems"] = json!(max);
+    }
+    schema
+}
+
+/// A closed object: keys outside `properties` are rejected.
+fn object(properties: &[(&str, Value)], required: &[&str]) -> Value {
+    let properties: Map<String, Value> = properties
+        .iter()
+        .map(|(name, schema)| (name.to_string(), schema.clone()))
+        .collect();
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+/// One zod validation issue, as its JSON fields in zod's key order.
+struct Issue {
+    code: &'static str,
+    fields: Vec<(&'static str, Value)>,
+}
+
+impl Issue {
+    fn field(&self, key: &str) -> Option<&Value> {
+        self.fields.iter().find(|(k, _)| *k == key).map(|(_, v)| v)
+    }
+
+    /// Dotted path of the parameter the issue is about.
+    fn param(&self) -> String {
+        let path = self.field("path").and_then(Value::as_array);
+        path.into_iter()
+            .flatten()
+            .map(|segment| match segment {
+                Value::String(s) => s.clone(),
+                other => other.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    /// The summary line for missing, unexpected and mistyped parameters.
+    fn summary(&self) -> Option<String> {
+        let text = |key| self.field(key).and_then(Value::as_str).unwrap_or_default();
+        match self.code {
+            "invalid_type" if text("received") == "undefined" => Some(format!(
+                "The required parameter `{}` is missing",
+                self.param()
+            )),
+            "invalid_type" => Some(format!(
+                "The parameter `{}` type is expected as `{}` but provided as `{}`",
+                self.param(),
+                text("expected"),
+                text("received")
+            )),
+            "unrecognized_keys" => {
+                let keys = self.field("keys").and_then(Value::as_array);
+                let lines: Vec<String> = keys
+                    .into_iter()
+                    .flatten()
+                    .filter_map(Value::as_str)
+                    .map(|key| format!("An unexpected parameter `{}` was provided", key))
+                    .collect();
+                Some(lines.join("\n"))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Check `value` against `schema`, adding any issues found at `path`.
+fn check(schema: &Value, value: &Value, path: &mut Vec<Value>, issues: &mut Vec<Issue>) {
+    let expected = expected_type(schema);
+    if !expected.is_empty() && !has_type(schema, value) {
+        issues.push(invalid_type(&expected, parsed_type(value), path));
+        return;
+    }
+
+    if let Some(options) = schema.get("enum").and_then(Value::as_array) {
+        if !options.contains(value) {
+            issues.push(Issue {
+                code: "invalid_enum_value",
+                fields: vec![
+                    ("received", value.clone()),
+                    ("code", json!("invalid_enum_value")),
+                    ("options", Value::Array(options.clone())),
+                    ("path", json!(path)),
+                    (
+                        "message",
+                        json!(format!(
+                            "Invalid enum value. Expected {}, received '{}'",
+                            join_values(options),
+                            value.as_str().unwrap_or_default()
+                        )),
+                    ),
+                ],
+            });
+        }
+        return;
+    }
+
+    match value {
+        Value::String(s) => {
+            if let Some(min) = schema.get("minLength").and_then(Value::as_u64) {
+                if (s.chars().count() as u64) < min {
+                    issues.push(size_issue("too_small", "string", min, path));
+                }
+            }
+        }
+        Value::Array(items) => {
+            let len = items.len() as u64;
+            if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+                if len < min {
+                    issues.push(size_issue("too_small", "array", min, path));
+                }
+            }
+            if let Some(max) = schema.get("maxItems").and_then(Value::as_u64) {
+                if len > max {
+                    issues.push(size_issue("too_big", "array", max, path));
+                }
+            }
+            if let Some(item_schema) = schema.get("items") {
+                for (i, item) in items.iter().enumerate() {
+                    path.push(json!(i));
+                    check(item_schema, item, path, issues);
+                    path.pop();
+                }
+            }
+        }
+        Value::Object(fields) => check_object(schema, fields, path, issues),
+        _ => {}
+    }
+}
+
+fn check_object(
+    schema: &Value,
+    fields: &Map<String, Value>,
+    path: &mut Vec<Value>,
+    issues: &mut Vec<Issue>,
+) {
+    let empty = Map::new();
+    let properties = schema
+        .get("properties")
+        .and_then(Value::as_object)
+        .unwrap_or(&empty);
+    let required = |name: &str| {
+        schema
+            .get("required")
+            .and_then(Value::as_array)
+            .is_some_and(|required| required.iter().any(|r| r == name))
+    };
+
+    for (name, property) in properties {
+        path.push(json!(name));
+        match fields.get(name) {
+            Some(value) => check(property, value, path, issues),
+            None if required(name) => issues.push(Issue {
+                code: "invalid_type",
+                fields: vec![
+                    ("code", json!("invalid_type")),
+                    ("expected", json!(expected_type(property))),
+                    ("received", json!("undefined")),
+                    ("path", json!(path)),
+                    ("message", json!("Required")),
+                ],
+            }),
+            None => {}
+        }
+        path.pop();
+    }
+
+    let extra = fields
+        .iter()
+        .filter(|(name, _)| !properties.contains_key(*name));
+    match schema.get("additionalProperties") {
+        Some(Value::Bool(false)) => {
+            let keys: Vec<&String> = extra.map(|(name, _)| name).collect();
+            if !keys.is_empty() {
+                let quoted: Vec<String> = keys.iter().map(|key| format!("'{}'", key)).collect();
+                issues.push(Issue {
+                    code: "unrecognized_keys",
+                    fields: vec![
+                        ("code", json!("unrecognized_keys")),
+                        ("keys", json!(keys)),
+                        ("path", json!(path)),
+                        (
+                            "message",
+                            json!(format!(
+                                "Unrecognized key(s) in object: {}",
+                                quoted.join(", ")
+                            )),
+                        ),
+                    ],
+                });
+            }
+        }
+        Some(value_schema @ Value::Object(_)) => {
+            for (name, value) in extra {
+                path.push(json!(name));
+                check(value_schema, value, path, issues);
+                path.pop();
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The type zod reports as expected: the enum values, or the JSON type.
+fn expected_type(schema: &Value) -> String {
+    if let Some(options) = schema.get("enum").and_then(Value::as_array) {
+        return join_values(options);
+    }
+    schema
+        .get("type")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn has_type(schema: &Value, value: &Value) -> bool {
+    match schema.get("type").and_then(Value::as_str) {
+        Some("string") => value.is_string(),
+        Some("number") => value.is_number(),
+        Some("boolean") => value.is_boolean(),
+        Some("array") => value.is_array(),
+        Some("object") => value.is_object(),
+        _ => true,
+    }
+}
+
+/// Zod's name for the type of `value`.
+fn parsed_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn join_values(options: &[Value]) -> String {
+    options
+        .iter()
+        .map(|option| format!("'{}'", option.as_str().unwrap_or_default()))
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
+
+fn invalid_type(expected: &str, received: &str, path: &[Value]) -> Issue {
+    Issue {
+        code: "invalid_type",
+        fields: vec![
+            ("code", json!("invalid_type")),
+            ("expected", json!(expected)),
+            ("received", json!(received)),
+            ("path", json!(path)),
+            (
+                "message",
+                json!(format!("Expected {}, received {}", expected, received)),
+            ),
+        ],
+    }
+}
+
+fn size_issue(code: &'static str, kind: &str, limit: u64, path: &[Value]) -> Issue {
+    let (bound, relation) = match code {
+        "too_small" => ("minimum", "at least"),
+        _ => ("maximum", "at most"),
+    };
+    let (noun, unit) = match kind {
+        "string" => ("String", "character(s)"),
+        _ => ("Array", "element(s)"),
+    };
+    Issue {
+        code,
+        fields: vec![
+            ("code", json!(code)),
+            (bound, json!(limit)),
+            ("type", json!(kind)),
+            ("inclusive", json!(true)),
+            ("exact", json!(false)),
+            (
+                "message",
+                json!(format!(
+                    "{} must contain {} {} {}",
+                    noun, relation, limit, unit
+                )),
+            ),
+            ("path", json!(path)),
+        ],
+    }
+}
+
+/// The issues as zod prints them: `JSON.stringify(issues, null, 2)`.
+fn issues_json(issues: &[Issue]) -> String {
+    let objects: Vec<String> = issues
+        .iter()
+        .map(|issue| {
+            let fields: Vec<String> = issue
+                .fields
+                .iter()
+                .map(|(key, value)| {
+                    let value = serde_json::to_string_pretty(value)
+                        .unwrap_or_default()
+                        .replace('\n', "\n    ");
+                    format!("    \"{}\": {}", key, value)
+                })
+                .collect();
+            format!("  {{\n{}\n  }}", fields.join(",\n"))
+        })
+        .collect();
+    format!("[\n{}\n]", objects.join(",\n"))
+}
+
+#[cfg(test)]
+#[path = "schema_tests.rs"]
+mod tests;
@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+use yare::parameterized;
+
+#[parameterized(
+    bash = { ToolName::Bash, json!({ "command": "ls", "timeout": 5000, "run_in_background": false }) },
+    read = { ToolName::Read, json!({ "file_path": "/tmp/a", "offset": 1, "limit": 10 }) },
+    multi_edit = { ToolName::MultiEdit, json!({ "file_path": "a", "edits": [{ "old_string": "a", "new_string": "b" }] }) },
+    grep = { ToolName::Grep, json!({ "pattern": "x", "output_mode": "count", "-i": true }) },
+    todo_write = { ToolName::TodoWrite, json!({ "todos": [{ "content": "Do", "status": "pending", "activeForm": "Doing" }] }) },
+    exit_plan_mode = { ToolName::ExitPlanMode, json!({ "plan_content": "# Plan" }) },
+    task_list = { ToolName::TaskList, json!({}) },
+    task_metadata = { ToolName::TaskCreate, json!({ "subject": "s", "description": "d", "metadata": { "any": 1 } }) },
+)]
+fn accepts_valid_input(tool: ToolName, input: Value) {
+    assert_eq!(validate_input(tool, &input), Ok(()));
+}
+
+#[parameterized(
+    missing = {
+        ToolName::Bash, json!({}),
+        "Bash failed due to the following issue:\nThe required parameter `command` is missing"
+    },
+    unexpected = {
+        ToolName::Read, json!({ "file_path": "a", "path": "a" }),
+        "Read failed due to the following issue:\nAn unexpected parameter `path` was provided"
+    },
+    wrong_type = {
+        ToolName::Bash, json!({ "command": "ls", "timeout": "5s" }),
+        "Bash failed due to the following issue:\nThe parameter `timeout` type is expected as `number` but provided as `string`"
+    },
+    nested = {
+        ToolName::MultiEdit, json!({ "file_path": "a", "edits": [{ "old_string": "a" }] }),
+        "MultiEdit failed due to the following issue:\nThe required parameter `edits.0.new_string` is missing"
+    },
+    several = {
+        ToolName::Write, json!({ "path": "a", "content": 1 }),
+        "Write failed due to the following issues:\nThe required parameter `file_path` is missing\nThe parameter `content` type is expected as `string` but provided as `number`\nAn unexpected parameter `path` was provided"
+    },
+    declared_order = {
+        ToolName::Read, json!({ "file_path": "a", "offset": "1", "limit": "2" }),
+        "Read failed due to the following issues:\nThe parameter `offset` type is expected as `number` but provided as `string`\nThe parameter `limit` type is expected as `number` but provided as `string`"
+    },
+)]
+fn summarizes_parameter_issues(tool: ToolName, input: Value, expected: &str) {
+    assert_eq!(
+        validate_input(tool, &input),
+        Err(format!("InputValidationError: {}", expected))
+    );
+}
+
+#[test]
+fn lists_other_issues_as_zod_json() {
+    let input = json!({ "pattern": "x", "output_mode": "lines" });
+
+    let err = validate_input(ToolName::Grep, &input).unwrap_err();
+
+    assert_eq!(
+        err,
+        r#"InputValidationError: [
+  {
+    "received": "lines",
+    "code": "invalid_enum_value",
+    "options": [
+      "content",
+      "files_with_matches",
+      "count"
+    ],
+    "path": [
+      "output_mode"
+    ],
+    "message": "Invalid enum value. Expected 'content' | 'files_with_matches' | 'count', received 'lines'"
+  }
+]"#
+    );
+}
+
+#[test]
+fn checks_array_lengths() {
+    let input = json!({ "file_path": "a", "edits": [] });
+
+    let err = validate_input(ToolName::MultiEdit, &input).unwrap_err();
+
+    assert!(err.contains("\"code\": \"too_small\""));
+    assert!(err.contains("Array must contain at least 1 element(s)"));
+}
+
+#[test]
+fn schemas_are_closed_objects() {
+    let schema = input_schema(ToolName::Edit);
+
+    assert_eq!(schema["type"], "object");
+    assert_eq!(schema["additionalProperties"], false);
+    assert_eq!(
+        schema["required"],
+        json!(["file_path", "old_string", "new_string"])
+    );
+    assert_eq!(schema["properties"]["replace_all"]["type"], "boolean");
+}
@@ -19,14 +19,21 @@ pub enum ToolName {
     Read,
     Write,
     Edit,
+    MultiEdit,
     Glob,
     Grep,
+    BashOutput,
+    KillShell,
 
     // Stateful tools
     TodoWrite,
     EnterPlanMode,
     ExitPlanMode,
     AskUserQuestion,
+    TaskCreate,
+    TaskGet,
+    TaskUpdate,
+    TaskList,
 
     // Network tools
     WebFetch,
@@ -35,6 +42,7 @@ pub enum ToolName {
     // Other
     NotebookEdit,
     Task,
+    Skill,
 }
 
 impl ToolName {
@@ -45,16 +53,24 @@ impl ToolName {
             Self::Read => "Read",
             Self::Write => "Write",
             Self::Edit => "Edit",
+            Self::MultiEdit => "MultiEdit",
             Self::Glob => "Glob",
             Self::Grep => "Grep",
+            Self::BashOutput => "BashOutput",
+            Self::KillShell => "KillShell",
             Self::TodoWrite => "TodoWrite",
             Self::EnterPlanMode => "EnterPlanMode",
             Self::ExitPlanMode => "ExitPlanMode",
             Self::AskUserQuestion => "AskUserQuestion",
+            Self::TaskCreate => "TaskCreate",
+            Self::TaskGet => "TaskGet",
+            Self::TaskUpdate => "TaskUpdate",
+            Self::TaskList => "TaskList",
             Self::WebFetch => "WebFetch",
             Self::WebSearch => "WebSearch",
             Self::NotebookEdit => "NotebookEdit",
             Self::Task => "Task",
+            Self::Skill => "Skill",
         }
     }
 
@@ -65,16 +81,24 @@ impl ToolName {
             "Read" => Some(Self::Read),
             "Write" => Some(Self::Write),
             "Edit" => Some(Self::Edit),
+            "MultiEdit" => Some(Self::MultiEdit),
             "Glob" => Some(Self::Glob),
             "Grep" => Some(Self::Grep),
+            "BashOutput" => Some(Self::BashOutput),
+            "KillShell" => Some(Self::KillShell),
             "TodoWrite" => Some(Self::TodoWrite),
             "EnterPlanMode" => Some(Self::EnterPlanMode),
             "ExitPlanMode" => Some(Self::ExitPlanMode),
             "AskUserQuestion" => Some(Self::AskUserQuestion),
+            "TaskCreate" => Some(Self::TaskCreate),
+            "TaskGet" => Some(Self::TaskGet),
+            "TaskUpdate" => Some(Self::TaskUpdate),
+            "TaskList" => Some(Self::TaskList),
             "WebFetch" => Some(Self::WebFetch),
             "WebSearch" => Some(Self::WebSearch),
             "NotebookEdit" => Some(Self::NotebookEdit),
             "Task" => Some(Self::Task),
+            "Skill" => Some(Self::Skill),
             _ => None,
         }
     }
@@ -82,16 +106,31 @@ impl ToolName {
     /// Get the permission action for this tool.
     pub const fn action(&self) -> &'static str {
         match self {
-            Self::Bash => "execute",
-            Self::Read | Self::Glob | Self::Grep => "read",
-            Self::Write | Self::Edit | Self::NotebookEdit => "write",
+            Self::Bash | Self::KillShell => "execute",
+            Self::Read | Self::Glob | Self::Grep | Self::BashOutput => "read",
+            Self::Write | Self::Edit | Self::MultiEdit | Self::NotebookEdit => "write",
             Self::WebFetch | Self::WebSearch => "network",
             Self::Task => "delegate",
-            Self::TodoWrite | Self::EnterPlanMode | Self::ExitPlanMode | Self::AskUserQuestion => {
-                "state"
-            }
+            Self::TodoWrite
+            | Self::EnterPlanMode
+            | Self::ExitPlanMode
+            | Self::AskUserQuestion
+            | Self::TaskCreate
+            | Self::TaskGet
+            | Self::TaskUpdate
+            | Self::TaskList
+            | Self::Skill => "state",
         }
     }
+
+    /// Whether calls to this tool may run at the same time as other
+    /// concurrency-safe calls. Only tools that change nothing qualify.
+    pub const fn is_concurrency_safe(&self) -> bool {
+        matches!(
+            self,
+            Self::Read | Self::Glob | Self::Grep | Self::WebFetch | Self::WebSearch
+        )
+    }
 }
 
 impl fmt::Display for ToolName {
@@ -9,8 +9,11 @@ fn as_str_returns_correct_string() {
     assert_eq!(ToolName::Read.as_str(), "Read");
     assert_eq!(ToolName::Write.as_str(), "Write");
     assert_eq!(ToolName::Edit.as_str(), "Edit");
+    assert_eq!(ToolName::MultiEdit.as_str(), "MultiEdit");
     assert_eq!(ToolName::Glob.as_str(), "Glob");
     assert_eq!(ToolName::Grep.as_str(), "Grep");
+    assert_eq!(ToolName::BashOutput.as_str(), "BashOutput");
+    assert_eq!(ToolName::KillShell.as_str(), "KillShell");
     assert_eq!(ToolName::TodoWrite.as_str(), "TodoWrite");
     assert_eq!(ToolName::ExitPlanMode.as_str(), "ExitPlanMode");
     assert_eq!(ToolName::WebFetch.as_str(), "WebFetch");
@@ -18,6 +21,8 @@ fn as_str_returns_correct_string() {
     assert_eq!(ToolName::NotebookEdit.as_str(), "NotebookEdit");
     assert_eq!(ToolName::Task.as_str(), "Task");
     assert_eq!(ToolName::AskUserQuestion.as_str(), "AskUserQuestion");
+    assert_eq!(ToolName::TaskCreate.as_str(), "TaskCreate");
+    assert_eq!(ToolName::TaskList.as_str(), "TaskList");
 }
 
 #[test]
@@ -26,8 +31,11 @@ fn parse_parses_valid_names() {
     assert_eq!(ToolName::parse("Read"), Some(ToolName::Read));
     assert_eq!(ToolName::parse("Write"), Some(ToolName::Write));
     assert_eq!(ToolName::parse("Edit"), Some(ToolName::Edit));
+    assert_eq!(ToolName::parse("MultiEdit"), Some(ToolName::MultiEdit));
     assert_eq!(ToolName::parse("Glob"), Some(ToolName::Glob));
     assert_eq!(ToolName::parse("Grep"), Some(ToolName::Grep));
+    assert_eq!(ToolName::parse("BashOutput"), Some(ToolName::BashOutput));
+    assert_eq!(ToolName::parse("KillShell"), Some(ToolName::KillShell));
     assert_eq!(ToolName::parse("TodoWrite"), Some(ToolName::TodoWrite));
     assert_eq!(
         ToolName::parse("ExitPlanMode"),
@@ -40,6 +48,7 @@ fn parse_parses_valid_names() {
         Some(ToolName::NotebookEdit)
     );
     assert_eq!(ToolName::parse("Task"), Some(ToolName::Task));
+    assert_eq!(ToolName::parse("Skill"), Some(ToolName::Skill));
     assert_eq!(
         ToolName::parse("AskUserQuestion"),
         Some(ToolName::AskUserQuestion)
@@ -59,8 +68,11 @@ fn action_returns_correct_permission_type() {
     assert_eq!(ToolName::Read.action(), "read");
     assert_eq!(ToolName::Glob.action(), "read");
     assert_eq!(ToolName::Grep.action(), "read");
+    assert_eq!(ToolName::BashOutput.action(), "read");
+    assert_eq!(ToolName::KillShell.action(), "execute");
     assert_eq!(ToolName::Write.action(), "write");
     assert_eq!(ToolName::Edit.action(), "write");
+    assert_eq!(ToolName::MultiEdit.action(), "write");
     assert_eq!(ToolName::NotebookEdit.action(), "write");
     assert_eq!(ToolName::WebFetch.action(), "network");
     assert_eq!(ToolName::WebSearch.action(), "network");
@@ -68,6 +80,11 @@ fn action_returns_correct_permission_type() {
     assert_eq!(ToolName::TodoWrite.action(), "state");
     assert_eq!(ToolName::ExitPlanMode.action(), "state");
     assert_eq!(ToolName::AskUserQuestion.action(), "state");
+    assert_eq!(ToolName::TaskCreate.action(), "state");
+    assert_eq!(ToolName::TaskGet.action(), "state");
+    assert_eq!(ToolName::TaskUpdate.action(), "state");
+    assert_eq!(ToolName::TaskList.action(), "state");
+    assert_eq!(ToolName::Skill.action(), "state");
 }
 
 #[test]
@@ -76,6 +93,20 @@ fn display_impl() {
     assert_eq!(format!("{}", ToolName::TodoWrite), "TodoWrite");
 }
 
+#[test]
+fn only_tools_that_change_nothing_are_concurrency_safe() {
+    assert!(ToolName::Read.is_concurrency_safe());
+    assert!(ToolName::Glob.is_concurrency_safe());
+    assert!(ToolName::Grep.is_concurrency_safe());
+    assert!(ToolName::WebFetch.is_concurrency_safe());
+    assert!(ToolName::WebSearch.is_concurrency_safe());
+    assert!(!ToolName::Bash.is_concurrency_safe());
+    assert!(!ToolName::BashOutput.is_concurrency_safe());
+    assert!(!ToolName::Write.is_concurrency_safe());
+    assert!(!ToolName::Task.is_concurrency_safe());
+    assert!(!ToolName::AskUserQuestion.is_concurrency_safe());
+}
+
 #[test]
 fn roundtrip_all_variants() {
     let variants = [
@@ -83,6 +114,7 @@ fn roundtrip_all_variants() {
         ToolName::Read,
         ToolName::Write,
         ToolName::Edit,
+        ToolName::MultiEdit,
         ToolName::Glob,
         ToolName::Grep,
         ToolName::TodoWrite,
@@ -92,6 +124,11 @@ fn roundtrip_all_variants() {
         ToolName::NotebookEdit,
         ToolName::Task,
         ToolName::AskUserQuestion,
+        ToolName::TaskCreate,
+        ToolName::TaskGet,
+        ToolName::TaskUpdate,
+        ToolName::TaskList,
+        ToolName::Skill,
     ];
     for variant in variants {
         let s = variant.as_str();
@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! In-memory filesystem for the `vfs` tool execution mode.
+//!
+//! Read, Write, Edit, Glob and Grep operate on a tree of files held in
+//! memory instead of the disk. The tree is seeded from the scenario and can
+//! be exported or compared with expected files when the session ends.
+//! Files hold raw bytes, so binary fixtures such as images can be read.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use glob::{MatchOptions, Pattern, PatternError};
+use parking_lot::RwLock;
+
+use super::sandbox::normalize;
+
+/// `ENOENT`, so missing files report like the disk does.
+const NOT_FOUND: i32 = 2;
+/// `EISDIR`, for reading or writing a path that is a directory.
+const IS_A_DIRECTORY: i32 = 21;
+
+/// In-memory file tree keyed by absolute path.
+#[derive(Debug)]
+pub struct Vfs {
+    /// Directory that relative paths resolve against.
+    cwd: PathBuf,
+    /// File contents by normalized absolute path.
+    files: RwLock<BTreeMap<PathBuf, Vec<u8>>>,
+}
+
+impl Vfs {
+    /// Create an empty tree rooted at the given working directory.
+    pub fn new(cwd: impl Into<PathBuf>) -> Self {
+        Self {
+            cwd: normalize(&cwd.into()),
+            files: RwLock::new(BTreeMap::new()),
+        }
+    }
+
+    /// Working directory for relative paths.
+    pub fn cwd(&self) -> &Path {
+        &self.cwd
+    }
+
+    /// Resolve a tool path to a normalized absolute path.
+    pub fn resolve(&self, path: &str, cwd: Option<&Path>) -> PathBuf {
+        normalize(&cwd.unwrap_or(&self.cwd).join(path))
+    }
+
+    /// Add a file, resolving relative paths against the working directory.
+    pub fn insert(&self, path: &str, content: impl Into<Vec<u8>>) {
+        let path = self.resolve(path, None);
+        self.files.write().insert(path, content.into());
+    }
+
+    /// Seed the tree with every file under `dir`, placed under the working directory.
+    pub fn seed_dir(&self, dir: &Path) -> io::Result<()> {
+        let mut dirs = vec![dir.to_path_buf()];
+        while let Some(current) = dirs.pop() {
+            for entry in fs::read_dir(&current)? {
+                let entry = entry?;
+                let path = entry.path();
+                if entry.file_type()?.is_dir() {
+                    dirs.push(path);
+                } else if let Ok(rel) = path.strip_prefix(dir) {
+                    let content = fs::read(&path)?;
+                    self.files.write().insert(self.cwd.join(rel), content);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether a file exists at `path`.
+    pub fn is_file(&self, path: &Path) -> bool {
+        self.files.read().contains_key(path)
+    }
+
+    /// Whether `path` is a directory, i.e. some file lives beneath it.
+    pub fn is_dir(&self, path: &Path) -> bool {
+        self.files
+            .read()
+            .keys()
+            .any(|file| file != path && file.starts_with(path))
+    }
+
+    /// Read a file's bytes.
+    pub fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        if let Some(content) = self.files.read().get(path) {
+            return Ok(content.clone());
+        }
+        if self.is_dir(path) {
+            return Err(io::Error::from_raw_os_error(IS_A_DIRECTORY));
+        }
+        Err(io::Error::from_raw_os_error(NOT_FOUND))
+    }
+
+    /// Read a file as UTF-8 text, failing like `fs::read_to_string` when it
+    /// is not.
+    pub fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        String::from_utf8(self.read(path)?).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "stream did not contain valid UTF-8",
+            )
+        })
+    }
+
+    /// Create or replace a file. Parent directories are implicit.
+    pub fn write(&self, path: &Path, content: impl AsRef<[u8]>) -> io::Result<()> {
+        if self.is_dir(path) {
+            return Err(io::Error::from_raw_os_error(IS_A_DIRECTORY));
+        }
+        self.files
+            .write()
+            .insert(path.to_path_buf(), content.as_ref().to_vec());
+        Ok(())
+    }
+
+    /// Files at or beneath `path`, in path order.
+    pub fn files_under(&self, path: &Path) -> Vec<PathBuf> {
+        self.files
+            .read()
+            .keys()
+            .filter(|file| file.starts_with(path))
+            .cloned()
+            .collect()
+    }
+
+    /// Files whose absolute path matches a glob pattern.
+    ///
+    /// `*` stays within one path component; `**` crosses directories.
+    pub fn glob(&self, pattern: &str) -> Result<Vec<PathBuf>, PatternError> {
+        let pattern = Pattern::new(pattern)?;
+        let options = MatchOptions {
+            require_literal_separator: true,
+            ..MatchOptions::new()
+        };
+        Ok(self
+            .files
+            .read()
+            .keys()
+            .filter(|file| pattern.matches_path_with(file, options))
+            .cloned()
+            .collect())
+    }
+
+    /// Copy of every file in the tree.
+    pub fn snapshot(&self) -> BTreeMap<PathBuf, Vec<u8>> {
+        self.files.read().clone()
+    }
+
+    /// Write the tree to `dir` on disk.
+    ///
+    /// Files under the working directory keep their relative path; others
+    /// are placed by their absolute path beneath `dir`.
+    pub fn export(&self, dir: &Path) -> io::Result<()> {
+        for (path, content) in self.files.read().iter() {
+            let rel = path
+                .strip_prefix(&self.cwd)
+                .or_else(|_| path.strip_prefix("/"))
+                .unwrap_or(path);
+            let target = dir.join(rel);
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(target, content)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "vfs_tests.rs"]
+mod tests;
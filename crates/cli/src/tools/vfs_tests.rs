@@ -0,0 +1,112 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use tempfile::TempDir;
+
+fn seeded() -> Vfs {
+    let vfs = Vfs::new("/work");
+    vfs.insert("src/main.rs", "fn main() {}");
+    vfs.insert("src/lib.rs", "pub fn run() {}");
+    vfs.insert("/etc/hosts", "127.0.0.1 localhost");
+    vfs
+}
+
+#[test]
+fn insert_resolves_relative_paths_against_cwd() {
+    let vfs = seeded();
+    assert!(vfs.is_file(Path::new("/work/src/main.rs")));
+    assert!(vfs.is_dir(Path::new("/work/src")));
+    assert_eq!(vfs.resolve("../other", None), PathBuf::from("/other"));
+}
+
+#[test]
+fn read_reports_missing_and_directory() {
+    let vfs = seeded();
+    assert_eq!(
+        vfs.read_to_string(Path::new("/work/src/main.rs")).unwrap(),
+        "fn main() {}"
+    );
+
+    let missing = vfs.read(Path::new("/work/nope.rs")).unwrap_err();
+    assert_eq!(missing.kind(), io::ErrorKind::NotFound);
+    assert!(vfs.read(Path::new("/work/src")).is_err());
+}
+
+#[test]
+fn write_creates_and_replaces_files() {
+    let vfs = seeded();
+    vfs.write(Path::new("/work/new/file.txt"), "a").unwrap();
+    vfs.write(Path::new("/work/src/main.rs"), "b").unwrap();
+
+    assert_eq!(vfs.read(Path::new("/work/new/file.txt")).unwrap(), b"a");
+    assert_eq!(vfs.read(Path::new("/work/src/main.rs")).unwrap(), b"b");
+    assert!(vfs.write(Path::new("/work/src"), "c").is_err());
+}
+
+#[test]
+fn glob_respects_path_separators() {
+    let vfs = seeded();
+    vfs.insert("src/nested/deep.rs", "");
+
+    let shallow = vfs.glob("/work/src/*.rs").unwrap();
+    assert_eq!(
+        shallow,
+        vec![
+            PathBuf::from("/work/src/lib.rs"),
+            PathBuf::from("/work/src/main.rs")
+        ]
+    );
+
+    let deep = vfs.glob("/work/**/*.rs").unwrap();
+    assert_eq!(deep.len(), 3);
+}
+
+#[test]
+fn files_under_lists_directory_contents() {
+    let vfs = seeded();
+    assert_eq!(vfs.files_under(Path::new("/work")).len(), 2);
+    assert_eq!(vfs.files_under(Path::new("/work/src/lib.rs")).len(), 1);
+}
+
+#[test]
+fn seed_dir_and_export_round_trip() {
+    let fixture = TempDir::new().unwrap();
+    fs::create_dir(fixture.path().join("src")).unwrap();
+    fs::write(fixture.path().join("src/main.rs"), "fn main() {}").unwrap();
+
+    let vfs = Vfs::new("/work");
+    vfs.seed_dir(fixture.path()).unwrap();
+    assert!(vfs.is_file(Path::new("/work/src/main.rs")));
+
+    vfs.insert("/etc/hosts", "localhost");
+    let out = TempDir::new().unwrap();
+    vfs.export(out.path()).unwrap();
+    assert_eq!(
+        fs::read_to_string(out.path().join("src/main.rs")).unwrap(),
+        "fn main() {}"
+    );
+    assert_eq!(
+        fs::read_to_string(out.path().join("etc/hosts")).unwrap(),
+        "localhost"
+    );
+}
+
+#[test]
+fn binary_files_keep_their_bytes() {
+    let bytes = vec![0x89, b'P', b'N', b'G', 0xff, 0x00];
+    let fixture = TempDir::new().unwrap();
+    fs::write(fixture.path().join("logo.png"), &bytes).unwrap();
+
+    let vfs = Vfs::new("/work");
+    vfs.seed_dir(fixture.path()).unwrap();
+
+    let path = Path::new("/work/logo.png");
+    assert_eq!(vfs.read(path).unwrap(), bytes);
+    let err = vfs.read_to_string(path).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+    let out = TempDir::new().unwrap();
+    vfs.export(out.path()).unwrap();
+    assert_eq!(fs::read(out.path().join("logo.png")).unwrap(), bytes);
+}
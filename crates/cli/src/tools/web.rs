@@ -0,0 +1,207 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Offline web for WebFetch and WebSearch.
+//!
+//! Pages and search results come from the scenario's `[web]` fixtures.
+//! With `web.localhost = true`, `http://localhost` URLs that have no page
+//! fixture are fetched from a local server, so a test can stand up its own
+//! HTTP stand-in.
+
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::time::Duration;
+
+use url::Url;
+
+use crate::config::{WebConfig, WebPageSpec, WebSearchSpec};
+
+/// How long a localhost fetch may take to connect, send and receive.
+const LOCALHOST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// One HTTP response, before any redirect is followed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WebResponse {
+    pub status: u16,
+    pub content_type: String,
+    pub body: String,
+    /// Target of a redirect response.
+    pub location: Option<String>,
+}
+
+impl WebResponse {
+    /// Reason phrase for the status code.
+    pub fn status_text(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            201 => "Created",
+            204 => "No Content",
+            301 => "Moved Permanently",
+            302 => "Found",
+            303 => "See Other",
+            307 => "Temporary Redirect",
+            308 => "Permanent Redirect",
+            400 => "Bad Request",
+            401 => "Unauthorized",
+            403 => "Forbidden",
+            404 => "Not Found",
+            429 => "Too Many Requests",
+            500 => "Internal Server Error",
+            502 => "Bad Gateway",
+            503 => "Service Unavailable",
+            _ => "",
+        }
+    }
+
+    /// Whether this response redirects elsewhere.
+    pub fn is_redirect(&self) -> bool {
+        (300..400).contains(&self.status) && self.location.is_some()
+    }
+}
+
+/// Web fixtures for the session.
+#[derive(Clone, Debug, Default)]
+pub struct Web {
+    /// Pages keyed by normalized URL.
+    pages: HashMap<String, WebPageSpec>,
+    search: HashMap<String, WebSearchSpec>,
+    localhost: bool,
+}
+
+impl Web {
+    /// Index the scenario's fixtures.
+    pub fn new(config: &WebConfig) -> Self {
+        let pages = config
+            .pages
+            .iter()
+            .map(|(url, page)| (normalize(url), page.clone()))
+            .collect();
+        Self {
+            pages,
+            search: config.search.clone(),
+            localhost: config.localhost,
+        }
+    }
+
+    /// Fetch one URL without following redirects.
+    ///
+    /// An `http` URL without a fixture falls back to its `https` fixture,
+    /// as the real tool upgrades insecure URLs.
+    pub fn fetch(&self, url: &Url) -> Result<WebResponse, String> {
+        let page = self.pages.get(url.as_str()).or_else(|| {
+            if url.scheme() != "http" {
+                return None;
+            }
+            let mut https = url.clone();
+            https.set_scheme("https").ok()?;
+            self.pages.get(https.as_str())
+        });
+        if let Some(page) = page {
+            return Ok(WebResponse {
+                status: page
+                    .status
+                    .unwrap_or(if page.redirect.is_some() { 301 } else { 200 }),
+                content_type: page
+                    .content_type
+                    .clone()
+                    .unwrap_or_else(|| "text/html".to_string()),
+                body: page.body_text(),
+                location: page.redirect.clone(),
+            });
+        }
+
+        if self.localhost && url.scheme() == "http" && is_localhost(url) {
+            return fetch_local(url).map_err(|e| format!("Failed to fetch {}: {}", url, e));
+        }
+        Err(format!(
+            "Failed to fetch {}: no web fixture for this URL",
+            url
+        ))
+    }
+
+    /// Results for a query, if the scenario declares any.
+    pub fn search(&self, query: &str) -> Option<&WebSearchSpec> {
+        self.search.get(query)
+    }
+}
+
+/// The URL as `Url` prints it (e.g. with a trailing `/` for a bare host).
+fn normalize(url: &str) -> String {
+    Url::parse(url)
+        .map(String::from)
+        .unwrap_or_else(|_| url.to_string())
+}
+
+/// Whether the URL points at this machine.
+fn is_localhost(url: &Url) -> bool {
+    matches!(url.host_str(), Some("localhost" | "127.0.0.1" | "[::1]"))
+}
+
+/// GET a URL from a local HTTP server.
+fn fetch_local(url: &Url) -> std::io::Result<WebResponse> {
+    // `localhost` may resolve to both ::1 and 127.0.0.1; use whichever answers
+    let mut last_err = std::io::Error::other("no address");
+    let mut connected = None;
+    for addr in url.socket_addrs(|| Some(80))? {
+        match TcpStream::connect_timeout(&addr, LOCALHOST_TIMEOUT) {
+            Ok(stream) => {
+                connected = Some(stream);
+                break;
+            }
+            Err(e) => last_err = e,
+        }
+    }
+    let mut stream = connected.ok_or(last_err)?;
+    stream.set_read_timeout(Some(LOCALHOST_TIMEOUT))?;
+    stream.set_write_timeout(Some(LOCALHOST_TIMEOUT))?;
+
+    let path = match url.query() {
+        Some(query) => format!("{}?{}", url.path(), query),
+        None => url.path().to_string(),
+    };
+    let host = url.host_str().unwrap_or("localhost");
+    let request = format!(
+        "GET {} HTTP/1.0\r\nHost: {}\r\nAccept: text/markdown, text/html, */*\r\nConnection: close\r\n\r\n",
+        path, host
+    );
+    stream.write_all(request.as_bytes())?;
+
+    let mut raw = Vec::new();
+    stream.read_to_end(&mut raw)?;
+    parse_response(&raw, url).ok_or_else(|| std::io::Error::other("malformed HTTP response"))
+}
+
+/// Parse an HTTP/1.x response; relative `Location`s resolve against `url`.
+fn parse_response(raw: &[u8], url: &Url) -> Option<WebResponse> {
+    let split = raw.windows(4).position(|w| w == b"\r\n\r\n")?;
+    let head = String::from_utf8_lossy(&raw[..split]);
+    let body = String::from_utf8_lossy(&raw[split + 4..]).to_string();
+
+    let mut lines = head.lines();
+    let status = lines.next()?.split_whitespace().nth(1)?.parse().ok()?;
+    let mut content_type = String::new();
+    let mut location = None;
+    for line in lines {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        if name.eq_ignore_ascii_case("content-type") {
+            content_type = value.to_string();
+        } else if name.eq_ignore_ascii_case("location") {
+            location = url.join(value).ok().map(String::from);
+        }
+    }
+
+    Some(WebResponse {
+        status,
+        content_type,
+        body,
+        location,
+    })
+}
+
+#[cfg(test)]
+#[path = "web_tests.rs"]
+mod tests;
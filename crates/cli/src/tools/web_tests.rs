@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+fn web(toml_str: &str) -> Web {
+    Web::new(&toml::from_str(toml_str).unwrap())
+}
+
+fn url(s: &str) -> Url {
+    Url::parse(s).unwrap()
+}
+
+#[test]
+fn fetch_returns_page_fixture() {
+    let web = web(r#"
+[pages."https://example.com"]
+body = "Hello"
+content_type = "text/markdown"
+"#);
+    // The bare host is stored as `https://example.com/`
+    let response = web.fetch(&url("https://example.com/")).unwrap();
+    assert_eq!(response.status, 200);
+    assert_eq!(response.status_text(), "OK");
+    assert_eq!(response.content_type, "text/markdown");
+    assert_eq!(response.body, "Hello");
+    assert!(!response.is_redirect());
+}
+
+#[test]
+fn http_falls_back_to_https_fixture() {
+    let web = web(r#"
+[pages."https://example.com/a"]
+body = "secure"
+"#);
+    let response = web.fetch(&url("http://example.com/a")).unwrap();
+    assert_eq!(response.body, "secure");
+}
+
+#[test]
+fn redirect_defaults_to_moved_permanently() {
+    let web = web(r#"
+[pages."https://example.com/old"]
+redirect = "https://example.com/new"
+"#);
+    let response = web.fetch(&url("https://example.com/old")).unwrap();
+    assert_eq!(response.status, 301);
+    assert!(response.is_redirect());
+    assert_eq!(
+        response.location.as_deref(),
+        Some("https://example.com/new")
+    );
+}
+
+#[test]
+fn missing_fixture_is_an_error() {
+    let web = Web::default();
+    let err = web.fetch(&url("https://example.com/none")).unwrap_err();
+    assert_eq!(
+        err,
+        "Failed to fetch https://example.com/none: no web fixture for this URL"
+    );
+}
+
+#[test]
+fn parse_response_reads_status_headers_and_body() {
+    let raw = b"HTTP/1.1 302 Found\r\nContent-Type: text/plain\r\nLocation: /next\r\n\r\nbody";
+    let response = parse_response(raw, &url("http://localhost:8080/start")).unwrap();
+    assert_eq!(response.status, 302);
+    assert_eq!(response.content_type, "text/plain");
+    assert_eq!(
+        response.location.as_deref(),
+        Some("http://localhost:8080/next")
+    );
+    assert_eq!(response.body, "body");
+}
+
+#[test]
+fn parse_response_rejects_garbage() {
+    assert!(parse_response(b"not http", &url("http://localhost/")).is_none());
+}
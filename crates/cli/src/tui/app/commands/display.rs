@@ -5,11 +5,13 @@
 //!
 //! Contains:
 //! - `wrap_response_paragraph` - Word-wrap text for display
+//! - `format_thinking_display` - Format thinking blocks as a "∴ Thinking…" section
 //! - `format_completed_tool_display` - Format completed tool calls
 //! - `format_tool_call_display` - Format tool calls for permission dialogs
 //! - `tool_call_to_permission_type` - Convert tool calls to permission types
 
 use crate::config::ToolCallSpec;
+use crate::state::ContentBlock;
 use crate::tui::widgets::permission::{DiffKind, DiffLine, PermissionType};
 
 // ============================================================================
@@ -61,6 +63,29 @@ pub(super) fn wrap_response_paragraph(text: &str, terminal_width: usize) -> Stri
     result
 }
 
+/// Format a thinking block as the "∴ Thinking…" section the transcript view
+/// expands it to.
+///
+/// Thinking text is indented under the header; redacted thinking has no
+/// readable content, so only the header is shown.
+pub(super) fn format_thinking_display(
+    block: &ContentBlock,
+    terminal_width: usize,
+) -> Option<String> {
+    match block {
+        ContentBlock::Thinking { thinking, .. } => {
+            let mut display = "\u{2234} Thinking\u{2026}\n".to_string();
+            for line in thinking.lines() {
+                display.push_str("\n  ");
+                display.push_str(&wrap_response_paragraph(line, terminal_width));
+            }
+            Some(display)
+        }
+        ContentBlock::RedactedThinking { .. } => Some("\u{2234} Thinking\u{2026}".to_string()),
+        _ => None,
+    }
+}
+
 /// Join display parts where the first part is unprefixed (gets ⏺ from display layer)
 /// and subsequent parts get their own ⏺ prefix.
 pub(super) fn join_display_parts(parts: &[String]) -> String {
@@ -9,15 +9,18 @@
 
 use crate::hooks::{NOTIFICATION_ELICITATION_DIALOG, NOTIFICATION_IDLE_PROMPT};
 use crate::runtime::TurnResult;
+use crate::state::ContentBlock;
 use crate::tui::spinner;
 use crate::tui::streaming::{StreamingConfig, StreamingResponse};
 use crate::tui::widgets::elicitation::{ElicitationResult, ElicitationState};
 use crate::tui::widgets::permission::PermissionType;
 use crate::tui::widgets::plan_approval::{PlanApprovalResult, PlanApprovalState};
+use crate::tui::widgets::usage_limit::rate_limit_error_block;
+use crate::usage::estimate_tokens;
 
 use super::display::{
-    format_completed_tool_display, format_tool_call_display, join_display_parts,
-    tool_call_to_permission_type, wrap_response_paragraph,
+    format_completed_tool_display, format_thinking_display, format_tool_call_display,
+    join_display_parts, tool_call_to_permission_type, wrap_response_paragraph,
 };
 
 use super::super::state::TuiAppState;
@@ -86,6 +89,24 @@ impl TuiAppState {
             // Add the new user prompt to conversation display
             append_to_conversation(&mut inner, "❯", &prompt);
 
+            // Prompts are rejected while the usage limit is reached
+            if let Some(notice) = inner
+                .config
+                .usage_limit
+                .as_ref()
+                .filter(|limit| limit.blocks_prompts())
+                .map(|limit| limit.notice())
+            {
+                inner.display.response_content.clear();
+                inner
+                    .sessions
+                    .current_session()
+                    .add_turn(prompt, String::new());
+                append_error_block(&mut inner, &notice);
+                restore_input_state(&mut inner);
+                return;
+            }
+
             inner.mode = AppMode::Thinking;
             inner.is_compacting = false;
             inner.display.response_content.clear();
@@ -103,8 +124,9 @@ impl TuiAppState {
             // Drop lock before execution so the render thread can see Thinking mode
         }
 
-        // Use Runtime::execute() for shared agent loop
-        self.execute_with_runtime(prompt);
+        // Use Runtime::execute() for shared agent loop, expanding a custom
+        // slash command into its prompt first
+        self.run_with_runtime(prompt, true);
     }
 
     /// Confirm the elicitation dialog and re-execute with answers.
@@ -254,6 +276,15 @@ impl TuiAppState {
     /// Handles all post-turn actions internally: permission prompts,
     /// notification hooks, and stop hook continuations.
     fn execute_with_runtime(&self, prompt: String) {
+        self.run_with_runtime(prompt, false);
+    }
+
+    /// Execute a prompt, first expanding a custom slash command when
+    /// `expand_command` is set.
+    ///
+    /// A command that fails to expand shows its error under the prompt and
+    /// returns to input without running a turn.
+    fn run_with_runtime(&self, prompt: String, expand_command: bool) {
         // Take the runtime out while holding the lock briefly
         let mut runtime = {
             let mut inner = self.inner.lock();
@@ -270,7 +301,14 @@ impl TuiAppState {
         // Lock is NOT held during this blocking call
         let handle = tokio::runtime::Handle::current();
         let outcome = tokio::task::block_in_place(|| {
-            handle.block_on(async { runtime.execute(&prompt).await })
+            handle.block_on(async {
+                let prompt = if expand_command {
+                    runtime.expand_prompt(&prompt).await?
+                } else {
+                    prompt
+                };
+                Ok::<_, String>(runtime.execute(&prompt).await)
+            })
         });
 
         // Re-acquire lock to put runtime back and handle the result
@@ -279,11 +317,17 @@ impl TuiAppState {
 
         // Handle the outcome (success or failure)
         let action = match outcome {
-            Ok(result) => handle_turn_result(&mut inner, result),
-            Err(failure_spec) => {
+            Ok(Ok(result)) => handle_turn_result(&mut inner, result),
+            Ok(Err(failure_spec)) => {
                 handle_failure(&mut inner, &failure_spec);
                 TurnAction::Done
             }
+            Err(error) => {
+                inner.display.response_content.clear();
+                append_error_block(&mut inner, &error);
+                restore_input_state(&mut inner);
+                return;
+            }
         };
         drop(inner);
 
@@ -324,7 +368,10 @@ fn handle_failure(inner: &mut TuiAppStateInner, failure_spec: &crate::config::Fa
         }
         FailureSpec::AuthError { message } => format!("Error: {}", message),
         FailureSpec::RateLimit { retry_after } => {
-            format!("Error: Rate limited. Retry after {} seconds.", retry_after)
+            // A 429 renders as an error block under the prompt, like the real CLI
+            append_error_block(inner, &rate_limit_error_block(*retry_after));
+            restore_input_state(inner);
+            return;
         }
         FailureSpec::OutOfCredits => "Error: No credits remaining".to_string(),
         FailureSpec::PartialResponse { partial_text } => {
@@ -342,6 +389,8 @@ fn handle_failure(inner: &mut TuiAppStateInner, failure_spec: &crate::config::Fa
 ///
 /// Returns a `TurnAction` indicating what side-effect to fire.
 fn handle_turn_result(inner: &mut TuiAppStateInner, result: TurnResult) -> TurnAction {
+    append_thinking(inner, &result);
+
     // Build display parts from completed tool calls
     let tool_calls = result.response.tool_calls().to_vec();
     let completed_count = result.tool_results.len();
@@ -548,6 +597,35 @@ fn append_to_conversation(inner: &mut TuiAppStateInner, prefix: &str, content: &
         .push_str(&format!("{} {}", prefix, content));
 }
 
+/// Append the turn's thinking sections between the prompt and the response.
+///
+/// Each section shows as one collapsed line until Ctrl+O expands it.
+/// Thinking is billed as output, so it always counts toward the status
+/// token total.
+fn append_thinking(inner: &mut TuiAppStateInner, result: &TurnResult) {
+    let width = inner.display.terminal_width as usize;
+    for block in result.steps.iter().flat_map(|step| &step.thinking) {
+        if let ContentBlock::Thinking { thinking, .. } = block {
+            inner.status.output_tokens += estimate_tokens(thinking);
+        }
+        if let Some(section) = format_thinking_display(block, width) {
+            inner.display.append_thinking(section);
+        }
+    }
+}
+
+/// Append an elbow-connected error block under the latest prompt.
+fn append_error_block(inner: &mut TuiAppStateInner, message: &str) {
+    inner
+        .display
+        .conversation_display
+        .push_str(&format!("\n  \u{23BF}  {}", message));
+
+    if let Some(turn) = inner.sessions.current_session().turns.last_mut() {
+        turn.response = message.to_string();
+    }
+}
+
 /// Set up response display with streaming simulation.
 fn setup_response_display(inner: &mut TuiAppStateInner, response_text: String) {
     inner.mode = AppMode::Responding;
@@ -152,3 +152,22 @@ fn unknown_tool_returns_none() {
 
     assert!(tool_call_to_permission_type(&call).is_none());
 }
+
+#[test]
+fn thinking_display_indents_text_under_header() {
+    let block = crate::state::ContentBlock::Thinking {
+        thinking: "Weighing options".to_string(),
+        signature: "sig".to_string(),
+    };
+    let display = super::format_thinking_display(&block, 80).unwrap();
+    assert_eq!(display, "\u{2234} Thinking\u{2026}\n\n  Weighing options");
+}
+
+#[test]
+fn redacted_thinking_display_shows_header_only() {
+    let block = crate::state::ContentBlock::RedactedThinking {
+        data: "opaque".to_string(),
+    };
+    let display = super::format_thinking_display(&block, 80).unwrap();
+    assert_eq!(display, "\u{2234} Thinking\u{2026}");
+}
@@ -27,10 +27,10 @@ fn resolve_model_id(model: &str) -> String {
 pub(in crate::tui::app) use export::{do_clipboard_export, do_file_export};
 
 use crate::permission::PermissionMode;
+use crate::tui::slash_menu::is_builtin;
 use crate::tui::widgets::context::ContextUsage;
 use crate::tui::widgets::export::ExportDialog;
 use crate::tui::widgets::help::HelpDialog;
-use crate::tui::widgets::tasks::TasksDialog;
 
 use super::state::{DialogState, TuiAppState, TuiAppStateInner};
 use super::types::{AppMode, ExitReason};
@@ -56,8 +56,14 @@ impl TuiAppState {
         }
         inner.input.history_index = None;
 
+        // Custom commands run as prompts; built-in ones take precedence
+        let is_custom_command = inner.runtime.as_ref().is_some_and(|rt| {
+            rt.custom_command(&input)
+                .is_some_and(|command| !is_builtin(&command.name))
+        });
+
         // Check for slash commands (not applicable in shell mode)
-        if !was_shell_mode && input.starts_with('/') {
+        if !was_shell_mode && input.starts_with('/') && !is_custom_command {
             handle_command_inner(&mut inner, &input);
         } else if was_shell_mode {
             // Shell mode: execute command via Bash
@@ -194,8 +200,7 @@ pub(super) fn handle_command_inner(inner: &mut TuiAppStateInner, input: &str) {
             inner.display.response_content = TuiAppState::format_todos(&inner.todos);
         }
         "/tasks" => {
-            inner.mode = AppMode::TasksDialog;
-            inner.dialog = DialogState::Tasks(TasksDialog::new());
+            inner.open_tasks_dialog();
         }
         "/export" => {
             inner.mode = AppMode::ExportDialog;
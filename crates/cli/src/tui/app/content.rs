@@ -77,7 +77,7 @@ pub(crate) fn render_conversation_area(state: &RenderState) -> AnyElement<'stati
 
     // Add conversation display (includes user prompts and past responses)
     if !state.display.conversation_display.is_empty() {
-        content.push_str(&state.display.conversation_display);
+        content.push_str(&state.display.conversation_text());
     }
 
     // Add current response if present
@@ -164,7 +164,7 @@ pub(crate) fn render_slash_menu(state: &RenderState) -> AnyElement<'static> {
     for cmd in menu.filtered_commands[..visible_end].iter() {
         // Format: 2-space indent + /command padded to 24 chars + description
         let cmd_display = format!("/{}", cmd.name);
-        let desc = truncate_with_ellipsis(cmd.description, desc_max);
+        let desc = truncate_with_ellipsis(&cmd.description, desc_max);
         content.push_str(&format!("  {:<24}{}\n", cmd_display, desc));
     }
 
@@ -205,11 +205,33 @@ pub(crate) fn render_stash_indicator(state: &RenderState) -> AnyElement<'static>
     .into()
 }
 
+/// Render usage limit notice if the scenario configures one
+pub(crate) fn render_usage_limit_notice(state: &RenderState) -> AnyElement<'static> {
+    let Some(ref limit) = state.usage_limit else {
+        return element! { View {} }.into();
+    };
+
+    let notice = if state.is_tty {
+        use crate::tui::colors::{escape, LOGO_FG};
+        let accent_fg = escape::fg(LOGO_FG.0, LOGO_FG.1, LOGO_FG.2);
+        format!("  {}{}{}", accent_fg, limit.notice(), escape::RESET)
+    } else {
+        format!("  {}", limit.notice())
+    };
+
+    element! {
+        View(flex_direction: FlexDirection::Column) {
+            Text(content: notice, wrap: TextWrap::NoWrap)
+        }
+    }
+    .into()
+}
+
 /// Get argument hint text for completed slash commands (if any).
 ///
 /// Returns the hint string (e.g., "[open]") to be appended inline after the input,
 /// or None if no hint should be shown.
-pub(crate) fn get_argument_hint(state: &RenderState) -> Option<&'static str> {
+pub(crate) fn get_argument_hint(state: &RenderState) -> Option<&str> {
     // Only show hint when menu is closed and input starts with a completed command
     if state.display.slash_menu.is_some() || !state.input.buffer.starts_with('/') {
         return None;
@@ -220,11 +242,11 @@ pub(crate) fn get_argument_hint(state: &RenderState) -> Option<&'static str> {
     let cmd_name = cmd_text.split_whitespace().next().unwrap_or("");
 
     // Find exact match
-    if let Some(cmd) = COMMANDS.iter().find(|c| c.name == cmd_name) {
-        return cmd.argument_hint;
-    }
-
-    None
+    COMMANDS
+        .iter()
+        .chain(&state.display.custom_commands)
+        .find(|c| c.name == cmd_name)
+        .and_then(|c| c.argument_hint.as_deref())
 }
 
 /// Render animated spinner with text
@@ -50,7 +50,12 @@ pub(crate) fn render_tasks_dialog(dialog: &TasksDialog, width: usize) -> AnyElem
                 } else {
                     "  "
                 };
-                format!("{}{}", indicator, task.description)
+                format!(
+                    "{}{} ({})",
+                    indicator,
+                    task.description,
+                    task.status.label()
+                )
             })
             .collect::<Vec<_>>()
             .join("\n")
@@ -230,6 +235,8 @@ pub(crate) fn render_export_dialog(dialog: &ExportDialog, width: usize) -> AnyEl
 
 impl TuiAppState {
     /// Handle key events in export dialog mode
+    // NOTE(style): `confirm_selection` mutates the dialog, so keep it out of match guards
+    #[allow(clippy::collapsible_match)]
     pub(in crate::tui::app) fn handle_export_dialog_key(&self, key: KeyEvent) {
         let mut inner = self.inner.lock();
 
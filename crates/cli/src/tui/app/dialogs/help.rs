@@ -6,7 +6,7 @@
 use iocraft::prelude::*;
 use unicode_width::UnicodeWidthStr;
 
-use crate::tui::slash_menu::COMMANDS;
+use crate::tui::slash_menu::{SlashCommand, COMMANDS};
 use crate::tui::widgets::help::{HelpDialog, HelpTab};
 
 use crate::tui::app::state::TuiAppState;
@@ -310,7 +310,11 @@ fn write_word_wrapped(
 // ── Help dialog ──────────────────────────────────────────────────────────
 
 /// Render help dialog
-pub(crate) fn render_help_dialog(dialog: &HelpDialog, width: usize) -> AnyElement<'static> {
+pub(crate) fn render_help_dialog(
+    dialog: &HelpDialog,
+    custom_commands: &[SlashCommand],
+    width: usize,
+) -> AnyElement<'static> {
     let inner_width = width.saturating_sub(2);
 
     // Build tab header line
@@ -383,22 +387,8 @@ pub(crate) fn render_help_dialog(dialog: &HelpDialog, width: usize) -> AnyElemen
             .into()
         }
         HelpTab::Commands => {
-            let selected = dialog.commands_selected;
-            let cmd = COMMANDS.get(selected);
-            let next_cmd = COMMANDS.get(selected + 1);
-
-            let selected_line = format!("  ❯ /{}", cmd.map(|c| c.name).unwrap_or(""));
-            let description_line = format!("    {}", cmd.map(|c| c.description).unwrap_or(""));
-            let next_line = if let Some(next) = next_cmd {
-                format!("    /{}", next.name)
-            } else {
-                String::new()
-            };
-            let next_description = if let Some(next) = next_cmd {
-                format!("    {}", next.description)
-            } else {
-                String::new()
-            };
+            let (selected_line, description_line, next_line, next_description) =
+                command_browser_lines(COMMANDS, dialog.commands_selected);
 
             element! {
                 View(flex_direction: FlexDirection::Column, width: 100pct) {
@@ -415,7 +405,7 @@ pub(crate) fn render_help_dialog(dialog: &HelpDialog, width: usize) -> AnyElemen
             }
             .into()
         }
-        HelpTab::CustomCommands => element! {
+        HelpTab::CustomCommands if custom_commands.is_empty() => element! {
             View(flex_direction: FlexDirection::Column, width: 100pct) {
                 Text(content: tab_header)
                 Text(content: "")
@@ -426,17 +416,63 @@ pub(crate) fn render_help_dialog(dialog: &HelpDialog, width: usize) -> AnyElemen
             }
         }
         .into(),
+        HelpTab::CustomCommands => {
+            let (selected_line, description_line, next_line, next_description) =
+                command_browser_lines(custom_commands, dialog.custom_selected);
+
+            element! {
+                View(flex_direction: FlexDirection::Column, width: 100pct) {
+                    Text(content: tab_header)
+                    Text(content: "")
+                    Text(content: "  Browse custom commands:")
+                    Text(content: selected_line)
+                    Text(content: description_line)
+                    Text(content: next_line)
+                    Text(content: next_description)
+                    Text(content: footer)
+                }
+            }
+            .into()
+        }
     }
 }
 
+/// Lines for the selected command and the one after it in a command tab.
+fn command_browser_lines(
+    commands: &[SlashCommand],
+    selected: usize,
+) -> (String, String, String, String) {
+    let cmd = commands.get(selected);
+    let next_cmd = commands.get(selected + 1);
+
+    let selected_line = format!("  ❯ /{}", cmd.map(|c| c.name.as_ref()).unwrap_or(""));
+    let description_line = format!("    {}", cmd.map(|c| c.description.as_ref()).unwrap_or(""));
+    let next_line = if let Some(next) = next_cmd {
+        format!("    /{}", next.name)
+    } else {
+        String::new()
+    };
+    let next_description = if let Some(next) = next_cmd {
+        format!("    {}", next.description)
+    } else {
+        String::new()
+    };
+    (selected_line, description_line, next_line, next_description)
+}
+
 impl TuiAppState {
     /// Handle key events in help dialog mode
     pub(in crate::tui::app) fn handle_help_dialog_key(&self, key: KeyEvent) {
         let mut inner = self.inner.lock();
+        let custom_count = inner.display.custom_commands.len();
 
         let Some(dialog) = inner.dialog.as_help_mut() else {
             return;
         };
+        let total = match dialog.active_tab {
+            HelpTab::CustomCommands => custom_count,
+            _ => COMMANDS.len(),
+        };
 
         match key.code {
             KeyCode::Esc => {
@@ -444,8 +480,8 @@ impl TuiAppState {
             }
             KeyCode::Tab | KeyCode::Right => dialog.next_tab(),
             KeyCode::Left | KeyCode::BackTab => dialog.prev_tab(),
-            KeyCode::Up => dialog.select_prev(COMMANDS.len()),
-            KeyCode::Down => dialog.select_next(COMMANDS.len()),
+            KeyCode::Up => dialog.select_prev(total),
+            KeyCode::Down => dialog.select_next(total),
             _ => {}
         }
     }
@@ -3,10 +3,15 @@
 
 //! Display/rendering state for the TUI application.
 
-use crate::tui::slash_menu::SlashMenuState;
+use std::borrow::Cow;
+
+use crate::tui::slash_menu::{SlashCommand, SlashMenuState};
 
 use crate::tui::app::types::{ExitHint, DEFAULT_TERMINAL_WIDTH};
 
+/// Line a thinking section collapses to in the conversation.
+pub const COLLAPSED_THINKING: &str = "\u{2234} Thinking\u{2026} (ctrl+o to expand)";
+
 /// Display/rendering state
 #[derive(Clone, Debug, Default)]
 pub struct DisplayState {
@@ -20,12 +25,18 @@ pub struct DisplayState {
     pub conversation_display: String,
     /// Whether conversation was compacted
     pub is_compacted: bool,
+    /// Full text of each thinking section, in conversation order
+    pub thinking_sections: Vec<String>,
+    /// Transcript view (Ctrl+O): thinking sections are shown expanded
+    pub show_transcript: bool,
     /// Terminal width
     pub terminal_width: u16,
     /// Show shortcuts panel
     pub show_shortcuts_panel: bool,
     /// Slash menu state
     pub slash_menu: Option<SlashMenuState>,
+    /// Custom commands shown in the slash menu
+    pub custom_commands: Vec<SlashCommand>,
     /// Exit hint
     pub exit_hint: Option<ExitHint>,
     /// When exit hint was shown (milliseconds from clock)
@@ -81,9 +92,45 @@ impl DisplayState {
     /// Clear conversation display
     pub fn clear_conversation(&mut self) {
         self.conversation_display.clear();
+        self.thinking_sections.clear();
         self.is_compacted = false;
     }
 
+    /// Add a thinking section, collapsed until the transcript view is on
+    pub fn append_thinking(&mut self, section: String) {
+        self.append_to_conversation(COLLAPSED_THINKING);
+        self.thinking_sections.push(section);
+    }
+
+    /// Conversation as rendered, with thinking sections expanded in the
+    /// transcript view.
+    ///
+    /// Collapsed lines pair with the newest sections, so older lines whose
+    /// section is gone stay collapsed.
+    pub fn conversation_text(&self) -> Cow<'_, str> {
+        if !self.show_transcript || self.thinking_sections.is_empty() {
+            return Cow::Borrowed(&self.conversation_display);
+        }
+        let parts: Vec<&str> = self
+            .conversation_display
+            .split(COLLAPSED_THINKING)
+            .collect();
+        let collapsed = parts.len() - 1;
+        let sections =
+            &self.thinking_sections[self.thinking_sections.len().saturating_sub(collapsed)..];
+        let kept = collapsed - sections.len();
+
+        let mut text = parts[0].to_string();
+        for (i, part) in parts[1..].iter().enumerate() {
+            match i.checked_sub(kept) {
+                Some(section) => text.push_str(&sections[section]),
+                None => text.push_str(COLLAPSED_THINKING),
+            }
+            text.push_str(part);
+        }
+        Cow::Owned(text)
+    }
+
     /// Show exit hint
     pub fn show_exit_hint(&mut self, hint: ExitHint, timestamp: u64) {
         self.exit_hint = Some(hint);
@@ -107,7 +154,7 @@ impl DisplayState {
                 if let Some(menu) = self.slash_menu.as_mut() {
                     menu.set_filter(filter);
                 } else {
-                    let mut menu = SlashMenuState::new();
+                    let mut menu = SlashMenuState::with_commands(&self.custom_commands);
                     menu.set_filter(filter);
                     self.slash_menu = Some(menu);
                 }
@@ -136,3 +183,7 @@ impl DisplayState {
         self.escape_pressed_at = None;
     }
 }
+
+#[cfg(test)]
+#[path = "display_state_tests.rs"]
+mod tests;
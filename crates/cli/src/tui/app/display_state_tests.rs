@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+#[test]
+fn thinking_is_collapsed_until_transcript_view() {
+    let mut display = DisplayState::default();
+    display.append_to_conversation("❯ hi");
+    display.append_thinking("∴ Thinking…\n\n  Greeting them".to_string());
+    display.append_to_conversation("⏺ Hello!");
+
+    assert_eq!(
+        display.conversation_text(),
+        format!("❯ hi\n\n{}\n\n⏺ Hello!", COLLAPSED_THINKING)
+    );
+
+    display.show_transcript = true;
+    assert_eq!(
+        display.conversation_text(),
+        "❯ hi\n\n∴ Thinking…\n\n  Greeting them\n\n⏺ Hello!"
+    );
+}
+
+#[test]
+fn collapsed_lines_pair_with_newest_sections() {
+    let mut display = DisplayState::default();
+    display.append_to_conversation(COLLAPSED_THINKING);
+    display.append_thinking("∴ Thinking…\n\n  Second".to_string());
+    display.show_transcript = true;
+
+    assert_eq!(
+        display.conversation_text(),
+        format!("{}\n\n∴ Thinking…\n\n  Second", COLLAPSED_THINKING)
+    );
+}
+
+#[test]
+fn clear_conversation_drops_thinking_sections() {
+    let mut display = DisplayState::default();
+    display.append_thinking("∴ Thinking…".to_string());
+    display.clear_conversation();
+
+    assert!(display.thinking_sections.is_empty());
+    assert_eq!(display.conversation_text(), "");
+}
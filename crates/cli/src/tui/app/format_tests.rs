@@ -29,6 +29,7 @@ fn make_render_state(model: &str, claude_version: Option<&str>, is_tty: bool) ->
         provider: Some("Claude API".to_string()),
         show_welcome_back: true,
         welcome_back_right_panel: None,
+        usage_limit: None,
     }
 }
 
@@ -22,6 +22,11 @@ macro_rules! ctrl_key {
         matches!($code, KeyCode::Char('\x1a'))
             || (matches!($code, KeyCode::Char('z')) && $modifiers.contains(KeyModifiers::CONTROL))
     };
+    // Ctrl+B: ASCII 0x02 or 'b' with CONTROL
+    (b, $modifiers:expr, $code:expr) => {
+        matches!($code, KeyCode::Char('\x02'))
+            || (matches!($code, KeyCode::Char('b')) && $modifiers.contains(KeyModifiers::CONTROL))
+    };
     // Ctrl+S: ASCII 0x13 or 's' with CONTROL
     (s, $modifiers:expr, $code:expr) => {
         matches!($code, KeyCode::Char('\x13'))
@@ -59,6 +64,8 @@ impl TuiAppState {
     }
 
     /// Handle key events in input mode
+    // NOTE(style): Guards stay nested so Ctrl+D/Ctrl+T never fall through to text insertion
+    #[allow(clippy::collapsible_match)]
     pub(super) fn handle_input_key(&self, key: KeyEvent) {
         let mut inner = self.inner.lock();
 
@@ -144,6 +151,11 @@ impl TuiAppState {
                 inner.display.response_content.clear();
             }
 
+            // Ctrl+O - Toggle the transcript view (expands thinking)
+            (m, KeyCode::Char('o')) if m.contains(KeyModifiers::CONTROL) => {
+                inner.display.show_transcript = !inner.display.show_transcript;
+            }
+
             // Meta+t (Alt+t) - Toggle thinking mode
             // Also matches Escape followed by 't' within 100ms (PTY escape sequence)
             (m, KeyCode::Char('t'))
@@ -170,14 +182,18 @@ impl TuiAppState {
                 inner.mode = AppMode::ModelPicker;
             }
 
-            // Ctrl+T - Show todos (only when todos exist)
+            // Ctrl+T - Show tasks, or todos (only when either exists)
             (m, KeyCode::Char('t')) if m.contains(KeyModifiers::CONTROL) => {
-                if !inner.todos.is_empty() {
+                if let Some(store) = inner.task_store.as_ref().filter(|s| !s.is_empty()) {
+                    inner.display.response_content = Self::format_tasks(store);
+                    inner.display.is_command_output = true;
+                    inner.display.conversation_display = "Task List".to_string();
+                } else if !inner.todos.is_empty() {
                     inner.display.response_content = Self::format_todos(&inner.todos);
                     inner.display.is_command_output = true;
                     inner.display.conversation_display = "Todo List".to_string();
                 }
-                // When no todos, do nothing (no visible change)
+                // When neither exists, do nothing (no visible change)
             }
 
             // Shift+Tab - Cycle permission mode
@@ -337,6 +353,17 @@ impl TuiAppState {
                 inner.input.undo();
             }
 
+            // Ctrl+B - Show background tasks (only when any were started)
+            _ if ctrl_key!(b, key.modifiers, key.code) => {
+                let has_tasks = inner
+                    .background_shells
+                    .as_ref()
+                    .is_some_and(|shells| !shells.list().is_empty());
+                if has_tasks {
+                    inner.open_tasks_dialog();
+                }
+            }
+
             // Ctrl+S - Stash/restore prompt
             // Note: Ctrl+S is encoded as ASCII 0x13 or Char('s') with CONTROL
             _ if ctrl_key!(s, key.modifiers, key.code) => {
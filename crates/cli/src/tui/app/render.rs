@@ -15,7 +15,7 @@ mod format;
 
 pub(crate) use content::{
     get_argument_hint, render_conversation_area, render_shortcuts_panel, render_slash_menu,
-    render_stash_indicator,
+    render_stash_indicator, render_usage_limit_notice,
 };
 pub(crate) use format::{
     format_header_lines, format_status_bar, format_status_bar_styled, format_welcome_back_box,
@@ -64,7 +64,10 @@ fn render_active_dialog(state: &RenderState, width: usize) -> Option<AnyElement<
             .dialog
             .as_export()
             .map(|d| render_export_dialog(d, width)),
-        AppMode::HelpDialog => state.dialog.as_help().map(|d| render_help_dialog(d, width)),
+        AppMode::HelpDialog => state
+            .dialog
+            .as_help()
+            .map(|d| render_help_dialog(d, &state.display.custom_commands, width)),
         AppMode::HooksDialog => state
             .dialog
             .as_hooks()
@@ -261,6 +264,7 @@ pub(crate) fn render_main_content(state: &RenderState) -> AnyElement<'static> {
                     View(flex_direction: FlexDirection::Column, width: 100pct) {
                         // Input area with separators (NoWrap to preserve ANSI)
                         #(render_stash_indicator(state))
+                        #(render_usage_limit_notice(state))
                         Text(content: separator.clone(), wrap: TextWrap::NoWrap)
                         Text(content: input_display, wrap: TextWrap::NoWrap)
                         Text(content: separator, wrap: TextWrap::NoWrap)
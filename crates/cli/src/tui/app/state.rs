@@ -20,9 +20,13 @@ use crate::permission::PermissionMode;
 use crate::runtime::Runtime;
 use crate::state::session::SessionManager;
 use crate::state::todos::{TodoState, TodoStatus};
+use crate::state::{TaskStatus, TaskStore};
 use crate::time::{Clock, ClockHandle};
+use crate::tools::BackgroundShells;
+use crate::tui::slash_menu::SlashCommand;
 use crate::tui::widgets::context::ContextUsage;
 use crate::tui::widgets::permission::{RichPermissionDialog, SessionPermissionKey};
+use crate::tui::widgets::tasks::{TaskInfo, TasksDialog};
 
 use crate::tui::widgets::setup::SetupState;
 
@@ -49,6 +53,11 @@ pub(super) struct TuiAppStateInner {
     // Core dependencies
     /// Runtime for shared execution (required for TUI mode)
     pub runtime: Option<Runtime>,
+    /// Background shells from the runtime, kept here because the runtime is
+    /// taken out while a prompt executes
+    pub background_shells: Option<Arc<BackgroundShells>>,
+    /// Task list from the runtime, kept for the same reason
+    pub task_store: Option<Arc<TaskStore>>,
     /// Session manager for conversation state
     pub sessions: SessionManager,
     /// Clock for timing
@@ -112,6 +121,16 @@ impl TuiAppStateInner {
         self.exit_reason = Some(reason);
     }
 
+    /// Show the background tasks dialog with the session's shells.
+    pub fn open_tasks_dialog(&mut self) {
+        let mut dialog = TasksDialog::new();
+        if let Some(ref shells) = self.background_shells {
+            dialog.set_tasks(shells.list().into_iter().map(TaskInfo::from).collect());
+        }
+        self.mode = AppMode::TasksDialog;
+        self.dialog = DialogState::Tasks(dialog);
+    }
+
     /// Dismiss any active dialog and return to input mode.
     pub fn dismiss_dialog(&mut self, name: &str) {
         self.mode = AppMode::Input;
@@ -166,9 +185,20 @@ impl TuiAppState {
                 // Focused state groups
                 input: InputState::default(),
                 dialog,
-                display: DisplayState::new(),
+                display: DisplayState {
+                    custom_commands: runtime
+                        .iter()
+                        .flat_map(|rt| rt.commands())
+                        .map(SlashCommand::from)
+                        .collect(),
+                    ..DisplayState::new()
+                },
 
                 // Core dependencies
+                background_shells: runtime
+                    .as_ref()
+                    .map(|rt| Arc::clone(rt.background_shells())),
+                task_store: runtime.as_ref().map(|rt| Arc::clone(rt.task_store())),
                 runtime,
                 sessions,
                 clock,
@@ -234,6 +264,7 @@ impl TuiAppState {
             provider: inner.config.provider.clone(),
             show_welcome_back: inner.config.show_welcome_back,
             welcome_back_right_panel: inner.config.welcome_back_right_panel.clone(),
+            usage_limit: inner.config.usage_limit.clone(),
         }
     }
 
@@ -381,6 +412,30 @@ impl TuiAppState {
         }
     }
 
+    /// Format the task list for display, marking pending tasks that are
+    /// still waiting on other tasks.
+    pub(super) fn format_tasks(store: &TaskStore) -> String {
+        store
+            .list()
+            .iter()
+            .map(|task| {
+                let status = match task.status {
+                    TaskStatus::Pending => "◻",
+                    TaskStatus::InProgress => "◼",
+                    TaskStatus::Completed => "✔",
+                };
+                let mut line = format!("{} #{} {}", status, task.id, task.subject);
+                let blockers = store.open_blockers(task);
+                if task.status == TaskStatus::Pending && !blockers.is_empty() {
+                    let refs: Vec<String> = blockers.iter().map(|id| format!("#{}", id)).collect();
+                    line.push_str(&format!(" › blocked by {}", refs.join(", ")));
+                }
+                line
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     /// Generate a random farewell message for /exit command
     pub(super) fn random_farewell() -> &'static str {
         const FAREWELLS: &[&str] = &["Goodbye!", "Bye!", "See ya!", "Catch you later!"];
@@ -8,8 +8,10 @@ use std::path::PathBuf;
 use crate::config::{ResolvedTimeouts, ScenarioConfig, DEFAULT_MODEL, DEFAULT_USER_NAME};
 use crate::permission::PermissionMode;
 use crate::runtime::Runtime;
+use crate::time::ClockHandle;
 use crate::tui::widgets::permission::RichPermissionDialog;
 use crate::tui::widgets::trust::TrustChoice;
+use crate::tui::widgets::UsageLimit;
 
 use super::state::{DialogState, DisplayState, InputState};
 
@@ -43,6 +45,8 @@ pub struct TuiConfig {
     pub show_welcome_back: bool,
     /// Right panel rows for the welcome back box (None = default Tips/Recent activity)
     pub welcome_back_right_panel: Option<Vec<String>>,
+    /// Simulated plan usage limit state
+    pub usage_limit: Option<UsageLimit>,
 }
 
 impl Default for TuiConfig {
@@ -64,6 +68,7 @@ impl Default for TuiConfig {
             provider: None,
             show_welcome_back: false,
             welcome_back_right_panel: None,
+            usage_limit: None,
         }
     }
 }
@@ -131,6 +136,13 @@ impl TuiConfig {
             provider: config.identity.provider.clone(),
             show_welcome_back: config.identity.show_welcome_back.unwrap_or(false),
             welcome_back_right_panel: config.identity.welcome_back_right_panel.clone(),
+            usage_limit: config.usage_limit.as_ref().map(|ul| {
+                UsageLimit::from_config(
+                    ul,
+                    config.timing.launch_timestamp.as_deref(),
+                    runtime.clock(),
+                )
+            }),
         }
     }
 
@@ -145,6 +157,7 @@ impl TuiConfig {
         cli_claude_version: Option<&str>,
         is_tty: bool,
         initial_prompt: Option<String>,
+        clock: &ClockHandle,
     ) -> Self {
         // CLI permission mode overrides scenario (unless CLI is default)
         let permission_mode = if *cli_permission_mode != PermissionMode::Default {
@@ -192,6 +205,9 @@ impl TuiConfig {
             provider: config.identity.provider.clone(),
             show_welcome_back: config.identity.show_welcome_back.unwrap_or(false),
             welcome_back_right_panel: config.identity.welcome_back_right_panel.clone(),
+            usage_limit: config.usage_limit.as_ref().map(|ul| {
+                UsageLimit::from_config(ul, config.timing.launch_timestamp.as_deref(), clock)
+            }),
         }
     }
 }
@@ -274,6 +290,8 @@ pub struct RenderState {
     pub show_welcome_back: bool,
     /// Right panel rows for the welcome back box
     pub welcome_back_right_panel: Option<Vec<String>>,
+    /// Simulated plan usage limit state
+    pub usage_limit: Option<UsageLimit>,
 }
 
 /// Permission request state using the rich permission dialog
@@ -254,6 +254,7 @@ fn header_shows_claude_code_when_version_specified() {
         None,
         false,
         None,
+        &clock,
     );
     let state = TuiAppState::for_test(sessions, clock, tui_config);
     let render = state.render_state();
@@ -288,11 +289,44 @@ fn cli_version_overrides_scenario() {
         Some("2.0.0"), // CLI override
         false,
         None,
+        &ClockHandle::default(),
     );
 
     assert_eq!(tui_config.claude_version, Some("2.0.0".to_string()));
 }
 
+#[test]
+fn scenario_usage_limit_resets_from_given_clock() {
+    use crate::config::{ScenarioConfig, UsageLimitConfig, UsageLimitState};
+
+    let scenario_config = ScenarioConfig {
+        usage_limit: Some(UsageLimitConfig {
+            state: UsageLimitState::Reached,
+            resets_in_minutes: Some(60),
+            fallback_model: None,
+        }),
+        ..Default::default()
+    };
+
+    let tui_config = TuiConfig::from_scenario(
+        &scenario_config,
+        None,
+        &PermissionMode::Default,
+        false,
+        false,
+        None,
+        false,
+        None,
+        &ClockHandle::fake_at(1_736_937_000_000),
+    );
+
+    let usage_limit = tui_config.usage_limit.unwrap();
+    assert_eq!(
+        usage_limit.resets_at.to_rfc3339(),
+        "2025-01-15T11:30:00+00:00"
+    );
+}
+
 // =========================================================================
 // Escape to Clear Input Tests
 // =========================================================================
@@ -295,6 +295,63 @@ fn alt_t_opens_thinking_dialog() {
     assert_eq!(state.mode(), AppMode::ThinkingToggle);
 }
 
+// ========================
+// Ctrl+T Task List Tests
+// ========================
+
+#[test]
+fn ctrl_t_shows_tasks_with_open_blockers() {
+    use crate::state::{TaskStatus, TaskStore, TaskUpdate};
+    use std::sync::Arc;
+
+    let state = create_test_app();
+    let store = Arc::new(TaskStore::in_memory());
+    store.create("Build", "", None, None).unwrap();
+    store.create("Test", "", None, None).unwrap();
+    store.create("Deploy", "", None, None).unwrap();
+    let update = |id: &str, update: TaskUpdate| store.update(id, update).unwrap();
+    update(
+        "1",
+        TaskUpdate {
+            status: Some(TaskStatus::Completed),
+            ..Default::default()
+        },
+    );
+    update(
+        "2",
+        TaskUpdate {
+            status: Some(TaskStatus::InProgress),
+            ..Default::default()
+        },
+    );
+    update(
+        "3",
+        TaskUpdate {
+            add_blocked_by: vec!["1".into(), "2".into()],
+            ..Default::default()
+        },
+    );
+    state.inner.lock().task_store = Some(store);
+
+    state.handle_key_event(key_event(KeyCode::Char('t'), KeyModifiers::CONTROL));
+
+    let inner = state.inner.lock();
+    assert_eq!(inner.display.conversation_display, "Task List");
+    assert_eq!(
+        inner.display.response_content,
+        "✔ #1 Build\n◼ #2 Test\n◻ #3 Deploy › blocked by #2"
+    );
+}
+
+#[test]
+fn ctrl_t_without_tasks_or_todos_does_nothing() {
+    let state = create_test_app();
+
+    state.handle_key_event(key_event(KeyCode::Char('t'), KeyModifiers::CONTROL));
+
+    assert!(state.inner.lock().display.response_content.is_empty());
+}
+
 // ========================
 // Meta+P / Alt+P Model Picker Tests
 // ========================
@@ -397,3 +454,57 @@ fn thinking_dialog_key_2_disables_thinking() {
     assert_eq!(state.mode(), AppMode::Input);
     assert!(!state.render_state().thinking_enabled);
 }
+
+// ========================
+// Ctrl+B Background Task Tests
+// ========================
+
+#[test]
+fn ctrl_b_without_background_tasks_does_nothing() {
+    let state = create_test_app();
+
+    state.handle_key_event(key_event(KeyCode::Char('b'), KeyModifiers::CONTROL));
+
+    assert_eq!(state.render_state().mode, AppMode::Input);
+}
+
+#[test]
+#[cfg(unix)]
+fn ctrl_b_lists_background_tasks() {
+    let state = create_test_app();
+    let shells = std::sync::Arc::new(crate::tools::BackgroundShells::new());
+    let mut cmd = std::process::Command::new("sh");
+    cmd.arg("-c").arg("sleep 30");
+    shells.spawn(cmd, "sleep 30", Some("Nap")).unwrap();
+    state.inner.lock().background_shells = Some(std::sync::Arc::clone(&shells));
+
+    state.handle_key_event(key_event(KeyCode::Char('b'), KeyModifiers::CONTROL));
+
+    let render = state.render_state();
+    assert_eq!(render.mode, AppMode::TasksDialog);
+    let dialog = render.dialog.as_tasks().unwrap();
+    assert_eq!(dialog.tasks.len(), 1);
+    assert_eq!(dialog.tasks[0].id, "bash_1");
+    assert_eq!(dialog.tasks[0].description, "Nap");
+    assert_eq!(
+        dialog.tasks[0].status,
+        crate::tui::widgets::tasks::TaskStatus::Running
+    );
+}
+
+// ========================
+// Ctrl+O Transcript Tests
+// ========================
+
+#[test]
+fn ctrl_o_toggles_transcript_view() {
+    let state = create_test_app();
+    assert!(!state.render_state().display.show_transcript);
+
+    state.handle_key_event(key_event(KeyCode::Char('o'), KeyModifiers::CONTROL));
+    assert!(state.render_state().display.show_transcript);
+    assert_eq!(state.render_state().input.buffer, "");
+
+    state.handle_key_event(key_event(KeyCode::Char('o'), KeyModifiers::CONTROL));
+    assert!(!state.render_state().display.show_transcript);
+}
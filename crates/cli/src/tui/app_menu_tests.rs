@@ -54,7 +54,11 @@ fn test_typing_characters_filters_menu() {
 
     let menu = render.display.slash_menu.unwrap();
     // Should filter to commands matching "co" - compact, config, context, etc.
-    let names: Vec<_> = menu.filtered_commands.iter().map(|c| c.name).collect();
+    let names: Vec<_> = menu
+        .filtered_commands
+        .iter()
+        .map(|c| c.name.as_ref())
+        .collect();
     assert!(names.contains(&"compact"));
     assert!(names.contains(&"config"));
     assert!(names.contains(&"context"));
@@ -77,6 +77,7 @@ mod status_bar_rendering {
             provider: None,
             show_welcome_back: false,
             welcome_back_right_panel: None,
+            usage_limit: None,
         }
     }
 
@@ -7,6 +7,10 @@
 //! available commands. As additional characters are typed, the menu filters
 //! using fuzzy subsequence matching.
 
+use std::borrow::Cow;
+
+use crate::state::CustomCommand;
+
 #[cfg(test)]
 #[path = "slash_menu_tests.rs"]
 mod tests;
@@ -15,11 +19,11 @@ mod tests;
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SlashCommand {
     /// Command name without the leading `/` (e.g., "add-dir")
-    pub name: &'static str,
+    pub name: Cow<'static, str>,
     /// Human-readable description
-    pub description: &'static str,
+    pub description: Cow<'static, str>,
     /// Optional argument hint (e.g., "<path>")
-    pub argument_hint: Option<&'static str>,
+    pub argument_hint: Option<Cow<'static, str>>,
 }
 
 impl SlashCommand {
@@ -29,6 +33,17 @@ impl SlashCommand {
     }
 }
 
+impl From<&CustomCommand> for SlashCommand {
+    /// A custom command, described with where it was defined.
+    fn from(command: &CustomCommand) -> Self {
+        Self {
+            name: Cow::Owned(command.name.clone()),
+            description: Cow::Owned(command.menu_description()),
+            argument_hint: command.argument_hint.clone().map(Cow::Owned),
+        }
+    }
+}
+
 /// Maximum number of commands visible in the autocomplete menu at once.
 pub const MENU_VISIBLE_COUNT: usize = 6;
 
@@ -37,142 +52,156 @@ pub const MENU_VISIBLE_COUNT: usize = 6;
 /// This list matches Claude Code v2.1.29's command set.
 pub static COMMANDS: &[SlashCommand] = &[
     SlashCommand {
-        name: "add-dir",
-        description: "Add a new working directory",
-        argument_hint: Some("<path>"),
+        name: Cow::Borrowed("add-dir"),
+        description: Cow::Borrowed("Add a new working directory"),
+        argument_hint: Some(Cow::Borrowed("<path>")),
     },
     SlashCommand {
-        name: "agents",
-        description: "Manage agent configurations",
+        name: Cow::Borrowed("agents"),
+        description: Cow::Borrowed("Manage agent configurations"),
         argument_hint: None,
     },
     SlashCommand {
-        name: "chrome",
-        description: "Claude in Chrome (Beta) settings",
+        name: Cow::Borrowed("chrome"),
+        description: Cow::Borrowed("Claude in Chrome (Beta) settings"),
         argument_hint: None,
     },
     SlashCommand {
-        name: "clear",
-        description: "Clear conversation history and free up context",
+        name: Cow::Borrowed("clear"),
+        description: Cow::Borrowed("Clear conversation history and free up context"),
         argument_hint: None,
     },
     SlashCommand {
-        name: "compact",
-        description: "Clear conversation history but keep a summary in context",
+        name: Cow::Borrowed("compact"),
+        description: Cow::Borrowed("Clear conversation history but keep a summary in context"),
         argument_hint: None,
     },
     SlashCommand {
-        name: "config",
-        description: "Open config panel",
+        name: Cow::Borrowed("config"),
+        description: Cow::Borrowed("Open config panel"),
         argument_hint: None,
     },
     SlashCommand {
-        name: "context",
-        description: "Visualize current context usage as a colored grid",
+        name: Cow::Borrowed("context"),
+        description: Cow::Borrowed("Visualize current context usage as a colored grid"),
         argument_hint: None,
     },
     SlashCommand {
-        name: "exit",
-        description: "Exit the REPL",
+        name: Cow::Borrowed("exit"),
+        description: Cow::Borrowed("Exit the REPL"),
         argument_hint: None,
     },
     SlashCommand {
-        name: "export",
-        description: "Export the current conversation to a file or clipboard",
+        name: Cow::Borrowed("export"),
+        description: Cow::Borrowed("Export the current conversation to a file or clipboard"),
         argument_hint: None,
     },
     SlashCommand {
-        name: "fork",
-        description: "Create a fork of the current conversation at this point",
+        name: Cow::Borrowed("fork"),
+        description: Cow::Borrowed("Create a fork of the current conversation at this point"),
         argument_hint: None,
     },
     SlashCommand {
-        name: "help",
-        description: "Show help and available commands",
+        name: Cow::Borrowed("help"),
+        description: Cow::Borrowed("Show help and available commands"),
         argument_hint: None,
     },
     SlashCommand {
-        name: "hooks",
-        description: "Manage hook configurations for tool events",
+        name: Cow::Borrowed("hooks"),
+        description: Cow::Borrowed("Manage hook configurations for tool events"),
         argument_hint: None,
     },
     SlashCommand {
-        name: "init",
-        description: "Initialize a new project or configuration",
+        name: Cow::Borrowed("init"),
+        description: Cow::Borrowed("Initialize a new project or configuration"),
         argument_hint: None,
     },
     SlashCommand {
-        name: "login",
-        description: "Log in to your account",
+        name: Cow::Borrowed("login"),
+        description: Cow::Borrowed("Log in to your account"),
         argument_hint: None,
     },
     SlashCommand {
-        name: "logout",
-        description: "Log out of your account",
+        name: Cow::Borrowed("logout"),
+        description: Cow::Borrowed("Log out of your account"),
         argument_hint: None,
     },
     SlashCommand {
-        name: "mcp",
-        description: "Manage MCP server connections",
+        name: Cow::Borrowed("mcp"),
+        description: Cow::Borrowed("Manage MCP server connections"),
         argument_hint: None,
     },
     SlashCommand {
-        name: "memory",
-        description: "View or manage conversation memory",
+        name: Cow::Borrowed("memory"),
+        description: Cow::Borrowed("View or manage conversation memory"),
         argument_hint: None,
     },
     SlashCommand {
-        name: "model",
-        description: "Switch the active model",
-        argument_hint: Some("<model>"),
+        name: Cow::Borrowed("model"),
+        description: Cow::Borrowed("Switch the active model"),
+        argument_hint: Some(Cow::Borrowed("<model>")),
     },
     SlashCommand {
-        name: "permissions",
-        description: "View or manage permissions",
+        name: Cow::Borrowed("permissions"),
+        description: Cow::Borrowed("View or manage permissions"),
         argument_hint: None,
     },
     SlashCommand {
-        name: "plan",
-        description: "Create or switch to plan mode",
-        argument_hint: Some("[open]"),
+        name: Cow::Borrowed("plan"),
+        description: Cow::Borrowed("Create or switch to plan mode"),
+        argument_hint: Some(Cow::Borrowed("[open]")),
     },
     SlashCommand {
-        name: "pr-comments",
-        description: "View pull request comments",
+        name: Cow::Borrowed("pr-comments"),
+        description: Cow::Borrowed("View pull request comments"),
         argument_hint: None,
     },
     SlashCommand {
-        name: "review",
-        description: "Review code changes",
+        name: Cow::Borrowed("review"),
+        description: Cow::Borrowed("Review code changes"),
         argument_hint: None,
     },
     SlashCommand {
-        name: "status",
-        description: "Show current session status",
+        name: Cow::Borrowed("status"),
+        description: Cow::Borrowed("Show current session status"),
         argument_hint: None,
     },
     SlashCommand {
-        name: "tasks",
-        description: "List and manage background tasks",
+        name: Cow::Borrowed("tasks"),
+        description: Cow::Borrowed("List and manage background tasks"),
         argument_hint: None,
     },
     SlashCommand {
-        name: "terminal-setup",
-        description: "Configure terminal settings",
+        name: Cow::Borrowed("terminal-setup"),
+        description: Cow::Borrowed("Configure terminal settings"),
         argument_hint: None,
     },
     SlashCommand {
-        name: "todos",
-        description: "Show the current todo list",
+        name: Cow::Borrowed("todos"),
+        description: Cow::Borrowed("Show the current todo list"),
         argument_hint: None,
     },
     SlashCommand {
-        name: "vim",
-        description: "Toggle vim keybindings mode",
+        name: Cow::Borrowed("vim"),
+        description: Cow::Borrowed("Toggle vim keybindings mode"),
         argument_hint: None,
     },
 ];
 
+/// Commands available in non-interactive mode.
+///
+/// Reported as `slash_commands` in the stream-json init event.
+pub static PRINT_MODE_COMMANDS: &[&str] = &[
+    "compact",
+    "context",
+    "cost",
+    "init",
+    "pr-comments",
+    "release-notes",
+    "review",
+    "security-review",
+];
+
 /// Check if `query` matches `text` using fuzzy subsequence matching.
 ///
 /// Returns true if all characters in query appear in text in order,
@@ -211,10 +240,28 @@ pub fn fuzzy_matches(query: &str, text: &str) -> bool {
 pub fn filter_commands(query: &str) -> Vec<&'static SlashCommand> {
     COMMANDS
         .iter()
-        .filter(|cmd| fuzzy_matches(query, cmd.name))
+        .filter(|cmd| fuzzy_matches(query, &cmd.name))
         .collect()
 }
 
+/// Check if `name` is a built-in command.
+pub fn is_builtin(name: &str) -> bool {
+    COMMANDS.iter().any(|cmd| cmd.name == name)
+}
+
+/// Built-in commands merged with custom ones, in alphabetical order.
+///
+/// Built-ins win over custom commands with the same name.
+pub fn menu_commands(custom: &[SlashCommand]) -> Vec<SlashCommand> {
+    let mut commands: Vec<SlashCommand> = COMMANDS
+        .iter()
+        .cloned()
+        .chain(custom.iter().filter(|c| !is_builtin(&c.name)).cloned())
+        .collect();
+    commands.sort_by(|a, b| a.name.cmp(&b.name));
+    commands
+}
+
 /// State of the slash command autocomplete menu.
 #[derive(Clone, Debug)]
 pub struct SlashMenuState {
@@ -223,7 +270,9 @@ pub struct SlashMenuState {
     /// Index of the currently selected command in the filtered list
     pub selected_index: usize,
     /// Cached filtered commands (updated when filter changes)
-    pub filtered_commands: Vec<&'static SlashCommand>,
+    pub filtered_commands: Vec<SlashCommand>,
+    /// Every command the menu can show
+    commands: Vec<SlashCommand>,
 }
 
 impl Default for SlashMenuState {
@@ -235,17 +284,29 @@ impl Default for SlashMenuState {
 impl SlashMenuState {
     /// Create a new menu state showing all commands.
     pub fn new() -> Self {
+        Self::with_commands(&[])
+    }
+
+    /// Create a new menu state showing the built-in and given custom commands.
+    pub fn with_commands(custom: &[SlashCommand]) -> Self {
+        let commands = menu_commands(custom);
         Self {
             filter: String::new(),
             selected_index: 0,
-            filtered_commands: filter_commands(""),
+            filtered_commands: commands.clone(),
+            commands,
         }
     }
 
     /// Update the filter and refresh the command list.
     pub fn set_filter(&mut self, filter: String) {
         self.filter = filter.clone();
-        self.filtered_commands = filter_commands(&filter);
+        self.filtered_commands = self
+            .commands
+            .iter()
+            .filter(|cmd| fuzzy_matches(&filter, &cmd.name))
+            .cloned()
+            .collect();
         // Reset selection if it's out of bounds
         if self.selected_index >= self.filtered_commands.len() {
             self.selected_index = 0;
@@ -271,7 +332,7 @@ impl SlashMenuState {
     }
 
     /// Get the currently selected command.
-    pub fn selected_command(&self) -> Option<&'static SlashCommand> {
-        self.filtered_commands.get(self.selected_index).copied()
+    pub fn selected_command(&self) -> Option<&SlashCommand> {
+        self.filtered_commands.get(self.selected_index)
     }
 }
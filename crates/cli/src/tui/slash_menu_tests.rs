@@ -2,6 +2,7 @@
 // Copyright (c) 2026 Alfred Jean LLC
 
 use super::*;
+use crate::state::CommandSource;
 
 // =============================================================================
 // Fuzzy Matching Tests
@@ -63,7 +64,7 @@ fn test_filter_commands_empty() {
 #[test]
 fn test_filter_commands_co() {
     let results = filter_commands("co");
-    let names: Vec<_> = results.iter().map(|c| c.name).collect();
+    let names: Vec<_> = results.iter().map(|c| c.name.as_ref()).collect();
     assert!(names.contains(&"compact"));
     assert!(names.contains(&"config"));
     assert!(names.contains(&"context"));
@@ -75,7 +76,7 @@ fn test_filter_commands_co() {
 #[test]
 fn test_filter_commands_h() {
     let results = filter_commands("h");
-    let names: Vec<_> = results.iter().map(|c| c.name).collect();
+    let names: Vec<_> = results.iter().map(|c| c.name.as_ref()).collect();
     assert!(names.contains(&"help"));
     assert!(names.contains(&"hooks"));
 }
@@ -88,7 +89,7 @@ fn test_filter_commands_no_match() {
 
 #[test]
 fn test_commands_alphabetical_order() {
-    let names: Vec<_> = COMMANDS.iter().map(|c| c.name).collect();
+    let names: Vec<_> = COMMANDS.iter().map(|c| c.name.as_ref()).collect();
     let mut sorted = names.clone();
     sorted.sort();
     assert_eq!(names, sorted, "COMMANDS should be in alphabetical order");
@@ -112,7 +113,11 @@ fn test_slash_menu_set_filter() {
     state.set_filter("co".to_string());
     assert_eq!(state.filter, "co");
     // Should have filtered to co-prefixed commands
-    let names: Vec<_> = state.filtered_commands.iter().map(|c| c.name).collect();
+    let names: Vec<_> = state
+        .filtered_commands
+        .iter()
+        .map(|c| c.name.as_ref())
+        .collect();
     assert!(names.contains(&"compact"));
     assert!(names.contains(&"config"));
 }
@@ -198,17 +203,68 @@ fn test_slash_command_full_name() {
 #[test]
 fn test_slash_command_with_argument() {
     let add_dir = COMMANDS.iter().find(|c| c.name == "add-dir").unwrap();
-    assert_eq!(add_dir.argument_hint, Some("<path>"));
+    assert_eq!(add_dir.argument_hint.as_deref(), Some("<path>"));
 
     let model = COMMANDS.iter().find(|c| c.name == "model").unwrap();
-    assert_eq!(model.argument_hint, Some("<model>"));
+    assert_eq!(model.argument_hint.as_deref(), Some("<model>"));
 }
 
 #[test]
 fn test_slash_command_without_argument() {
     let clear = COMMANDS.iter().find(|c| c.name == "clear").unwrap();
-    assert_eq!(clear.argument_hint, None);
+    assert_eq!(clear.argument_hint.as_deref(), None);
 
     let help = COMMANDS.iter().find(|c| c.name == "help").unwrap();
-    assert_eq!(help.argument_hint, None);
+    assert_eq!(help.argument_hint.as_deref(), None);
+}
+
+// =============================================================================
+// Custom Command Tests
+// =============================================================================
+
+fn custom(name: &str, source: CommandSource) -> SlashCommand {
+    let command = CustomCommand {
+        name: name.to_string(),
+        description: "Custom".to_string(),
+        argument_hint: Some("<issue>".to_string()),
+        allowed_tools: Vec::new(),
+        model: None,
+        body: String::new(),
+        source,
+    };
+    SlashCommand::from(&command)
+}
+
+#[test]
+fn test_custom_command_shows_its_source() {
+    let cmd = custom("fix-issue", CommandSource::Project);
+    assert_eq!(cmd.description, "Custom (project)");
+    assert_eq!(cmd.argument_hint.as_deref(), Some("<issue>"));
+}
+
+#[test]
+fn test_slash_menu_merges_custom_commands() {
+    let mut state = SlashMenuState::with_commands(&[
+        custom("fix-issue", CommandSource::Project),
+        custom("clear", CommandSource::User),
+    ]);
+    assert_eq!(state.filtered_commands.len(), COMMANDS.len() + 1);
+
+    state.set_filter("fi".to_string());
+    let names: Vec<_> = state
+        .filtered_commands
+        .iter()
+        .map(|c| c.name.as_ref())
+        .collect();
+    assert!(names.contains(&"fix-issue"));
+
+    // Built-in commands win over custom ones with the same name
+    state.set_filter(String::new());
+    let clear: Vec<_> = state
+        .filtered_commands
+        .iter()
+        .filter(|c| c.name == "clear")
+        .collect();
+    assert_eq!(clear.len(), 1);
+    assert!(!clear[0].description.ends_with("(user)"));
 }
@@ -91,37 +91,26 @@ impl HelpDialog {
         self.active_tab = self.active_tab.prev();
     }
 
+    /// Selected index in the current tab's command list, if it has one.
+    fn selected_mut(&mut self) -> Option<&mut usize> {
+        match self.active_tab {
+            HelpTab::Commands => Some(&mut self.commands_selected),
+            HelpTab::CustomCommands => Some(&mut self.custom_selected),
+            HelpTab::General => None,
+        }
+    }
+
     /// Move selection up in current command list (wraps at boundaries)
     pub fn select_prev(&mut self, total_commands: usize) {
-        match self.active_tab {
-            HelpTab::Commands => {
-                if total_commands > 0 {
-                    if self.commands_selected == 0 {
-                        self.commands_selected = total_commands - 1;
-                    } else {
-                        self.commands_selected -= 1;
-                    }
-                }
-            }
-            HelpTab::CustomCommands => {
-                // Similar logic for custom commands when implemented
-            }
-            HelpTab::General => {}
+        if let Some(selected) = self.selected_mut().filter(|_| total_commands > 0) {
+            *selected = selected.checked_sub(1).unwrap_or(total_commands - 1);
         }
     }
 
     /// Move selection down in current command list (wraps at boundaries)
     pub fn select_next(&mut self, total_commands: usize) {
-        match self.active_tab {
-            HelpTab::Commands => {
-                if total_commands > 0 {
-                    self.commands_selected = (self.commands_selected + 1) % total_commands;
-                }
-            }
-            HelpTab::CustomCommands => {
-                // Similar logic for custom commands when implemented
-            }
-            HelpTab::General => {}
+        if let Some(selected) = self.selected_mut().filter(|_| total_commands > 0) {
+            *selected = (*selected + 1) % total_commands;
         }
     }
 }
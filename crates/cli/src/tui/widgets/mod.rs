@@ -20,9 +20,11 @@ pub mod setup;
 pub mod tasks;
 pub mod thinking;
 pub mod trust;
+pub mod usage_limit;
 
 pub use elicitation::ElicitationState;
 pub use hooks::{HookType, HooksDialog, HooksView};
 pub use memory::MemoryDialog;
 pub use model_picker::{ModelChoice, ModelPickerDialog};
 pub use plan_approval::PlanApprovalState;
+pub use usage_limit::UsageLimit;
@@ -13,6 +13,8 @@
 mod tests;
 
 use super::scrollable::ScrollState;
+use crate::tools::background::ShellSummary;
+use crate::tools::ShellStatus;
 
 /// Background task info for display
 #[derive(Clone, Debug)]
@@ -27,6 +29,35 @@ pub enum TaskStatus {
     Running,
     Completed,
     Failed,
+    Killed,
+}
+
+impl TaskStatus {
+    /// Lowercase label shown next to the task
+    pub fn label(&self) -> &'static str {
+        match self {
+            TaskStatus::Running => "running",
+            TaskStatus::Completed => "completed",
+            TaskStatus::Failed => "failed",
+            TaskStatus::Killed => "killed",
+        }
+    }
+}
+
+impl From<ShellSummary> for TaskInfo {
+    fn from(shell: ShellSummary) -> Self {
+        let status = match shell.status {
+            ShellStatus::Running => TaskStatus::Running,
+            ShellStatus::Completed => TaskStatus::Completed,
+            ShellStatus::Failed => TaskStatus::Failed,
+            ShellStatus::Killed => TaskStatus::Killed,
+        };
+        Self {
+            id: shell.id,
+            description: shell.description,
+            status,
+        }
+    }
 }
 
 /// State for the /tasks dialog
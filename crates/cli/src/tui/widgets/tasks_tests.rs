@@ -96,3 +96,17 @@ fn empty_list_navigation_is_noop() {
     dialog.move_selection_up();
     assert_eq!(dialog.selected_index(), 0);
 }
+
+#[test]
+fn task_info_from_shell_summary() {
+    let task = TaskInfo::from(ShellSummary {
+        id: "bash_2".to_string(),
+        command: "npm run dev".to_string(),
+        description: "Start dev server".to_string(),
+        status: ShellStatus::Killed,
+    });
+    assert_eq!(task.id, "bash_2");
+    assert_eq!(task.description, "Start dev server");
+    assert_eq!(task.status, TaskStatus::Killed);
+    assert_eq!(task.status.label(), "killed");
+}
@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Usage limit notices.
+//!
+//! Scenario-controlled plan usage states shown above the input area.
+//! Reset times are computed from the session launch timestamp, keeping
+//! the UTC offset it was written with so rendered times are deterministic.
+
+use chrono::{DateTime, Duration, FixedOffset, Timelike};
+
+use crate::config::{UsageLimitConfig, UsageLimitState};
+use crate::time::Clock;
+
+/// Resolved usage limit state for rendering
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UsageLimit {
+    pub state: UsageLimitState,
+    /// When the limit window resets
+    pub resets_at: DateTime<FixedOffset>,
+    /// Model named in the Opus fallback notice
+    pub fallback_model: String,
+}
+
+impl UsageLimit {
+    /// Resolve from scenario config and the scenario's `launch_timestamp`.
+    ///
+    /// Falls back to the clock's current time (UTC) when no timestamp is
+    /// configured.
+    pub fn from_config(
+        config: &UsageLimitConfig,
+        launch_timestamp: Option<&str>,
+        clock: &impl Clock,
+    ) -> Self {
+        let launched = launch_timestamp
+            .and_then(|ts| DateTime::parse_from_rfc3339(ts).ok())
+            .unwrap_or_else(|| {
+                DateTime::from_timestamp_millis(clock.now_millis() as i64)
+                    .unwrap_or_default()
+                    .fixed_offset()
+            });
+        let minutes = config
+            .resets_in_minutes
+            .unwrap_or(UsageLimitConfig::DEFAULT_RESETS_IN_MINUTES);
+
+        Self {
+            state: config.state,
+            resets_at: launched + Duration::minutes(minutes as i64),
+            fallback_model: config
+                .fallback_model
+                .clone()
+                .unwrap_or_else(|| UsageLimitConfig::DEFAULT_FALLBACK_MODEL.to_string()),
+        }
+    }
+
+    /// Whether prompts are rejected until the limit resets
+    pub fn blocks_prompts(&self) -> bool {
+        self.state == UsageLimitState::Reached
+    }
+
+    /// Notice text shown above the input area
+    pub fn notice(&self) -> String {
+        let resets = format_reset_time(&self.resets_at);
+        match self.state {
+            UsageLimitState::Approaching => {
+                format!("Approaching usage limit · resets {}", resets)
+            }
+            UsageLimitState::Reached => format!("5-hour limit reached · resets {}", resets),
+            UsageLimitState::OpusApproaching => {
+                format!("Approaching Opus usage limit · resets {}", resets)
+            }
+            UsageLimitState::OpusReached => {
+                format!("Opus limit reached, now using {}", self.fallback_model)
+            }
+        }
+    }
+}
+
+/// Format a reset time like the real CLI: "3pm", "3:30pm", "12am".
+pub fn format_reset_time(time: &DateTime<FixedOffset>) -> String {
+    let (is_pm, hour) = time.hour12();
+    let suffix = if is_pm { "pm" } else { "am" };
+    if time.minute() == 0 {
+        format!("{}{}", hour, suffix)
+    } else {
+        format!("{}:{:02}{}", hour, time.minute(), suffix)
+    }
+}
+
+/// In-transcript error block shown after a 429 response
+pub fn rate_limit_error_block(retry_after: u64) -> String {
+    let error = serde_json::json!({
+        "type": "error",
+        "error": {
+            "type": "rate_limit_error",
+            "message": "Rate limit exceeded",
+            "retry_after": retry_after
+        }
+    });
+    format!("API Error: 429 {}", error)
+}
+
+#[cfg(test)]
+#[path = "usage_limit_tests.rs"]
+mod tests;
@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use crate::time::ClockHandle;
+use yare::parameterized;
+
+fn config(state: UsageLimitState, resets_in_minutes: Option<u64>) -> UsageLimitConfig {
+    UsageLimitConfig {
+        state,
+        resets_in_minutes,
+        fallback_model: None,
+    }
+}
+
+#[parameterized(
+    approaching = { UsageLimitState::Approaching, "Approaching usage limit · resets 3pm" },
+    reached = { UsageLimitState::Reached, "5-hour limit reached · resets 3pm" },
+    opus_approaching = { UsageLimitState::OpusApproaching, "Approaching Opus usage limit · resets 3pm" },
+    opus_reached = { UsageLimitState::OpusReached, "Opus limit reached, now using Sonnet 4.5" },
+)]
+fn notice_text(state: UsageLimitState, expected: &str) {
+    let limit = UsageLimit::from_config(
+        &config(state, None),
+        Some("2025-01-15T10:00:00Z"),
+        &ClockHandle::fake_at_epoch(),
+    );
+    assert_eq!(limit.notice(), expected);
+}
+
+#[test]
+fn reset_time_keeps_launch_offset() {
+    let limit = UsageLimit::from_config(
+        &config(UsageLimitState::Reached, Some(90)),
+        Some("2025-01-15T10:00:00-08:00"),
+        &ClockHandle::fake_at_epoch(),
+    );
+    assert_eq!(limit.notice(), "5-hour limit reached · resets 11:30am");
+}
+
+#[parameterized(
+    midnight = { "2025-01-15T00:00:00Z", "12am" },
+    noon = { "2025-01-15T12:00:00Z", "12pm" },
+    minutes = { "2025-01-15T15:05:00Z", "3:05pm" },
+)]
+fn reset_time_format(timestamp: &str, expected: &str) {
+    let time = DateTime::parse_from_rfc3339(timestamp).unwrap();
+    assert_eq!(format_reset_time(&time), expected);
+}
+
+#[test]
+fn custom_fallback_model() {
+    let mut cfg = config(UsageLimitState::OpusReached, None);
+    cfg.fallback_model = Some("Sonnet 4".to_string());
+    let limit = UsageLimit::from_config(&cfg, None, &ClockHandle::fake_at_epoch());
+    assert_eq!(limit.notice(), "Opus limit reached, now using Sonnet 4");
+}
+
+#[test]
+fn only_reached_blocks_prompts() {
+    let reached = UsageLimit::from_config(
+        &config(UsageLimitState::Reached, None),
+        None,
+        &ClockHandle::fake_at_epoch(),
+    );
+    let approaching = UsageLimit::from_config(
+        &config(UsageLimitState::Approaching, None),
+        None,
+        &ClockHandle::fake_at_epoch(),
+    );
+    assert!(reached.blocks_prompts());
+    assert!(!approaching.blocks_prompts());
+}
+
+#[test]
+fn rate_limit_block_includes_status_and_error_type() {
+    let block = rate_limit_error_block(60);
+    assert!(block.starts_with("API Error: 429 {"));
+    assert!(block.contains(r#""type":"rate_limit_error""#));
+    assert!(block.contains(r#""retry_after":60"#));
+}
+
+#[test]
+fn reset_time_without_launch_timestamp_uses_clock() {
+    // 2025-01-15T10:00:00Z
+    let clock = ClockHandle::fake_at(1_736_935_200_000);
+    let limit = UsageLimit::from_config(&config(UsageLimitState::Reached, None), None, &clock);
+    assert_eq!(limit.notice(), "5-hour limit reached · resets 3pm");
+}
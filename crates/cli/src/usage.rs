@@ -23,6 +23,25 @@ impl TokenCounts {
     pub fn total(&self) -> u32 {
         self.input_tokens + self.output_tokens
     }
+
+    /// Estimate usage for a response without an explicit usage spec.
+    pub fn estimate(text: &str) -> Self {
+        Self::new(DEFAULT_INPUT_TOKENS, estimate_tokens(text))
+    }
+
+    /// Accumulate another step's counts into this one.
+    pub fn add(&mut self, other: &TokenCounts) {
+        self.input_tokens += other.input_tokens;
+        self.output_tokens += other.output_tokens;
+    }
+}
+
+/// Input tokens reported when a response has no usage spec.
+pub const DEFAULT_INPUT_TOKENS: u32 = 100;
+
+/// Estimate token count from text (rough approximation: 4 chars per token).
+pub fn estimate_tokens(text: &str) -> u32 {
+    (text.len() / 4).max(1) as u32
 }
 
 /// Extended token counts including cache metrics.
@@ -0,0 +1,118 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+//! Integration tests for custom slash commands in print mode.
+
+mod common;
+
+use common::claudeless_bin;
+use std::fs;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+const SCENARIO: &str = r#"
+name = "slash-commands"
+
+[[responses]]
+pattern = { type = "exact", text = "Fix issue #42 on main" }
+response = "Fixed issue 42"
+
+[[responses]]
+pattern = { type = "contains", text = "" }
+response = "Unexpected prompt"
+"#;
+
+fn write_command(work_dir: &TempDir, name: &str, content: &str) {
+    let dir = work_dir.path().join(".claude").join("commands");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join(format!("{}.md", name)), content).unwrap();
+}
+
+fn run(work_dir: &TempDir, prompt: &str) -> Output {
+    let state_dir = TempDir::new().unwrap();
+    let scenario_path = work_dir.path().join("scenario.toml");
+    fs::write(&scenario_path, SCENARIO).unwrap();
+
+    Command::new(claudeless_bin())
+        .env("CLAUDELESS_STATE_DIR", state_dir.path())
+        .current_dir(work_dir.path())
+        .args(["--scenario", scenario_path.to_str().unwrap()])
+        .args(["--output-format", "stream-json", "--verbose", "-p", prompt])
+        .output()
+        .expect("Failed to run claudeless")
+}
+
+fn events(output: &Output) -> Vec<serde_json::Value> {
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_custom_command_expands_in_print_mode() {
+    let work_dir = TempDir::new().unwrap();
+    write_command(
+        &work_dir,
+        "fix-issue",
+        "---\ndescription: Fix an issue\nallowed-tools: Bash(echo:*)\nmodel: claude-haiku-4-5-20251001\n---\nFix issue #$1 on !`echo main`\n",
+    );
+
+    let output = run(&work_dir, "/fix-issue 42");
+
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let events = events(&output);
+    let init = &events[0];
+    let slash_commands: Vec<&str> = init["slash_commands"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c.as_str().unwrap())
+        .collect();
+    assert!(slash_commands.contains(&"fix-issue"));
+
+    let assistant = events.iter().find(|e| e["type"] == "assistant").unwrap();
+    assert_eq!(assistant["message"]["model"], "claude-haiku-4-5-20251001");
+    assert_eq!(assistant["message"]["content"][0]["text"], "Fixed issue 42");
+}
+
+#[test]
+fn test_custom_command_bash_needs_allowed_tools() {
+    let work_dir = TempDir::new().unwrap();
+    write_command(&work_dir, "fix-issue", "Fix issue #$1 on !`echo main`\n");
+
+    let output = run(&work_dir, "/fix-issue 42");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr)
+        .contains("Bash command permission check failed for pattern"));
+}
+
+#[test]
+fn test_disable_slash_commands_skips_custom_commands() {
+    let work_dir = TempDir::new().unwrap();
+    write_command(&work_dir, "fix-issue", "Fix issue #$1 on main\n");
+    let state_dir = TempDir::new().unwrap();
+    let scenario_path = work_dir.path().join("scenario.toml");
+    fs::write(&scenario_path, SCENARIO).unwrap();
+
+    let output = Command::new(claudeless_bin())
+        .env("CLAUDELESS_STATE_DIR", state_dir.path())
+        .current_dir(work_dir.path())
+        .args(["--scenario", scenario_path.to_str().unwrap()])
+        .args(["--disable-slash-commands", "-p", "/fix-issue 42"])
+        .output()
+        .expect("Failed to run claudeless");
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim(),
+        "Unexpected prompt"
+    );
+}
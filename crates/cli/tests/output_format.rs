@@ -251,3 +251,99 @@ fn test_json_output_exit_code_zero_on_success() {
         output
     );
 }
+
+// =============================================================================
+// JSON Result Statistics Tests
+// =============================================================================
+
+fn run_json(scenario_toml: &str) -> serde_json::Value {
+    let scenario = write_scenario(scenario_toml);
+    let output = Command::new(claudeless_bin())
+        .args([
+            "--scenario",
+            scenario.path().to_str().unwrap(),
+            "--output-format",
+            "json",
+            "-p",
+            "run",
+        ])
+        .output()
+        .expect("Failed to run claudeless");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let first_line = stdout.lines().next().unwrap_or_default();
+    serde_json::from_str(first_line).unwrap()
+}
+
+#[test]
+fn test_json_result_counts_auto_continued_turns() {
+    let parsed = run_json(
+        r#"
+        name = "turns"
+        permission_mode = "bypass-permissions"
+
+        [timeouts]
+        response_delay_ms = 0
+
+        [tool_execution]
+        mode = "mock"
+
+        [tool_execution.tools.Bash]
+        result = "ok"
+
+        [[responses]]
+        pattern = { type = "any" }
+        response = { text = "Running", tool_calls = [{ tool = "Bash", input = { command = "ls" } }], delay_ms = 30 }
+
+        [[responses.turns]]
+        expect = { type = "any" }
+        response = { text = "All done", delay_ms = 20 }
+        "#,
+    );
+
+    assert_eq!(parsed["num_turns"], 2);
+    assert_eq!(parsed["result"], "All done");
+    assert_eq!(parsed["duration_api_ms"], 50);
+    assert!(parsed["duration_ms"].as_u64().unwrap() >= 50);
+}
+
+#[test]
+fn test_json_result_reports_permission_denials() {
+    let parsed = run_json(
+        r#"
+        name = "denied"
+
+        [[responses]]
+        pattern = { type = "any" }
+        response = { text = "Trying", tool_calls = [{ tool = "Bash", input = { command = "rm -rf build" } }] }
+        "#,
+    );
+
+    let denials = parsed["permission_denials"].as_array().unwrap();
+    assert_eq!(denials.len(), 1);
+    assert_eq!(denials[0]["tool_name"], "Bash");
+    assert_eq!(denials[0]["tool_use_id"], "toolu_00000000");
+    assert_eq!(denials[0]["tool_input"]["command"], "rm -rf build");
+}
+
+#[test]
+fn test_json_result_cost_matches_model_usage() {
+    let parsed = run_json(
+        r#"
+        name = "cost"
+        default_model = "claude-test"
+
+        [[responses]]
+        pattern = { type = "any" }
+        response = { text = "Hi", usage = { input_tokens = 1000, output_tokens = 200 } }
+        "#,
+    );
+
+    assert_eq!(parsed["usage"]["input_tokens"], 1000);
+    assert_eq!(parsed["usage"]["output_tokens"], 200);
+    let model_usage = parsed["modelUsage"].as_object().unwrap();
+    let model_cost: f64 = model_usage
+        .values()
+        .map(|m| m["costUSD"].as_f64().unwrap())
+        .sum();
+    assert_eq!(parsed["cost_usd"].as_f64().unwrap(), model_cost);
+}
@@ -252,6 +252,8 @@ mod permission_checker_integration {
                 auto_approve: true,
                 result: None,
                 error: None,
+                command: None,
+                max_result_chars: None,
                 answers: None,
             },
         );
@@ -284,6 +286,8 @@ mod permission_checker_integration {
                 auto_approve: false,
                 result: None,
                 error: Some("Simulated failure".to_string()),
+                command: None,
+                max_result_chars: None,
                 answers: None,
             },
         );
@@ -327,6 +331,8 @@ mod priority_order {
                 auto_approve: false,
                 result: None,
                 error: Some("Error".to_string()),
+                command: None,
+                max_result_chars: None,
                 answers: None,
             },
         );
@@ -134,7 +134,6 @@ fn test_stream_json_is_ndjson() {
 ///
 /// Real Claude starts stream with {"type":"system","subtype":"init",...}
 #[test]
-#[ignore] // DEFERRED: Requires output format fix (epic-05x-fix-cli)
 fn test_stream_json_starts_with_system_init() {
     let scenario = write_scenario(
         r#"
@@ -181,6 +180,95 @@ fn test_stream_json_starts_with_system_init() {
     );
 }
 
+/// Behavior observed with: claude --version 2.1.12 (Claude Code)
+///
+/// The init event reports the session's cwd, model, permission mode and version
+#[test]
+fn test_stream_json_init_reflects_session_context() {
+    let scenario = write_scenario(
+        r#"
+        name = "test"
+        claude_version = "2.1.30"
+        [[responses]]
+        pattern = { type = "any" }
+        response = "ok"
+        "#,
+    );
+    let dir = tempfile::tempdir().unwrap();
+
+    let output = Command::new(claudeless_bin())
+        .args([
+            "--scenario",
+            scenario.path().to_str().unwrap(),
+            "--output-format",
+            "stream-json",
+            "--model",
+            "claude-sonnet-4-20250514",
+            "--permission-mode",
+            "plan",
+            "--cwd",
+            dir.path().to_str().unwrap(),
+            "-p",
+            "test",
+        ])
+        .output()
+        .expect("Failed to run claudeless");
+
+    assert!(output.status.success(), "Expected success: {:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let init: serde_json::Value = serde_json::from_str(stdout.lines().next().unwrap()).unwrap();
+
+    assert_eq!(init["cwd"], dir.path().to_str().unwrap());
+    assert_eq!(init["model"], "claude-sonnet-4-20250514");
+    assert_eq!(init["permissionMode"], "plan");
+    assert_eq!(init["claude_code_version"], "2.1.30");
+    assert_eq!(init["output_style"], "default");
+    assert!(init["agents"]
+        .as_array()
+        .unwrap()
+        .contains(&serde_json::json!("general-purpose")));
+    assert!(init["slash_commands"]
+        .as_array()
+        .unwrap()
+        .contains(&serde_json::json!("compact")));
+}
+
+/// `output_style` and `apiKeySource` come from settings
+#[test]
+fn test_stream_json_init_reads_settings() {
+    let scenario = write_scenario(
+        r#"
+        name = "test"
+        [[responses]]
+        pattern = { type = "any" }
+        response = "ok"
+        "#,
+    );
+
+    let output = Command::new(claudeless_bin())
+        .args([
+            "--scenario",
+            scenario.path().to_str().unwrap(),
+            "--output-format",
+            "stream-json",
+            "--settings",
+            r#"{"outputStyle": "Explanatory", "apiKeyHelper": "echo key"}"#,
+            "--disable-slash-commands",
+            "-p",
+            "test",
+        ])
+        .output()
+        .expect("Failed to run claudeless");
+
+    assert!(output.status.success(), "Expected success: {:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let init: serde_json::Value = serde_json::from_str(stdout.lines().next().unwrap()).unwrap();
+
+    assert_eq!(init["output_style"], "Explanatory");
+    assert_eq!(init["apiKeySource"], "apiKeyHelper");
+    assert_eq!(init["slash_commands"], serde_json::json!([]));
+}
+
 /// Behavior observed with: claude --version 2.1.12 (Claude Code)
 ///
 /// Real Claude ends stream with {"type":"result","subtype":"success",...}
@@ -251,3 +339,204 @@ fn test_stream_json_exit_code_zero_on_success() {
         output
     );
 }
+
+/// Real Claude writes one assistant/user pair per model step:
+/// assistant(tool_use) → user(tool_result) → assistant(text) → result
+#[test]
+fn test_stream_json_interleaves_agent_steps() {
+    let scenario = write_scenario(
+        r#"
+        name = "steps"
+        permission_mode = "bypass-permissions"
+
+        [tool_execution]
+        mode = "mock"
+
+        [[responses]]
+        pattern = { type = "any" }
+        response = { text = "", tool_calls = [{ tool = "Bash", input = { command = "ls" }, result = "file.txt" }] }
+
+        [[responses.turns]]
+        expect = { type = "any" }
+        response = { text = "Checking", tool_calls = [{ tool = "Bash", input = { command = "pwd" }, result = "/work" }] }
+
+        [[responses.turns]]
+        expect = { type = "any" }
+        response = "All done"
+        "#,
+    );
+
+    let output = Command::new(claudeless_bin())
+        .args([
+            "--scenario",
+            scenario.path().to_str().unwrap(),
+            "--output-format",
+            "stream-json",
+            "-p",
+            "list files",
+        ])
+        .output()
+        .expect("Failed to run claudeless");
+
+    assert!(output.status.success(), "Expected success: {:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let events: Vec<serde_json::Value> = stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+
+    let types: Vec<&str> = events.iter().map(|e| e["type"].as_str().unwrap()).collect();
+    assert_eq!(
+        types,
+        [
+            "system",
+            "assistant",
+            "user",
+            "assistant",
+            "user",
+            "assistant",
+            "result"
+        ]
+    );
+
+    // Each tool_result answers the tool_use from the preceding assistant event
+    for pair in [(1, 2), (3, 4)] {
+        let tool_use = events[pair.0]["message"]["content"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|block| block["type"] == "tool_use")
+            .unwrap();
+        let tool_result = &events[pair.1]["message"]["content"][0];
+        assert_eq!(tool_result["type"], "tool_result");
+        assert_eq!(tool_result["tool_use_id"], tool_use["id"]);
+    }
+    assert_eq!(
+        events[2]["message"]["content"][0]["content"][0]["text"],
+        "file.txt"
+    );
+    assert_ne!(
+        events[2]["message"]["content"][0]["tool_use_id"],
+        events[4]["message"]["content"][0]["tool_use_id"],
+        "Tool use IDs should be unique across steps"
+    );
+
+    // Each assistant message has its own ID
+    let ids: std::collections::HashSet<&str> = [1, 3, 5]
+        .iter()
+        .map(|&i| events[i]["message"]["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(ids.len(), 3, "Message IDs should be unique per step");
+
+    assert_eq!(events[6]["num_turns"], 3);
+    assert_eq!(events[6]["result"], "All done");
+}
+
+/// Real Claude sends one user event per tool_result, as the session JSONL
+/// records them, even when a step makes several calls.
+#[test]
+fn test_stream_json_writes_user_event_per_tool_result() {
+    let scenario = write_scenario(
+        r#"
+        name = "parallel-steps"
+        permission_mode = "bypass-permissions"
+
+        [tool_execution]
+        mode = "mock"
+
+        [[responses]]
+        pattern = { type = "any" }
+        response = { text = "", tool_calls = [
+            { tool = "Bash", input = { command = "ls" }, result = "file.txt" },
+            { tool = "Bash", input = { command = "pwd" }, result = "/work" },
+        ] }
+
+        [[responses.turns]]
+        expect = { type = "any" }
+        response = "All done"
+        "#,
+    );
+
+    let output = Command::new(claudeless_bin())
+        .args([
+            "--scenario",
+            scenario.path().to_str().unwrap(),
+            "--output-format",
+            "stream-json",
+            "-p",
+            "list files",
+        ])
+        .output()
+        .expect("Failed to run claudeless");
+
+    assert!(output.status.success(), "Expected success: {:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let events: Vec<serde_json::Value> = stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+
+    let types: Vec<&str> = events.iter().map(|e| e["type"].as_str().unwrap()).collect();
+    assert_eq!(
+        types,
+        ["system", "assistant", "user", "user", "assistant", "result"]
+    );
+
+    let tool_uses: Vec<&serde_json::Value> = events[1]["message"]["content"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|block| block["type"] == "tool_use")
+        .collect();
+    assert_eq!(tool_uses.len(), 2);
+    for (tool_use, event) in tool_uses.iter().zip(&events[2..4]) {
+        let content = event["message"]["content"].as_array().unwrap();
+        assert_eq!(content.len(), 1);
+        assert_eq!(content[0]["tool_use_id"], tool_use["id"]);
+    }
+    assert_eq!(
+        events[3]["message"]["content"][0]["content"][0]["text"],
+        "/work"
+    );
+}
+
+/// Thinking from the scenario precedes the text in the assistant event.
+#[test]
+fn test_stream_json_emits_thinking_block() {
+    let scenario = write_scenario(
+        r#"
+        name = "thinking"
+
+        [[responses]]
+        pattern = { type = "any" }
+        response = { text = "Answer", thinking = "Working it out" }
+        "#,
+    );
+
+    let output = Command::new(claudeless_bin())
+        .args([
+            "--scenario",
+            scenario.path().to_str().unwrap(),
+            "--output-format",
+            "stream-json",
+            "-p",
+            "question",
+        ])
+        .output()
+        .expect("Failed to run claudeless");
+
+    assert!(output.status.success(), "Expected success: {:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let assistant: serde_json::Value = stdout
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .find(|e| e["type"] == "assistant")
+        .expect("assistant event");
+
+    let content = &assistant["message"]["content"];
+    assert_eq!(content[0]["type"], "thinking");
+    assert_eq!(content[0]["thinking"], "Working it out");
+    assert!(content[0]["signature"].is_string());
+    assert_eq!(content[1]["type"], "text");
+    assert_eq!(content[1]["text"], "Answer");
+}
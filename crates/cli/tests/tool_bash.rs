@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+//! Integration tests for the live Bash tool and background shells.
+
+mod common;
+
+use common::claudeless_bin;
+use std::fs;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+fn run(scenario: &str) -> (TempDir, Output) {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir(dir.path().join("sub")).unwrap();
+    let scenario_path = dir.path().join("scenario.toml");
+    fs::write(&scenario_path, scenario).unwrap();
+    let output = Command::new(claudeless_bin())
+        .current_dir(dir.path())
+        .args([
+            "--scenario",
+            scenario_path.to_str().unwrap(),
+            "--output-format",
+            "stream-json",
+            "--verbose",
+            "-p",
+            "go",
+        ])
+        .output()
+        .expect("Failed to run claudeless");
+    (dir, output)
+}
+
+/// Each tool result's error flag and text, from the stream-json `user` events.
+fn tool_results(output: &Output) -> Vec<(bool, String)> {
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .filter(|event| event["type"] == "user")
+        .flat_map(|event| event["message"]["content"].as_array().cloned().unwrap())
+        .map(|block| {
+            (
+                block["is_error"] == true,
+                block["content"][0]["text"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+#[cfg(unix)]
+fn test_bash_keeps_shell_state_and_manages_background_shells() {
+    let (_dir, output) = run(r#"
+name = "bash"
+permission_mode = "bypass-permissions"
+
+[tool_execution]
+mode = "live"
+
+[[responses]]
+pattern = { type = "any" }
+response = { text = "", tool_calls = [
+    { tool = "Bash", input = { command = "cd sub && export GREETING=hi" } },
+    { tool = "Bash", input = { command = "echo $GREETING from $(basename $(pwd))" } },
+    { tool = "Bash", input = { command = "sleep 30", timeout = 100 } },
+    { tool = "Bash", input = { command = "sleep 30", run_in_background = true } },
+    { tool = "KillShell", input = { shell_id = "bash_1" } },
+    { tool = "BashOutput", input = { bash_id = "bash_1" } },
+] }
+
+[[responses.turns]]
+expect = { type = "any" }
+response = "Done"
+"#);
+    assert!(output.status.success(), "Expected success: {:?}", output);
+
+    let results = tool_results(&output);
+    assert_eq!(results.len(), 6, "{:?}", results);
+    assert_eq!(
+        results[1],
+        (false, "hi from sub\n\nExit code: 0".to_string())
+    );
+    assert_eq!(
+        results[2],
+        (true, "Command timed out after 100ms".to_string())
+    );
+    assert_eq!(
+        results[3],
+        (
+            false,
+            "Command running in background with ID: bash_1".to_string()
+        )
+    );
+    assert_eq!(
+        results[4],
+        (
+            false,
+            "Successfully killed shell: bash_1 (sleep 30)".to_string()
+        )
+    );
+    assert!(results[5].1.starts_with("<status>killed</status>"));
+}
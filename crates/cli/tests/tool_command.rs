@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+//! Integration tests for tools overridden by an external command.
+
+mod common;
+
+use common::claudeless_bin;
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+
+#[test]
+#[cfg(unix)]
+fn test_tool_command_overrides_tool() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = TempDir::new().unwrap();
+    let scenarios = dir.path().join("scenarios");
+    fs::create_dir(&scenarios).unwrap();
+    let script = scenarios.join("fake-bash.sh");
+    fs::write(
+        &script,
+        r#"#!/bin/sh
+if grep -q '"command":"fail"'; then
+  echo '{"content": "fake failure", "is_error": true}'
+else
+  echo '{"content": "fake output"}'
+fi
+"#,
+    )
+    .unwrap();
+    fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+    let scenario_path = scenarios.join("scenario.toml");
+    fs::write(
+        &scenario_path,
+        r#"
+name = "tool-command"
+permission_mode = "bypass-permissions"
+
+[tool_execution]
+mode = "mock"
+
+[tool_execution.tools.Bash]
+command = "./fake-bash.sh"
+
+[[responses]]
+pattern = { type = "any" }
+response = { text = "", tool_calls = [
+    { tool = "Bash", input = { command = "ls" } },
+    { tool = "Bash", input = { command = "fail" } },
+    { tool = "Bash", input = { command = "ls" }, result = "canned" },
+] }
+
+[[responses.turns]]
+expect = { type = "any" }
+response = "Done"
+"#,
+    )
+    .unwrap();
+
+    let output = Command::new(claudeless_bin())
+        .current_dir(dir.path())
+        .args([
+            "--scenario",
+            scenario_path.to_str().unwrap(),
+            "--output-format",
+            "stream-json",
+            "--verbose",
+            "-p",
+            "go",
+        ])
+        .output()
+        .expect("Failed to run claudeless");
+    assert!(output.status.success(), "Expected success: {:?}", output);
+
+    let results: Vec<(bool, String)> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .filter(|event| event["type"] == "user")
+        .flat_map(|event| event["message"]["content"].as_array().cloned().unwrap())
+        .map(|block| {
+            (
+                block["is_error"] == true,
+                block["content"][0]["text"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        results,
+        vec![
+            (false, "fake output".to_string()),
+            (true, "fake failure".to_string()),
+            (false, "canned".to_string()),
+        ]
+    );
+}
+
+#[test]
+#[cfg(unix)]
+fn test_read_only_tool_commands_run_concurrently() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = TempDir::new().unwrap();
+    let script = dir.path().join("slow-read.sh");
+    // The first Read waits for the second one to finish, which only
+    // happens when both run at the same time
+    fs::write(
+        &script,
+        r#"#!/bin/sh
+if grep -q '"file_path":"first"'; then
+  i=0
+  while [ ! -f second.done ] && [ $i -lt 100 ]; do sleep 0.05; i=$((i+1)); done
+  [ -f second.done ] && echo '{"content": "first saw second"}' || echo '{"content": "first ran alone"}'
+else
+  touch second.done
+  echo '{"content": "second"}'
+fi
+"#,
+    )
+    .unwrap();
+    fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+    let scenario_path = dir.path().join("scenario.toml");
+    fs::write(
+        &scenario_path,
+        format!(
+            r#"
+name = "concurrent-reads"
+permission_mode = "bypass-permissions"
+
+[tool_execution]
+mode = "mock"
+
+[tool_execution.tools.Read]
+command = "{}"
+
+[[responses]]
+pattern = {{ type = "any" }}
+response = {{ text = "", tool_calls = [
+    {{ tool = "Read", input = {{ file_path = "first" }} }},
+    {{ tool = "Read", input = {{ file_path = "second" }} }},
+] }}
+
+[[responses.turns]]
+expect = {{ type = "any" }}
+response = "Done"
+"#,
+            script.display()
+        ),
+    )
+    .unwrap();
+
+    let output = Command::new(claudeless_bin())
+        .current_dir(dir.path())
+        .args([
+            "--scenario",
+            scenario_path.to_str().unwrap(),
+            "--output-format",
+            "stream-json",
+            "--verbose",
+            "-p",
+            "go",
+        ])
+        .output()
+        .expect("Failed to run claudeless");
+    assert!(output.status.success(), "Expected success: {:?}", output);
+
+    // Results keep call order even though the second call finished first
+    let results: Vec<String> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .filter(|event| event["type"] == "user")
+        .flat_map(|event| event["message"]["content"].as_array().cloned().unwrap())
+        .map(|block| block["content"][0]["text"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(results, vec!["first saw second", "second"]);
+}
@@ -0,0 +1,200 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+//! Integration tests for built-in tool input validation.
+
+mod common;
+
+use common::claudeless_bin;
+use serde_json::Value;
+use std::fs;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+const SCENARIO: &str = r#"
+name = "malformed-input"
+permission_mode = "bypass-permissions"
+
+[tool_execution]
+mode = "live"
+
+[[responses]]
+pattern = { type = "any" }
+response = { text = "", tool_calls = [
+    { tool = "Bash", input = { cmd = "echo hi" } },
+    { tool = "Grep", input = { pattern = "x", output_mode = "lines" } },
+    { tool = "Read", input = { path = "notes.txt" }, result = "canned" },
+    { tool = "Bash", input = { command = "echo hi" } },
+] }
+
+[[responses.turns]]
+expect = { type = "any" }
+response = "Done"
+"#;
+
+fn run(state_dir: &TempDir, extra_args: &[&str]) -> Output {
+    let dir = TempDir::new().unwrap();
+    let scenario_path = dir.path().join("scenario.toml");
+    fs::write(&scenario_path, SCENARIO).unwrap();
+    Command::new(claudeless_bin())
+        .env("CLAUDELESS_STATE_DIR", state_dir.path())
+        .current_dir(dir.path())
+        .args([
+            "--scenario",
+            scenario_path.to_str().unwrap(),
+            "--output-format",
+            "stream-json",
+            "--verbose",
+        ])
+        .args(extra_args)
+        .args(["-p", "go"])
+        .output()
+        .expect("Failed to run claudeless")
+}
+
+fn events(output: &Output) -> Vec<Value> {
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+/// Session JSONL lines that carry a message.
+fn session_messages(state_dir: &TempDir) -> Vec<Value> {
+    let project = fs::read_dir(state_dir.path().join("projects"))
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap();
+    let jsonl = fs::read_dir(project.path())
+        .unwrap()
+        .map(|e| e.unwrap().path())
+        .find(|p| p.extension().is_some_and(|e| e == "jsonl"))
+        .unwrap();
+    fs::read_to_string(jsonl)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str::<Value>(line).unwrap())
+        .filter(|line| line.get("message").is_some())
+        .collect()
+}
+
+#[test]
+fn test_malformed_tool_input_returns_input_validation_error() {
+    let state_dir = TempDir::new().unwrap();
+    let output = run(&state_dir, &[]);
+    assert!(output.status.success(), "Expected success: {:?}", output);
+
+    let results: Vec<(bool, String)> = events(&output)
+        .iter()
+        .filter(|event| event["type"] == "user")
+        .flat_map(|event| event["message"]["content"].as_array().cloned().unwrap())
+        .map(|block| {
+            (
+                block["is_error"] == true,
+                block["content"][0]["text"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+
+    assert_eq!(results.len(), 4, "{:?}", results);
+    assert_eq!(
+        results[0],
+        (
+            true,
+            "<tool_use_error>InputValidationError: Bash failed due to the following issues:\n\
+             The required parameter `command` is missing\n\
+             An unexpected parameter `cmd` was provided</tool_use_error>"
+                .to_string()
+        )
+    );
+    assert!(results[1].0);
+    assert!(
+        results[1].1.contains("\"code\": \"invalid_enum_value\""),
+        "{}",
+        results[1].1
+    );
+    // Input is checked before a canned result stands in for the tool
+    assert_eq!(
+        results[2],
+        (
+            true,
+            "<tool_use_error>InputValidationError: Read failed due to the following issues:\n\
+             The required parameter `file_path` is missing\n\
+             An unexpected parameter `path` was provided</tool_use_error>"
+                .to_string()
+        )
+    );
+    assert!(!results[3].0);
+}
+
+#[test]
+fn test_rejected_calls_are_recorded_to_session_jsonl() {
+    let state_dir = TempDir::new().unwrap();
+    let output = run(&state_dir, &[]);
+    assert!(output.status.success(), "Expected success: {:?}", output);
+
+    let blocks: Vec<Value> = session_messages(&state_dir)
+        .iter()
+        .filter_map(|line| line["message"]["content"].as_array().cloned())
+        .flatten()
+        .collect();
+    let tool_uses: Vec<&Value> = blocks.iter().filter(|b| b["type"] == "tool_use").collect();
+    let tool_results: Vec<&Value> = blocks
+        .iter()
+        .filter(|b| b["type"] == "tool_result")
+        .collect();
+
+    let names: Vec<&str> = tool_uses
+        .iter()
+        .map(|b| b["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, ["Bash", "Grep", "Read", "Bash"]);
+    assert_eq!(tool_results.len(), 4, "{:?}", tool_results);
+    for (tool_use, result) in tool_uses.iter().zip(&tool_results) {
+        assert_eq!(result["tool_use_id"], tool_use["id"]);
+    }
+    let first = tool_results[0]["content"].to_string();
+    assert!(
+        first.contains("InputValidationError: Bash failed"),
+        "{}",
+        first
+    );
+}
+
+#[test]
+fn test_init_event_carries_tool_schemas() {
+    let state_dir = TempDir::new().unwrap();
+    let output = run(
+        &state_dir,
+        &[
+            "--allowed-tools",
+            "Bash(git:*)",
+            "--allowed-tools",
+            "Bash(ls:*)",
+            "--allowed-tools",
+            "Read",
+        ],
+    );
+    assert!(output.status.success(), "Expected success: {:?}", output);
+
+    let events = events(&output);
+    let init = events
+        .iter()
+        .find(|event| event["subtype"] == "init")
+        .unwrap();
+    let schemas = init["tool_schemas"].as_array().unwrap();
+
+    let names: Vec<&str> = schemas
+        .iter()
+        .map(|schema| schema["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, vec!["Bash", "Read"]);
+    let read = &schemas[1]["input_schema"];
+    assert_eq!(read["required"], serde_json::json!(["file_path"]));
+    assert_eq!(read["additionalProperties"], false);
+    let properties: Vec<&String> = read["properties"].as_object().unwrap().keys().collect();
+    assert_eq!(properties, vec!["file_path", "offset", "limit"]);
+}
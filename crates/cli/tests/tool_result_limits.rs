@@ -0,0 +1,180 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+//! Integration tests for tool result size limits.
+
+mod common;
+
+use common::claudeless_bin;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+/// Run the scenario and return each tool result's text.
+fn run(state_dir: &TempDir, work_dir: &TempDir, scenario_path: &Path) -> Vec<String> {
+    let run = Command::new(claudeless_bin())
+        .env("CLAUDELESS_STATE_DIR", state_dir.path())
+        .current_dir(work_dir.path())
+        .args([
+            "--scenario",
+            scenario_path.to_str().unwrap(),
+            "--output-format",
+            "stream-json",
+            "--verbose",
+            "-p",
+            "go",
+        ])
+        .output()
+        .expect("Failed to run claudeless");
+    assert!(run.status.success(), "Expected success: {:?}", run);
+
+    String::from_utf8_lossy(&run.stdout)
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .filter(|event| event["type"] == "user")
+        .flat_map(|event| event["message"]["content"].as_array().cloned().unwrap())
+        .map(|block| block["content"][0]["text"].as_str().unwrap().to_string())
+        .collect()
+}
+
+/// The saved file path in a `<persisted-output>` preview.
+fn persisted_path(preview: &str) -> &str {
+    preview
+        .split("saved to: ")
+        .nth(1)
+        .and_then(|rest| rest.lines().next())
+        .unwrap()
+}
+
+#[test]
+fn test_oversized_result_is_saved_to_session_tool_results() {
+    let state_dir = TempDir::new().unwrap();
+    let work_dir = TempDir::new().unwrap();
+    let output = "0123456789\n".repeat(30);
+    let scenario_path = work_dir.path().join("scenario.toml");
+    fs::write(
+        &scenario_path,
+        format!(
+            r#"
+name = "large-output"
+permission_mode = "bypass-permissions"
+
+[tool_execution]
+mode = "mock"
+
+[tool_execution.tools.Bash]
+max_result_chars = 100
+
+[[responses]]
+pattern = {{ type = "any" }}
+response = {{ text = "", tool_calls = [
+    {{ tool = "Bash", input = {{ command = "seq" }}, result = "{}" }},
+    {{ tool = "Bash", input = {{ command = "true" }}, result = "short" }},
+] }}
+
+[[responses.turns]]
+expect = {{ type = "any" }}
+response = "Done"
+"#,
+            output.replace('\n', "\\n")
+        ),
+    )
+    .unwrap();
+
+    let results = run(&state_dir, &work_dir, &scenario_path);
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[1], "short");
+
+    let persisted = &results[0];
+    assert!(
+        persisted.starts_with(
+            "<persisted-output>\nOutput too large (330 bytes). Full output saved to: "
+        ),
+        "{}",
+        persisted
+    );
+    let path = persisted_path(persisted);
+    assert!(path.contains("/tool-results/"), "{}", path);
+    assert!(Path::new(path).starts_with(state_dir.path()), "{}", path);
+    assert_eq!(fs::read_to_string(path).unwrap(), output);
+}
+
+/// A live Bash scenario printing `seq 1 20000`, with extra `[tool_execution]`
+/// settings.
+fn seq_scenario(work_dir: &TempDir, tool_execution: &str) -> std::path::PathBuf {
+    let scenario_path = work_dir.path().join("scenario.toml");
+    fs::write(
+        &scenario_path,
+        format!(
+            r#"
+name = "large-bash-output"
+permission_mode = "bypass-permissions"
+
+[tool_execution]
+mode = "live"
+{tool_execution}
+
+[[responses]]
+pattern = {{ type = "any" }}
+response = {{ text = "", tool_calls = [
+    {{ tool = "Bash", input = {{ command = "seq 1 20000" }} }},
+] }}
+
+[[responses.turns]]
+expect = {{ type = "any" }}
+response = "Done"
+"#
+        ),
+    )
+    .unwrap();
+    scenario_path
+}
+
+/// `seq 1 20000` cut at Bash's default 30000 characters.
+fn truncated_seq() -> String {
+    let full: Vec<String> = (1..=20000).map(|n| n.to_string()).collect();
+    let full = full.join("\n");
+    let (kept, rest) = full.split_at(30_000);
+    assert_eq!(rest.lines().count(), 13779);
+    format!(
+        "{}\n\n... [13779 lines truncated] ...\n\nExit code: 0",
+        kept
+    )
+}
+
+#[test]
+#[cfg(unix)]
+fn test_live_bash_output_is_truncated_not_persisted() {
+    let state_dir = TempDir::new().unwrap();
+    let work_dir = TempDir::new().unwrap();
+    let scenario_path = seq_scenario(&work_dir, "");
+
+    let results = run(&state_dir, &work_dir, &scenario_path);
+    assert_eq!(results, vec![truncated_seq()]);
+}
+
+#[test]
+#[cfg(unix)]
+fn test_truncated_bash_output_is_persisted_over_its_limit() {
+    let state_dir = TempDir::new().unwrap();
+    let work_dir = TempDir::new().unwrap();
+    let scenario_path = seq_scenario(
+        &work_dir,
+        "\n[tool_execution.tools.Bash]\nmax_result_chars = 1000",
+    );
+
+    let results = run(&state_dir, &work_dir, &scenario_path);
+    assert_eq!(results.len(), 1);
+    assert!(
+        results[0].starts_with("<persisted-output>\n"),
+        "{}",
+        results[0]
+    );
+
+    // Bash truncates first; the size limit saves what is left
+    let saved = fs::read_to_string(persisted_path(&results[0])).unwrap();
+    assert_eq!(saved, truncated_seq());
+}
@@ -0,0 +1,198 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+//! Integration tests for the live tool sandbox.
+//!
+//! With `[tool_execution.sandbox]` (or `--sandbox`), live tools run in a
+//! temporary copy of the project. The real project is never modified, paths
+//! outside the copy are refused, and the resulting diff can be written to a
+//! file or checked against an `expect` table.
+
+mod common;
+
+use common::{claudeless_bin, write_scenario};
+use std::fs;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+fn project() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("notes.txt"), "original\n").unwrap();
+    dir
+}
+
+fn run(scenario: &tempfile::NamedTempFile, project: &TempDir, extra: &[&str]) -> Output {
+    Command::new(claudeless_bin())
+        .args(["--scenario", scenario.path().to_str().unwrap()])
+        .args(["--cwd", project.path().to_str().unwrap()])
+        .args(extra)
+        .args(["--output-format", "stream-json", "--verbose", "-p", "go"])
+        .output()
+        .expect("Failed to run claudeless")
+}
+
+/// Tool results from the stream-json `user` events, in order.
+fn tool_results(output: &Output) -> Vec<serde_json::Value> {
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .filter(|event| event["type"] == "user")
+        .flat_map(|event| event["message"]["content"].as_array().cloned().unwrap())
+        .collect()
+}
+
+fn editing_scenario(sandbox_table: &str) -> tempfile::NamedTempFile {
+    write_scenario(&format!(
+        r#"
+        name = "sandbox"
+        permission_mode = "bypass-permissions"
+
+        [tool_execution]
+        mode = "live"
+
+        {sandbox_table}
+
+        [[responses]]
+        pattern = {{ type = "any" }}
+        response = {{ text = "", tool_calls = [
+            {{ tool = "Write", input = {{ file_path = "created.txt", content = "new" }} }},
+            {{ tool = "Bash", input = {{ command = "echo changed > notes.txt" }} }},
+        ] }}
+
+        [[responses.turns]]
+        expect = {{ type = "any" }}
+        response = "Done"
+        "#
+    ))
+}
+
+#[test]
+fn test_sandbox_leaves_project_untouched() {
+    let project = project();
+    let scenario = editing_scenario("[tool_execution.sandbox]");
+
+    let output = run(&scenario, &project, &[]);
+    assert!(output.status.success(), "Expected success: {:?}", output);
+
+    let results = tool_results(&output);
+    assert_eq!(results.len(), 2);
+    assert!(
+        results.iter().all(|r| r["is_error"] != true),
+        "{:?}",
+        results
+    );
+
+    assert_eq!(
+        fs::read_to_string(project.path().join("notes.txt")).unwrap(),
+        "original\n"
+    );
+    assert!(!project.path().join("created.txt").exists());
+}
+
+#[test]
+fn test_sandbox_diff_written_to_file() {
+    let project = project();
+    let out = TempDir::new().unwrap();
+    let diff_file = out.path().join("diff.json");
+    let scenario = editing_scenario("");
+
+    let output = run(
+        &scenario,
+        &project,
+        &["--sandbox-diff", diff_file.to_str().unwrap()],
+    );
+    assert!(output.status.success(), "Expected success: {:?}", output);
+
+    let diff: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&diff_file).unwrap()).unwrap();
+    assert_eq!(diff["added"], serde_json::json!(["created.txt"]));
+    assert_eq!(diff["modified"], serde_json::json!(["notes.txt"]));
+    assert_eq!(diff["deleted"], serde_json::json!([]));
+}
+
+#[test]
+fn test_sandbox_expect_passes_on_match() {
+    let project = project();
+    let scenario = editing_scenario(
+        r#"
+        [tool_execution.sandbox.expect]
+        added = ["created.txt"]
+        modified = ["notes.txt"]
+        "#,
+    );
+
+    let output = run(&scenario, &project, &[]);
+    assert!(output.status.success(), "Expected success: {:?}", output);
+}
+
+#[test]
+fn test_sandbox_expect_fails_on_mismatch() {
+    let project = project();
+    let scenario = editing_scenario(
+        r#"
+        [tool_execution.sandbox.expect]
+        added = ["created.txt"]
+        "#,
+    );
+
+    let output = run(&scenario, &project, &[]);
+    assert!(!output.status.success(), "Expected failure: {:?}", output);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Sandbox diff does not match expectation"),
+        "stderr: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_sandbox_refuses_paths_outside_project() {
+    let project = project();
+    let outside = TempDir::new().unwrap();
+    let target = outside.path().join("escaped.txt");
+    let scenario = write_scenario(&format!(
+        r#"
+        name = "sandbox-escape"
+        permission_mode = "bypass-permissions"
+
+        [tool_execution]
+        mode = "live"
+
+        [tool_execution.sandbox]
+
+        [[responses]]
+        pattern = {{ type = "any" }}
+        response = {{ text = "", tool_calls = [
+            {{ tool = "Write", input = {{ file_path = "{}", content = "x" }} }},
+            {{ tool = "Read", input = {{ file_path = "../notes.txt" }} }},
+            {{ tool = "Bash", input = {{ command = "echo x > {}" }} }},
+        ] }}
+
+        [[responses.turns]]
+        expect = {{ type = "any" }}
+        response = "Done"
+        "#,
+        target.display(),
+        target.display()
+    ));
+
+    let output = run(&scenario, &project, &[]);
+    assert!(output.status.success(), "Expected success: {:?}", output);
+
+    let results = tool_results(&output);
+    assert_eq!(results.len(), 3);
+    for result in &results {
+        assert_eq!(result["is_error"], true, "{:?}", result);
+        assert!(
+            result["content"][0]["text"]
+                .as_str()
+                .unwrap()
+                .contains("is outside the sandbox"),
+            "{:?}",
+            result
+        );
+    }
+    assert!(!target.exists());
+}
@@ -0,0 +1,182 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+//! Integration tests for skill discovery and the Skill tool.
+
+mod common;
+
+use common::claudeless_bin;
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+const SCENARIO: &str = r#"
+name = "skill"
+
+[[responses]]
+pattern = { type = "exact", text = "release" }
+response = { text = "Using the release skill", tool_calls = [{ tool = "Skill", input = { skill = "release" } }] }
+turns = [{ expect = { type = "skill", name = "release" }, response = "Released by the book" }]
+"#;
+
+fn write_skill(skills_dir: &Path, name: &str, content: &str) {
+    let dir = skills_dir.join(name);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("SKILL.md"), content).unwrap();
+}
+
+fn run(state_dir: &TempDir, work_dir: &TempDir, extra_args: &[&str]) -> Output {
+    let scenario_path = work_dir.path().join("scenario.toml");
+    fs::write(&scenario_path, SCENARIO).unwrap();
+
+    Command::new(claudeless_bin())
+        .env("CLAUDELESS_STATE_DIR", state_dir.path())
+        .current_dir(work_dir.path())
+        .args(["--scenario", scenario_path.to_str().unwrap()])
+        .args(extra_args)
+        .args([
+            "--output-format",
+            "stream-json",
+            "--verbose",
+            "-p",
+            "release",
+        ])
+        .output()
+        .expect("Failed to run claudeless")
+}
+
+/// Session JSONL lines that carry a message.
+fn session_messages(state_dir: &TempDir) -> Vec<serde_json::Value> {
+    let project = fs::read_dir(state_dir.path().join("projects"))
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap();
+    let jsonl = fs::read_dir(project.path())
+        .unwrap()
+        .map(|e| e.unwrap().path())
+        .find(|p| p.extension().is_some_and(|e| e == "jsonl"))
+        .unwrap();
+    fs::read_to_string(jsonl)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .filter(|line| line.get("message").is_some())
+        .collect()
+}
+
+#[test]
+fn test_skills_are_discovered_and_loaded_into_the_turn() {
+    let state_dir = TempDir::new().unwrap();
+    let work_dir = TempDir::new().unwrap();
+    let project_skills = work_dir.path().join(".claude").join("skills");
+    write_skill(
+        &project_skills,
+        "release",
+        "---\nname: release\ndescription: Cuts a release\n---\nTag, build and publish.\n",
+    );
+    write_skill(
+        &state_dir.path().join("skills"),
+        "notes",
+        "---\ndescription: Writes release notes\n---\nSummarize the changes.\n",
+    );
+
+    let output = run(&state_dir, &work_dir, &[]);
+    assert!(output.status.success(), "claudeless failed: {:?}", output);
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let events: Vec<serde_json::Value> = stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(events[0]["skills"], serde_json::json!(["notes", "release"]));
+    let slash_commands = events[0]["slash_commands"].as_array().unwrap();
+    assert!(slash_commands.contains(&serde_json::json!("release")));
+    assert_eq!(events.last().unwrap()["result"], "Released by the book");
+
+    // The tool result is followed by the skill's instructions as a meta message
+    let messages = session_messages(&state_dir);
+    let result_index = messages
+        .iter()
+        .position(|m| m["toolUseResult"]["commandName"] == "release")
+        .unwrap();
+    let tool_result = &messages[result_index];
+    assert_eq!(
+        tool_result["message"]["content"][0]["content"],
+        "Launching skill: release"
+    );
+    assert_eq!(tool_result["toolUseResult"]["success"], true);
+
+    let meta = &messages[result_index + 1];
+    assert_eq!(meta["isMeta"], true);
+    assert_eq!(meta["parentUuid"], tool_result["uuid"]);
+    assert_eq!(
+        meta["sourceToolUseID"],
+        tool_result["message"]["content"][0]["tool_use_id"]
+    );
+    let text = meta["message"]["content"][0]["text"].as_str().unwrap();
+    assert!(
+        text.starts_with("Base directory for this skill: "),
+        "{}",
+        text
+    );
+    assert!(text.ends_with("Tag, build and publish."), "{}", text);
+}
+
+#[test]
+fn test_disable_slash_commands_disables_skills() {
+    let state_dir = TempDir::new().unwrap();
+    let work_dir = TempDir::new().unwrap();
+    write_skill(
+        &work_dir.path().join(".claude").join("skills"),
+        "release",
+        "---\ndescription: Cuts a release\n---\nTag it.\n",
+    );
+
+    let output = run(&state_dir, &work_dir, &["--disable-slash-commands"]);
+    assert!(output.status.success(), "claudeless failed: {:?}", output);
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let init: serde_json::Value = serde_json::from_str(stdout.lines().next().unwrap()).unwrap();
+    assert_eq!(init["skills"], serde_json::json!([]));
+    assert!(stdout.contains("Unknown skill: release"), "{}", stdout);
+}
+
+#[test]
+fn test_plugin_dir_plugins_are_reported_in_init_event() {
+    let state_dir = TempDir::new().unwrap();
+    let work_dir = TempDir::new().unwrap();
+    let plugin_dir = work_dir.path().join("release-plugin");
+    fs::create_dir_all(plugin_dir.join(".claude-plugin")).unwrap();
+    fs::write(
+        plugin_dir.join(".claude-plugin").join("plugin.json"),
+        r#"{"name": "release-tools"}"#,
+    )
+    .unwrap();
+    write_skill(
+        &plugin_dir.join("skills"),
+        "notes",
+        "---\ndescription: Writes release notes\n---\nSummarize the changes.\n",
+    );
+
+    let output = run(
+        &state_dir,
+        &work_dir,
+        &["--plugin-dir", "release-plugin", "--plugin-dir", "missing"],
+    );
+    assert!(output.status.success(), "claudeless failed: {:?}", output);
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let init: serde_json::Value = serde_json::from_str(stdout.lines().next().unwrap()).unwrap();
+    assert_eq!(
+        init["plugins"],
+        serde_json::json!([{
+            "name": "release-tools",
+            "path": work_dir.path().join("release-plugin").to_string_lossy(),
+        }])
+    );
+    assert_eq!(init["skills"], serde_json::json!(["release-tools:notes"]));
+}
@@ -0,0 +1,194 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+//! Integration tests for the Task tool, scenario subagents and custom agents.
+
+mod common;
+
+use common::claudeless_bin;
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+
+const SCENARIO: &str = r#"
+name = "task"
+
+[default_response]
+text = "Delegating"
+tool_calls = [{ tool = "Task", input = { description = "Find entry point", prompt = "Where is main?", subagent_type = "Explore" } }]
+
+[[subagents]]
+subagent_type = "Explore"
+response = { text = "", tool_calls = [{ tool = "Glob", input = { pattern = "*.rs" } }] }
+turns = [{ expect = { type = "contains", text = "main.rs" }, response = "main is in main.rs" }]
+"#;
+
+/// Session JSONL lines that carry a message.
+fn session_messages(state_dir: &TempDir) -> Vec<serde_json::Value> {
+    let projects = state_dir.path().join("projects");
+    let project = fs::read_dir(&projects).unwrap().next().unwrap().unwrap();
+    let jsonl = fs::read_dir(project.path())
+        .unwrap()
+        .map(|e| e.unwrap().path())
+        .find(|p| p.extension().is_some_and(|e| e == "jsonl"))
+        .unwrap();
+    fs::read_to_string(jsonl)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .filter(|line| line.get("message").is_some())
+        .collect()
+}
+
+#[test]
+fn test_task_runs_subagent_with_sidechain_transcript() {
+    let state_dir = TempDir::new().unwrap();
+    let work_dir = TempDir::new().unwrap();
+    fs::write(work_dir.path().join("main.rs"), "fn main() {}\n").unwrap();
+    let scenario_path = work_dir.path().join("scenario.toml");
+    fs::write(&scenario_path, SCENARIO).unwrap();
+
+    let output = Command::new(claudeless_bin())
+        .env("CLAUDELESS_STATE_DIR", state_dir.path())
+        .current_dir(work_dir.path())
+        .args([
+            "--scenario",
+            scenario_path.to_str().unwrap(),
+            "--output-format",
+            "stream-json",
+            "--verbose",
+            "-p",
+            "find main",
+        ])
+        .output()
+        .expect("Failed to run claudeless");
+    assert!(output.status.success(), "claudeless failed: {:?}", output);
+
+    // The Task result is the subagent's final text
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let task_result = stdout
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .find(|event| event["type"] == "user")
+        .unwrap();
+    assert_eq!(
+        task_result["message"]["content"][0]["content"][0]["text"],
+        "main is in main.rs"
+    );
+
+    let messages = session_messages(&state_dir);
+    let task_use = messages
+        .iter()
+        .find(|m| m["message"]["content"][0]["name"] == "Task")
+        .unwrap();
+    assert_eq!(task_use["isSidechain"], false);
+
+    let sidechain: Vec<_> = messages
+        .iter()
+        .filter(|m| m["isSidechain"] == true)
+        .collect();
+    // Prompt, Glob call, Glob result, final answer
+    assert_eq!(sidechain.len(), 4, "sidechain: {:#?}", sidechain);
+    assert_eq!(sidechain[0]["message"]["content"], "Where is main?");
+    assert_eq!(sidechain[0]["parentUuid"], task_use["uuid"]);
+    for pair in sidechain.windows(2) {
+        assert_eq!(pair[1]["parentUuid"], pair[0]["uuid"]);
+        assert_eq!(pair[1]["agentId"], pair[0]["agentId"]);
+    }
+    assert_eq!(sidechain[1]["message"]["content"][0]["name"], "Glob");
+    assert_eq!(
+        sidechain[3]["message"]["content"][0]["text"],
+        "main is in main.rs"
+    );
+
+    // The main conversation's Task result follows the Task call
+    let task_id = &task_use["message"]["content"][0]["id"];
+    let main_result = messages
+        .iter()
+        .find(|m| m["message"]["content"][0]["tool_use_id"] == *task_id)
+        .unwrap();
+    assert_eq!(main_result["isSidechain"], false);
+    assert_eq!(main_result["parentUuid"], task_use["uuid"]);
+    assert_eq!(main_result["toolUseResult"]["totalToolUseCount"], 1);
+
+    // The subagent's tokens count toward the session's usage
+    let subagent_usage = &main_result["toolUseResult"]["usage"];
+    let result = stdout
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .find(|event| event["type"] == "result")
+        .unwrap();
+    for field in ["input_tokens", "output_tokens"] {
+        assert!(
+            result["usage"][field].as_u64() > subagent_usage[field].as_u64(),
+            "{}: {} vs {}",
+            field,
+            result["usage"],
+            subagent_usage
+        );
+    }
+}
+
+const AGENT_SCENARIO: &str = r#"
+name = "custom-agent"
+
+[default_response]
+text = "Asking the reviewer"
+tool_calls = [{ tool = "Task", input = { description = "Review", prompt = "Review main.rs", subagent_type = "reviewer" } }]
+
+[[subagents]]
+subagent_type = "reviewer"
+response = { text = "", tool_calls = [{ tool = "Glob", input = { pattern = "*.rs" } }] }
+turns = [{ expect = { type = "contains", text = "No such tool available: Glob" }, response = "Reviewed without Glob" }]
+"#;
+
+#[test]
+fn test_custom_agents_are_reported_and_limited_to_their_tools() {
+    let state_dir = TempDir::new().unwrap();
+    let work_dir = TempDir::new().unwrap();
+    let agents_dir = work_dir.path().join(".claude").join("agents");
+    fs::create_dir_all(&agents_dir).unwrap();
+    fs::write(
+        agents_dir.join("reviewer.md"),
+        "---\nname: reviewer\ndescription: Reviews code\ntools: Read, Grep\n---\nYou review code.\n",
+    )
+    .unwrap();
+    let scenario_path = work_dir.path().join("scenario.toml");
+    fs::write(&scenario_path, AGENT_SCENARIO).unwrap();
+
+    let output = Command::new(claudeless_bin())
+        .env("CLAUDELESS_STATE_DIR", state_dir.path())
+        .current_dir(work_dir.path())
+        .args([
+            "--scenario",
+            scenario_path.to_str().unwrap(),
+            "--agents",
+            r#"{"helper": {"description": "Helps", "prompt": "Help"}}"#,
+            "--output-format",
+            "stream-json",
+            "--verbose",
+            "-p",
+            "review",
+        ])
+        .output()
+        .expect("Failed to run claudeless");
+    assert!(output.status.success(), "claudeless failed: {:?}", output);
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let events: Vec<serde_json::Value> = stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    let agents = events[0]["agents"].as_array().unwrap();
+    for name in ["general-purpose", "helper", "reviewer"] {
+        assert!(agents.contains(&serde_json::json!(name)), "{:?}", agents);
+    }
+
+    let task_result = events.iter().find(|event| event["type"] == "user").unwrap();
+    assert_eq!(
+        task_result["message"]["content"][0]["content"][0]["text"],
+        "Reviewed without Glob"
+    );
+}
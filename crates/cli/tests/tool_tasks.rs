@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+//! Integration tests for TaskCreate, TaskUpdate, TaskList and TaskGet.
+//!
+//! Tasks are saved as `~/.claude/tasks/{sessionId}/{id}.json`, and each tool
+//! result is recorded as `toolUseResult` in the session transcript.
+
+mod common;
+
+use common::claudeless_bin;
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+const SCENARIO: &str = r#"
+name = "tasks"
+
+[default_response]
+text = "Planning"
+tool_calls = [
+    { tool = "TaskCreate", input = { subject = "Build", description = "Compile the project", activeForm = "Building" } },
+    { tool = "TaskCreate", input = { subject = "Deploy", description = "Ship it" } },
+    { tool = "TaskUpdate", input = { taskId = "2", addBlockedBy = ["1"] } },
+    { tool = "TaskUpdate", input = { taskId = "1", status = "in_progress", owner = "main" } },
+    { tool = "TaskList", input = {} },
+]
+
+[tool_execution]
+mode = "live"
+"#;
+
+const EXPECT_GRAPH: &str = r#"
+[[tasks.expect]]
+subject = "Build"
+status = "in_progress"
+owner = "main"
+blocks = ["2"]
+
+[[tasks.expect]]
+subject = "Deploy"
+status = "pending"
+blocked_by = ["1"]
+"#;
+
+fn run(state_dir: &TempDir, scenario: &str) -> Output {
+    let work_dir = TempDir::new().unwrap();
+    let scenario_path = work_dir.path().join("scenario.toml");
+    fs::write(&scenario_path, scenario).unwrap();
+
+    Command::new(claudeless_bin())
+        .env("CLAUDELESS_STATE_DIR", state_dir.path())
+        .current_dir(work_dir.path())
+        .args(["--scenario", scenario_path.to_str().unwrap(), "-p", "plan"])
+        .output()
+        .expect("Failed to run claudeless")
+}
+
+/// The single session directory under `tasks/`.
+fn session_tasks_dir(state_dir: &Path) -> std::path::PathBuf {
+    fs::read_dir(state_dir.join("tasks"))
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap()
+        .path()
+}
+
+/// `toolUseResult` values recorded in the session transcript.
+fn tool_use_results(state_dir: &Path) -> Vec<serde_json::Value> {
+    let project = fs::read_dir(state_dir.join("projects"))
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap();
+    let jsonl = fs::read_dir(project.path())
+        .unwrap()
+        .map(|e| e.unwrap().path())
+        .find(|p| p.extension().is_some_and(|e| e == "jsonl"))
+        .unwrap();
+    fs::read_to_string(jsonl)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .filter_map(|line| line.get("toolUseResult").cloned())
+        .collect()
+}
+
+#[test]
+fn test_tasks_are_saved_and_recorded_in_transcript() {
+    let state_dir = TempDir::new().unwrap();
+
+    let output = run(&state_dir, SCENARIO);
+    assert!(output.status.success(), "claudeless failed: {:?}", output);
+
+    let tasks_dir = session_tasks_dir(state_dir.path());
+    let deploy: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(tasks_dir.join("2.json")).unwrap()).unwrap();
+    assert_eq!(deploy["subject"], "Deploy");
+    assert_eq!(deploy["blockedBy"], serde_json::json!(["1"]));
+    let build: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(tasks_dir.join("1.json")).unwrap()).unwrap();
+    assert_eq!(build["status"], "in_progress");
+    assert_eq!(build["blocks"], serde_json::json!(["2"]));
+
+    let results = tool_use_results(state_dir.path());
+    assert_eq!(results.len(), 5);
+    assert_eq!(results[0]["task"]["id"], "1");
+    assert_eq!(
+        results[2]["updatedFields"],
+        serde_json::json!(["blockedBy"])
+    );
+    assert_eq!(
+        results[3]["statusChange"],
+        serde_json::json!({ "from": "pending", "to": "in_progress" })
+    );
+    assert_eq!(
+        results[4]["tasks"][1]["blockedBy"],
+        serde_json::json!(["1"])
+    );
+}
+
+#[test]
+fn test_expected_task_graph_passes() {
+    let state_dir = TempDir::new().unwrap();
+
+    let output = run(&state_dir, &format!("{}{}", SCENARIO, EXPECT_GRAPH));
+
+    assert!(output.status.success(), "claudeless failed: {:?}", output);
+}
+
+#[test]
+fn test_unexpected_task_graph_fails_session() {
+    let state_dir = TempDir::new().unwrap();
+    let scenario = format!(
+        "{}{}",
+        SCENARIO,
+        EXPECT_GRAPH.replace("status = \"in_progress\"", "status = \"completed\"")
+    );
+
+    let output = run(&state_dir, &scenario);
+
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("#1 Build: expected status 'completed', found 'in_progress'"),
+        "stderr: {}",
+        stderr
+    );
+}
@@ -0,0 +1,308 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+//! Integration tests for the `vfs` tool execution mode.
+//!
+//! File tools operate on an in-memory tree seeded from `[[files]]` and
+//! `fixture_dir`. Nothing touches the disk; the final tree can be exported
+//! with `export_dir` or checked against `[[tool_execution.vfs.expect]]`.
+
+mod common;
+
+use common::claudeless_bin;
+use std::fs;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+/// Write a scenario (and any fixture files) into a temp directory.
+fn scenario_dir(scenario: &str, fixtures: &[(&str, &str)]) -> TempDir {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("scenario.toml"), scenario).unwrap();
+    for (path, content) in fixtures {
+        let path = dir.path().join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+    dir
+}
+
+fn run(dir: &TempDir) -> Output {
+    Command::new(claudeless_bin())
+        .args([
+            "--scenario",
+            dir.path().join("scenario.toml").to_str().unwrap(),
+            "--output-format",
+            "stream-json",
+            "--verbose",
+            "-p",
+            "go",
+        ])
+        .output()
+        .expect("Failed to run claudeless")
+}
+
+/// Text of each tool result from the stream-json `user` events.
+fn tool_results(output: &Output) -> Vec<(bool, String)> {
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .filter(|event| event["type"] == "user")
+        .flat_map(|event| event["message"]["content"].as_array().cloned().unwrap())
+        .map(|block| {
+            (
+                block["is_error"] == true,
+                block["content"][0]["text"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+const HEADER: &str = r#"
+name = "vfs"
+permission_mode = "bypass-permissions"
+working_directory = "/vfs-project"
+
+[tool_execution]
+mode = "vfs"
+"#;
+
+#[test]
+fn test_vfs_tools_operate_on_seeded_tree() {
+    let dir = scenario_dir(
+        &format!(
+            r#"{HEADER}
+[tool_execution.vfs]
+fixture_dir = "fixture"
+
+[[files]]
+path = "src/main.rs"
+content = {{ "$file" = "main.rs" }}
+
+[[responses]]
+pattern = {{ type = "any" }}
+response = {{ text = "", tool_calls = [
+    {{ tool = "Read", input = {{ file_path = "/vfs-project/src/main.rs" }} }},
+    {{ tool = "Read", input = {{ file_path = "README.md" }} }},
+    {{ tool = "Edit", input = {{ file_path = "src/main.rs", old_string = "{{}}", new_string = "{{ run() }}" }} }},
+    {{ tool = "Write", input = {{ file_path = "src/lib.rs", content = "pub fn run() {{}}" }} }},
+    {{ tool = "Glob", input = {{ pattern = "src/*.rs" }} }},
+    {{ tool = "Grep", input = {{ pattern = "run", output_mode = "content" }} }},
+] }}
+
+[[responses.turns]]
+expect = {{ type = "any" }}
+response = "Done"
+"#
+        ),
+        &[
+            ("main.rs", "fn main() {}"),
+            ("fixture/README.md", "# Project"),
+        ],
+    );
+
+    let output = run(&dir);
+    assert!(output.status.success(), "Expected success: {:?}", output);
+
+    let results = tool_results(&output);
+    assert_eq!(results.len(), 6);
+    assert!(
+        results.iter().all(|(is_error, _)| !is_error),
+        "{:?}",
+        results
+    );
+    assert!(results[0].1.starts_with("     1\u{2192}fn main() {}\n\n"));
+    assert!(results[1].1.starts_with("     1\u{2192}# Project\n\n"));
+    assert_eq!(
+        results[4].1,
+        "/vfs-project/src/lib.rs\n/vfs-project/src/main.rs"
+    );
+    assert!(results[5]
+        .1
+        .contains("/vfs-project/src/lib.rs:1:pub fn run() {}"));
+    assert!(results[5]
+        .1
+        .contains("/vfs-project/src/main.rs:1:fn main() { run() }"));
+
+    assert!(!std::path::Path::new("/vfs-project").exists());
+}
+
+#[test]
+fn test_vfs_export_and_expect() {
+    let out = TempDir::new().unwrap();
+    let dir = scenario_dir(
+        &format!(
+            r#"{HEADER}
+[tool_execution.vfs]
+export_dir = "{}"
+
+[[tool_execution.vfs.expect]]
+path = "notes.txt"
+content = "updated"
+
+[[files]]
+path = "notes.txt"
+content = "original"
+
+[[responses]]
+pattern = {{ type = "any" }}
+response = {{ text = "", tool_calls = [
+    {{ tool = "Read", input = {{ file_path = "notes.txt" }} }},
+    {{ tool = "Write", input = {{ file_path = "notes.txt", content = "updated" }} }},
+] }}
+
+[[responses.turns]]
+expect = {{ type = "any" }}
+response = "Done"
+"#,
+            out.path().display()
+        ),
+        &[],
+    );
+
+    let output = run(&dir);
+    assert!(output.status.success(), "Expected success: {:?}", output);
+    assert_eq!(
+        fs::read_to_string(out.path().join("notes.txt")).unwrap(),
+        "updated"
+    );
+}
+
+#[test]
+fn test_vfs_expect_mismatch_fails() {
+    let dir = scenario_dir(
+        &format!(
+            r#"{HEADER}
+[[tool_execution.vfs.expect]]
+path = "notes.txt"
+content = "updated"
+
+[[files]]
+path = "notes.txt"
+content = "original"
+
+[[responses]]
+pattern = {{ type = "any" }}
+response = "Nothing to do"
+"#
+        ),
+        &[],
+    );
+
+    let output = run(&dir);
+    assert!(!output.status.success(), "Expected failure: {:?}", output);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("notes.txt: content differs"),
+        "stderr: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_vfs_write_requires_prior_read() {
+    let dir = scenario_dir(
+        &format!(
+            r#"{HEADER}
+[[files]]
+path = "notes.txt"
+content = "original"
+
+[[responses]]
+pattern = {{ type = "any" }}
+response = {{ text = "", tool_calls = [
+    {{ tool = "Write", input = {{ file_path = "notes.txt", content = "updated" }} }},
+] }}
+
+[[responses.turns]]
+expect = {{ type = "any" }}
+response = "Done"
+"#
+        ),
+        &[],
+    );
+
+    let output = run(&dir);
+    assert!(output.status.success(), "Expected success: {:?}", output);
+    let results = tool_results(&output);
+    assert_eq!(
+        results,
+        vec![(
+            true,
+            "File has not been read yet. Read it first before writing to it.".to_string()
+        )]
+    );
+}
+
+#[test]
+fn test_vfs_refuses_bash() {
+    let dir = scenario_dir(
+        &format!(
+            r#"{HEADER}
+[[responses]]
+pattern = {{ type = "any" }}
+response = {{ text = "", tool_calls = [
+    {{ tool = "Bash", input = {{ command = "touch /tmp/should-not-exist" }} }},
+] }}
+
+[[responses.turns]]
+expect = {{ type = "any" }}
+response = "Done"
+"#
+        ),
+        &[],
+    );
+
+    let output = run(&dir);
+    assert!(output.status.success(), "Expected success: {:?}", output);
+    let results = tool_results(&output);
+    assert_eq!(results.len(), 1);
+    assert!(results[0].0);
+    assert!(results[0].1.contains("not available in vfs mode"));
+}
+
+#[test]
+fn test_vfs_reads_binary_fixtures() {
+    let dir = scenario_dir(
+        &format!(
+            r#"{HEADER}
+[tool_execution.vfs]
+fixture_dir = "fixture"
+
+[[responses]]
+pattern = {{ type = "any" }}
+response = {{ text = "", tool_calls = [
+    {{ tool = "Read", input = {{ file_path = "logo.png" }} }},
+    {{ tool = "Read", input = {{ file_path = "data.bin" }} }},
+] }}
+
+[[responses.turns]]
+expect = {{ type = "any" }}
+response = "Done"
+"#
+        ),
+        &[],
+    );
+    fs::create_dir(dir.path().join("fixture")).unwrap();
+    fs::write(dir.path().join("fixture/logo.png"), b"\x89PNG\xff").unwrap();
+    fs::write(dir.path().join("fixture/data.bin"), [0u8, 159, 146, 150]).unwrap();
+
+    let output = run(&dir);
+    assert!(output.status.success(), "Expected success: {:?}", output);
+
+    let blocks: Vec<serde_json::Value> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .filter(|event| event["type"] == "user")
+        .flat_map(|event| event["message"]["content"].as_array().cloned().unwrap())
+        .collect();
+    assert_eq!(blocks.len(), 2);
+    assert_eq!(blocks[0]["content"][0]["type"], "image");
+    assert_eq!(blocks[0]["content"][0]["source"]["media_type"], "image/png");
+    assert_eq!(blocks[1]["is_error"], true);
+    assert!(blocks[1]["content"][0]["text"]
+        .as_str()
+        .unwrap()
+        .contains("cannot read binary files"));
+}
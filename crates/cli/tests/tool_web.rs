@@ -0,0 +1,144 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+//! Integration tests for WebFetch and WebSearch against scenario web fixtures.
+
+mod common;
+
+use common::claudeless_bin;
+use std::fs;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+fn run(scenario: &str) -> Output {
+    let dir = TempDir::new().unwrap();
+    let scenario_path = dir.path().join("scenario.toml");
+    fs::write(&scenario_path, scenario).unwrap();
+    Command::new(claudeless_bin())
+        .current_dir(dir.path())
+        .args([
+            "--scenario",
+            scenario_path.to_str().unwrap(),
+            "--output-format",
+            "stream-json",
+            "--verbose",
+            "--settings",
+            r#"{"permissions": {"allow": ["WebFetch", "WebSearch"], "deny": ["WebFetch(domain:blocked.example)"]}}"#,
+            "-p",
+            "go",
+        ])
+        .output()
+        .expect("Failed to run claudeless")
+}
+
+/// Each tool result's error flag and text, from the stream-json `user` events.
+fn tool_results(output: &Output) -> Vec<(bool, String)> {
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .filter(|event| event["type"] == "user")
+        .flat_map(|event| event["message"]["content"].as_array().cloned().unwrap())
+        .map(|block| {
+            (
+                block["is_error"] == true,
+                block["content"][0]["text"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_web_fetch_and_search_use_fixtures() {
+    let output = run(r#"
+name = "web"
+
+[web.pages."https://example.com/docs"]
+body = "Example docs"
+
+[web.pages."https://example.com/moved"]
+redirect = "https://other.org/docs"
+
+[web.search."example docs"]
+summary = "The docs live on example.com."
+results = [{ title = "Example", url = "https://example.com/docs" }]
+
+[[responses]]
+pattern = { type = "any" }
+response = { text = "", tool_calls = [
+    { tool = "WebFetch", input = { url = "http://example.com/docs", prompt = "Summarize" } },
+    { tool = "WebFetch", input = { url = "https://example.com/moved", prompt = "Summarize" } },
+    { tool = "WebFetch", input = { url = "https://example.com/missing", prompt = "Summarize" } },
+    { tool = "WebFetch", input = { url = "https://blocked.example/", prompt = "Summarize" } },
+    { tool = "WebSearch", input = { query = "example docs" } },
+] }
+
+[[responses.turns]]
+expect = { type = "any" }
+response = "Done"
+"#);
+    assert!(output.status.success(), "Expected success: {:?}", output);
+
+    let results = tool_results(&output);
+    assert_eq!(results.len(), 5, "{:?}", results);
+    // http is upgraded to the https fixture
+    assert_eq!(results[0], (false, "Example docs".to_string()));
+    assert!(results[1].1.starts_with("REDIRECT DETECTED"));
+    assert!(results[1]
+        .1
+        .contains("Redirect URL: https://other.org/docs"));
+    assert!(results[2].0);
+    assert!(results[2].1.contains("no web fixture for this URL"));
+    assert!(results[3].0, "domain rule should deny: {:?}", results[3]);
+    assert!(results[4].1.starts_with(
+        "Web search results for query: \"example docs\"\n\nLinks: [{\"title\":\"Example\""
+    ));
+    assert!(results[4].1.ends_with("The docs live on example.com."));
+}
+
+#[test]
+fn test_web_fetch_localhost_server() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let server = std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut request = Vec::new();
+        let mut chunk = [0u8; 1024];
+        while !request.ends_with(b"\r\n\r\n") {
+            let n = stream.read(&mut chunk).unwrap();
+            assert!(n > 0, "request ended early");
+            request.extend_from_slice(&chunk[..n]);
+        }
+        stream
+            .write_all(b"HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\n\r\nfrom the local server")
+            .unwrap();
+    });
+
+    let output = run(&format!(
+        r#"
+name = "web-localhost"
+
+[web]
+localhost = true
+
+[[responses]]
+pattern = {{ type = "any" }}
+response = {{ text = "", tool_calls = [
+    {{ tool = "WebFetch", input = {{ url = "http://localhost:{port}/page", prompt = "Read" }} }},
+] }}
+
+[[responses.turns]]
+expect = {{ type = "any" }}
+response = "Done"
+"#
+    ));
+    server.join().unwrap();
+    assert!(output.status.success(), "Expected success: {:?}", output);
+    assert_eq!(
+        tool_results(&output),
+        vec![(false, "from the local server".to_string())]
+    );
+}
@@ -12,7 +12,7 @@
 
 mod common;
 
-use common::capture_key_sequence;
+use common::{capture_key_sequence, TuiTestSession};
 
 /// Behavior observed with: claude --version 2.1.12 (Claude Code)
 ///
@@ -110,3 +110,44 @@ fn test_thinking_dialog_escape_cancels() {
         after_escape
     );
 }
+
+/// Scenario thinking renders as a collapsed "∴ Thinking…" line before the
+/// response, and Ctrl+O expands it
+#[test]
+fn test_thinking_block_renders_before_response() {
+    let scenario = r#"
+name = "thinking-block"
+
+[[responses]]
+pattern = { type = "any" }
+response = { text = "The answer is 4.", thinking = "Two plus two is four." }
+"#;
+    let tui = TuiTestSession::new("thinking-block", scenario);
+    tui.wait_for("for shortcuts");
+
+    tui.send_line("what is 2+2?");
+    let capture = tui.wait_for("The answer is 4.");
+
+    let header = capture.find("\u{2234} Thinking\u{2026} (ctrl+o to expand)");
+    let answer = capture.find("The answer is 4.");
+    assert!(
+        header.is_some() && header < answer,
+        "Collapsed thinking should precede the response.\nCapture:\n{}",
+        capture
+    );
+    assert!(
+        !capture.contains("Two plus two is four."),
+        "Thinking text should start collapsed.\nCapture:\n{}",
+        capture
+    );
+
+    tui.send_keys("C-o");
+    let capture = tui.wait_for("Two plus two is four.");
+    let body = capture.find("Two plus two is four.");
+    let answer = capture.find("The answer is 4.");
+    assert!(
+        body < answer,
+        "Expanded thinking should precede the response.\nCapture:\n{}",
+        capture
+    );
+}
@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+//! TUI usage limit and rate limit tests.
+//!
+//! ## Usage Limit Behavior
+//! - `[usage_limit]` shows a notice above the input area
+//! - Reset times are computed from `launch_timestamp` (default: 5 hours later)
+//! - When the 5-hour limit is reached, prompts are rejected with the notice
+//! - A `rate_limit` failure renders an `API Error: 429` block under the prompt
+
+mod common;
+
+use common::TuiTestSession;
+
+fn usage_limit_scenario(state: &str) -> String {
+    format!(
+        r#"
+name = "usage-limit"
+launch_timestamp = "2025-01-15T10:00:00Z"
+
+[usage_limit]
+state = "{state}"
+
+[[responses]]
+pattern = {{ type = "any" }}
+response = "Hello!"
+"#
+    )
+}
+
+#[test]
+fn test_tui_approaching_usage_limit_notice() {
+    let tui = TuiTestSession::new("usage-approaching", &usage_limit_scenario("approaching"));
+    let capture = tui.wait_for("Approaching usage limit");
+
+    assert!(
+        capture.contains("Approaching usage limit · resets 3pm"),
+        "Should show approaching notice with reset time.\nCapture:\n{}",
+        capture
+    );
+}
+
+#[test]
+fn test_tui_usage_limit_reached_rejects_prompt() {
+    let tui = TuiTestSession::new("usage-reached", &usage_limit_scenario("reached"));
+    tui.wait_for("5-hour limit reached");
+
+    tui.send_line("hello");
+    let capture = tui.wait_for("⎿  5-hour limit reached");
+
+    assert!(
+        capture.contains("⎿  5-hour limit reached · resets 3pm"),
+        "Should show limit block under the prompt.\nCapture:\n{}",
+        capture
+    );
+    assert!(
+        !capture.contains("Hello!"),
+        "Prompt should not be answered.\nCapture:\n{}",
+        capture
+    );
+}
+
+#[test]
+fn test_tui_opus_limit_fallback_notice() {
+    let tui = TuiTestSession::new("usage-opus", &usage_limit_scenario("opus_reached"));
+    let capture = tui.wait_for("Opus limit reached");
+
+    assert!(
+        capture.contains("Opus limit reached, now using Sonnet 4.5"),
+        "Should show Opus fallback notice.\nCapture:\n{}",
+        capture
+    );
+}
+
+#[test]
+fn test_tui_rate_limit_shows_api_error_block() {
+    let scenario = r#"
+name = "rate-limit"
+
+[[responses]]
+pattern = { type = "any" }
+failure = { type = "rate_limit", retry_after = 30 }
+"#;
+    let tui = TuiTestSession::new("rate-limit-429", scenario);
+
+    tui.send_line("hello");
+    let capture = tui.wait_for("API Error: 429");
+
+    assert!(
+        capture.contains("⎿  API Error: 429"),
+        "Should show 429 error block under the prompt.\nCapture:\n{}",
+        capture
+    );
+    assert!(
+        capture.contains("rate_limit_error"),
+        "Should include the error type.\nCapture:\n{}",
+        capture
+    );
+}
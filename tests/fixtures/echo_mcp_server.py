@@ -7,9 +7,12 @@
 Implements the MCP protocol for testing:
 - initialize: Returns server info and capabilities
 - notifications/initialized: Acknowledges initialization
-- tools/list: Returns one echo tool
+- tools/list: Returns the echo, fail and slow_echo tools
 - tools/call: Echoes back the input arguments
 
+slow_echo is read-only and answers from its own thread after `seconds`, so
+overlapping calls can finish out of order.
+
 Usage:
     python3 echo_mcp_server.py
 
@@ -18,6 +21,24 @@ and writes JSON-RPC responses to stdout.
 """
 import json
 import sys
+import threading
+import time
+
+write_lock = threading.Lock()
+
+
+def send(resp):
+    with write_lock:
+        print(json.dumps(resp), flush=True)
+
+
+def slow_echo(req_id, arguments):
+    time.sleep(arguments.get("seconds", 0))
+    result = {
+        "content": [{"type": "text", "text": json.dumps(arguments)}],
+        "isError": False,
+    }
+    send({"jsonrpc": "2.0", "id": req_id, "result": result})
 
 
 def main():
@@ -53,6 +74,15 @@ def main():
                             "description": "Always returns an error",
                             "inputSchema": {"type": "object"},
                         },
+                        {
+                            "name": "slow_echo",
+                            "description": "Echo back input arguments after a delay",
+                            "inputSchema": {
+                                "type": "object",
+                                "properties": {"seconds": {"type": "number"}},
+                            },
+                            "annotations": {"readOnlyHint": True},
+                        },
                     ]
                 }
             elif method == "tools/call":
@@ -64,6 +94,9 @@ def main():
                         "content": [{"type": "text", "text": json.dumps(arguments)}],
                         "isError": False,
                     }
+                elif tool_name == "slow_echo":
+                    threading.Thread(target=slow_echo, args=(req_id, arguments)).start()
+                    continue
                 elif tool_name == "fail":
                     result = {
                         "content": [{"type": "text", "text": "Intentional failure"}],
@@ -79,7 +112,7 @@ def main():
                             "message": f"Tool not found: {tool_name}",
                         },
                     }
-                    print(json.dumps(resp), flush=True)
+                    send(resp)
                     continue
             else:
                 # Unknown method
@@ -88,12 +121,12 @@ def main():
                     "id": req_id,
                     "error": {"code": -32601, "message": f"Method not found: {method}"},
                 }
-                print(json.dumps(resp), flush=True)
+                send(resp)
                 continue
 
             if req_id is not None:
                 resp = {"jsonrpc": "2.0", "id": req_id, "result": result}
-                print(json.dumps(resp), flush=True)
+                send(resp)
 
         except json.JSONDecodeError as e:
             if "req_id" in locals() and req_id is not None:
@@ -102,7 +135,7 @@ def main():
                     "id": req_id,
                     "error": {"code": -32700, "message": f"Parse error: {e}"},
                 }
-                print(json.dumps(err), flush=True)
+                send(err)
         except Exception as e:
             if "req_id" in locals() and req_id is not None:
                 err = {
@@ -110,7 +143,7 @@ def main():
                     "id": req_id,
                     "error": {"code": -32603, "message": str(e)},
                 }
-                print(json.dumps(err), flush=True)
+                send(err)
 
 
 if __name__ == "__main__":